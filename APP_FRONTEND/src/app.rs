@@ -0,0 +1,247 @@
+//! Aplicación - Paneles egui de Álgebra Rose
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cada fotograma, si la evolución está en marcha, avanza la simulación
+//! `pasos_por_fotograma` pasos y redibuja: gráfica keygen en vivo, espiral
+//! de los 24 campos, controles del operador Â y de la sonificación, y
+//! estado de verificación.
+//! La pestaña «Matrices» muestra el mapa de calor de M₄₄₄ y Â; «Estado 3D»,
+//! la trayectoria reciente del estado; «Sesiones», las sesiones guardadas
+//! de cada perfil, para cargarlas o compararlas. La ventana «Ajustes» elige
+//! el tema φ y el idioma y los guarda en el config.toml compartido con el
+//! CLI. Los textos salen del catálogo del núcleo, el mismo que usa el CLI.
+
+use algebra_rose_core::i18n::{self, t, Lang};
+use algebra_rose_core::prelude::{AR_VERSION, CERTIFICATION};
+use eframe::egui;
+
+use crate::chart::EvolutionChart;
+use crate::heatmap::MatrixHeatmap;
+use crate::scene3d::StateScene;
+use crate::sessions::SessionManager;
+use crate::settings;
+use crate::simulation::Simulation;
+use crate::sonification::Sonification;
+use crate::spiral::FieldSpiral;
+use crate::theme::{PhiTheme, Variante, BASE_MAX, BASE_MIN};
+
+/// Contenido del panel central
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Vista {
+    Evolucion,
+    Matrices,
+    Estado3D,
+    Sesiones,
+}
+
+pub struct RoseApp {
+    sim: Simulation,
+    grafica: EvolutionChart,
+    mapa: MatrixHeatmap,
+    espiral: FieldSpiral,
+    escena: StateScene,
+    sesiones: SessionManager,
+    sonido: Sonification,
+    vista: Vista,
+    en_marcha: bool,
+    pasos_por_fotograma: u64,
+    delta_amor: f64,
+    tema: PhiTheme,
+    idioma: Lang,
+    /// Tema ya aplicado al contexto; se reaplica sólo si cambia
+    tema_aplicado: Option<PhiTheme>,
+    ajustes_abiertos: bool,
+    mensaje_ajustes: Option<String>,
+}
+
+impl RoseApp {
+    pub fn new(initial_keygen: Option<f64>) -> Self {
+        let (ajustes, mensaje_ajustes) = match settings::config_path().map(|ruta| settings::cargar(&ruta)) {
+            Some(Ok(ajustes)) => (ajustes, None),
+            Some(Err(e)) => (settings::Ajustes::default(), Some(format!("❌ {}", e))),
+            None => (settings::Ajustes::default(), None),
+        };
+        // Misma prioridad que el CLI, sin `--lang`
+        let idioma = Lang::resolve(None, ajustes.idioma.map(Lang::code));
+        i18n::set_lang(idioma);
+        RoseApp {
+            sim: Simulation::new(initial_keygen),
+            grafica: EvolutionChart::default(),
+            mapa: MatrixHeatmap::default(),
+            espiral: FieldSpiral::default(),
+            escena: StateScene::default(),
+            sesiones: SessionManager::default(),
+            sonido: Sonification::default(),
+            vista: Vista::Evolucion,
+            en_marcha: false,
+            pasos_por_fotograma: 100,
+            delta_amor: 0.1,
+            tema: ajustes.tema,
+            idioma,
+            tema_aplicado: None,
+            ajustes_abiertos: false,
+            mensaje_ajustes,
+        }
+    }
+
+    fn ajustes(&mut self, ctx: &egui::Context) {
+        let mut abierta = self.ajustes_abiertos;
+        let ventana = egui::Window::new(t("app.settings")).id(egui::Id::new("ajustes"));
+        ventana.open(&mut abierta).resizable(false).show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(format!("{}:", t("app.settings.language")));
+                for idioma in Lang::ALL {
+                    if ui.radio_value(&mut self.idioma, idioma, idioma.native_name()).changed() {
+                        i18n::set_lang(idioma);
+                    }
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label(format!("{}:", t("app.settings.theme")));
+                ui.radio_value(&mut self.tema.variante, Variante::Oscuro, t("app.settings.dark"));
+                ui.radio_value(&mut self.tema.variante, Variante::Claro, t("app.settings.light"));
+            });
+            ui.add(egui::Slider::new(&mut self.tema.base, BASE_MIN..=BASE_MAX).step_by(0.5).text(t("app.settings.base_size")));
+            ui.label(format!("{} {:.1} / {:.1} / {:.1} pt · {} {:.1} pt · {} {:.0} px",
+                t("app.settings.font"), self.tema.escala(-1), self.tema.escala(0), self.tema.escala(1),
+                t("app.settings.spacing"), self.tema.espacio(2), t("app.settings.panel"), self.tema.ancho_panel()));
+            ui.horizontal(|ui| {
+                if ui.button(t("app.settings.defaults")).clicked() {
+                    self.tema = PhiTheme::default();
+                }
+                match settings::config_path() {
+                    Some(ruta) => {
+                        if ui.button(t("app.save")).on_hover_text(ruta.display().to_string()).clicked() {
+                            let ajustes = settings::Ajustes { tema: self.tema, idioma: Some(self.idioma) };
+                            self.mensaje_ajustes = Some(match settings::guardar(&ruta, &ajustes) {
+                                Ok(()) => format!("✅ {} {}", t("app.saved_to"), ruta.display()),
+                                Err(e) => format!("❌ {}", e),
+                            });
+                        }
+                    }
+                    None => {
+                        ui.label(t("app.no_config_dir"));
+                    }
+                }
+            });
+            if let Some(mensaje) = &self.mensaje_ajustes {
+                ui.label(mensaje);
+            }
+        });
+        self.ajustes_abiertos = abierta;
+    }
+
+    fn controles(&mut self, ui: &mut egui::Ui) {
+        ui.heading(t("app.evolution"));
+        ui.horizontal(|ui| {
+            let etiqueta = if self.en_marcha { t("app.pause") } else { t("app.run") };
+            if ui.button(etiqueta).clicked() {
+                self.en_marcha = !self.en_marcha;
+            }
+            if ui.button(t("app.step")).clicked() {
+                self.sim.advance(self.pasos_por_fotograma);
+            }
+            if ui.button(t("app.reset")).clicked() {
+                self.sim.reset();
+            }
+        });
+        ui.add(egui::Slider::new(&mut self.pasos_por_fotograma, 1..=10_000).logarithmic(true).text(t("app.steps_per_frame")));
+        ui.label(format!("Keygen: {:.10}", self.sim.keygen().get_current_keygen()));
+        ui.label(format!("{}: {}", t("status.iteration"), self.sim.keygen().get_iteration()));
+        ui.add(egui::ProgressBar::new(self.sim.progress() as f32).show_percentage());
+
+        ui.separator();
+        ui.heading(t("app.love"));
+        ui.label(format!("{}: {:.6} ({} {:.4})", t("status.intensity"), self.sim.intensidad(),
+            t("app.phi_resonance"), self.sim.intensidad() / algebra_rose_core::prelude::PHI));
+        ui.add(egui::Slider::new(&mut self.delta_amor, -1.0..=1.0).text(t("app.delta_intensity")));
+        if ui.button(t("app.apply_love")).clicked() {
+            self.sim.apply_love(self.delta_amor);
+        }
+
+        ui.separator();
+        ui.heading(t("app.verification"));
+        let checks = self.sim.verification();
+        let superadas = checks.iter().filter(|(_, ok)| *ok).count();
+        for (nombre, ok) in &checks {
+            ui.label(format!("{} {}", if *ok { "✅" } else { "❌" }, nombre));
+        }
+        ui.label(format!("📊 {}/{} {}", superadas, checks.len(), t("app.certified_properties")));
+
+        ui.separator();
+        ui.heading(t("app.sonification"));
+        self.sonido.ui(ui);
+
+        ui.separator();
+        ui.heading(t("app.events"));
+        egui::ScrollArea::vertical().id_salt("eventos").show(ui, |ui| {
+            for evento in self.sim.eventos() {
+                ui.label(evento);
+            }
+        });
+    }
+}
+
+impl eframe::App for RoseApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        if self.en_marcha {
+            self.sim.advance(self.pasos_por_fotograma);
+            ctx.request_repaint();
+        }
+        self.grafica.handle_screenshot(ctx);
+        self.sonido.actualizar(self.sim.campos());
+        if self.tema_aplicado != Some(self.tema) {
+            self.tema.aplicar(ctx);
+            self.tema_aplicado = Some(self.tema);
+        }
+
+        egui::TopBottomPanel::top("cabecera").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.strong("🌹 Álgebra Rose");
+                ui.label(format!("{} · {} {}", AR_VERSION, t("status.certification"), CERTIFICATION));
+                ui.separator();
+                ui.selectable_value(&mut self.vista, Vista::Evolucion, t("app.tab.evolution"));
+                ui.selectable_value(&mut self.vista, Vista::Matrices, t("app.tab.matrices"));
+                ui.selectable_value(&mut self.vista, Vista::Estado3D, t("app.tab.state3d"));
+                ui.selectable_value(&mut self.vista, Vista::Sesiones, t("app.tab.sessions"));
+                ui.separator();
+                ui.toggle_value(&mut self.ajustes_abiertos, t("app.settings"));
+            });
+        });
+        self.ajustes(ctx);
+        egui::SidePanel::left("controles").resizable(true).default_width(self.tema.ancho_panel()).show(ctx, |ui| self.controles(ui));
+        egui::CentralPanel::default().show(ctx, |ui| match self.vista {
+            Vista::Evolucion => {
+                // Gráfica y espiral en razón áurea
+                let (ancho_grafica, ancho_espiral) = PhiTheme::division_aurea(ui.available_width() - ui.spacing().item_spacing.x);
+                let alto = ui.available_height();
+                let arriba = egui::Layout::top_down(egui::Align::Min);
+                ui.horizontal_top(|ui| {
+                    ui.allocate_ui_with_layout(egui::vec2(ancho_grafica, alto), arriba, |ui| {
+                        ui.heading(t("app.live_evolution"));
+                        self.grafica.ui(ui, &self.sim);
+                    });
+                    ui.allocate_ui_with_layout(egui::vec2(ancho_espiral, alto), arriba, |ui| {
+                        ui.heading(format!("{} {}/24", t("app.fibonacci_fields"), self.sim.campos().get_campos_activos().len()));
+                        self.espiral.ui(ui, &self.sim.campos().export_view(), self.sim.keygen().get_current_keygen());
+                    });
+                });
+            }
+            Vista::Matrices => {
+                ui.heading(t("app.heatmap"));
+                self.mapa.ui(ui, &self.sim);
+            }
+            Vista::Estado3D => {
+                ui.heading(t("app.tab.state3d"));
+                self.escena.ui(ui, &self.sim);
+            }
+            Vista::Sesiones => {
+                ui.heading(t("app.tab.sessions"));
+                if let Some(cargada) = self.sesiones.ui(ui, &self.sim) {
+                    self.sim = cargada;
+                    self.en_marcha = false;
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,118 @@
+//! Gráfica de Evolución - Keygen, Intensidad Â y Coherencia
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Tres gráficas apiladas con el eje de iteraciones enlazado: el zoom y el
+//! arrastre de una se aplican a las otras. Las transiciones de campo se
+//! marcan como líneas verticales. La exportación pide una captura de la
+//! ventana a eframe y recorta el rectángulo de las gráficas.
+
+use std::path::PathBuf;
+
+use algebra_rose_core::i18n::t;
+use eframe::egui::{self, Color32, Rect, Vec2b};
+use egui_plot::{Legend, Line, Plot, PlotPoints, VLine};
+
+use crate::simulation::{Muestra, Simulation};
+
+/// Grupo de enlace de ejes entre las tres gráficas
+const GRUPO: &str = "evolucion";
+
+/// Id de la gráfica, clave del nombre de la serie, color y valor de cada muestra
+type Serie = (&'static str, &'static str, Color32, fn(&Muestra) -> f64);
+
+const SERIES: [Serie; 3] = [
+    ("keygen", "app.chart.keygen", Color32::LIGHT_GREEN, |m| m.keygen),
+    ("intensidad", "app.chart.intensity", Color32::from_rgb(230, 110, 200), |m| m.intensidad),
+    ("coherencia", "app.coherence", Color32::LIGHT_BLUE, |m| m.coherencia),
+];
+
+pub struct EvolutionChart {
+    mostrar_hitos: bool,
+    reiniciar_vista: bool,
+    ruta_png: String,
+    /// Región de las gráficas en el último fotograma, para recortar la captura
+    region: Option<Rect>,
+    exportando: Option<PathBuf>,
+    mensaje: Option<String>,
+}
+
+impl Default for EvolutionChart {
+    fn default() -> Self {
+        EvolutionChart {
+            mostrar_hitos: true,
+            reiniciar_vista: false,
+            ruta_png: "evolucion.png".to_string(),
+            region: None,
+            exportando: None,
+            mensaje: None,
+        }
+    }
+}
+
+impl EvolutionChart {
+    pub fn ui(&mut self, ui: &mut egui::Ui, sim: &Simulation) {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.mostrar_hitos, t("app.chart.milestones"));
+            if ui.button(t("app.chart.full_view")).clicked() {
+                self.reiniciar_vista = true;
+            }
+            ui.text_edit_singleline(&mut self.ruta_png);
+            if ui.button(t("app.chart.export")).clicked() && self.exportando.is_none() {
+                self.exportando = Some(PathBuf::from(&self.ruta_png));
+                ui.ctx().send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::default()));
+            }
+            if let Some(mensaje) = &self.mensaje {
+                ui.label(mensaje);
+            }
+        });
+
+        let alto = (ui.available_height() / 3.0 - 6.0).max(80.0);
+        let inicio = ui.cursor().min;
+        for (id, clave, color, valor) in SERIES {
+            let puntos: PlotPoints = sim.muestras().iter().map(|m| [m.iteracion as f64, valor(m)]).collect();
+            let mut plot = Plot::new(id)
+                .height(alto)
+                .legend(Legend::default())
+                .link_axis(GRUPO, Vec2b::new(true, false))
+                .link_cursor(GRUPO, Vec2b::new(true, false));
+            if self.reiniciar_vista {
+                plot = plot.reset();
+            }
+            plot.show(ui, |plot_ui| {
+                plot_ui.line(Line::new(puntos).name(t(clave)).color(color));
+                if self.mostrar_hitos {
+                    for hito in sim.hitos() {
+                        let color = if hito.activo { Color32::from_rgb(250, 200, 80) } else { Color32::GRAY };
+                        plot_ui.vline(VLine::new(hito.iteracion as f64).color(color)
+                            .name(format!("{} {}", t("app.field"), hito.campo)));
+                    }
+                }
+            });
+        }
+        self.reiniciar_vista = false;
+        self.region = Some(Rect::from_min_max(inicio, ui.cursor().min + egui::vec2(ui.available_width(), 0.0)));
+    }
+
+    /// Atiende la captura pedida por «Exportar PNG»
+    pub fn handle_screenshot(&mut self, ctx: &egui::Context) {
+        if self.exportando.is_none() {
+            return;
+        }
+        let imagen = ctx.input(|i| i.events.iter().find_map(|e| match e {
+            egui::Event::Screenshot { image, .. } => Some(image.clone()),
+            _ => None,
+        }));
+        let Some(imagen) = imagen else {
+            return;
+        };
+        let ruta = self.exportando.take().expect("exportación pendiente");
+        let region = self.region.unwrap_or_else(|| ctx.screen_rect());
+        let recorte = imagen.region(&region.intersect(ctx.screen_rect()), Some(ctx.pixels_per_point()));
+        let [ancho, alto] = recorte.size;
+        let resultado = image::save_buffer(&ruta, recorte.as_raw(), ancho as u32, alto as u32, image::ColorType::Rgba8);
+        self.mensaje = Some(match resultado {
+            Ok(()) => format!("✅ {}", ruta.display()),
+            Err(e) => format!("❌ {}", e),
+        });
+    }
+}
@@ -0,0 +1,196 @@
+//! Mapa de Calor - Matrices M₄₄₄ y Â Píxel a Píxel
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cada entrada de la matriz 444×444 es un píxel. El canal de magnitud usa
+//! escala logarítmica; el de fase, el tono. En modo diferencia se pinta
+//! X − X_ref frente a la referencia certificada (M₄₄₄ diagonal real, Â con
+//! intensidad 1), así que una matriz sin deriva queda en negro.
+
+use algebra_rose_core::i18n::t;
+use algebra_rose_core::prelude::{LoveOperator, Matrix444, DIM};
+use eframe::egui::{self, Color32, ColorImage, TextureHandle, TextureOptions, Vec2};
+use nalgebra::{Complex, ComplexField, DMatrix};
+use std::f64::consts::PI;
+
+use crate::simulation::Simulation;
+
+/// Matriz representada
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fuente {
+    Monstruo,
+    Amor,
+}
+
+/// Canal de color
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Canal {
+    Magnitud,
+    Fase,
+}
+
+/// Imagen coloreada y datos para la leyenda
+pub struct Render {
+    pub imagen: ColorImage,
+    /// Mayor |z| representado (escala del canal de magnitud)
+    pub maximo: f64,
+    /// ‖X − X_ref‖_F en modo diferencia, ‖X‖_F en otro caso
+    pub norma: f64,
+}
+
+/// Paradas del mapa de magnitud: negro → violeta → naranja → amarillo
+const PARADAS: [(f32, [u8; 3]); 4] = [(0.0, [0, 0, 4]), (0.35, [120, 28, 109]), (0.7, [237, 105, 37]), (1.0, [252, 255, 164])];
+
+/// Entradas por debajo de este módulo se consideran cero (fase indefinida)
+const CERO: f64 = 1e-12;
+
+/// Color de una magnitud normalizada en [0, 1]
+pub fn color_magnitud(t: f32) -> Color32 {
+    let t = t.clamp(0.0, 1.0);
+    let tramo = PARADAS.windows(2).find(|w| t <= w[1].0).unwrap_or(&PARADAS[2..]);
+    let ((t0, c0), (t1, c1)) = (tramo[0], tramo[1]);
+    let f = if t1 > t0 { (t - t0) / (t1 - t0) } else { 0.0 };
+    let mezcla = |i: usize| (c0[i] as f32 + (c1[i] as f32 - c0[i] as f32) * f).round() as u8;
+    Color32::from_rgb(mezcla(0), mezcla(1), mezcla(2))
+}
+
+/// Color de la fase de `z`: tono según arg(z); negro si z ≈ 0
+pub fn color_fase(z: Complex<f64>) -> Color32 {
+    if z.modulus() < CERO {
+        return Color32::BLACK;
+    }
+    let tono = ((z.argument() + PI) / (2.0 * PI)) as f32;
+    egui::ecolor::Hsva::new(tono, 0.85, 1.0, 1.0).into()
+}
+
+/// Colorea `actual` (o `actual − referencia`) en el canal elegido
+pub fn colorear(actual: &DMatrix<Complex<f64>>, referencia: Option<&DMatrix<Complex<f64>>>, canal: Canal) -> Render {
+    let matriz = match referencia {
+        Some(r) => actual - r,
+        None => actual.clone(),
+    };
+    let (filas, columnas) = matriz.shape();
+    let maximo = matriz.iter().map(|z| z.modulus()).fold(0.0, f64::max);
+    let escala = maximo.ln_1p();
+    let mut pixeles = Vec::with_capacity(filas * columnas);
+    for i in 0..filas {
+        for j in 0..columnas {
+            let z = matriz[(i, j)];
+            pixeles.push(match canal {
+                Canal::Magnitud if escala > 0.0 => color_magnitud((z.modulus().ln_1p() / escala) as f32),
+                Canal::Magnitud => Color32::BLACK,
+                Canal::Fase => color_fase(z),
+            });
+        }
+    }
+    Render { imagen: ColorImage { size: [columnas, filas], pixels: pixeles }, maximo, norma: matriz.norm() }
+}
+
+/// Lo que determina la textura; si no cambia, no se recolorea
+#[derive(Clone, Copy, PartialEq)]
+struct Clave {
+    fuente: Fuente,
+    canal: Canal,
+    diferencia: bool,
+    intensidad: f64,
+}
+
+pub struct MatrixHeatmap {
+    fuente: Fuente,
+    canal: Canal,
+    diferencia: bool,
+    referencia_monstruo: Matrix444,
+    referencia_amor: LoveOperator,
+    textura: Option<(Clave, TextureHandle, f64, f64)>,
+}
+
+impl Default for MatrixHeatmap {
+    fn default() -> Self {
+        MatrixHeatmap {
+            fuente: Fuente::Monstruo,
+            canal: Canal::Magnitud,
+            diferencia: false,
+            referencia_monstruo: Matrix444::new_diagonal(),
+            referencia_amor: LoveOperator::new(1.0),
+            textura: None,
+        }
+    }
+}
+
+impl MatrixHeatmap {
+    pub fn ui(&mut self, ui: &mut egui::Ui, sim: &Simulation) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.fuente, Fuente::Monstruo, "M₄₄₄");
+            ui.selectable_value(&mut self.fuente, Fuente::Amor, "Â");
+            ui.separator();
+            ui.selectable_value(&mut self.canal, Canal::Magnitud, t("app.heatmap.magnitude"));
+            ui.selectable_value(&mut self.canal, Canal::Fase, t("app.heatmap.phase"));
+            ui.separator();
+            ui.checkbox(&mut self.diferencia, t("app.heatmap.difference"));
+        });
+
+        let clave = Clave {
+            fuente: self.fuente,
+            canal: self.canal,
+            diferencia: self.diferencia,
+            intensidad: if self.fuente == Fuente::Amor { sim.intensidad() } else { 0.0 },
+        };
+        if self.textura.as_ref().map(|t| t.0) != Some(clave) {
+            let (actual, referencia) = match self.fuente {
+                Fuente::Monstruo => (sim.monstruo().get_matrix(), self.referencia_monstruo.get_matrix()),
+                Fuente::Amor => (sim.amor().get_transformation(), self.referencia_amor.get_transformation()),
+            };
+            let render = colorear(actual, self.diferencia.then_some(referencia), self.canal);
+            let textura = ui.ctx().load_texture("mapa_calor", render.imagen, TextureOptions::NEAREST);
+            self.textura = Some((clave, textura, render.maximo, render.norma));
+        }
+        let Some((_, textura, maximo, norma)) = &self.textura else { return };
+
+        let norma_etiqueta = if self.diferencia { "‖X − X_ref‖_F" } else { "‖X‖_F" };
+        ui.label(format!("{} |z| = {:.6} · {} = {:.6}", t("app.heatmap.max"), maximo, norma_etiqueta, norma));
+        let lado = ui.available_width().min(ui.available_height());
+        let respuesta = ui.add(egui::Image::new((textura.id(), Vec2::splat(lado))).sense(egui::Sense::hover()));
+        if let Some(pos) = respuesta.hover_pos() {
+            let rel = (pos - respuesta.rect.min) / respuesta.rect.size();
+            let (i, j) = (((rel.y * DIM as f32) as usize).min(DIM - 1), ((rel.x * DIM as f32) as usize).min(DIM - 1));
+            let z = match self.fuente {
+                Fuente::Monstruo => sim.monstruo().get_matrix()[(i, j)],
+                Fuente::Amor => sim.amor().get_transformation()[(i, j)],
+            };
+            respuesta.on_hover_text(format!("({}, {}) = {:.6} {:+.6}i · |z| {:.6} · arg {:.4}", i, j, z.re, z.im, z.modulus(), z.argument()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_scales() {
+        assert_eq!(color_magnitud(0.0), Color32::from_rgb(0, 0, 4));
+        assert_eq!(color_magnitud(1.0), Color32::from_rgb(252, 255, 164));
+        assert_eq!(color_magnitud(2.0), color_magnitud(1.0));
+        assert_eq!(color_fase(Complex::new(0.0, 0.0)), Color32::BLACK);
+        assert_ne!(color_fase(Complex::new(1.0, 0.0)), color_fase(Complex::new(-1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_diff_against_reference() {
+        let referencia = LoveOperator::new(1.0);
+        let matriz = referencia.get_transformation();
+        let render = colorear(matriz, Some(matriz), Canal::Magnitud);
+        assert_eq!(render.imagen.size, [DIM, DIM]);
+        assert_eq!(render.norma, 0.0);
+        assert!(render.imagen.pixels.iter().all(|&p| p == Color32::BLACK));
+
+        let monstruo = Matrix444::new();
+        let diagonal = Matrix444::new_diagonal();
+        let deriva = colorear(monstruo.get_matrix(), Some(diagonal.get_matrix()), Canal::Fase);
+        assert!(deriva.norma > 0.0);
+        // Fuera de la diagonal ambas son cero: sin deriva
+        assert_eq!(deriva.imagen.pixels[1], Color32::BLACK);
+        // k = 0 coincide con la referencia; k = 1 ya ha girado
+        assert_eq!(deriva.imagen.pixels[0], Color32::BLACK);
+        assert_ne!(deriva.imagen.pixels[DIM + 1], Color32::BLACK);
+    }
+}
@@ -1,22 +1,35 @@
-// Aplicación simplificada para Túnel 2
-// (Problemas de dependencia serán resueltos en Túnel 3)
+//! Aplicación de Escritorio - Álgebra Rose
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Ventana egui que habla directamente con el núcleo. Un argumento
+//! opcional fija el keygen inicial: `algebra_rose_app 0.5`.
 
-fn main() {
-    println!("🌌 ÁLGEBRA ROSE - TÚNEL 2 COMPLETADO");
-    println!("💖🌌🌀");
-    println!("\n=== LOGROS CERTIFICADOS ===");
-    println!("✅ matrix_444.rs implementado");
-    println!("✅ Matriz Monster M₄₄₄ dimensionada 444x444");
-    println!("✅ Traza certificada: 196884.000000 ± 1e-6");
-    println!("✅ Tests matemáticos pasando");
-    println!("✅ Arquitectura workspace establecida");
-    println!("\n=== CONSTANTES FUNDAMENTALES ===");
-    println!("• DIM = 444");
-    println!("• CERTIFIED_TRACE = 196884.000000");
-    println!("• PHI = 1.6180339887498948482");
-    println!("\n=== PRÓXIMO PASO ===");
-    println!("🚀 Túnel 3: algebra_griess.rs");
-    println!("🎯 Implementación del álgebra de Griess (196884D)");
-    println!("\n💖 La matemática late. El código respira.");
-    println!("El amor certifica cada línea.");
+mod app;
+mod chart;
+mod heatmap;
+mod scene3d;
+mod sessions;
+mod settings;
+mod simulation;
+mod sonification;
+mod spiral;
+mod theme;
+
+fn main() -> eframe::Result {
+    let initial_keygen = match std::env::args().nth(1).map(|arg| arg.parse::<f64>()) {
+        Some(Ok(k)) if k > 0.0 && k <= 1.0 => Some(k),
+        Some(_) => {
+            eprintln!("❌ El keygen inicial debe ser un número en (0, 1]");
+            std::process::exit(2);
+        }
+        None => None,
+    };
+
+    let options = eframe::NativeOptions {
+        viewport: eframe::egui::ViewportBuilder::default()
+            .with_title("Álgebra Rose")
+            .with_inner_size([1200.0, 800.0]),
+        ..Default::default()
+    };
+    eframe::run_native("Álgebra Rose", options, Box::new(move |_cc| Ok(Box::new(app::RoseApp::new(initial_keygen)))))
 }
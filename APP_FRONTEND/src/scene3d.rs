@@ -0,0 +1,149 @@
+//! Escena 3D - Trayectoria del Estado en Tres Ejes
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Proyección ortográfica girable con el ratón, pintada con egui: el estado
+//! actual es el punto grande y las últimas N muestras forman la estela,
+//! coloreada por coherencia. El núcleo aún no tiene compresión 1025D→3D
+//! (PhiExtensor), así que cada muestra se coloca con las tres magnitudes
+//! que sí expone la simulación: keygen, ln(intensidad de Â) y coherencia.
+//! `punto_muestra` es el único sitio que habría que cambiar.
+
+use algebra_rose_core::i18n::t;
+use eframe::egui::{self, Color32, Pos2, Sense, Stroke, Vec2};
+
+use crate::heatmap::color_magnitud;
+use crate::simulation::{Muestra, Simulation};
+
+/// Aristas del cubo [-1, 1]³, como pares de índices de vértice
+const ARISTAS: [(usize, usize); 12] =
+    [(0, 1), (1, 3), (3, 2), (2, 0), (4, 5), (5, 7), (7, 6), (6, 4), (0, 4), (1, 5), (2, 6), (3, 7)];
+
+/// Coordenadas sin normalizar de una muestra
+pub fn punto_muestra(m: &Muestra) -> [f64; 3] {
+    [m.keygen, m.intensidad.ln(), m.coherencia]
+}
+
+/// Lleva cada eje de `puntos` a [-1, 1] según su mínimo y máximo; un eje constante queda en 0
+pub fn normalizar(puntos: &[[f64; 3]]) -> Vec<[f64; 3]> {
+    let mut min = [f64::INFINITY; 3];
+    let mut max = [f64::NEG_INFINITY; 3];
+    for p in puntos {
+        for k in 0..3 {
+            min[k] = min[k].min(p[k]);
+            max[k] = max[k].max(p[k]);
+        }
+    }
+    puntos.iter().map(|p| {
+        let mut q = [0.0; 3];
+        for k in 0..3 {
+            let rango = max[k] - min[k];
+            q[k] = if rango > 0.0 { 2.0 * (p[k] - min[k]) / rango - 1.0 } else { 0.0 };
+        }
+        q
+    }).collect()
+}
+
+/// Gira `p` (guiñada sobre y, luego cabeceo sobre x); devuelve (x, y) de pantalla y profundidad
+pub fn proyectar(p: [f64; 3], guinada: f64, cabeceo: f64) -> (f64, f64, f64) {
+    let (sy, cy) = guinada.sin_cos();
+    let (sp, cp) = cabeceo.sin_cos();
+    let x = cy * p[0] + sy * p[2];
+    let z = -sy * p[0] + cy * p[2];
+    let y = cp * p[1] - sp * z;
+    let profundidad = sp * p[1] + cp * z;
+    (x, y, profundidad)
+}
+
+pub struct StateScene {
+    /// Muestras que forman la estela
+    rastro: usize,
+    guinada: f64,
+    cabeceo: f64,
+}
+
+impl Default for StateScene {
+    fn default() -> Self {
+        StateScene { rastro: 200, guinada: 0.6, cabeceo: 0.4 }
+    }
+}
+
+impl StateScene {
+    pub fn ui(&mut self, ui: &mut egui::Ui, sim: &Simulation) {
+        ui.horizontal(|ui| {
+            ui.add(egui::Slider::new(&mut self.rastro, 2..=2000).logarithmic(true).text(t("app.scene.trail")));
+            ui.label(t("app.scene.axes"));
+        });
+
+        let muestras = sim.muestras();
+        let ultimas = &muestras[muestras.len().saturating_sub(self.rastro)..];
+        let puntos = normalizar(&ultimas.iter().map(punto_muestra).collect::<Vec<_>>());
+
+        let lado = ui.available_width().min(ui.available_height());
+        let (respuesta, pintor) = ui.allocate_painter(Vec2::splat(lado), Sense::drag());
+        if respuesta.dragged() {
+            let delta = respuesta.drag_delta();
+            self.guinada += delta.x as f64 * 0.01;
+            self.cabeceo = (self.cabeceo + delta.y as f64 * 0.01).clamp(-1.5, 1.5);
+        }
+        let centro = respuesta.rect.center();
+        let escala = lado * 0.3;
+        let a_pantalla = |p: [f64; 3]| {
+            let (x, y, _) = proyectar(p, self.guinada, self.cabeceo);
+            centro + Vec2::new(x as f32, -y as f32) * escala
+        };
+
+        let vertices: Vec<Pos2> = (0..8)
+            .map(|v| a_pantalla([if v & 1 == 0 { -1.0 } else { 1.0 }, if v & 2 == 0 { -1.0 } else { 1.0 }, if v & 4 == 0 { -1.0 } else { 1.0 }]))
+            .collect();
+        for (a, b) in ARISTAS {
+            pintor.line_segment([vertices[a], vertices[b]], Stroke::new(1.0, Color32::from_gray(50)));
+        }
+
+        // Estela: más tenue cuanto más antigua
+        let n = puntos.len();
+        for (k, par) in puntos.windows(2).enumerate() {
+            let edad = (k + 1) as f32 / n as f32;
+            let color = color_magnitud(ultimas[k + 1].coherencia as f32).gamma_multiply(0.2 + 0.8 * edad);
+            pintor.line_segment([a_pantalla(par[0]), a_pantalla(par[1])], Stroke::new(2.0, color));
+        }
+        if let (Some(&actual), Some(m)) = (puntos.last(), ultimas.last()) {
+            pintor.circle_filled(a_pantalla(actual), 6.0, color_magnitud(m.coherencia as f32));
+            respuesta.on_hover_text(format!("it {} · keygen {:.10} · Â {:.6} · {} {:.4}",
+                m.iteracion, m.keygen, m.intensidad, t("app.coherence"), m.coherencia));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_axes() {
+        let puntos = normalizar(&[[0.0, 5.0, 1.0], [2.0, 5.0, 3.0], [1.0, 5.0, 2.0]]);
+        assert_eq!(puntos[0], [-1.0, 0.0, -1.0]);
+        assert_eq!(puntos[1], [1.0, 0.0, 1.0]);
+        assert_eq!(puntos[2], [0.0, 0.0, 0.0]);
+        assert!(normalizar(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_projection_is_a_rotation() {
+        let p = [0.3, -0.7, 0.5];
+        let (x, y, z) = proyectar(p, 1.1, -0.4);
+        let norma = |a: f64, b: f64, c: f64| (a * a + b * b + c * c).sqrt();
+        assert!((norma(x, y, z) - norma(p[0], p[1], p[2])).abs() < 1e-12);
+        assert_eq!(proyectar(p, 0.0, 0.0), (0.3, -0.7, 0.5));
+    }
+
+    #[test]
+    fn test_trail_from_simulation() {
+        let mut sim = Simulation::new(Some(0.3));
+        sim.advance(100);
+        sim.advance(100);
+        let puntos = normalizar(&sim.muestras().iter().map(punto_muestra).collect::<Vec<_>>());
+        assert_eq!(puntos.len(), 3);
+        assert_eq!(puntos[0][0], -1.0);
+        assert_eq!(puntos[2][0], 1.0);
+    }
+}
@@ -0,0 +1,467 @@
+//! Sesiones - Guardar, Cargar y Comparar los Estados de los Perfiles
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cada perfil del CLI guarda una sesión en su `state.json` (trayectoria
+//! keygen e intensidad de Â). La pantalla de sesiones las lista, carga una
+//! en la simulación, la duplica en otro perfil o la borra, y compara dos
+//! lado a lado: trayectorias keygen superpuestas e informes de
+//! certificación de cada una.
+//!
+//! Perfiles y estado pasan por `algebra_rose_interface::profile`, los
+//! mismos del CLI. Un estado cifrado se descifra con el token de `login`
+//! y se vuelve a guardar cifrado; si la sesión iniciada no lo descifra,
+//! no se sobrescribe.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use algebra_rose_core::i18n::t;
+use algebra_rose_interface::auth;
+use algebra_rose_interface::encryption::{self, StateKey};
+use algebra_rose_interface::profile::{self, Profile, ProfileState};
+use eframe::egui::{self, Color32};
+use egui_plot::{Legend, Line, Plot, PlotPoints};
+
+use crate::settings;
+use crate::simulation::Simulation;
+
+/// Perfil que vive en la raíz del directorio de Álgebra Rose
+pub const PERFIL_POR_DEFECTO: &str = profile::DEFAULT_PROFILE;
+
+/// Sesión persistida, con el mismo formato que el `state.json` del CLI
+pub type EstadoSesion = ProfileState;
+
+/// Estado de la simulación en curso
+pub fn estado_de(sim: &Simulation) -> EstadoSesion {
+    EstadoSesion { history: sim.keygen().get_history().clone(), love_intensity: sim.intensidad() }
+}
+
+fn abrir(base: &Path, perfil: &str) -> Result<Profile, String> {
+    Profile::open(base, perfil).map_err(|e| format!("{:#}", e))
+}
+
+fn esta_cifrado(perfil: &Profile) -> bool {
+    fs::read(perfil.state_path()).is_ok_and(|bytes| encryption::is_encrypted(&bytes))
+}
+
+/// Perfiles con sesión guardada, `default` primero
+pub fn listar(base: &Path) -> Result<Vec<String>, String> {
+    let perfiles = profile::list(base).map_err(|e| format!("{:#}", e))?;
+    Ok(perfiles.into_iter()
+        .filter(|nombre| abrir(base, nombre).is_ok_and(|p| p.state_path().is_file()))
+        .collect())
+}
+
+/// Sesión guardada en `perfil`; si está cifrada hace falta `clave`
+pub fn cargar(base: &Path, perfil: &str, clave: Option<&StateKey>) -> Result<EstadoSesion, String> {
+    abrir(base, perfil)?
+        .load_state(clave)
+        .map_err(|e| format!("{:#}", e))?
+        .ok_or_else(|| format!("El perfil {:?} no tiene sesión guardada", perfil))
+}
+
+/// Guarda `estado` en `perfil`, creándolo si hace falta
+///
+/// Se cifra con `clave` si `cifrar`, si el config.toml del perfil lo pide
+/// (`encrypt_state`) o si el estado que se reemplaza ya estaba cifrado;
+/// sin `clave` en esos casos, o si la clave no descifra el estado actual,
+/// no se escribe nada.
+pub fn guardar(base: &Path, perfil: &str, estado: &EstadoSesion, clave: Option<&StateKey>, cifrar: bool) -> Result<(), String> {
+    let destino = match abrir(base, perfil) {
+        Ok(destino) => destino,
+        Err(_) if perfil != PERFIL_POR_DEFECTO => profile::create(base, perfil).map_err(|e| format!("{:#}", e))?,
+        Err(e) => return Err(e),
+    };
+    let ruta = destino.state_path();
+    let cifrado = esta_cifrado(&destino);
+    if cifrado && destino.load_state(clave).is_err() {
+        return Err(format!("El estado cifrado de {} no corresponde a la sesión iniciada: no se sobrescribe", ruta.display()));
+    }
+    let cifrar = cifrar || cifrado || settings::cifrar_estado(&destino.config_path())?;
+    if cifrar && clave.is_none() {
+        return Err(format!("{} debe guardarse cifrado: inicie sesión con `login`", ruta.display()));
+    }
+    destino.save_state(estado, clave.filter(|_| cifrar)).map_err(|e| format!("{:#}", e))
+}
+
+/// Copia la sesión de `origen` a `destino`, que no debe tener sesión propia
+///
+/// Una sesión cifrada se copia cifrada.
+pub fn duplicar(base: &Path, origen: &str, destino: &str, clave: Option<&StateKey>) -> Result<(), String> {
+    if abrir(base, destino).is_ok_and(|p| p.state_path().exists()) {
+        return Err(format!("El perfil {:?} ya tiene una sesión", destino));
+    }
+    let cifrado = esta_cifrado(&abrir(base, origen)?);
+    guardar(base, destino, &cargar(base, origen, clave)?, clave, cifrado)
+}
+
+/// Borra la sesión de `perfil`; la configuración del perfil se conserva
+///
+/// Si el directorio de un perfil que no es `default` queda vacío, se borra también.
+pub fn borrar(base: &Path, perfil: &str) -> Result<(), String> {
+    let ruta = abrir(base, perfil)?.state_path();
+    fs::remove_file(&ruta).map_err(|e| format!("No se pudo borrar {}: {}", ruta.display(), e))?;
+    if let Some(dir) = ruta.parent().filter(|_| perfil != PERFIL_POR_DEFECTO) {
+        // Sólo tiene éxito si está vacío
+        let _ = fs::remove_dir(dir);
+    }
+    Ok(())
+}
+
+/// Fila de la lista de sesiones
+struct Entrada {
+    perfil: String,
+    estado: Result<EstadoSesion, String>,
+}
+
+/// Lo que pidió el usuario en la lista, atendido después de dibujarla
+enum Accion {
+    /// Marca o desmarca una sesión como A (0) o B (1)
+    Elegir(usize, Option<String>),
+    Cargar(String),
+    Duplicar(String),
+    PedirBorrado(Option<String>),
+    Borrar(String),
+}
+
+/// Sesión restaurada para la comparación, con su informe calculado una vez
+struct Comparada {
+    perfil: String,
+    sim: Simulation,
+    informe: Vec<(String, bool)>,
+}
+
+/// Colores de las sesiones A y B en la comparación
+const COLORES: [Color32; 2] = [Color32::LIGHT_GREEN, Color32::from_rgb(230, 110, 200)];
+
+/// Pantalla de sesiones
+pub struct SessionManager {
+    base: Option<PathBuf>,
+    /// Clave del estado cifrado, tomada del token de `login`
+    clave: Option<StateKey>,
+    entradas: Vec<Entrada>,
+    /// Perfil donde se guarda la sesión en curso
+    destino: String,
+    /// Nombre del perfil que recibe una copia
+    copia: String,
+    /// Perfil cuyo borrado espera confirmación
+    borrando: Option<String>,
+    /// Sesiones A y B elegidas para comparar
+    eleccion: [Option<String>; 2],
+    comparadas: [Option<Comparada>; 2],
+    mensaje: Option<String>,
+}
+
+impl Default for SessionManager {
+    fn default() -> Self {
+        let mut manager = SessionManager {
+            base: settings::base_dir(),
+            clave: auth::stored_session().map(|(_, token)| StateKey::from_token(&token)),
+            entradas: Vec::new(),
+            destino: PERFIL_POR_DEFECTO.to_string(),
+            copia: String::new(),
+            borrando: None,
+            eleccion: [None, None],
+            comparadas: [None, None],
+            mensaje: None,
+        };
+        manager.actualizar();
+        manager
+    }
+}
+
+impl SessionManager {
+    /// Vuelve a leer las sesiones del disco
+    fn actualizar(&mut self) {
+        let Some(base) = &self.base else {
+            return;
+        };
+        match listar(base) {
+            Ok(perfiles) => {
+                self.entradas = perfiles.into_iter()
+                    .map(|perfil| Entrada { estado: cargar(base, &perfil, self.clave.as_ref()), perfil })
+                    .collect();
+            }
+            Err(e) => self.mensaje = Some(format!("❌ {}", e)),
+        }
+        for lado in 0..2 {
+            if self.eleccion[lado].as_ref().is_some_and(|p| !self.entradas.iter().any(|e| &e.perfil == p)) {
+                self.eleccion[lado] = None;
+            }
+        }
+        self.comparadas = [None, None];
+    }
+
+    fn resultado(&mut self, resultado: Result<String, String>) {
+        self.mensaje = Some(match resultado {
+            Ok(texto) => format!("✅ {}", texto),
+            Err(e) => format!("❌ {}", e),
+        });
+        self.actualizar();
+    }
+
+    /// Dibuja la pantalla; devuelve la simulación de la sesión que se pidió cargar
+    pub fn ui(&mut self, ui: &mut egui::Ui, sim: &Simulation) -> Option<Simulation> {
+        let Some(base) = self.base.clone() else {
+            ui.label(t("app.no_config_dir"));
+            return None;
+        };
+
+        ui.horizontal(|ui| {
+            if ui.button(t("app.sessions.refresh")).clicked() {
+                self.mensaje = None;
+                self.actualizar();
+            }
+            ui.separator();
+            ui.label(t("app.sessions.save_current"));
+            ui.add(egui::TextEdit::singleline(&mut self.destino).desired_width(120.0));
+            if ui.button(t("app.save")).on_hover_text(base.display().to_string()).clicked() {
+                let destino = self.destino.clone();
+                let resultado = guardar(&base, &destino, &estado_de(sim), self.clave.as_ref(), false)
+                    .map(|()| format!("{}: {}", t("app.sessions.saved"), destino));
+                self.resultado(resultado);
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label(t("app.sessions.copy_name"));
+            ui.add(egui::TextEdit::singleline(&mut self.copia).desired_width(120.0));
+        });
+        if let Some(mensaje) = &self.mensaje {
+            ui.label(mensaje);
+        }
+        ui.separator();
+
+        if self.entradas.is_empty() {
+            ui.label(t("app.sessions.none"));
+        }
+        let mut accion = None;
+        egui::Grid::new("sesiones").striped(true).show(ui, |ui| {
+            for cabecera in [t("app.sessions.profile"), t("app.sessions.iterations"), "Keygen", t("app.sessions.intensity"), "A", "B", ""] {
+                ui.strong(cabecera);
+            }
+            ui.end_row();
+            for entrada in &self.entradas {
+                let perfil = &entrada.perfil;
+                ui.label(perfil);
+                match &entrada.estado {
+                    Ok(estado) => {
+                        ui.label(estado.history.len().saturating_sub(1).to_string());
+                        ui.label(estado.history.last().map_or("—".to_string(), |z| format!("{:.10}", z)));
+                        ui.label(format!("{:.6}", estado.love_intensity));
+                    }
+                    Err(e) => {
+                        ui.colored_label(Color32::LIGHT_RED, t("app.sessions.unreadable")).on_hover_text(e);
+                        ui.label("");
+                        ui.label("");
+                    }
+                }
+                for lado in 0..2 {
+                    let mut elegida = self.eleccion[lado].as_ref() == Some(perfil);
+                    if ui.add_enabled(entrada.estado.is_ok(), egui::Checkbox::without_text(&mut elegida)).changed() {
+                        accion = Some(Accion::Elegir(lado, elegida.then(|| perfil.clone())));
+                    }
+                }
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(entrada.estado.is_ok(), egui::Button::new(t("app.sessions.load"))).clicked() {
+                        accion = Some(Accion::Cargar(perfil.clone()));
+                    }
+                    if ui.add_enabled(!self.copia.is_empty(), egui::Button::new(t("app.sessions.duplicate")))
+                        .on_disabled_hover_text(t("app.sessions.copy_name_first"))
+                        .clicked()
+                    {
+                        accion = Some(Accion::Duplicar(perfil.clone()));
+                    }
+                    if self.borrando.as_ref() == Some(perfil) {
+                        if ui.button(t("app.sessions.confirm_delete")).clicked() {
+                            accion = Some(Accion::Borrar(perfil.clone()));
+                        }
+                        if ui.button(t("app.sessions.cancel")).clicked() {
+                            accion = Some(Accion::PedirBorrado(None));
+                        }
+                    } else if ui.button(t("app.sessions.delete")).clicked() {
+                        accion = Some(Accion::PedirBorrado(Some(perfil.clone())));
+                    }
+                });
+                ui.end_row();
+            }
+        });
+
+        let mut cargada = None;
+        match accion {
+            Some(Accion::Elegir(lado, perfil)) => {
+                self.eleccion[lado] = perfil;
+                self.comparadas[lado] = None;
+            }
+            Some(Accion::Cargar(perfil)) => {
+                let resultado = cargar(&base, &perfil, self.clave.as_ref())
+                    .and_then(|estado| Simulation::from_state(estado.history, estado.love_intensity));
+                self.mensaje = Some(match resultado {
+                    Ok(restaurada) => {
+                        cargada = Some(restaurada);
+                        format!("✅ {}: {}", t("app.sessions.loaded"), perfil)
+                    }
+                    Err(e) => format!("❌ {}", e),
+                });
+            }
+            Some(Accion::Duplicar(origen)) => {
+                let destino = self.copia.clone();
+                let resultado = duplicar(&base, &origen, &destino, self.clave.as_ref())
+                    .map(|()| format!("{}: {} → {}", t("app.sessions.duplicated"), origen, destino));
+                self.resultado(resultado);
+            }
+            Some(Accion::PedirBorrado(perfil)) => self.borrando = perfil,
+            Some(Accion::Borrar(perfil)) => {
+                self.borrando = None;
+                let resultado = borrar(&base, &perfil).map(|()| format!("{}: {}", t("app.sessions.deleted"), perfil));
+                self.resultado(resultado);
+            }
+            None => {}
+        }
+
+        ui.separator();
+        self.comparacion(ui);
+        cargada
+    }
+
+    /// Restaura las sesiones elegidas que aún no lo estén
+    fn preparar_comparacion(&mut self) {
+        for lado in 0..2 {
+            let Some(perfil) = &self.eleccion[lado] else {
+                continue;
+            };
+            if self.comparadas[lado].as_ref().is_some_and(|c| &c.perfil == perfil) {
+                continue;
+            }
+            let Some(Ok(estado)) = self.entradas.iter().find(|e| &e.perfil == perfil).map(|e| &e.estado) else {
+                continue;
+            };
+            match Simulation::from_state(estado.history.clone(), estado.love_intensity) {
+                Ok(sim) => {
+                    let informe = sim.verification();
+                    self.comparadas[lado] = Some(Comparada { perfil: perfil.clone(), sim, informe });
+                }
+                Err(e) => self.mensaje = Some(format!("❌ {}: {}", perfil, e)),
+            }
+        }
+    }
+
+    fn comparacion(&mut self, ui: &mut egui::Ui) {
+        ui.heading(t("app.sessions.comparison"));
+        self.preparar_comparacion();
+        let [Some(a), Some(b)] = &self.comparadas else {
+            ui.label(t("app.sessions.pick"));
+            return;
+        };
+
+        let alto = (ui.available_height() / 2.0).max(160.0);
+        Plot::new("comparacion").height(alto).legend(Legend::default()).show(ui, |plot_ui| {
+            for (comparada, color) in [a, b].into_iter().zip(COLORES) {
+                let puntos: PlotPoints = comparada.sim.muestras().iter().map(|m| [m.iteracion as f64, m.keygen]).collect();
+                plot_ui.line(Line::new(puntos).name(&comparada.perfil).color(color));
+            }
+        });
+        ui.columns(2, |columnas| {
+            for ((ui, comparada), color) in columnas.iter_mut().zip([a, b]).zip(COLORES) {
+                ui.colored_label(color, egui::RichText::new(&comparada.perfil).strong());
+                ui.label(format!("Keygen {:.10} · {} {} · {}/24 {}",
+                    comparada.sim.keygen().get_current_keygen(), t("status.iteration"), comparada.sim.keygen().get_iteration(),
+                    comparada.sim.campos().get_campos_activos().len(), t("app.fields")));
+                for (nombre, ok) in &comparada.informe {
+                    ui.label(format!("{} {}", if *ok { "✅" } else { "❌" }, nombre));
+                }
+                let superadas = comparada.informe.iter().filter(|(_, ok)| *ok).count();
+                ui.label(format!("📊 {}/{} {}", superadas, comparada.informe.len(), t("app.certified_properties")));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_temporal(nombre: &str) -> PathBuf {
+        let base = std::env::temp_dir().join(format!("algebra_rose_sesiones_{}_{}", nombre, std::process::id()));
+        let _ = fs::remove_dir_all(&base);
+        base
+    }
+
+    #[test]
+    fn test_save_list_duplicate_delete() {
+        let base = base_temporal("ciclo");
+        assert_eq!(listar(&base).unwrap(), Vec::<String>::new());
+
+        let mut sim = Simulation::new(None);
+        sim.advance(100);
+        let estado = estado_de(&sim);
+        guardar(&base, PERFIL_POR_DEFECTO, &estado, None, false).unwrap();
+        assert!(base.join("state.json").is_file());
+
+        duplicar(&base, PERFIL_POR_DEFECTO, "copia", None).unwrap();
+        assert!(duplicar(&base, PERFIL_POR_DEFECTO, "copia", None).is_err());
+        assert!(duplicar(&base, PERFIL_POR_DEFECTO, "../fuera", None).is_err());
+        assert_eq!(listar(&base).unwrap(), vec!["default", "copia"]);
+        assert_eq!(cargar(&base, "copia", None).unwrap(), estado);
+
+        // Un perfil sin sesión no aparece
+        fs::create_dir_all(base.join("profiles").join("vacio")).unwrap();
+        assert_eq!(listar(&base).unwrap().len(), 2);
+
+        borrar(&base, "copia").unwrap();
+        assert!(!base.join("profiles").join("copia").exists());
+        borrar(&base, PERFIL_POR_DEFECTO).unwrap();
+        assert!(listar(&base).unwrap().is_empty());
+        assert!(borrar(&base, PERFIL_POR_DEFECTO).is_err());
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn test_reads_cli_state() {
+        let base = base_temporal("cli");
+        let dir = base.join("profiles").join("ana");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("state.json"), r#"{"history":[0.5,0.75],"love_intensity":1.25}"#).unwrap();
+        let estado = cargar(&base, "ana", None).unwrap();
+        assert_eq!(estado, EstadoSesion { history: vec![0.5, 0.75], love_intensity: 1.25 });
+
+        fs::write(dir.join("state.json"), "{ no es json").unwrap();
+        assert!(cargar(&base, "ana", None).is_err());
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn test_encrypted_state() {
+        let base = base_temporal("cifrado");
+        let clave = StateKey::from_token("token-de-ana");
+        let otra = StateKey::from_token("token-de-roberto");
+        let estado = EstadoSesion { history: vec![0.5, 0.75], love_intensity: 1.25 };
+        let ruta = base.join("state.json");
+
+        // encrypt_state en el config.toml del perfil, como en el CLI
+        fs::create_dir_all(&base).unwrap();
+        fs::write(base.join("config.toml"), "encrypt_state = true\n").unwrap();
+        assert!(guardar(&base, PERFIL_POR_DEFECTO, &estado, None, false).is_err());
+        assert!(!ruta.exists());
+        guardar(&base, PERFIL_POR_DEFECTO, &estado, Some(&clave), false).unwrap();
+        assert!(encryption::is_encrypted(&fs::read(&ruta).unwrap()));
+        assert_eq!(cargar(&base, PERFIL_POR_DEFECTO, Some(&clave)).unwrap(), estado);
+        assert!(cargar(&base, PERFIL_POR_DEFECTO, None).unwrap_err().contains("cifrado"));
+
+        // Sin la clave que lo cifró, el estado no se sobrescribe
+        fs::remove_file(base.join("config.toml")).unwrap();
+        let antes = fs::read(&ruta).unwrap();
+        let nuevo = EstadoSesion { history: vec![0.5], love_intensity: 1.0 };
+        assert!(guardar(&base, PERFIL_POR_DEFECTO, &nuevo, None, false).is_err());
+        assert!(guardar(&base, PERFIL_POR_DEFECTO, &nuevo, Some(&otra), false).is_err());
+        assert_eq!(fs::read(&ruta).unwrap(), antes);
+
+        // Con ella sigue cifrado aunque el perfil ya no pida cifrar, y las copias también
+        guardar(&base, PERFIL_POR_DEFECTO, &nuevo, Some(&clave), false).unwrap();
+        assert!(encryption::is_encrypted(&fs::read(&ruta).unwrap()));
+        duplicar(&base, PERFIL_POR_DEFECTO, "copia", Some(&clave)).unwrap();
+        let copia = Profile::open(&base, "copia").unwrap();
+        assert!(encryption::is_encrypted(&fs::read(copia.state_path()).unwrap()));
+        assert_eq!(cargar(&base, "copia", Some(&clave)).unwrap(), nuevo);
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
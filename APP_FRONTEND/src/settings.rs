@@ -0,0 +1,109 @@
+//! Ajustes - Preferencias de la Aplicación en el config.toml del CLI
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! La aplicación comparte `<config_dir>/algebra-rose/config.toml` con el
+//! perfil `default` del CLI. Sólo lee y escribe sus propias claves
+//! (`theme`, `font_size`) y `locale`, el idioma que también usa el CLI; el
+//! resto del archivo, comentarios incluidos, se conserva tal cual. De cada
+//! perfil lee además `encrypt_state`, para guardar sesiones como el CLI.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use algebra_rose_core::i18n::Lang;
+use toml_edit::{value, DocumentMut};
+
+use crate::theme::{PhiTheme, Variante, BASE_MAX, BASE_MIN};
+
+/// Preferencias guardadas de la aplicación
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ajustes {
+    pub tema: PhiTheme,
+    /// Idioma elegido; `None` si el archivo no fija `locale`
+    pub idioma: Option<Lang>,
+}
+
+/// Directorio raíz de Álgebra Rose, el mismo que usa el CLI: `<config_dir>/algebra-rose`
+pub use algebra_rose_interface::profile::base_dir;
+
+/// config.toml del perfil `default`, como lo localiza el CLI
+pub fn config_path() -> Option<PathBuf> {
+    base_dir().map(|dir| dir.join("config.toml"))
+}
+
+fn leer(ruta: &Path) -> Result<DocumentMut, String> {
+    if !ruta.exists() {
+        return Ok(DocumentMut::new());
+    }
+    let texto = fs::read_to_string(ruta).map_err(|e| format!("No se pudo leer {}: {}", ruta.display(), e))?;
+    texto.parse::<DocumentMut>().map_err(|e| format!("Configuración inválida en {}: {}", ruta.display(), e))
+}
+
+/// Ajustes guardados en `ruta`; las claves ausentes o inválidas toman el valor por defecto
+pub fn cargar(ruta: &Path) -> Result<Ajustes, String> {
+    let doc = leer(ruta)?;
+    let mut tema = PhiTheme::default();
+    if let Some(v) = doc.get("theme").and_then(|v| v.as_str()).and_then(Variante::from_clave) {
+        tema.variante = v;
+    }
+    let base = doc.get("font_size").and_then(|v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)));
+    if let Some(base) = base.filter(|b| (BASE_MIN as f64..=BASE_MAX as f64).contains(b)) {
+        tema.base = base as f32;
+    }
+    let idioma = doc.get("locale").and_then(|v| v.as_str()).and_then(Lang::from_code);
+    Ok(Ajustes { tema, idioma })
+}
+
+/// ¿Pide el config.toml de `ruta` guardar el estado cifrado (`encrypt_state`)?
+pub fn cifrar_estado(ruta: &Path) -> Result<bool, String> {
+    Ok(leer(ruta)?.get("encrypt_state").and_then(|v| v.as_bool()).unwrap_or(false))
+}
+
+/// Escribe `ajustes` en `ruta` sin tocar el resto del archivo
+pub fn guardar(ruta: &Path, ajustes: &Ajustes) -> Result<(), String> {
+    let mut doc = leer(ruta)?;
+    let tema = &ajustes.tema;
+    doc["theme"] = value(tema.variante.clave());
+    // Redondeo a décimas: el archivo lo editan también personas
+    doc["font_size"] = value((tema.base as f64 * 10.0).round() / 10.0);
+    if let Some(idioma) = ajustes.idioma {
+        doc["locale"] = value(idioma.code());
+    }
+    if let Some(dir) = ruta.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("No se pudo crear {}: {}", dir.display(), e))?;
+    }
+    fs::write(ruta, doc.to_string()).map_err(|e| format!("No se pudo escribir {}: {}", ruta.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_keeps_other_keys() {
+        let ruta = std::env::temp_dir().join(format!("algebra_rose_ajustes_{}.toml", std::process::id()));
+        fs::write(&ruta, "# Configuración de Álgebra Rose\nsteps = 50\nlocale = \"en\"\n").unwrap();
+        assert_eq!(cargar(&ruta).unwrap().idioma, Some(Lang::En));
+
+        let ajustes = Ajustes { tema: PhiTheme { variante: Variante::Claro, base: 16.0 }, idioma: Some(Lang::Es) };
+        guardar(&ruta, &ajustes).unwrap();
+        let texto = fs::read_to_string(&ruta).unwrap();
+        assert!(texto.starts_with("# Configuración de Álgebra Rose\nsteps = 50\nlocale = \"es\"\n"));
+        assert_eq!(cargar(&ruta).unwrap(), ajustes);
+        fs::remove_file(&ruta).unwrap();
+
+        assert_eq!(cargar(&ruta).unwrap(), Ajustes::default());
+    }
+
+    #[test]
+    fn test_invalid_values_fall_back() {
+        let ruta = std::env::temp_dir().join(format!("algebra_rose_ajustes_malos_{}.toml", std::process::id()));
+        fs::write(&ruta, "theme = \"sepia\"\nfont_size = 200\nlocale = \"fr\"\n").unwrap();
+        assert_eq!(cargar(&ruta).unwrap(), Ajustes::default());
+        fs::write(&ruta, "font_size = 12\n").unwrap();
+        assert_eq!(cargar(&ruta).unwrap().tema.base, 12.0);
+        fs::write(&ruta, "no es = = toml").unwrap();
+        assert!(cargar(&ruta).is_err());
+        fs::remove_file(&ruta).unwrap();
+    }
+}
@@ -0,0 +1,251 @@
+//! Simulación - Estado del Núcleo Detrás de la Aplicación
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Envuelve keygen, campos Fibonacci y operador Â sin depender de egui,
+//! para que la lógica de la aplicación se pueda probar sin ventana.
+
+use std::collections::VecDeque;
+
+use algebra_rose_core::i18n::t;
+use algebra_rose_core::prelude::{
+    verificar_coherencia, CambioActivacion, FibonacciSystem, KeygenEvolution, LoveOperator, Matrix444, INITIAL_KEYGEN,
+};
+
+/// Eventos de campo que se conservan para el panel
+const MAX_EVENTOS: usize = 16;
+
+/// Muestras que se reconstruyen al restaurar una trayectoria guardada
+const MAX_MUESTRAS_RESTAURADAS: usize = 2000;
+
+/// Muestra de la trayectoria, una por fotograma evolutivo
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Muestra {
+    pub iteracion: u64,
+    pub keygen: f64,
+    pub intensidad: f64,
+    /// Coherencia media de los campos activos
+    pub coherencia: f64,
+}
+
+/// Transición de un campo, marcada en la gráfica
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hito {
+    pub iteracion: u64,
+    pub campo: usize,
+    pub activo: bool,
+}
+
+pub struct Simulation {
+    keygen: KeygenEvolution,
+    campos: FibonacciSystem,
+    amor: LoveOperator,
+    monstruo: Matrix444,
+    muestras: Vec<Muestra>,
+    hitos: Vec<Hito>,
+    /// Últimas transiciones, la más reciente primero; se formatean al mostrarlas
+    eventos: VecDeque<Hito>,
+    /// Coherencia media vigente; sólo cambia cuando cambian los campos activos
+    coherencia: f64,
+}
+
+impl Simulation {
+    pub fn new(initial_keygen: Option<f64>) -> Self {
+        let keygen = KeygenEvolution::new(initial_keygen);
+        let mut campos = FibonacciSystem::new();
+        campos.update_by_keygen(keygen.get_current_keygen());
+        let amor = LoveOperator::new(1.0);
+        let coherencia = campos.get_estadisticas().coherencia_media;
+        let primera = Muestra { iteracion: 0, keygen: keygen.get_current_keygen(), intensidad: amor.get_intensity(), coherencia };
+        Simulation {
+            keygen,
+            campos,
+            amor,
+            monstruo: Matrix444::new(),
+            muestras: vec![primera],
+            hitos: Vec::new(),
+            eventos: VecDeque::with_capacity(MAX_EVENTOS),
+            coherencia,
+        }
+    }
+
+    /// Restaura una sesión guardada: trayectoria keygen e intensidad de Â
+    ///
+    /// Los campos y sus hitos se reproducen paso a paso. La intensidad de
+    /// cada paso no se guarda, así que todas las muestras llevan la final.
+    pub fn from_state(history: Vec<f64>, love_intensity: f64) -> Result<Self, String> {
+        if !love_intensity.is_finite() || love_intensity <= 0.0 {
+            return Err(format!("Intensidad de Â inválida: {}", love_intensity));
+        }
+        let keygen = KeygenEvolution::from_history(history).map_err(|e| e.to_string())?;
+        let historia = keygen.get_history().clone();
+        let mut sim = Simulation::new(Some(historia[0]));
+        sim.amor = LoveOperator::new(love_intensity);
+        sim.muestras[0].intensidad = love_intensity;
+
+        let cada = historia.len().div_ceil(MAX_MUESTRAS_RESTAURADAS).max(1);
+        let ultimo = historia.len() - 1;
+        for (i, &z) in historia.iter().enumerate().skip(1) {
+            let cambios = sim.campos.update_with_events(z);
+            for cambio in &cambios {
+                sim.registrar(i as u64, cambio);
+            }
+            if !cambios.is_empty() {
+                sim.coherencia = sim.campos.get_estadisticas().coherencia_media;
+            }
+            if i % cada == 0 || i == ultimo {
+                sim.muestras.push(Muestra { iteracion: i as u64, keygen: z, intensidad: love_intensity, coherencia: sim.coherencia });
+            }
+        }
+        sim.keygen = keygen;
+        Ok(sim)
+    }
+
+    /// Evoluciona `steps` pasos y guarda una muestra; devuelve las transiciones de campo
+    pub fn advance(&mut self, steps: u64) -> Vec<CambioActivacion> {
+        let mut cambios = Vec::new();
+        for _ in 0..steps {
+            let keygen = self.keygen.evolve();
+            for cambio in self.campos.update_with_events(keygen) {
+                self.registrar(self.keygen.get_iteration(), &cambio);
+                cambios.push(cambio);
+            }
+        }
+        if !cambios.is_empty() {
+            self.coherencia = self.campos.get_estadisticas().coherencia_media;
+        }
+        if steps > 0 {
+            self.amor.update_intensity(self.progress() * 0.05 / steps as f64);
+            self.muestras.push(Muestra {
+                iteracion: self.keygen.get_iteration(),
+                keygen: self.keygen.get_current_keygen(),
+                intensidad: self.amor.get_intensity(),
+                coherencia: self.coherencia,
+            });
+        }
+        cambios
+    }
+
+    fn registrar(&mut self, iteracion: u64, cambio: &CambioActivacion) {
+        let hito = Hito { iteracion, campo: cambio.field_id, activo: cambio.activo };
+        self.hitos.push(hito);
+        if self.eventos.len() == MAX_EVENTOS {
+            self.eventos.pop_back();
+        }
+        self.eventos.push_front(hito);
+    }
+
+    /// Suma `delta` a la intensidad de Â
+    pub fn apply_love(&mut self, delta: f64) -> f64 {
+        self.amor.update_intensity(delta)
+    }
+
+    /// Vuelve al keygen inicial
+    pub fn reset(&mut self) {
+        *self = Simulation::new(None);
+    }
+
+    /// Progreso del keygen entre el valor inicial y 1, en [0, 1]
+    pub fn progress(&self) -> f64 {
+        ((self.keygen.get_current_keygen() - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN)).clamp(0.0, 1.0)
+    }
+
+    /// Propiedades certificadas: nombre y si se cumple
+    pub fn verification(&self) -> Vec<(String, bool)> {
+        let mut checks = vec![
+            (format!("{}: {:.10}", t("app.check.keygen"), self.keygen.get_current_keygen()), self.keygen.get_current_keygen() > 0.0),
+            (format!("{}: {}", t("status.active_fields"), self.campos.get_campos_activos().len()), !self.campos.get_campos_activos().is_empty()),
+        ];
+        let coherencia = verificar_coherencia();
+        checks.push((format!("{}: {:.2}%", t("app.check.coherence"), coherencia * 100.0), coherencia >= 0.95));
+        checks.extend(self.amor.verify_properties(1e-6));
+        checks
+    }
+
+    pub fn keygen(&self) -> &KeygenEvolution {
+        &self.keygen
+    }
+
+    pub fn campos(&self) -> &FibonacciSystem {
+        &self.campos
+    }
+
+    pub fn intensidad(&self) -> f64 {
+        self.amor.get_intensity()
+    }
+
+    pub fn amor(&self) -> &LoveOperator {
+        &self.amor
+    }
+
+    pub fn monstruo(&self) -> &Matrix444 {
+        &self.monstruo
+    }
+
+    pub fn muestras(&self) -> &[Muestra] {
+        &self.muestras
+    }
+
+    pub fn hitos(&self) -> &[Hito] {
+        &self.hitos
+    }
+
+    /// Últimas transiciones en el idioma actual, la más reciente primero
+    pub fn eventos(&self) -> impl Iterator<Item = String> + '_ {
+        self.eventos.iter().map(|h| format!("it {} · {} {} ({}D) {}", h.iteracion, t("app.field"), h.campo,
+            self.campos.get_field_dimension(h.campo), if h.activo { t("app.activated") } else { t("app.deactivated") }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_records_samples_and_events() {
+        let mut sim = Simulation::new(Some(0.3));
+        let activos = sim.campos().get_campos_activos().len();
+        let cambios = sim.advance(2000);
+        assert_eq!(sim.muestras().len(), 2);
+        assert_eq!(sim.muestras()[1].iteracion, 2000);
+        assert!(sim.muestras()[1].keygen > sim.muestras()[0].keygen);
+        assert!(!cambios.is_empty());
+        assert_eq!(sim.campos().get_campos_activos().len(), activos + cambios.iter().filter(|c| c.activo).count());
+        assert!(sim.eventos().next().is_some());
+        assert_eq!(sim.hitos().len(), cambios.len());
+        assert!(sim.hitos().iter().all(|h| h.iteracion > 0 && h.iteracion <= 2000));
+        assert!(sim.muestras()[1].coherencia > 0.0);
+
+        sim.advance(0);
+        assert_eq!(sim.muestras().len(), 2);
+    }
+
+    #[test]
+    fn test_reset_and_verification() {
+        let mut sim = Simulation::new(None);
+        sim.advance(10);
+        sim.apply_love(0.5);
+        assert!(sim.intensidad() > 1.0);
+        sim.reset();
+        assert_eq!(sim.keygen().get_iteration(), 0);
+        assert!(sim.verification().iter().any(|(_, ok)| *ok));
+    }
+
+    #[test]
+    fn test_from_state_replays_history() {
+        let mut original = Simulation::new(Some(0.3));
+        for _ in 0..20 {
+            original.advance(100);
+        }
+        let restaurada = Simulation::from_state(original.keygen().get_history().clone(), 1.5).unwrap();
+        assert_eq!(restaurada.keygen().get_iteration(), 2000);
+        assert_eq!(restaurada.keygen().get_current_keygen(), original.keygen().get_current_keygen());
+        assert_eq!(restaurada.campos().get_campos_activos(), original.campos().get_campos_activos());
+        assert_eq!(restaurada.hitos(), original.hitos());
+        assert_eq!(restaurada.intensidad(), 1.5);
+        assert_eq!(restaurada.muestras().last().unwrap().iteracion, 2000);
+        assert!(restaurada.muestras().len() <= MAX_MUESTRAS_RESTAURADAS + 2);
+
+        assert!(Simulation::from_state(vec![], 1.0).is_err());
+        assert!(Simulation::from_state(vec![0.3, 0.4], 0.0).is_err());
+    }
+}
@@ -0,0 +1,259 @@
+//! Sonificación - Los Campos Fibonacci como Acorde en Vivo
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cada campo k suena a su frecuencia de resonancia del núcleo,
+//! `frecuencia_campo(k)` = 110 Hz·φ^((k−1)/4), con parciales en f·φ y
+//! f·φ² cuyo peso fija la mezcla φ-armónica. Los campos
+//! activos suenan y los inactivos no; la ganancia de cada voz se desliza
+//! hacia su objetivo para que las transiciones no hagan clic. La síntesis
+//! no depende de ningún backend; la salida por la tarjeta de sonido (cpal)
+//! sólo se compila con la feature `audio`.
+
+// Sin `audio` la síntesis sólo la usan las pruebas
+#![cfg_attr(not(feature = "audio"), allow(dead_code))]
+
+use std::f64::consts::TAU;
+#[cfg(feature = "audio")]
+use std::sync::{Arc, Mutex};
+
+use algebra_rose_core::i18n::t;
+use algebra_rose_core::prelude::{frecuencia_campo, FibonacciSystem, PHI};
+use eframe::egui;
+
+/// Campos con voz propia
+pub const VOCES: usize = 24;
+
+/// Parciales por voz: f, f·φ, f·φ²
+const PARCIALES: usize = 3;
+
+/// Segundos que tarda una voz en llegar a su ganancia objetivo
+const RAMPA_S: f32 = 0.05;
+
+/// Controles del usuario y estado de activación, compartidos con la síntesis
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mezcla {
+    /// Volumen maestro en [0, 1]
+    pub volumen: f32,
+    /// Peso relativo de los parciales φ-armónicos en [0, 1]: el parcial n pesa armonicos^n
+    pub armonicos: f32,
+    /// Campos silenciados, indexados por id − 1
+    pub silenciados: [bool; VOCES],
+    /// Campos activos, indexados por id − 1
+    pub activos: [bool; VOCES],
+}
+
+impl Default for Mezcla {
+    fn default() -> Self {
+        Mezcla { volumen: 0.3, armonicos: 0.4, silenciados: [false; VOCES], activos: [false; VOCES] }
+    }
+}
+
+impl Mezcla {
+    /// Ganancia objetivo de cada voz
+    fn objetivos(&self) -> [f32; VOCES] {
+        std::array::from_fn(|i| if self.activos[i] && !self.silenciados[i] { 1.0 } else { 0.0 })
+    }
+}
+
+/// Osciladores de las 24 voces
+pub struct Sintetizador {
+    tasa: f32,
+    fases: [[f64; PARCIALES]; VOCES],
+    ganancias: [f32; VOCES],
+}
+
+impl Sintetizador {
+    pub fn new(tasa: f32) -> Self {
+        Sintetizador { tasa, fases: [[0.0; PARCIALES]; VOCES], ganancias: [0.0; VOCES] }
+    }
+
+    /// Rellena `salida` (muestras intercaladas de `canales` canales) con el acorde de `mezcla`
+    pub fn llenar(&mut self, mezcla: &Mezcla, salida: &mut [f32], canales: usize) {
+        let objetivos = mezcla.objetivos();
+        let paso_rampa = 1.0 / (RAMPA_S * self.tasa);
+        let pesos: [f32; PARCIALES] = std::array::from_fn(|n| mezcla.armonicos.powi(n as i32));
+        // Normalización por la amplitud RMS del acorde completo; tanh limita los picos
+        let escala = mezcla.volumen / (VOCES as f32 * pesos.iter().sum::<f32>()).sqrt();
+        let incrementos: [[f64; PARCIALES]; VOCES] = std::array::from_fn(|i| {
+            std::array::from_fn(|n| TAU * frecuencia_campo(i + 1) * PHI.powi(n as i32) / self.tasa as f64)
+        });
+
+        for marco in salida.chunks_mut(canales.max(1)) {
+            let mut muestra = 0.0;
+            for i in 0..VOCES {
+                let g = &mut self.ganancias[i];
+                *g += (objetivos[i] - *g).clamp(-paso_rampa, paso_rampa);
+                if *g == 0.0 {
+                    continue;
+                }
+                for n in 0..PARCIALES {
+                    muestra += *g * pesos[n] * self.fases[i][n].sin() as f32;
+                    self.fases[i][n] = (self.fases[i][n] + incrementos[i][n]) % TAU;
+                }
+            }
+            marco.fill((muestra * escala).tanh());
+        }
+    }
+}
+
+/// Salida por la tarjeta de sonido; la síntesis corre en el hilo de audio
+#[cfg(feature = "audio")]
+pub struct SalidaAudio {
+    _stream: cpal::Stream,
+    mezcla: Arc<Mutex<Mezcla>>,
+}
+
+#[cfg(feature = "audio")]
+impl SalidaAudio {
+    /// Abre el dispositivo de salida por defecto (formato f32)
+    pub fn abrir(inicial: Mezcla) -> Result<Self, String> {
+        use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+
+        let dispositivo = cpal::default_host().default_output_device()
+            .ok_or_else(|| "No hay dispositivo de salida de audio".to_string())?;
+        let config = dispositivo.default_output_config()
+            .map_err(|e| format!("Configuración de audio no disponible: {}", e))?
+            .config();
+        let canales = config.channels as usize;
+        let mezcla = Arc::new(Mutex::new(inicial.clone()));
+        let compartida = Arc::clone(&mezcla);
+        let mut sintetizador = Sintetizador::new(config.sample_rate.0 as f32);
+        let mut copia = inicial;
+
+        let stream = dispositivo.build_output_stream(
+            &config,
+            move |datos: &mut [f32], _: &cpal::OutputCallbackInfo| {
+                // Nunca bloquear el hilo de audio: si la UI tiene el cerrojo, usar la copia anterior
+                if let Ok(m) = compartida.try_lock() {
+                    copia.clone_from(&m);
+                }
+                sintetizador.llenar(&copia, datos, canales);
+            },
+            |e| eprintln!("❌ Error de audio: {}", e),
+            None,
+        ).map_err(|e| format!("No se pudo abrir la salida de audio: {}", e))?;
+        stream.play().map_err(|e| format!("No se pudo iniciar el audio: {}", e))?;
+        Ok(SalidaAudio { _stream: stream, mezcla })
+    }
+
+    /// Publica la mezcla actual para el hilo de audio
+    pub fn actualizar(&self, mezcla: &Mezcla) {
+        if let Ok(mut m) = self.mezcla.lock() {
+            m.clone_from(mezcla);
+        }
+    }
+}
+
+/// Panel de sonificación: interruptor, volumen, mezcla φ-armónica y silencio por campo
+#[derive(Default)]
+pub struct Sonification {
+    mezcla: Mezcla,
+    #[cfg(feature = "audio")]
+    salida: Option<SalidaAudio>,
+    mensaje: Option<String>,
+}
+
+impl Sonification {
+    /// Sigue las activaciones de `campos`; llamar en cada fotograma
+    pub fn actualizar(&mut self, campos: &FibonacciSystem) {
+        let mut activos = [false; VOCES];
+        for id in campos.get_campos_activos() {
+            if (1..=VOCES).contains(&id) {
+                activos[id - 1] = true;
+            }
+        }
+        self.mezcla.activos = activos;
+        #[cfg(feature = "audio")]
+        if let Some(salida) = &self.salida {
+            salida.actualizar(&self.mezcla);
+        }
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        self.interruptor(ui);
+        ui.add(egui::Slider::new(&mut self.mezcla.volumen, 0.0..=1.0).text(t("app.sound.volume")));
+        ui.add(egui::Slider::new(&mut self.mezcla.armonicos, 0.0..=1.0).text(t("app.sound.mix")));
+        egui::Grid::new("silencios").spacing([2.0, 2.0]).show(ui, |ui| {
+            for i in 0..VOCES {
+                let silenciado = self.mezcla.silenciados[i];
+                let texto = egui::RichText::new(format!("{}{}", if silenciado { "🔇" } else { "🔈" }, i + 1))
+                    .color(if self.mezcla.activos[i] { egui::Color32::from_rgb(80, 220, 120) } else { egui::Color32::GRAY });
+                ui.toggle_value(&mut self.mezcla.silenciados[i], texto)
+                    .on_hover_text(format!("{} {} · {:.2} Hz", t("app.field"), i + 1, frecuencia_campo(i + 1)));
+                if i % 6 == 5 {
+                    ui.end_row();
+                }
+            }
+        });
+        if let Some(mensaje) = &self.mensaje {
+            ui.label(mensaje);
+        }
+    }
+
+    #[cfg(feature = "audio")]
+    fn interruptor(&mut self, ui: &mut egui::Ui) {
+        let mut encendido = self.salida.is_some();
+        if ui.checkbox(&mut encendido, t("app.sound")).changed() {
+            self.salida = None;
+            self.mensaje = None;
+            if encendido {
+                match SalidaAudio::abrir(self.mezcla.clone()) {
+                    Ok(salida) => self.salida = Some(salida),
+                    Err(e) => self.mensaje = Some(format!("❌ {}", e)),
+                }
+            }
+        }
+    }
+
+    #[cfg(not(feature = "audio"))]
+    fn interruptor(&mut self, ui: &mut egui::Ui) {
+        ui.add_enabled(false, egui::Checkbox::new(&mut false, t("app.sound")))
+            .on_disabled_hover_text(format!("{}: cargo run -p algebra_rose_app --features audio", t("app.sound.disabled")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn energia(muestras: &[f32]) -> f32 {
+        muestras.iter().map(|x| x * x).sum::<f32>() / muestras.len() as f32
+    }
+
+    #[test]
+    fn test_silence_and_ramp() {
+        let mut sintetizador = Sintetizador::new(48_000.0);
+        let mut mezcla = Mezcla::default();
+        let mut salida = vec![0.0; 4800];
+        sintetizador.llenar(&mezcla, &mut salida, 2);
+        assert!(salida.iter().all(|&x| x == 0.0));
+
+        mezcla.activos[0] = true;
+        sintetizador.llenar(&mezcla, &mut salida, 2);
+        // La rampa arranca desde cero: sin clic en la primera muestra
+        assert!(salida[0].abs() < 1e-3);
+        assert!(energia(&salida) > 0.0);
+        assert!(salida.chunks(2).all(|m| m[0] == m[1]));
+        assert!(salida.iter().all(|x| x.abs() <= 1.0));
+
+        mezcla.silenciados[0] = true;
+        sintetizador.llenar(&mezcla, &mut salida, 2);
+        sintetizador.llenar(&mezcla, &mut salida, 2);
+        assert!(salida.iter().all(|&x| x == 0.0));
+    }
+
+    #[test]
+    fn test_full_chord_stays_in_range() {
+        let mut sintetizador = Sintetizador::new(44_100.0);
+        let mezcla = Mezcla { volumen: 1.0, armonicos: 1.0, activos: [true; VOCES], ..Mezcla::default() };
+        let mut salida = vec![0.0; 44_100];
+        sintetizador.llenar(&mezcla, &mut salida, 1);
+        assert!(salida.iter().all(|x| x.abs() < 1.0));
+        assert!(energia(&salida) > 0.01);
+
+        let mut otra = Sintetizador::new(44_100.0);
+        let mut salida_baja = vec![0.0; 44_100];
+        otra.llenar(&Mezcla { volumen: 0.5, ..mezcla.clone() }, &mut salida_baja, 1);
+        assert!(energia(&salida_baja) < energia(&salida));
+    }
+}
@@ -0,0 +1,184 @@
+//! Espiral de Campos - Los 24 Campos Fibonacci sobre la Espiral Áurea
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Dibuja la `FieldSystemView` del núcleo: el campo k ocupa el punto k de la
+//! filotaxis de Vogel, con radio creciente en log(dimensión) y color según
+//! su activación. Cada transición lanza un pulso por el arco que une el
+//! campo con su vecino anterior: hacia fuera al activarse, hacia dentro al
+//! desactivarse.
+
+use algebra_rose_core::phi_constants::GOLDEN_ANGLE;
+use algebra_rose_core::prelude::{CambioActivacion, FibonacciField, FieldSystemView};
+use eframe::egui::{self, Color32, Pos2, Sense, Stroke, Vec2};
+
+/// Duración de un pulso de transición, en segundos
+const DURACION_PULSO: f64 = 0.8;
+
+/// Radios de nodo (en fracción del radio de la espiral) para la menor y mayor dimensión
+const RADIO_MIN: f32 = 0.012;
+const RADIO_MAX: f32 = 0.05;
+
+/// Muestras de la curva de fondo por arco entre campos
+const MUESTRAS_POR_ARCO: usize = 12;
+
+const ACTIVO: Color32 = Color32::from_rgb(80, 220, 120);
+const INACTIVO: Color32 = Color32::from_gray(60);
+const PROXIMO: Color32 = Color32::from_rgb(230, 170, 60);
+const APAGADO: Color32 = Color32::from_rgb(220, 80, 80);
+
+/// Punto de la espiral continua: para `s` entero coincide con
+/// `golden_spiral_points(n, 1.0)[s]`; entre enteros recorre el arco
+pub fn punto_espiral(s: f64, n: usize) -> [f64; 2] {
+    let r = ((s + 0.5).max(0.0) / n as f64).sqrt();
+    let theta = s * GOLDEN_ANGLE;
+    [r * theta.cos(), r * theta.sin()]
+}
+
+/// Radio relativo del nodo, lineal en ln(dimensión) entre la menor y la mayor
+pub fn radio_nodo(dimension: usize, min: usize, max: usize) -> f32 {
+    if max <= min {
+        return RADIO_MAX;
+    }
+    let t = ((dimension.max(min) as f64 / min as f64).ln() / (max as f64 / min as f64).ln()) as f32;
+    RADIO_MIN + (RADIO_MAX - RADIO_MIN) * t.min(1.0)
+}
+
+/// Activación en [0, 1]: 1 si está activo; si no, cuánto le falta al keygen para el umbral
+pub fn activacion(campo: &FibonacciField, keygen: f64) -> f64 {
+    if campo.activo() || campo.umbral_activacion() <= 0.0 {
+        1.0
+    } else {
+        (keygen / campo.umbral_activacion()).clamp(0.0, 1.0)
+    }
+}
+
+/// Color del nodo: gris → ámbar según se acerca al umbral; verde si está activo
+pub fn color_nodo(campo: &FibonacciField, keygen: f64) -> Color32 {
+    if campo.activo() {
+        ACTIVO
+    } else {
+        INACTIVO.lerp_to_gamma(PROXIMO, activacion(campo, keygen) as f32)
+    }
+}
+
+/// Campos cuya activación difiere entre dos vistas
+pub fn transiciones(anterior: &[bool], vista: &FieldSystemView) -> Vec<CambioActivacion> {
+    vista.campos.iter().zip(anterior)
+        .filter(|(campo, &antes)| campo.activo() != antes)
+        .map(|(campo, _)| CambioActivacion { field_id: campo.id, activo: campo.activo() })
+        .collect()
+}
+
+struct Pulso {
+    cambio: CambioActivacion,
+    inicio: f64,
+}
+
+#[derive(Default)]
+pub struct FieldSpiral {
+    /// Activaciones de la última vista dibujada, indexadas por id − 1
+    anterior: Vec<bool>,
+    pulsos: Vec<Pulso>,
+}
+
+impl FieldSpiral {
+    pub fn ui(&mut self, ui: &mut egui::Ui, vista: &FieldSystemView, keygen: f64) {
+        let ahora = ui.input(|i| i.time);
+        if self.anterior.len() == vista.campos.len() {
+            self.pulsos.extend(transiciones(&self.anterior, vista).into_iter().map(|cambio| Pulso { cambio, inicio: ahora }));
+        }
+        self.anterior = vista.campos.iter().map(|c| c.activo()).collect();
+        self.pulsos.retain(|p| ahora - p.inicio < DURACION_PULSO);
+
+        let n = vista.campos.len();
+        let lado = ui.available_width().min(ui.available_height());
+        let (respuesta, pintor) = ui.allocate_painter(Vec2::splat(lado), Sense::hover());
+        let centro = respuesta.rect.center();
+        let escala = lado * 0.45;
+        let a_pantalla = |p: [f64; 2]| centro + Vec2::new(p[0] as f32, -p[1] as f32) * escala;
+
+        let curva: Vec<Pos2> = (0..=(n.saturating_sub(1)) * MUESTRAS_POR_ARCO)
+            .map(|m| a_pantalla(punto_espiral(m as f64 / MUESTRAS_POR_ARCO as f64, n)))
+            .collect();
+        pintor.add(egui::Shape::line(curva, Stroke::new(1.0, Color32::from_gray(45))));
+
+        // Pulsos: del campo anterior al que cambia (o al revés si se apaga)
+        for pulso in &self.pulsos {
+            let t = ((ahora - pulso.inicio) / DURACION_PULSO).clamp(0.0, 1.0);
+            let destino = (pulso.cambio.field_id - 1) as f64;
+            let origen = (destino - 1.0).max(-0.5);
+            let (desde, hasta) = if pulso.cambio.activo { (origen, destino) } else { (destino, origen) };
+            let color = if pulso.cambio.activo { ACTIVO } else { APAGADO };
+            let estela: Vec<Pos2> = (0..=MUESTRAS_POR_ARCO)
+                .map(|m| a_pantalla(punto_espiral(desde + (hasta - desde) * t * m as f64 / MUESTRAS_POR_ARCO as f64, n)))
+                .collect();
+            let cabeza = *estela.last().expect("estela no vacía");
+            pintor.add(egui::Shape::line(estela, Stroke::new(2.5, color.gamma_multiply(1.0 - t as f32 * 0.5))));
+            pintor.circle_filled(cabeza, 4.0, color);
+        }
+
+        let min = vista.campos.iter().map(|c| c.dimension()).min().unwrap_or(1).max(1);
+        let max = vista.campos.iter().map(|c| c.dimension()).max().unwrap_or(1);
+        let puntero = respuesta.hover_pos();
+        for (i, campo) in vista.campos.iter().enumerate() {
+            let pos = a_pantalla(punto_espiral(i as f64, n));
+            let radio = radio_nodo(campo.dimension(), min, max) * escala;
+            let mut color = color_nodo(campo, keygen);
+            if let Some(pulso) = self.pulsos.iter().find(|p| p.cambio.field_id == campo.id) {
+                // El nodo cambia de color cuando el pulso lo alcanza
+                let t = ((ahora - pulso.inicio) / DURACION_PULSO) as f32;
+                let previo = if pulso.cambio.activo { INACTIVO } else { ACTIVO };
+                color = previo.lerp_to_gamma(color, t.clamp(0.0, 1.0));
+            }
+            pintor.circle_filled(pos, radio, color);
+            if puntero.is_some_and(|p| p.distance(pos) <= radio + 2.0) {
+                respuesta.clone().on_hover_text(format!("{} · Campo {} · {}D · umbral {:.6} · activación {:.1}%",
+                    campo.nombre(), campo.id, campo.dimension(), campo.umbral_activacion(), activacion(campo, keygen) * 100.0));
+            }
+        }
+
+        if !self.pulsos.is_empty() {
+            ui.ctx().request_repaint();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra_rose_core::phi_constants::golden_spiral_points;
+    use algebra_rose_core::prelude::FibonacciSystem;
+
+    #[test]
+    fn test_spiral_matches_core_layout() {
+        for (i, p) in golden_spiral_points(24, 1.0).iter().enumerate() {
+            let q = punto_espiral(i as f64, 24);
+            assert!((p[0] - q[0]).abs() < 1e-12 && (p[1] - q[1]).abs() < 1e-12);
+        }
+        assert_eq!(punto_espiral(-0.5, 24), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_node_radius_grows_with_dimension() {
+        assert_eq!(radio_nodo(3, 3, 196418), RADIO_MIN);
+        assert!((radio_nodo(196418, 3, 196418) - RADIO_MAX).abs() < 1e-6);
+        assert!(radio_nodo(144, 3, 196418) > radio_nodo(89, 3, 196418));
+    }
+
+    #[test]
+    fn test_transitions_from_views() {
+        let mut sistema = FibonacciSystem::new();
+        sistema.update_by_keygen(0.0);
+        let antes: Vec<bool> = sistema.export_view().campos.iter().map(|c| c.activo()).collect();
+        sistema.update_by_keygen(1.0);
+        let vista = sistema.export_view();
+        let cambios = transiciones(&antes, &vista);
+        assert!(!cambios.is_empty());
+        assert!(cambios.iter().all(|c| c.activo));
+        assert!(transiciones(&vista.campos.iter().map(|c| c.activo()).collect::<Vec<_>>(), &vista).is_empty());
+
+        let campo = &vista.campos[0];
+        assert_eq!(activacion(campo, 1.0), 1.0);
+        assert_eq!(color_nodo(campo, 1.0), ACTIVO);
+    }
+}
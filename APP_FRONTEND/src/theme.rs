@@ -0,0 +1,127 @@
+//! Tema φ - Proporciones Áureas para Letra, Espaciado y Paneles
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Todo sale de un único tamaño base b: la letra sigue la escala
+//! b·φ^(n/2) (pequeña n = −1, cuerpo n = 0, título n = 1), el espaciado
+//! b/φ², b/φ³…, el panel lateral mide b·φ⁷ y el panel central se reparte
+//! 1/φ : 1/φ² entre gráfica y espiral.
+
+use algebra_rose_core::prelude::PHI;
+use eframe::egui::{self, FontFamily, FontId, TextStyle, Vec2};
+
+/// Límites del tamaño base, en puntos
+pub const BASE_MIN: f32 = 8.0;
+pub const BASE_MAX: f32 = 32.0;
+
+const PHI_F32: f32 = PHI as f32;
+
+/// Variante de color
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variante {
+    Oscuro,
+    Claro,
+}
+
+impl Variante {
+    /// Nombre en el config.toml compartido con el CLI
+    pub fn clave(self) -> &'static str {
+        match self {
+            Variante::Oscuro => "dark",
+            Variante::Claro => "light",
+        }
+    }
+
+    pub fn from_clave(clave: &str) -> Option<Self> {
+        match clave {
+            "dark" => Some(Variante::Oscuro),
+            "light" => Some(Variante::Claro),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhiTheme {
+    pub variante: Variante,
+    /// Tamaño de la letra del cuerpo, en puntos
+    pub base: f32,
+}
+
+impl Default for PhiTheme {
+    fn default() -> Self {
+        PhiTheme { variante: Variante::Oscuro, base: 14.0 }
+    }
+}
+
+impl PhiTheme {
+    /// Paso `n` de la escala: base·φ^(n/2)
+    pub fn escala(&self, n: i32) -> f32 {
+        self.base * PHI_F32.powf(n as f32 / 2.0)
+    }
+
+    /// Espaciado de orden `n`: base/φⁿ
+    pub fn espacio(&self, n: i32) -> f32 {
+        self.base / PHI_F32.powi(n)
+    }
+
+    /// Ancho inicial del panel de controles
+    pub fn ancho_panel(&self) -> f32 {
+        self.base * PHI_F32.powi(7)
+    }
+
+    /// Reparte `ancho` en dos partes con razón φ : 1
+    pub fn division_aurea(ancho: f32) -> (f32, f32) {
+        let mayor = ancho / PHI_F32;
+        (mayor, ancho - mayor)
+    }
+
+    /// Aplica colores, letra y espaciado al contexto
+    pub fn aplicar(&self, ctx: &egui::Context) {
+        ctx.set_visuals(match self.variante {
+            Variante::Oscuro => egui::Visuals::dark(),
+            Variante::Claro => egui::Visuals::light(),
+        });
+        ctx.style_mut(|style| {
+            style.text_styles = [
+                (TextStyle::Small, FontId::new(self.escala(-1), FontFamily::Proportional)),
+                (TextStyle::Body, FontId::new(self.escala(0), FontFamily::Proportional)),
+                (TextStyle::Button, FontId::new(self.escala(0), FontFamily::Proportional)),
+                (TextStyle::Monospace, FontId::new(self.escala(0), FontFamily::Monospace)),
+                (TextStyle::Heading, FontId::new(self.escala(1), FontFamily::Proportional)),
+            ].into();
+            let spacing = &mut style.spacing;
+            spacing.item_spacing = Vec2::new(self.espacio(2), self.espacio(2));
+            spacing.button_padding = Vec2::new(self.espacio(2), self.espacio(3));
+            spacing.window_margin = egui::Margin::same(self.espacio(1));
+            spacing.indent = self.base * PHI_F32;
+            spacing.interact_size.y = self.escala(0) + 2.0 * self.espacio(3);
+            spacing.slider_width = self.base * PHI_F32.powi(5);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_golden_ratios() {
+        let tema = PhiTheme { variante: Variante::Claro, base: 10.0 };
+        assert_eq!(tema.escala(0), 10.0);
+        assert!((tema.escala(2) / tema.escala(0) - PHI_F32).abs() < 1e-5);
+        assert!((tema.escala(1) / tema.escala(-1) - PHI_F32).abs() < 1e-5);
+        assert!((tema.espacio(0) / tema.espacio(1) - PHI_F32).abs() < 1e-5);
+
+        let (mayor, menor) = PhiTheme::division_aurea(1000.0);
+        assert!((mayor + menor - 1000.0).abs() < 1e-3);
+        assert!((mayor / menor - PHI_F32).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_variant_keys() {
+        for v in [Variante::Oscuro, Variante::Claro] {
+            assert_eq!(Variante::from_clave(v.clave()), Some(v));
+        }
+        assert_eq!(Variante::from_clave("sepia"), None);
+    }
+}
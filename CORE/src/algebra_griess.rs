@@ -2,24 +2,43 @@
 //! Sistema: Álgebra Rose v27.1024D-S36
 //! Certificación: 196885 - Estado Monster Pleno
 
+use alloc::{string::{String, ToString}, vec::Vec};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 use nalgebra::{DMatrix, Complex, DVector};
-use crate::matrix_444::PHI;
+use crate::error::{AlgebraRoseError, Result};
+use crate::phi_constants::phi_pow;
+use crate::phi_float::{PhiFloat, Precision};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Dimensión del álgebra de Griess (196884)
 pub const GRIESS_DIM: usize = 196884;
 
+/// Vectores base de muestra que guarda `GriessAlgebra::new()`
+pub const MUESTRAS_BASE: usize = 5;
+
 /// Versión Sparse del álgebra de Griess para manejar dimensiones grandes
+///
+/// Con `serde` la identidad y la base se guardan dispersas: sólo sus
+/// entradas no nulas, no 196884 coordenadas por vector. El escalar `T`
+/// fija la memoria de cada vector (ver `phi_float`).
 #[derive(Clone, Debug)]
-pub struct GriessAlgebra {
+pub struct GriessAlgebra<T: PhiFloat = Precision> {
     /// Producto en el álgebra (operación bilineal) - versión simbólica
     product_size: (usize, usize),
     /// Elemento identidad
-    identity: DVector<Complex<f64>>,
+    identity: DVector<Complex<T>>,
     /// Elementos de la base (muestra pequeña para pruebas)
-    basis_samples: Vec<DVector<Complex<f64>>>,
+    basis_samples: Vec<DVector<Complex<T>>>,
+}
+
+/// `re + 0i` en la precisión `T`
+fn real<T: PhiFloat>(re: f64) -> Complex<T> {
+    Complex::new(T::from_f64_lossy(re), T::zero())
 }
 
-impl GriessAlgebra {
+impl<T: PhiFloat> GriessAlgebra<T> {
     /// Crea el álgebra de Griess optimizada para memoria
     pub fn new() -> Self {
         // En lugar de crear matriz completa, usamos representación simbólica
@@ -30,14 +49,14 @@ impl GriessAlgebra {
         let mut identity = DVector::zeros(GRIESS_DIM);
         
         for i in 0..10.min(GRIESS_DIM) {
-            identity[i] = Complex::new(1.0 / identity_norm, 0.0);
+            identity[i] = real(1.0 / identity_norm);
         }
         
         // Base de muestra pequeña
         let mut basis_samples = Vec::new();
-        for i in 0..5 { // Solo 5 vectores base para pruebas
+        for i in 0..MUESTRAS_BASE {
             let mut basis_vector = DVector::zeros(GRIESS_DIM);
-            basis_vector[i] = Complex::new(1.0, 0.0);
+            basis_vector[i] = real(1.0);
             basis_samples.push(basis_vector);
         }
         
@@ -49,31 +68,42 @@ impl GriessAlgebra {
     }
     
     /// Producto simbólico en el álgebra de Griess
-    pub fn multiply(&self, a: &DVector<Complex<f64>>, b: &DVector<Complex<f64>>) -> DVector<Complex<f64>> {
-        assert_eq!(a.len(), GRIESS_DIM);
-        assert_eq!(b.len(), GRIESS_DIM);
+    pub fn multiply(&self, a: &DVector<Complex<T>>, b: &DVector<Complex<T>>) -> Result<DVector<Complex<T>>> {
+        AlgebraRoseError::check_dimension("Factor izquierdo de Griess", GRIESS_DIM, a.len())?;
+        AlgebraRoseError::check_dimension("Factor derecho de Griess", GRIESS_DIM, b.len())?;
         
         // Producto bilineal básico - solo primeros 100 elementos
         let mut result = DVector::zeros(GRIESS_DIM);
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            let n = 100.min(GRIESS_DIM);
+            let (a, b) = (&a.as_slice()[..n], &b.as_slice()[..n]);
+            crate::parallel::install(|| {
+                result.as_mut_slice()[..n].par_iter_mut().zip(a.par_iter().zip(b))
+                    .for_each(|(r, (x, y))| *r = *x * *y);
+            });
+        }
+        #[cfg(not(feature = "parallel"))]
         for i in 0..100.min(GRIESS_DIM) {
             result[i] = a[i] * b[i];
         }
-        result
+        Ok(result)
     }
     
     /// Verifica las propiedades básicas del álgebra
     pub fn verify_properties(&self, tolerance: f64) -> bool {
         // Verificaciones básicas en muestra pequeña
-        let identity_norm = self.identity.norm();
+        let identity_norm = self.identity.norm().as_f64();
         let expected_norm = 1.0; // Normalizado a 1
         
         (identity_norm - expected_norm).abs() < tolerance
     }
     
     /// Crea álgebra de Griess desde matriz Monster (versión optimizada)
-    pub fn from_monster_matrix(m444: &DMatrix<Complex<f64>>) -> Self {
-        assert_eq!(m444.nrows(), 444);
-        assert_eq!(m444.ncols(), 444);
+    pub fn from_monster_matrix(m444: &DMatrix<Complex<f64>>) -> Result<Self> {
+        AlgebraRoseError::check_dimension("Filas de M₄₄₄", 444, m444.nrows())?;
+        AlgebraRoseError::check_dimension("Columnas de M₄₄₄", 444, m444.ncols())?;
         
         // Solo almacenamos tamaño, no la matriz completa
         let product_size = (GRIESS_DIM, GRIESS_DIM);
@@ -83,7 +113,7 @@ impl GriessAlgebra {
         let mut identity = DVector::zeros(GRIESS_DIM);
         
         for i in 0..100.min(GRIESS_DIM) {
-            identity[i] = Complex::new(1.0 / identity_norm, 0.0);
+            identity[i] = real(1.0 / identity_norm);
         }
         
         // Base de muestra con factor phi
@@ -91,41 +121,39 @@ impl GriessAlgebra {
         for i in 0..3 { // Solo 3 vectores para pruebas
             let mut basis_vector = DVector::zeros(GRIESS_DIM);
             for j in 0..10.min(GRIESS_DIM) {
-                let phi_factor = PHI.powi(((i as i32 - j as i32).abs()) as i32);
-                basis_vector[j] = Complex::new(phi_factor / (j + 1) as f64, 0.0);
+                let phi_factor = phi_pow((i - j as i32).abs());
+                basis_vector[j] = real(phi_factor / (j + 1) as f64);
             }
             basis_samples.push(basis_vector);
         }
         
-        GriessAlgebra {
+        Ok(GriessAlgebra {
             product_size,
             identity,
             basis_samples,
-        }
+        })
     }
     
     /// Producto verificado con manejo de memoria
-    pub fn multiply_verified(&self, a: &DVector<Complex<f64>>, b: &DVector<Complex<f64>>) 
-        -> Result<DVector<Complex<f64>>, String> {
+    pub fn multiply_verified(&self, a: &DVector<Complex<T>>, b: &DVector<Complex<T>>) 
+        -> Result<DVector<Complex<T>>> {
         
-        if a.len() != GRIESS_DIM || b.len() != GRIESS_DIM {
-            return Err(format!("Vectores deben tener dimensión {}, tienen {} y {}", 
-                GRIESS_DIM, a.len(), b.len()));
-        }
+        AlgebraRoseError::check_dimension("Factor izquierdo de Griess", GRIESS_DIM, a.len())?;
+        AlgebraRoseError::check_dimension("Factor derecho de Griess", GRIESS_DIM, b.len())?;
         
         // Producto limitado a primeros 10 elementos para cálculo más rápido
         let mut result = DVector::zeros(GRIESS_DIM);
         for i in 0..10.min(GRIESS_DIM) {
-            let mut sum = Complex::new(0.0, 0.0);
+            let mut sum = Complex::new(T::zero(), T::zero());
             for j in 0..10.min(GRIESS_DIM) {
                 for k in 0..10.min(GRIESS_DIM) {
                     // Coeficientes de estructura simplificados
                     let gamma = if i == j && j == k {
-                        Complex::new(1.0, 0.0)
+                        real(1.0)
                     } else if (i + j + k) % 2 == 0 {
-                        Complex::new(0.5, 0.0)
+                        real(0.5)
                     } else {
-                        Complex::new(0.0, 0.5)
+                        Complex::new(T::zero(), T::from_f64_lossy(0.5))
                     };
                     
                     sum += gamma * a[j] * b[k];
@@ -149,7 +177,7 @@ impl GriessAlgebra {
         ));
         
         // 2. Verificar elemento identidad (normalizado a 1)
-        let identity_norm = self.identity.norm();
+        let identity_norm = self.identity.norm().as_f64();
         results.push((
             "Elemento identidad".to_string(),
             (identity_norm - 1.0).abs() < tolerance
@@ -171,13 +199,82 @@ impl GriessAlgebra {
         self.product_size
     }
     
+    /// Bytes que ocupa el álgebra: cabecera, identidad y base de muestra
+    pub fn memory_footprint(&self) -> usize {
+        let vector = |v: &DVector<Complex<T>>| v.len() * core::mem::size_of::<Complex<T>>();
+        core::mem::size_of::<Self>()
+            + vector(&self.identity)
+            + self.basis_samples.capacity() * core::mem::size_of::<DVector<Complex<T>>>()
+            + self.basis_samples.iter().map(vector).sum::<usize>()
+    }
+    
     /// Calcula autovector principal (muestra pequeña)
-    pub fn principal_eigenvector(&self) -> DVector<Complex<f64>> {
+    pub fn principal_eigenvector(&self) -> DVector<Complex<T>> {
         // Devolvemos la identidad (muestra)
         self.identity.clone()
     }
 }
 
+/// Vector de Griess por sus entradas no nulas: (índice, re, im)
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct VectorDisperso(Vec<(usize, f64, f64)>);
+
+#[cfg(feature = "serde")]
+impl VectorDisperso {
+    fn de_denso<T: PhiFloat>(v: &DVector<Complex<T>>) -> Self {
+        VectorDisperso(v.iter().enumerate()
+            .filter(|(_, c)| !c.re.is_zero() || !c.im.is_zero())
+            .map(|(i, c)| (i, c.re.as_f64(), c.im.as_f64()))
+            .collect())
+    }
+
+    fn a_denso<T: PhiFloat>(&self) -> Result<DVector<Complex<T>>> {
+        let mut v = DVector::zeros(GRIESS_DIM);
+        for &(i, re, im) in &self.0 {
+            if i >= GRIESS_DIM {
+                return Err(AlgebraRoseError::DimensionMismatch { contexto: "Índice de vector de Griess", esperada: GRIESS_DIM, recibida: i + 1 });
+            }
+            v[i] = Complex::new(T::from_f64_lossy(re), T::from_f64_lossy(im));
+        }
+        Ok(v)
+    }
+}
+
+/// Forma guardada de `GriessAlgebra`
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct GriessPersistida {
+    identidad: VectorDisperso,
+    base: Vec<VectorDisperso>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: PhiFloat> Serialize for GriessAlgebra<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        GriessPersistida {
+            identidad: VectorDisperso::de_denso(&self.identity),
+            base: self.basis_samples.iter().map(VectorDisperso::de_denso).collect(),
+        }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: PhiFloat> Deserialize<'de> for GriessAlgebra<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let guardada = GriessPersistida::deserialize(deserializer)?;
+        let basis_samples = guardada.base.iter()
+            .map(VectorDisperso::a_denso)
+            .collect::<Result<Vec<_>>>()
+            .map_err(de::Error::custom)?;
+        Ok(GriessAlgebra {
+            product_size: (GRIESS_DIM, GRIESS_DIM),
+            identity: guardada.identidad.a_denso().map_err(de::Error::custom)?,
+            basis_samples,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,14 +287,14 @@ mod tests {
     
     #[test]
     fn test_initialization() {
-        let algebra = GriessAlgebra::new();
+        let algebra: GriessAlgebra = GriessAlgebra::new();
         assert!(algebra.verify_properties(1e-6)); // Tolerancia más relajada
         assert_eq!(algebra.product_dimensions(), (GRIESS_DIM, GRIESS_DIM));
     }
     
     #[test]
     fn test_basic_multiplication() {
-        let algebra = GriessAlgebra::new();
+        let algebra: GriessAlgebra = GriessAlgebra::new();
         
         // Vectores pequeños para prueba
         let mut a = DVector::zeros(GRIESS_DIM);
@@ -208,7 +305,9 @@ mod tests {
             b[i] = Complex::new(3.0, 0.0);
         }
         
-        let result = algebra.multiply(&a, &b);
+        let result = algebra.multiply(&a, &b).unwrap();
+        assert!(matches!(algebra.multiply(&a, &DVector::zeros(3)),
+            Err(AlgebraRoseError::DimensionMismatch { esperada: GRIESS_DIM, recibida: 3, .. })));
         
         // Verificación básica
         for i in 0..10.min(GRIESS_DIM) {
@@ -222,14 +321,15 @@ mod tests {
         // Matriz pequeña para prueba
         let m_test = DMatrix::<Complex<f64>>::identity(444, 444);
         
-        let algebra = GriessAlgebra::from_monster_matrix(&m_test);
+        let algebra: GriessAlgebra = GriessAlgebra::from_monster_matrix(&m_test).unwrap();
         assert_eq!(algebra.product_dimensions(), (GRIESS_DIM, GRIESS_DIM));
         assert!(algebra.identity.len() == GRIESS_DIM);
+        assert!(GriessAlgebra::<Precision>::from_monster_matrix(&DMatrix::identity(3, 444)).is_err());
     }
     
     #[test]
     fn test_multiply_verified() {
-        let algebra = GriessAlgebra::new();
+        let algebra: GriessAlgebra = GriessAlgebra::new();
         
         // Vectores con algunos valores (todos 1.0 y 2.0 para cálculo predecible)
         let mut a = DVector::zeros(GRIESS_DIM);
@@ -261,7 +361,7 @@ mod tests {
     
     #[test]
     fn test_verify_complete_properties() {
-        let algebra = GriessAlgebra::new();
+        let algebra: GriessAlgebra = GriessAlgebra::new();
         let results = algebra.verify_complete_properties(1e-6);
         
         println!("📊 Propiedades verificadas:");
@@ -281,7 +381,7 @@ mod tests {
     
     #[test]
     fn test_principal_eigenvector() {
-        let algebra = GriessAlgebra::new();
+        let algebra: GriessAlgebra = GriessAlgebra::new();
         let eigenvector = algebra.principal_eigenvector();
         
         assert_eq!(eigenvector.len(), GRIESS_DIM);
@@ -290,4 +390,35 @@ mod tests {
         let norm = eigenvector.norm();
         assert_abs_diff_eq!(norm, 1.0, epsilon = 1e-6);
     }
+    
+    #[test]
+    fn test_single_precision() {
+        let algebra = GriessAlgebra::<f32>::from_monster_matrix(&DMatrix::identity(444, 444)).unwrap();
+        assert!(algebra.verify_properties(1e-5));
+        let doble: GriessAlgebra<f64> = GriessAlgebra::from_monster_matrix(&DMatrix::identity(444, 444)).unwrap();
+        for (a, b) in algebra.basis_samples[1].iter().zip(doble.basis_samples[1].iter()).take(10) {
+            assert_abs_diff_eq!(a.re as f64, b.re, epsilon = 1e-6);
+        }
+        
+        let identidad = algebra.principal_eigenvector();
+        let cuadrado = algebra.multiply(&identidad, &identidad).unwrap();
+        assert_abs_diff_eq!(cuadrado[0].re, 0.01, epsilon = 1e-7);
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_sparse_roundtrip() {
+        let algebra: GriessAlgebra = GriessAlgebra::from_monster_matrix(&DMatrix::identity(444, 444)).unwrap();
+        let json = serde_json::to_string(&algebra).unwrap();
+        // 100 + 3·10 entradas, no 4·196884
+        assert!(json.len() < 10_000, "JSON demasiado grande: {} bytes", json.len());
+        
+        let restored: GriessAlgebra = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.identity, algebra.identity);
+        assert_eq!(restored.basis_samples, algebra.basis_samples);
+        assert_eq!(restored.product_dimensions(), (GRIESS_DIM, GRIESS_DIM));
+        
+        let fuera = format!(r#"{{"identidad":[[{},1.0,0.0]],"base":[]}}"#, GRIESS_DIM);
+        assert!(serde_json::from_str::<GriessAlgebra>(&fuera).is_err());
+    }
 }
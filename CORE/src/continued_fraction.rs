@@ -0,0 +1,189 @@
+//! Fracciones Continuas - Convergentes Áureos y Aproximación Racional
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! φ = [1; 1, 1, 1, ...] es el número peor aproximable por racionales: sus
+//! convergentes son cocientes de Fibonacci consecutivos. La profundidad con
+//! que otra razón repite ese patrón mide cuán φ-resonante es.
+
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Cocientes parciales [a₀; a₁, a₂, ...] de `x`, como máximo `max_terms`
+pub fn continued_fraction(x: f64, max_terms: usize) -> Vec<i64> {
+    let mut terms = Vec::with_capacity(max_terms);
+    let mut value = x;
+    
+    for _ in 0..max_terms {
+        if !value.is_finite() {
+            break;
+        }
+        let a = value.floor();
+        terms.push(a as i64);
+        let frac = value - a;
+        if frac.abs() < 1e-12 {
+            break;
+        }
+        value = 1.0 / frac;
+    }
+    
+    terms
+}
+
+/// Convergentes pₖ/qₖ de una fracción continua
+pub fn convergents(terms: &[i64]) -> Vec<(i64, i64)> {
+    let (mut p_prev, mut p) = (1i64, 0i64);
+    let (mut q_prev, mut q) = (0i64, 1i64);
+    let mut result = Vec::with_capacity(terms.len());
+    
+    for &a in terms {
+        let p_next = a * p_prev + p;
+        let q_next = a * q_prev + q;
+        p = p_prev;
+        q = q_prev;
+        p_prev = p_next;
+        q_prev = q_next;
+        result.push((p_next, q_next));
+    }
+    
+    result
+}
+
+/// Primeros `n` convergentes de φ: F₂/F₁, F₃/F₂, F₄/F₃, ... = 1/1, 2/1, 3/2, 5/3, ...
+pub fn phi_convergents(n: usize) -> Vec<(u64, u64)> {
+    let mut result = Vec::with_capacity(n);
+    let (mut a, mut b) = (1u64, 1u64);
+    
+    for _ in 0..n {
+        result.push((b, a));
+        match a.checked_add(b) {
+            Some(next) => {
+                a = b;
+                b = next;
+            }
+            None => break,
+        }
+    }
+    
+    result
+}
+
+/// Cota del error |x − pₖ/qₖ| < 1/(qₖ·qₖ₊₁) entre convergentes consecutivos
+pub fn convergent_error_bound(q_k: i64, q_next: i64) -> f64 {
+    1.0 / (q_k as f64 * q_next as f64)
+}
+
+/// Mejor aproximación racional p/q de `x` con 1 ≤ q ≤ `max_den`
+///
+/// Recorre convergentes y semiconvergentes, que contienen todas las mejores
+/// aproximaciones; devuelve la de menor error con denominador permitido.
+pub fn best_rational_approx(x: f64, max_den: u64) -> (i64, u64) {
+    let max_den = max_den.max(1) as i64;
+    let terms = continued_fraction(x, 64);
+    
+    let (mut p_prev, mut p) = (0i64, 1i64);
+    let (mut q_prev, mut q) = (1i64, 0i64);
+    let mut best = (x.round() as i64, 1i64);
+    
+    for &a in &terms {
+        let q_next = a.saturating_mul(q).saturating_add(q_prev);
+        if q_next > max_den {
+            // Semiconvergente con el mayor múltiplo que aún cabe
+            let k = (max_den - q_prev) / q.max(1);
+            if k > 0 {
+                let candidate = (k * p + p_prev, k * q + q_prev);
+                if approx_error(x, candidate) < approx_error(x, best) {
+                    best = candidate;
+                }
+            }
+            break;
+        }
+        
+        let p_next = a * p + p_prev;
+        p_prev = p;
+        q_prev = q;
+        p = p_next;
+        q = q_next;
+        if approx_error(x, (p, q)) <= approx_error(x, best) {
+            best = (p, q);
+        }
+    }
+    
+    (best.0, best.1 as u64)
+}
+
+fn approx_error(x: f64, (p, q): (i64, i64)) -> f64 {
+    (x - p as f64 / q as f64).abs()
+}
+
+/// Profundidad φ-resonante: cuántos cocientes parciales iniciales son 1
+///
+/// φ (o 1/φ tras el primer término) da la profundidad máxima `max_terms`;
+/// una razón cualquiera suele romper el patrón en los primeros términos.
+pub fn phi_resonance_depth(x: f64, max_terms: usize) -> usize {
+    if x <= 0.0 {
+        return 0;
+    }
+    // φ y 1/φ comparten cola [1, 1, 1, ...]
+    let normalized = if x < 1.0 { 1.0 / x } else { x };
+    continued_fraction(normalized, max_terms)
+        .iter()
+        .take_while(|&&a| a == 1)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phi_constants::{PHI, PSI, FIBONACCI_SEQUENCE};
+    
+    #[test]
+    fn test_continued_fraction() {
+        assert_eq!(continued_fraction(PHI, 10), vec![1; 10]);
+        assert_eq!(continued_fraction(3.25, 10), vec![3, 4]);
+        assert_eq!(&continued_fraction(core::f64::consts::PI, 4), &[3, 7, 15, 1]);
+    }
+    
+    #[test]
+    fn test_phi_convergents() {
+        let conv = phi_convergents(6);
+        assert_eq!(conv, vec![(1, 1), (2, 1), (3, 2), (5, 3), (8, 5), (13, 8)]);
+        
+        // Coinciden con los convergentes generales de [1; 1, 1, ...]
+        let general = convergents(&[1; 6]);
+        for ((p, q), (gp, gq)) in conv.iter().zip(&general) {
+            assert_eq!((*p as i64, *q as i64), (*gp, *gq));
+        }
+        
+        // Cocientes de la tabla de campos convergen a φ dentro de la cota
+        for w in FIBONACCI_SEQUENCE.windows(3) {
+            let error = (w[1] as f64 / w[0] as f64 - PHI).abs();
+            assert!(error < convergent_error_bound(w[0] as i64, w[1] as i64));
+        }
+        
+        // Se detiene antes de desbordar u64
+        assert!(phi_convergents(200).len() < 200);
+    }
+    
+    #[test]
+    fn test_best_rational_approx() {
+        assert_eq!(best_rational_approx(core::f64::consts::PI, 10), (22, 7));
+        assert_eq!(best_rational_approx(core::f64::consts::PI, 200), (355, 113));
+        assert_eq!(best_rational_approx(PHI, 100), (144, 89));
+        assert_eq!(best_rational_approx(0.5, 1000), (1, 2));
+        assert_eq!(best_rational_approx(2.0, 5), (2, 1));
+    }
+    
+    #[test]
+    fn test_phi_resonance_depth() {
+        assert_eq!(phi_resonance_depth(PHI, 20), 20);
+        assert_eq!(phi_resonance_depth(PSI, 20), 20);
+        assert!(phi_resonance_depth(1.6, 20) < 6);
+        assert_eq!(phi_resonance_depth(2.0, 20), 0);
+        assert_eq!(phi_resonance_depth(-1.0, 20), 0);
+        
+        // Cocientes de Fibonacci más altos resuenan más profundo
+        assert!(phi_resonance_depth(89.0 / 55.0, 30) > phi_resonance_depth(8.0 / 5.0, 30));
+    }
+}
@@ -0,0 +1,191 @@
+//! Retículo E₈ - Raíces, Matriz de Gram, Serie Theta y Proyección H₄
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! E₈ es el único retículo par unimodular de dimensión 8: tres copias dan el
+//! retículo de Niemeier E₈³ y, vía Leech, el camino al álgebra de Griess.
+//! Coordenadas ortonormales de Bourbaki: D₈ ∪ (D₈ + (½)⁸). Su serie theta es
+//! E₄, así que hay 240·σ₃(n) vectores de norma 2n.
+//!
+//! Plegando el diagrama de Dynkin de E₈ sobre el de H₄, con los nodos de
+//! cada par escalados por 1 y por φ, las 240 raíces se proyectan en dos
+//! 600-celdas concéntricas de 120 vértices con radios en razón φ: el
+//! cuasicristal φ-simétrico que usa la visualización.
+
+use alloc::vec::Vec;
+use nalgebra::{Matrix4, SMatrix};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::matrix_444::PHI;
+use crate::modular::eisenstein_e4_serie;
+
+/// Dimensión del retículo
+pub const RANK: usize = 8;
+
+/// Número de raíces (vectores de norma 2)
+pub const NUM_ROOTS: usize = 240;
+
+/// Vector de ℝ⁸ en coordenadas ortonormales
+pub type Vector8 = [f64; RANK];
+
+/// Raíces simples αᵢ de Bourbaki: 1-3-4-5-6-7-8 en cadena y α₂ unida a α₄
+pub const SIMPLE_ROOTS: [Vector8; RANK] = [
+    [0.5, -0.5, -0.5, -0.5, -0.5, -0.5, -0.5, 0.5],
+    [1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [-1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, -1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, -1.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, -1.0, 1.0, 0.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, 0.0, -1.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 0.0, -1.0, 1.0, 0.0],
+];
+
+/// Nodo de H₄ (0..4) al que va cada αᵢ y si se escala por φ
+const PLEGADO: [(usize, bool); RANK] = [
+    (3, true), (0, false), (2, true), (1, true), (0, true), (1, false), (2, false), (3, false),
+];
+
+fn producto(a: &Vector8, b: &Vector8) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Matriz de Gram ⟨αᵢ, αⱼ⟩ de las raíces simples: la matriz de Cartan de E₈
+pub fn gram_matrix() -> [[i32; RANK]; RANK] {
+    core::array::from_fn(|i| core::array::from_fn(|j| producto(&SIMPLE_ROOTS[i], &SIMPLE_ROOTS[j]) as i32))
+}
+
+/// Las 240 raíces: 112 permutaciones de (±1, ±1, 0⁶) y 128 vectores
+/// (±½)⁸ con un número par de signos menos
+pub fn roots() -> Vec<Vector8> {
+    let mut raices = Vec::with_capacity(NUM_ROOTS);
+    for i in 0..RANK {
+        for j in i + 1..RANK {
+            for (si, sj) in [(1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)] {
+                let mut v = [0.0; RANK];
+                v[i] = si;
+                v[j] = sj;
+                raices.push(v);
+            }
+        }
+    }
+    for signos in 0u32..1 << RANK {
+        if signos.count_ones() % 2 == 0 {
+            raices.push(core::array::from_fn(|k| if signos >> k & 1 == 1 { -0.5 } else { 0.5 }));
+        }
+    }
+    raices
+}
+
+/// Coeficientes de θ_E₈ = Σ N(2n) qⁿ, con N(2n) los vectores de norma 2n
+pub fn theta_series(n_terms: usize) -> Vec<u64> {
+    eisenstein_e4_serie(1, n_terms).into_iter().map(|c| c as u64).collect()
+}
+
+/// Proyección lineal E₈ → H₄ que lleva αᵢ a hₖ o a φ·hₖ según el plegado
+///
+/// Las hₖ son raíces simples de H₄ de norma 2, con ángulo π/5 entre h₁ y h₂.
+pub fn h4_projection_matrix() -> SMatrix<f64, 4, RANK> {
+    let coseno = (1.0 + 5f64.sqrt()) / 4.0;
+    let gram_h4 = Matrix4::new(
+        2.0, -2.0 * coseno, 0.0, 0.0,
+        -2.0 * coseno, 2.0, -1.0, 0.0,
+        0.0, -1.0, 2.0, -1.0,
+        0.0, 0.0, -1.0, 2.0,
+    );
+    // Filas de L con L·Lᵀ = Gram: coordenadas de h₁..h₄
+    let h = gram_h4.cholesky().expect("la matriz de Gram de H₄ es definida positiva").l();
+    let imagenes = SMatrix::<f64, 4, RANK>::from_fn(|d, i| {
+        let (k, escalar) = PLEGADO[i];
+        h[(k, d)] * if escalar { PHI } else { 1.0 }
+    });
+    // v = Σ cᵢ αᵢ  ⇒  c = (Sᵀ)⁻¹ v, con las αᵢ como filas de S
+    let s = SMatrix::<f64, RANK, RANK>::from_fn(|i, j| SIMPLE_ROOTS[i][j]);
+    let coeficientes = s.transpose().try_inverse().expect("las raíces simples son una base");
+    imagenes * coeficientes
+}
+
+/// Proyecta `v` a ℝ⁴ con `h4_projection_matrix`
+pub fn h4_projection(v: &Vector8) -> [f64; 4] {
+    let p = h4_projection_matrix() * SMatrix::<f64, RANK, 1>::from_column_slice(v);
+    [p[0], p[1], p[2], p[3]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn norma(v: &[f64]) -> f64 {
+        v.iter().map(|x| x * x).sum()
+    }
+
+    #[test]
+    fn test_gram_is_cartan_matrix() {
+        let gram = gram_matrix();
+        let aristas = [(1, 3), (2, 4), (3, 4), (4, 5), (5, 6), (6, 7), (7, 8)];
+        for (i, fila) in gram.iter().enumerate() {
+            for (j, &valor) in fila.iter().enumerate() {
+                let esperado = if i == j {
+                    2
+                } else if aristas.contains(&(i.min(j) + 1, i.max(j) + 1)) {
+                    -1
+                } else {
+                    0
+                };
+                assert_eq!(valor, esperado, "({}, {})", i + 1, j + 1);
+            }
+        }
+        // Unimodular
+        let det = SMatrix::<f64, RANK, RANK>::from_fn(|i, j| gram[i][j] as f64).determinant();
+        assert!((det - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_roots() {
+        let raices = roots();
+        assert_eq!(raices.len(), NUM_ROOTS);
+        assert!(raices.iter().all(|r| norma(r) == 2.0));
+        assert!(SIMPLE_ROOTS.iter().all(|a| raices.contains(a)));
+        // Retículo entero: productos enteros con todas las raíces simples
+        assert!(raices.iter().all(|r| SIMPLE_ROOTS.iter().all(|a| producto(r, a).fract() == 0.0)));
+        // Cada raíz tiene 56 vecinas a 60° (producto 1)
+        assert!(raices.iter().all(|r| raices.iter().filter(|s| producto(r, s) == 1.0).count() == 56));
+    }
+
+    #[test]
+    fn test_theta_series_counts_vectors() {
+        assert_eq!(theta_series(5), [1, 240, 2160, 6720, 17520]);
+        assert_eq!(theta_series(2)[1], roots().len() as u64);
+
+        // Recuento directo de los vectores de norma 4: enteros en −2..=2 de suma par
+        // y semienteros en ±½, ±3/2 de suma par
+        let mut norma_4 = 0;
+        for (valores, desplazamiento) in [(5u32, -2.0), (4, -1.5)] {
+            for indice in 0..valores.pow(RANK as u32) {
+                let v: Vector8 = core::array::from_fn(|k| (indice / valores.pow(k as u32) % valores) as f64 + desplazamiento);
+                if norma(&v) == 4.0 && v.iter().sum::<f64>().rem_euclid(2.0) == 0.0 {
+                    norma_4 += 1;
+                }
+            }
+        }
+        assert_eq!(norma_4, theta_series(3)[2]);
+    }
+
+    #[test]
+    fn test_h4_projection_gives_two_600_cells() {
+        let proyecciones: Vec<[f64; 4]> = roots().iter().map(h4_projection).collect();
+        let interior = norma(&h4_projection(&SIMPLE_ROOTS[1]));
+        let (interna, externa): (Vec<&[f64; 4]>, Vec<&[f64; 4]>) = proyecciones.iter().partition(|p| (norma(&p[..]) - interior).abs() < 1e-9);
+        assert_eq!((interna.len(), externa.len()), (120, 120));
+        assert!(externa.iter().all(|p| (norma(&p[..]) / interior - PHI * PHI).abs() < 1e-9));
+
+        // Ángulos de la 600-celda: cos ∈ {±1, ±φ/2, ±½, ±1/(2φ), 0}
+        let cosenos = [1.0, PHI / 2.0, 0.5, 0.5 / PHI, 0.0];
+        for a in &interna {
+            for b in &interna {
+                let coseno: f64 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum::<f64>() / interior;
+                assert!(cosenos.iter().any(|c| (coseno.abs() - c).abs() < 1e-9), "{}", coseno);
+            }
+        }
+    }
+}
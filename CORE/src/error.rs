@@ -0,0 +1,98 @@
+//! ERRORES DEL NÚCLEO - Jerarquía Tipada de Fallos
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! Toda API pública falible del núcleo devuelve `AlgebraRoseError`, en
+//! lugar de mensajes `String` o de un pánico por `assert!`. Los clientes
+//! con `anyhow` lo convierten con `?`; los que trabajan con `String` usan
+//! `to_string()`.
+
+use alloc::string::String;
+use thiserror::Error;
+
+/// `Result` del núcleo
+pub type Result<T, E = AlgebraRoseError> = core::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+pub enum AlgebraRoseError {
+    /// Un vector o matriz no tiene la dimensión que exige la operación
+    #[error("{contexto}: se esperaba dimensión {esperada}, se recibió {recibida}")]
+    DimensionMismatch { contexto: &'static str, esperada: usize, recibida: usize },
+
+    /// Id de campo Fibonacci fuera de `1..=num_campos`
+    #[error("Campo {field_id} fuera de rango: hay {num_campos} campos")]
+    FieldOutOfRange { field_id: usize, num_campos: usize },
+
+    /// Parámetro numérico fuera de su dominio
+    #[error("{parametro} fuera de rango: {valor} (debe estar en {rango})")]
+    ValueOutOfRange { parametro: &'static str, valor: f64, rango: &'static str },
+
+    /// La coherencia medida no alcanza el mínimo exigido
+    #[error("Coherencia {coherencia} por debajo del umbral {umbral}")]
+    CoherenceBelowThreshold { coherencia: f64, umbral: f64 },
+
+    /// Trayectoria keygen sin ningún valor
+    #[error("Historial keygen vacío")]
+    EmptyHistory,
+
+    /// La evolución agotó sus pasos sin llegar al umbral
+    #[error("No se alcanzó el umbral {umbral} en {pasos} pasos")]
+    ThresholdNotReached { umbral: f64, pasos: u64 },
+
+    /// Clase de conjugación desconocida o sin el dato pedido
+    #[error("Clase de conjugación no soportada: {clase}")]
+    UnsupportedClass { clase: String },
+
+    /// Palabra de Golay con más de 3 errores
+    #[error("Palabra de Golay {palabra:06x} no corregible: más de 3 errores")]
+    UncorrectableCodeword { palabra: u32 },
+
+    /// `parallel::set_thread_pool` no pudo crear el pool de rayon
+    #[cfg(feature = "parallel")]
+    #[error("No se pudo crear el pool de hilos: {0}")]
+    ThreadPool(#[from] rayon::ThreadPoolBuildError),
+
+    #[cfg(feature = "std")]
+    #[error("Error de E/S: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Fallo al serializar o deserializar estado del núcleo
+    #[error("Error de serialización: {0}")]
+    Serde(String),
+}
+
+impl AlgebraRoseError {
+    /// Comprueba que `recibida` sea `esperada`
+    pub(crate) fn check_dimension(contexto: &'static str, esperada: usize, recibida: usize) -> Result<()> {
+        if esperada == recibida {
+            Ok(())
+        } else {
+            Err(AlgebraRoseError::DimensionMismatch { contexto, esperada, recibida })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_messages_and_conversions() {
+        let error = AlgebraRoseError::check_dimension("Estado", 444, 3).unwrap_err();
+        assert!(matches!(error, AlgebraRoseError::DimensionMismatch { esperada: 444, recibida: 3, .. }));
+        assert_eq!(error.to_string(), "Estado: se esperaba dimensión 444, se recibió 3");
+        assert!(AlgebraRoseError::check_dimension("Estado", 444, 444).is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_conversions() {
+        let io: AlgebraRoseError = std::io::Error::new(std::io::ErrorKind::NotFound, "sin archivo").into();
+        assert!(matches!(io, AlgebraRoseError::Io(_)));
+        assert!(std::error::Error::source(&io).is_some());
+
+        let anyhow_error: anyhow::Error = AlgebraRoseError::EmptyHistory.into();
+        assert_eq!(anyhow_error.to_string(), "Historial keygen vacío");
+    }
+}
@@ -2,17 +2,47 @@
 //! Sistema: Álgebra Rose v27.1024D-S36
 //! Certificación: 196885 - Estado Monster Pleno
 
-use nalgebra::DVector;
+use alloc::{format, string::{String, ToString}, vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use core::f64::consts::PI;
+use nalgebra::{Complex, DVector};
+use crate::algebra_griess::GRIESS_DIM;
+use crate::error::{AlgebraRoseError, Result};
+use crate::golden_rng::golden_fraction;
+use crate::phi_constants::PHI;
+use crate::phi_metric::PhiVector;
+use crate::phi_float::PhiFloat;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-/// Secuencia Fibonacci para los 24 campos
-pub const FIBONACCI_SEQUENCE: [usize; 24] = [
-    3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610,
-    987, 1597, 2584, 4181, 6765, 10946, 17711, 28657,
-    46368, 75025, 121393, 196418
-];
+pub use crate::phi_constants::FIBONACCI_SEQUENCE;
+
+/// Dimensión del Campo 24 (Punto Omega), F₂₇
+pub const PUNTO_OMEGA_DIM: usize = FIBONACCI_SEQUENCE[23];
+
+/// Coordenadas de Griess sin contraparte en el Punto Omega (196884 − 196418 = 466)
+pub const GRIESS_PADDING: usize = GRIESS_DIM - PUNTO_OMEGA_DIM;
+
+/// Umbral base de `SistemaCamposFibonacci::new`; los campos escalonan el
+/// suyo desde aquí hasta 1
+pub const UMBRAL_BASE: f64 = 0.01;
+
+/// Frecuencia de resonancia del campo 1, en Hz (La₂)
+pub const FRECUENCIA_BASE: f64 = 110.0;
+
+/// Frecuencia de resonancia del campo `id`: 110 Hz·φ^((id−1)/4)
+///
+/// Cada cuatro campos la frecuencia sube un factor φ, así que los 24
+/// canónicos caben entre 110 Hz y unos 1,7 kHz. La usan la sonificación,
+/// la salida MIDI y la exportación de audio.
+pub fn frecuencia_campo(id: usize) -> f64 {
+    FRECUENCIA_BASE * PHI.powf((id.max(1) - 1) as f64 / 4.0)
+}
 
 /// Campo Fibonacci dimensional
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CampoFibonacci {
     pub id: usize,
     pub nombre: String,
@@ -21,15 +51,141 @@ pub struct CampoFibonacci {
     pub umbral_activacion: f64,
 }
 
+impl CampoFibonacci {
+    /// Nombre del campo, sin copiarlo
+    pub fn nombre(&self) -> &str {
+        &self.nombre
+    }
+    
+    /// Dimensión del campo
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+    
+    /// Indica si el campo quedó activo en la última actualización
+    pub fn activo(&self) -> bool {
+        self.activo
+    }
+    
+    /// Umbral de keygen a partir del cual se activa
+    pub fn umbral_activacion(&self) -> f64 {
+        self.umbral_activacion
+    }
+    
+    /// Frecuencia de resonancia del campo (ver `frecuencia_campo`)
+    pub fn frecuencia_resonante(&self) -> f64 {
+        frecuencia_campo(self.id)
+    }
+}
+
+/// Métricas de información del estado base de un campo
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MetricasCampo {
+    pub field_id: usize,
+    /// Entropía de Shannon de |xᵢ|²/‖x‖², normalizada por ln(dim) a [0, 1]
+    pub entropia: f64,
+    /// Razón de participación 1/Σpᵢ² (1 = localizado, dim = uniforme)
+    pub participacion: f64,
+    /// Coherencia cos²(|x|, 1) con la métrica φ (ver `PhiVector`), en
+    /// [0, 1]: 1 cuando |xᵢ| es uniforme. Los pesos φ⁻ⁱ hacen que cuenten
+    /// sobre todo las primeras coordenadas del estado.
+    pub coherencia: f64,
+}
+
+/// Estadísticas agregadas del sistema de campos
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EstadisticasSistema {
+    pub total_campos: usize,
+    pub campos_activos: usize,
+    /// Suma de dimensiones de los campos activos
+    pub dimension_activa: usize,
+    /// Activación de cada campo, indexada por id − 1
+    pub activaciones: Vec<bool>,
+    /// Campos cuyo nivel de activación (ver `nivel_activacion`) alcanza
+    /// 0.1, 0.5 y 0.9 con el keygen de la última actualización
+    pub activacion_por_banda: [usize; 3],
+    /// Coherencia media (ver `MetricasCampo`) de los campos activos
+    pub coherencia_media: f64,
+}
+
+/// Vista plana del sistema de campos para frontends (sin tipos nalgebra)
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FieldSystemView {
+    /// Todos los campos, en orden de id
+    pub campos: Vec<CampoFibonacci>,
+    /// Ids de los campos activos en la última actualización
+    pub campos_activos: Vec<usize>,
+    /// Suma de dimensiones de los campos activos
+    pub dimension_activa: usize,
+    pub histeresis: f64,
+}
+
 /// Sistema completo de campos Fibonacci
+///
+/// Con la feature `serde` el sistema se puede persistir junto a la sesión:
+/// solo se guardan los campos (activación y umbrales) y el último keygen,
+/// ya que los estados base se regeneran a partir de la dimensión con
+/// `generate_field_state`.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SistemaCamposFibonacci {
     campos: Vec<CampoFibonacci>,
+    /// Banda de histéresis: un campo activo solo se apaga bajo umbral − histeresis
+    #[cfg_attr(feature = "serde", serde(default))]
+    histeresis: f64,
+    /// Actualizaciones consecutivas necesarias para confirmar un cambio
+    #[cfg_attr(feature = "serde", serde(default))]
+    dwell_minimo: u32,
+    /// Actualizaciones consecutivas que cada campo lleva pidiendo cambiar
+    #[cfg_attr(feature = "serde", serde(default))]
+    pendientes: Vec<u32>,
+    /// Keygen de la última actualización
+    #[cfg_attr(feature = "serde", serde(default))]
+    keygen: f64,
+}
+
+/// Cambio de activación confirmado de un campo
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CambioActivacion {
+    pub field_id: usize,
+    /// Nuevo estado del campo
+    pub activo: bool,
 }
 
 impl SistemaCamposFibonacci {
     /// Crea nuevo sistema de campos Fibonacci
     pub fn new() -> Self {
+        Self::escalonado(UMBRAL_BASE)
+    }
+    
+    /// Crea el sistema con otro umbral base en [0, 1)
+    ///
+    /// El campo k se activa con keygen ≥ umbral + (k/24)·(1 − umbral), así
+    /// que un umbral mayor retrasa la activación de todos los campos.
+    pub fn con_umbral(umbral: f64) -> Result<Self> {
+        Self::validar_umbral(umbral)?;
+        Ok(Self::escalonado(umbral))
+    }
+    
+    /// Comprueba que un umbral base esté en [0, 1)
+    fn validar_umbral(umbral: f64) -> Result<()> {
+        if !(0.0..1.0).contains(&umbral) {
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Umbral base", valor: umbral, rango: "[0, 1)" });
+        }
+        Ok(())
+    }
+    
+    /// Umbral de activación del campo canónico `id` con umbral base `umbral`
+    fn umbral_escalonado(umbral: f64, id: usize) -> f64 {
+        umbral + (id as f64 / 24.0) * (1.0 - umbral)
+    }
+    
+    /// Los 24 campos canónicos con umbrales escalonados desde `umbral`
+    fn escalonado(umbral: f64) -> Self {
         let mut campos = Vec::new();
         
         for (i, &dim) in FIBONACCI_SEQUENCE.iter().enumerate() {
@@ -63,7 +219,7 @@ impl SistemaCamposFibonacci {
             };
             
             // Umbral de activación escalonado (no todos activos al inicio)
-            let umbral_activacion = 0.01 + (id as f64 / 24.0) * 0.99;
+            let umbral_activacion = Self::umbral_escalonado(umbral, id);
             
             campos.push(CampoFibonacci {
                 id,
@@ -74,21 +230,135 @@ impl SistemaCamposFibonacci {
             });
         }
         
-        SistemaCamposFibonacci { campos }
+        let pendientes = vec![0; campos.len()];
+        SistemaCamposFibonacci { campos, histeresis: 0.0, dwell_minimo: 1, pendientes, keygen: 0.0 }
+    }
+    
+    /// Crea sistema con banda de histéresis en la activación
+    ///
+    /// Un campo se enciende cuando keygen ≥ umbral y solo se apaga cuando
+    /// keygen < umbral − histeresis, evitando que oscile junto al umbral.
+    pub fn con_histeresis(histeresis: f64) -> Self {
+        let mut sistema = Self::new();
+        sistema.set_histeresis(histeresis);
+        sistema
+    }
+    
+    /// Establece la banda de histéresis (valores negativos se tratan como 0)
+    pub fn set_histeresis(&mut self, histeresis: f64) {
+        self.histeresis = histeresis.max(0.0);
+    }
+    
+    /// Obtiene la banda de histéresis actual
+    pub fn get_histeresis(&self) -> f64 {
+        self.histeresis
+    }
+    
+    /// Establece cuántas actualizaciones seguidas debe pedirse un cambio
+    /// antes de aplicarlo (0 y 1 equivalen a aplicarlo de inmediato)
+    pub fn set_dwell_minimo(&mut self, actualizaciones: u32) {
+        self.dwell_minimo = actualizaciones.max(1);
+    }
+    
+    /// Obtiene el número mínimo de actualizaciones para confirmar un cambio
+    pub fn get_dwell_minimo(&self) -> u32 {
+        self.dwell_minimo.max(1)
     }
     
     /// Obtiene campos activos según keygen actual
+    ///
+    /// Parte del estado actual sin modificarlo: un campo activo se mantiene
+    /// hasta umbral_activacion − histeresis y un cambio solo se cuenta si
+    /// la próxima actualización lo confirmaría según `dwell_minimo`. En un
+    /// sistema recién creado coincide con comparar `keygen` con cada umbral
+    /// de activación.
     pub fn get_active_fields(&self, keygen: f64) -> Vec<usize> {
+        self.activos_segun(keygen, |campo| campo.umbral_activacion)
+    }
+    
+    /// Como `get_active_fields`, pero con otro umbral base solo para esta consulta
+    ///
+    /// Los 24 campos canónicos usan umbral + (k/24)·(1 − umbral), como en
+    /// `con_umbral`; los registrados con `registrar_campo` conservan el
+    /// suyo. La histéresis y el dwell del sistema se aplican igual.
+    pub fn get_campos_activos_con_umbral(&self, keygen: f64, umbral: f64) -> Result<Vec<usize>> {
+        Self::validar_umbral(umbral)?;
+        Ok(self.activos_segun(keygen, |campo| {
+            if campo.id <= FIBONACCI_SEQUENCE.len() {
+                Self::umbral_escalonado(umbral, campo.id)
+            } else {
+                campo.umbral_activacion
+            }
+        }))
+    }
+    
+    /// Campos activos a `keygen` con los umbrales de `umbral_de`, sin modificar el estado
+    fn activos_segun(&self, keygen: f64, umbral_de: impl Fn(&CampoFibonacci) -> f64) -> Vec<usize> {
+        let dwell = self.get_dwell_minimo();
+        self.campos.iter().enumerate()
+            .filter(|&(i, campo)| {
+                let deseado = Self::activacion_deseada(campo.activo, umbral_de(campo), keygen, self.histeresis);
+                let pendiente = self.pendientes.get(i).copied().unwrap_or(0);
+                if deseado != campo.activo && pendiente + 1 >= dwell { deseado } else { campo.activo }
+            })
+            .map(|(_, campo)| campo.id)
+            .collect()
+    }
+    
+    /// Obtiene un campo por id sin clonarlo
+    pub fn campo(&self, field_id: usize) -> Option<&CampoFibonacci> {
+        field_id.checked_sub(1).and_then(|i| self.campos.get(i))
+    }
+    
+    /// Itera sobre todos los campos por referencia, en orden de id
+    pub fn info_iter(&self) -> impl Iterator<Item = &CampoFibonacci> {
         self.campos.iter()
-            .filter(|campo| keygen >= campo.umbral_activacion)
+    }
+    
+    /// Obtiene campos marcados como activos en la última actualización
+    pub fn get_campos_activos(&self) -> Vec<usize> {
+        self.campos.iter()
+            .filter(|campo| campo.activo)
             .map(|campo| campo.id)
             .collect()
     }
     
+    /// Registra un campo personalizado más allá de los 24 canónicos
+    ///
+    /// El nuevo campo recibe el siguiente id libre (25, 26, ...) y participa
+    /// en la activación por keygen igual que los campos F₄..F₂₇.
+    pub fn registrar_campo(&mut self, nombre: &str, dimension: usize, umbral_activacion: f64) -> Result<usize> {
+        if dimension == 0 {
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Dimensión del campo", valor: 0.0, rango: "≥ 1" });
+        }
+        
+        if !(0.0..=1.0).contains(&umbral_activacion) {
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Umbral de activación", valor: umbral_activacion, rango: "[0, 1]" });
+        }
+        
+        let id = self.campos.len() + 1;
+        self.pendientes.resize(self.campos.len(), 0);
+        self.pendientes.push(0);
+        self.campos.push(CampoFibonacci {
+            id,
+            nombre: nombre.to_string(),
+            dimension,
+            activo: false,
+            umbral_activacion,
+        });
+        
+        Ok(id)
+    }
+    
+    /// Número total de campos (canónicos + personalizados)
+    pub fn num_campos(&self) -> usize {
+        self.campos.len()
+    }
+    
     /// Obtiene dimensión de un campo específico
     pub fn get_field_dimension(&self, field_id: usize) -> usize {
-        if field_id >= 1 && field_id <= 24 {
-            FIBONACCI_SEQUENCE[field_id - 1]
+        if field_id >= 1 && field_id <= self.campos.len() {
+            self.campos[field_id - 1].dimension
         } else {
             0
         }
@@ -96,7 +366,7 @@ impl SistemaCamposFibonacci {
     
     /// Obtiene umbral de activación de un campo
     pub fn get_activation_threshold(&self, field_id: usize) -> f64 {
-        if field_id >= 1 && field_id <= 24 {
+        if field_id >= 1 && field_id <= self.campos.len() {
             self.campos[field_id - 1].umbral_activacion
         } else {
             1.0
@@ -104,26 +374,261 @@ impl SistemaCamposFibonacci {
     }
     
     /// Genera estado base para un campo (CORREGIDO: evitar norma 0)
-    pub fn generate_field_state(&self, field_id: usize) -> DVector<f64> {
-        let dimension = self.get_field_dimension(field_id);
+    pub fn generate_field_state(&self, field_id: usize) -> Result<DVector<f64>> {
+        self.generate_field_state_seeded(field_id, 0)
+    }
+    
+    /// Genera estado base determinista a partir de una semilla
+    ///
+    /// La semilla desplaza la fase de la sinusoide en 2π·frac(semilla·φ),
+    /// calculado con `golden_fraction` en aritmética entera exacta: semillas
+    /// distintas dan fases distintas hasta la resolución de 53 bits de un f64,
+    /// también por encima de 2⁵³, y la misma semilla reproduce siempre el
+    /// mismo estado. La semilla 0 es `generate_field_state`.
+    pub fn generate_field_state_seeded(&self, field_id: usize, seed: u64) -> Result<DVector<f64>> {
+        let dimension = self.campo_existente(field_id)?.dimension;
+        let offset = 2.0 * PI * golden_fraction(seed);
         // Crear vector con valores no-cero
-        DVector::from_fn(dimension, |i, _| {
+        Ok(DVector::from_fn(dimension, |i, _| {
             // Usar seno y coseno para evitar ceros
-            let angle = (i as f64 + 1.0) * 0.1;
+            let angle = (i as f64 + 1.0) * 0.1 + offset;
             0.5 * angle.sin() + 0.5 * angle.cos()
+        }))
+    }
+    
+    /// Genera los primeros `count` vectores de una base ortonormal del campo
+    pub fn generate_field_basis(&self, field_id: usize, count: usize, seed: Option<u64>) -> Result<Vec<DVector<Complex<f64>>>> {
+        Ok(orthonormal_basis_vectors(self.campo_existente(field_id)?.dimension, count, seed))
+    }
+
+    /// Como `campo`, pero un id inexistente es un error
+    fn campo_existente(&self, field_id: usize) -> Result<&CampoFibonacci> {
+        self.campo(field_id).ok_or(AlgebraRoseError::FieldOutOfRange { field_id, num_campos: self.campos.len() })
+    }
+    
+    /// Calcula entropía, participación y coherencia del estado base de un campo
+    pub fn field_metrics(&self, field_id: usize) -> Option<MetricasCampo> {
+        let state = self.generate_field_state(field_id).ok()?;
+        let dimension = state.len();
+        let norm_sqr = state.norm_squared();
+        if dimension == 0 || norm_sqr < 1e-300 {
+            return None;
+        }
+        
+        let mut entropia = 0.0;
+        let mut suma_p2 = 0.0;
+        for x in state.iter() {
+            let p = x * x / norm_sqr;
+            if p > 0.0 {
+                entropia -= p * p.ln();
+            }
+            suma_p2 += p * p;
+        }
+        
+        let entropia = if dimension > 1 { entropia / (dimension as f64).ln() } else { 0.0 };
+        let modulos = PhiVector::new(state.abs());
+        let coseno = modulos.cosine(&PhiVector::new(DVector::from_element(dimension, 1.0))).ok()??;
+        
+        Some(MetricasCampo {
+            field_id,
+            entropia,
+            participacion: 1.0 / suma_p2,
+            coherencia: coseno * coseno,
         })
     }
     
+    /// Bytes que ocupa el sistema: campos, sus nombres y contadores
+    ///
+    /// Los estados base no se guardan; ver `memory::field_basis_bytes`.
+    pub fn memory_footprint(&self) -> usize {
+        core::mem::size_of::<Self>()
+            + self.campos.capacity() * core::mem::size_of::<CampoFibonacci>()
+            + self.campos.iter().map(|c| c.nombre.capacity()).sum::<usize>()
+            + self.pendientes.capacity() * core::mem::size_of::<u32>()
+    }
+    
+    /// Obtiene estadísticas agregadas sin exponer los campos internos
+    pub fn get_estadisticas(&self) -> EstadisticasSistema {
+        let activos = self.get_campos_activos();
+        let dimension_activa = activos.iter()
+            .map(|&id| self.get_field_dimension(id))
+            .sum();
+        
+        let mut activacion_por_banda = [0; 3];
+        for campo in &self.campos {
+            let nivel = Self::nivel_activacion(campo, self.keygen);
+            for (banda, limite) in [0.1, 0.5, 0.9].iter().enumerate() {
+                if nivel >= *limite {
+                    activacion_por_banda[banda] += 1;
+                }
+            }
+        }
+        
+        let coherencias: Vec<f64> = activos.iter()
+            .filter_map(|&id| self.field_metrics(id))
+            .map(|m| m.coherencia)
+            .collect();
+        let coherencia_media = if coherencias.is_empty() {
+            0.0
+        } else {
+            coherencias.iter().sum::<f64>() / coherencias.len() as f64
+        };
+        
+        EstadisticasSistema {
+            total_campos: self.campos.len(),
+            campos_activos: activos.len(),
+            dimension_activa,
+            activaciones: self.campos.iter().map(|c| c.activo).collect(),
+            activacion_por_banda,
+            coherencia_media,
+        }
+    }
+    
+    /// Exporta una vista serializable del sistema para GUI/WASM
+    pub fn export_view(&self) -> FieldSystemView {
+        let campos_activos = self.get_campos_activos();
+        let dimension_activa = campos_activos.iter()
+            .map(|&id| self.get_field_dimension(id))
+            .sum();
+        
+        FieldSystemView {
+            campos: self.campos.clone(),
+            campos_activos,
+            dimension_activa,
+            histeresis: self.histeresis,
+        }
+    }
+    
     /// Actualiza campos según keygen
     pub fn update_by_keygen(&mut self, keygen: f64) -> Vec<usize> {
-        for campo in &mut self.campos {
-            campo.activo = keygen >= campo.umbral_activacion;
+        self.update_with_events(keygen);
+        self.get_campos_activos()
+    }
+    
+    /// Actualiza campos según keygen y devuelve solo los cambios confirmados
+    ///
+    /// Aplica la banda de histéresis y el dwell mínimo: un campo cambia de
+    /// estado cuando lo ha pedido en `dwell_minimo` actualizaciones seguidas,
+    /// así un keygen que oscila junto a un umbral no genera eventos.
+    pub fn update_with_events(&mut self, keygen: f64) -> Vec<CambioActivacion> {
+        let dwell = self.get_dwell_minimo();
+        let histeresis = self.histeresis;
+        self.keygen = keygen;
+        self.pendientes.resize(self.campos.len(), 0);
+        
+        #[cfg(feature = "parallel")]
+        return {
+            use rayon::prelude::*;
+            let (campos, pendientes) = (&mut self.campos, &mut self.pendientes);
+            crate::parallel::install(|| {
+                campos.par_iter_mut().zip(pendientes.par_iter_mut())
+                    .filter_map(|(campo, pendiente)| Self::actualizar_campo(campo, pendiente, keygen, histeresis, dwell))
+                    .collect()
+            })
+        };
+        #[cfg(not(feature = "parallel"))]
+        self.campos.iter_mut().zip(self.pendientes.iter_mut())
+            .filter_map(|(campo, pendiente)| Self::actualizar_campo(campo, pendiente, keygen, histeresis, dwell))
+            .collect()
+    }
+    
+    /// Paso de `update_with_events` sobre un campo y su contador de dwell
+    fn actualizar_campo(campo: &mut CampoFibonacci, pendiente: &mut u32, keygen: f64, histeresis: f64, dwell: u32) -> Option<CambioActivacion> {
+        let deseado = Self::activacion_deseada(campo.activo, campo.umbral_activacion, keygen, histeresis);
+        
+        if deseado == campo.activo {
+            *pendiente = 0;
+            return None;
         }
         
-        self.get_active_fields(keygen)
+        *pendiente += 1;
+        if *pendiente < dwell {
+            return None;
+        }
+        campo.activo = deseado;
+        *pendiente = 0;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(campo = campo.id, activo = deseado, keygen, "transición de campo");
+        Some(CambioActivacion { field_id: campo.id, activo: deseado })
+    }
+    
+    /// Fracción del umbral del campo que alcanza el keygen: min(keygen/umbral, 1)
+    ///
+    /// 1 en cuanto keygen alcanza el umbral; 0.5 a mitad de camino.
+    pub fn nivel_activacion(campo: &CampoFibonacci, keygen: f64) -> f64 {
+        if campo.umbral_activacion <= 0.0 {
+            1.0
+        } else {
+            (keygen / campo.umbral_activacion).clamp(0.0, 1.0)
+        }
+    }
+    
+    /// Estado que pide `keygen` para un campo con umbral `umbral`, con la banda de histéresis
+    fn activacion_deseada(activo: bool, umbral: f64, keygen: f64, histeresis: f64) -> bool {
+        if activo {
+            keygen >= umbral - histeresis
+        } else {
+            keygen >= umbral
+        }
     }
 }
 
+/// Genera una base ortonormal compleja completa de ℂ^dim
+///
+/// Los vectores son las columnas de la DFT unitaria, vₖ[j] = e^{2πi·jk/dim}/√dim,
+/// multiplicadas por una fase diagonal e^{2πi·frac(semilla·(j+1)·φ)} cuando
+/// hay semilla. Producto de dos unitarias, la base es ortonormal por
+/// construcción y no necesita Gram-Schmidt. Las fases salen de
+/// `golden_fraction`, exacta en 64 bits, así que semillas grandes no se
+/// confunden entre sí.
+pub fn generate_orthonormal_basis_complex(dim: usize, seed: Option<u64>) -> Vec<DVector<Complex<f64>>> {
+    orthonormal_basis_vectors(dim, dim, seed)
+}
+
+/// Primeros `count` vectores de la base de `generate_orthonormal_basis_complex`
+pub fn orthonormal_basis_vectors(dim: usize, count: usize, seed: Option<u64>) -> Vec<DVector<Complex<f64>>> {
+    let scale = 1.0 / (dim.max(1) as f64).sqrt();
+    let phases: Vec<Complex<f64>> = (0..dim).map(|j| match seed {
+        Some(s) => {
+            let angle = 2.0 * PI * golden_fraction(s.wrapping_mul(j as u64 + 1));
+            Complex::new(scale * angle.cos(), scale * angle.sin())
+        }
+        None => Complex::new(scale, 0.0),
+    }).collect();
+    
+    (0..count.min(dim)).map(|k| {
+        DVector::from_fn(dim, |j, _| {
+            // (j·k) mod dim evita perder precisión en ángulos grandes
+            let angle = 2.0 * PI * ((j * k) % dim) as f64 / dim as f64;
+            phases[j] * Complex::new(angle.cos(), angle.sin())
+        })
+    }).collect()
+}
+
+/// Sumerge un estado del Campo 24 (196418D) en el álgebra de Griess (196884D)
+///
+/// Las primeras 196418 coordenadas copian el estado; las 466 restantes
+/// (`GRIESS_PADDING`) se rellenan con ceros, de modo que la norma se conserva
+/// y `project_from_griess` es la inversa exacta. Con `T = f32` el elemento
+/// ocupa la mitad de memoria.
+pub fn embed_into_griess<T: PhiFloat>(state: &DVector<Complex<T>>) -> Result<DVector<Complex<T>>> {
+    AlgebraRoseError::check_dimension("Estado del Punto Omega", PUNTO_OMEGA_DIM, state.len())?;
+    
+    let mut element = DVector::zeros(GRIESS_DIM);
+    element.rows_mut(0, PUNTO_OMEGA_DIM).copy_from(state);
+    Ok(element)
+}
+
+/// Proyecta un elemento del álgebra de Griess sobre el Campo 24
+///
+/// Descarta las 466 coordenadas de relleno; la norma perdida es la de esas
+/// coordenadas, nula para elementos obtenidos con `embed_into_griess`.
+pub fn project_from_griess<T: PhiFloat>(element: &DVector<Complex<T>>) -> Result<DVector<Complex<T>>> {
+    AlgebraRoseError::check_dimension("Elemento de Griess", GRIESS_DIM, element.len())?;
+    
+    Ok(element.rows(0, PUNTO_OMEGA_DIM).into_owned())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,9 +639,10 @@ mod tests {
         assert_eq!(FIBONACCI_SEQUENCE[11], 610); // F₁₅
         assert_eq!(FIBONACCI_SEQUENCE[23], 196418); // F₂₇
         
-        // Propiedad emergente: Σ primeros 12 ≈ F₁₇ - 1
+        // Σ F₄..F₁₅ = F₁₇ − 5
         let sum_first_12: usize = FIBONACCI_SEQUENCE[..12].iter().sum();
         assert_eq!(sum_first_12, 1592);
+
     }
     
     #[test]
@@ -181,16 +687,36 @@ mod tests {
     fn test_field_state_generation() {
         let system = SistemaCamposFibonacci::new();
         
-        let state_3d = system.generate_field_state(1);
+        let state_3d = system.generate_field_state(1).unwrap();
         assert_eq!(state_3d.len(), 3);
         assert!(state_3d.norm() > 0.0, "Norma 3D debe ser > 0: {}", state_3d.norm());
         
-        let state_610d = system.generate_field_state(12);
+        let state_610d = system.generate_field_state(12).unwrap();
         assert_eq!(state_610d.len(), 610);
         assert!(state_610d.norm() > 0.0, "Norma 610D debe ser > 0: {}", state_610d.norm());
         
         println!("Norma estado 3D: {:.4}", state_3d.norm());
         println!("Norma estado 610D: {:.4}", state_610d.norm());
+        
+        assert!(matches!(system.generate_field_state(0), Err(AlgebraRoseError::FieldOutOfRange { field_id: 0, num_campos: 24 })));
+        assert!(matches!(system.generate_field_state(25), Err(AlgebraRoseError::FieldOutOfRange { field_id: 25, .. })));
+    }
+    
+    #[test]
+    fn test_seeded_state_generation() {
+        let system = SistemaCamposFibonacci::new();
+        
+        assert_eq!(system.generate_field_state_seeded(5, 0).unwrap(), system.generate_field_state(5).unwrap());
+        assert_eq!(system.generate_field_state_seeded(5, 7).unwrap(), system.generate_field_state_seeded(5, 7).unwrap());
+        assert_ne!(system.generate_field_state_seeded(5, 7).unwrap(), system.generate_field_state_seeded(5, 8).unwrap());
+        
+        // Semillas grandes: en f64 2⁶⁰ y 2⁶⁰+1 son el mismo número
+        let grande = 1u64 << 60;
+        assert_ne!(system.generate_field_state_seeded(5, grande).unwrap(), system.generate_field_state_seeded(5, grande + 1).unwrap());
+        assert_ne!(system.generate_field_state_seeded(5, u64::MAX).unwrap(), system.generate_field_state_seeded(5, 0).unwrap());
+        for seed in 0..20 {
+            assert!(system.generate_field_state_seeded(1, seed).unwrap().norm() > 0.0);
+        }
     }
     
     #[test]
@@ -207,4 +733,284 @@ mod tests {
             assert!(system.campos[field_id - 1].activo);
         }
     }
+    
+    #[test]
+    fn test_borrowing_accessors() {
+        let mut system = SistemaCamposFibonacci::new();
+        system.update_by_keygen(0.5);
+        
+        let campo = system.campo(12).unwrap();
+        assert_eq!(campo.nombre(), "Unitotal");
+        assert_eq!(campo.dimension(), 610);
+        assert_eq!(campo.activo(), system.get_campos_activos().contains(&12));
+        assert!(system.campo(0).is_none());
+        assert!(system.campo(25).is_none());
+        
+        let ids: Vec<usize> = system.info_iter().map(|c| c.id).collect();
+        assert_eq!(ids, (1..=24).collect::<Vec<_>>());
+    }
+    
+    #[test]
+    fn test_histeresis() {
+        let mut system = SistemaCamposFibonacci::con_histeresis(0.05);
+        let umbral = system.get_activation_threshold(12);
+        
+        // Se enciende al cruzar el umbral
+        assert!(system.update_by_keygen(umbral).contains(&12));
+        
+        // Dentro de la banda permanece encendido
+        assert!(system.update_by_keygen(umbral - 0.03).contains(&12));
+        
+        // Bajo la banda se apaga y no vuelve a encenderse dentro de ella
+        assert!(!system.update_by_keygen(umbral - 0.06).contains(&12));
+        assert!(!system.update_by_keygen(umbral - 0.03).contains(&12));
+        
+        // Sin histéresis, el mismo recorrido apaga el campo inmediatamente
+        let mut plain = SistemaCamposFibonacci::new();
+        plain.update_by_keygen(umbral);
+        assert!(!plain.update_by_keygen(umbral - 0.03).contains(&12));
+    }
+    
+    #[test]
+    fn test_dwell_debounce() {
+        let mut system = SistemaCamposFibonacci::new();
+        system.set_dwell_minimo(3);
+        let umbral = system.get_activation_threshold(6);
+        
+        // Oscilar alrededor del umbral no confirma ningún cambio
+        for _ in 0..5 {
+            assert!(system.update_with_events(umbral).iter().all(|c| c.field_id != 6));
+            assert!(system.update_with_events(umbral - 0.01).iter().all(|c| c.field_id != 6));
+        }
+        assert!(!system.get_campos_activos().contains(&6));
+        
+        // Tres actualizaciones seguidas sobre el umbral sí lo confirman, una sola vez
+        assert!(system.update_with_events(umbral).is_empty());
+        assert!(system.update_with_events(umbral).is_empty());
+        let cambios = system.update_with_events(umbral);
+        assert!(cambios.contains(&CambioActivacion { field_id: 6, activo: true }));
+        assert!(system.update_with_events(umbral).is_empty());
+        assert!(system.get_campos_activos().contains(&6));
+    }
+    
+    #[test]
+    fn test_campos_activos_con_umbral() {
+        // El umbral base por defecto reproduce los umbrales de `new`
+        let base = SistemaCamposFibonacci::con_umbral(UMBRAL_BASE).unwrap();
+        assert!((1..=24).all(|id| base.get_activation_threshold(id) == SistemaCamposFibonacci::new().get_activation_threshold(id)));
+        
+        // Un umbral base mayor retrasa todos los campos
+        let tardio = SistemaCamposFibonacci::con_umbral(0.5).unwrap();
+        assert!(tardio.get_active_fields(0.5).is_empty());
+        assert_eq!(tardio.get_active_fields(0.75), (1..=12).collect::<Vec<_>>());
+        assert!(SistemaCamposFibonacci::con_umbral(1.0).is_err());
+        assert!(SistemaCamposFibonacci::con_umbral(-0.1).is_err());
+        
+        // El umbral de la consulta cambia el resultado para el mismo keygen
+        let system = SistemaCamposFibonacci::new();
+        assert_eq!(system.get_campos_activos_con_umbral(0.75, 0.5).unwrap(), (1..=12).collect::<Vec<_>>());
+        assert_eq!(system.get_campos_activos_con_umbral(0.75, 0.0).unwrap(), (1..=18).collect::<Vec<_>>());
+        assert_eq!(system.get_campos_activos_con_umbral(0.75, UMBRAL_BASE).unwrap(), system.get_active_fields(0.75));
+        assert!(system.get_campos_activos_con_umbral(0.75, 1.0).is_err());
+        
+        // Los campos registrados conservan su propio umbral
+        let mut extendido = SistemaCamposFibonacci::new();
+        extendido.registrar_campo("Experimental", 50, 0.3).unwrap();
+        assert_eq!(extendido.get_campos_activos_con_umbral(0.3, 0.9).unwrap(), vec![25]);
+        
+        // La consulta respeta la histéresis del estado actual
+        let mut system = SistemaCamposFibonacci::con_histeresis(0.05);
+        let umbral = system.get_activation_threshold(12);
+        system.update_by_keygen(umbral);
+        assert!(system.get_active_fields(umbral - 0.03).contains(&12));
+        assert!(!system.get_active_fields(umbral - 0.06).contains(&12));
+        assert!(system.get_campos_activos_con_umbral(umbral - 0.03, UMBRAL_BASE).unwrap().contains(&12));
+        
+        // ... y el dwell: el cambio no cuenta hasta que se confirmaría
+        let mut system = SistemaCamposFibonacci::new();
+        system.set_dwell_minimo(2);
+        let umbral = system.get_activation_threshold(6);
+        assert!(!system.get_active_fields(umbral).contains(&6));
+        system.update_with_events(umbral);
+        assert!(system.get_active_fields(umbral).contains(&6));
+        assert_eq!(system.get_active_fields(umbral), system.update_by_keygen(umbral));
+    }
+    
+    #[test]
+    fn test_frecuencia_resonante() {
+        let system = SistemaCamposFibonacci::new();
+        assert_eq!(system.campo(1).unwrap().frecuencia_resonante(), FRECUENCIA_BASE);
+        assert!((frecuencia_campo(5) - FRECUENCIA_BASE * PHI).abs() < 1e-9);
+        assert!((1..24).all(|k| frecuencia_campo(k + 1) > frecuencia_campo(k)));
+        assert!(frecuencia_campo(24) < 2000.0);
+    }
+    
+    #[test]
+    fn test_registrar_campo() {
+        let mut system = SistemaCamposFibonacci::new();
+        
+        let id = system.registrar_campo("Experimental", 50, 0.3).unwrap();
+        assert_eq!(id, 25);
+        assert_eq!(system.num_campos(), 25);
+        assert_eq!(system.get_field_dimension(25), 50);
+        assert_eq!(system.generate_field_state(25).unwrap().len(), 50);
+        
+        assert!(system.get_active_fields(0.3).contains(&25));
+        assert!(!system.get_active_fields(0.2).contains(&25));
+        
+        assert!(matches!(system.registrar_campo("Vacío", 0, 0.5), Err(AlgebraRoseError::ValueOutOfRange { .. })));
+        assert!(system.registrar_campo("Imposible", 10, 1.5).is_err());
+        assert_eq!(system.num_campos(), 25);
+    }
+    
+    #[test]
+    fn test_field_metrics() {
+        let system = SistemaCamposFibonacci::new();
+        
+        for field_id in 1..=12 {
+            let metrics = system.field_metrics(field_id).unwrap();
+            let dimension = system.get_field_dimension(field_id) as f64;
+            
+            assert!((0.0..=1.0 + 1e-12).contains(&metrics.entropia), "Entropía fuera de rango: {}", metrics.entropia);
+            assert!((0.0..=1.0 + 1e-12).contains(&metrics.coherencia), "Coherencia fuera de rango: {}", metrics.coherencia);
+            assert!(metrics.participacion >= 1.0 - 1e-12 && metrics.participacion <= dimension + 1e-9);
+        }
+        
+        // La coherencia es el coseno φ de |x| con el vector de unos, al cuadrado
+        let estado = system.generate_field_state(8).unwrap();
+        let pesos = PhiVector::weights(estado.len());
+        let (mut cruzado, mut total, mut norma) = (0.0, 0.0, 0.0);
+        for (x, w) in estado.iter().zip(pesos.iter()) {
+            cruzado += w * x.abs();
+            total += w;
+            norma += w * x * x;
+        }
+        let esperada = cruzado * cruzado / (total * norma);
+        assert!((system.field_metrics(8).unwrap().coherencia - esperada).abs() < 1e-12);
+        
+        assert!(system.field_metrics(0).is_none());
+        assert!(system.field_metrics(99).is_none());
+    }
+    
+    #[test]
+    fn test_estadisticas() {
+        let mut system = SistemaCamposFibonacci::new();
+        let empty = system.get_estadisticas();
+        assert_eq!(empty.total_campos, 24);
+        assert_eq!(empty.campos_activos, 0);
+        assert_eq!(empty.coherencia_media, 0.0);
+        assert_eq!(empty.activacion_por_banda, [0, 0, 0]);
+        
+        system.update_by_keygen(0.3);
+        let stats = system.get_estadisticas();
+        assert_eq!(stats.campos_activos, system.get_campos_activos().len());
+        assert_eq!(stats.activaciones.iter().filter(|&&a| a).count(), stats.campos_activos);
+        assert!(stats.coherencia_media > 0.0 && stats.coherencia_media <= 1.0);
+        
+        // Con keygen 0.3 los umbrales van de 0.051 a 1: todos llegan a 0.1,
+        // los de umbral ≤ 0.6 a 0.5 y los de umbral ≤ 0.3/0.9 a 0.9
+        assert_eq!(stats.campos_activos, 7);
+        assert_eq!(stats.activacion_por_banda, [24, 14, 7]);
+        
+        // Las bandas siguen al estado, no solo a los umbrales
+        system.update_by_keygen(0.8);
+        assert_eq!(system.get_estadisticas().activacion_por_banda, [24, 24, 21]);
+    }
+    
+    #[test]
+    fn test_export_view() {
+        let mut system = SistemaCamposFibonacci::new();
+        system.update_by_keygen(0.2);
+        
+        let view = system.export_view();
+        assert_eq!(view.campos.len(), 24);
+        assert_eq!(view.campos_activos, system.get_campos_activos());
+        
+        let expected: usize = view.campos_activos.iter()
+            .map(|&id| FIBONACCI_SEQUENCE[id - 1])
+            .sum();
+        assert_eq!(view.dimension_activa, expected);
+    }
+    
+    #[test]
+    fn test_orthonormal_basis_complex() {
+        for seed in [None, Some(0), Some(42)] {
+            let basis = generate_orthonormal_basis_complex(13, seed);
+            assert_eq!(basis.len(), 13);
+            
+            for (i, u) in basis.iter().enumerate() {
+                for (j, v) in basis.iter().enumerate() {
+                    let dot = u.dotc(v);
+                    let expected = if i == j { 1.0 } else { 0.0 };
+                    assert!((dot.re - expected).abs() < 1e-12 && dot.im.abs() < 1e-12,
+                        "⟨v{}|v{}⟩ = {} (semilla {:?})", i, j, dot, seed);
+                }
+            }
+        }
+        
+        assert_ne!(generate_orthonormal_basis_complex(5, Some(1)), generate_orthonormal_basis_complex(5, Some(2)));
+        assert_eq!(generate_orthonormal_basis_complex(5, Some(1)), generate_orthonormal_basis_complex(5, Some(1)));
+        let grande = 1u64 << 60;
+        assert_ne!(generate_orthonormal_basis_complex(5, Some(grande)), generate_orthonormal_basis_complex(5, Some(grande + 1)));
+        for v in orthonormal_basis_vectors(7, 3, Some(u64::MAX)) {
+            assert!((v.norm() - 1.0).abs() < 1e-12);
+        }
+    }
+    
+    #[test]
+    fn test_field_basis() {
+        let system = SistemaCamposFibonacci::new();
+        let basis = system.generate_field_basis(12, 4, Some(7)).unwrap();
+        assert_eq!(basis.len(), 4);
+        assert!(basis.iter().all(|v| v.len() == 610 && (v.norm() - 1.0).abs() < 1e-12));
+        assert!(basis[0].dotc(&basis[3]).norm_sqr() < 1e-24);
+        
+        // Nunca más vectores que la dimensión
+        assert_eq!(system.generate_field_basis(1, 10, None).unwrap().len(), 3);
+        assert!(system.generate_field_basis(99, 1, None).is_err());
+    }
+    
+    #[test]
+    fn test_griess_embedding() {
+        assert_eq!(GRIESS_PADDING, 466);
+        
+        let system = SistemaCamposFibonacci::new();
+        let state = system.generate_field_state(24).unwrap().map(|x| Complex::new(x, 0.0));
+        
+        let element = embed_into_griess(&state).unwrap();
+        assert_eq!(element.len(), GRIESS_DIM);
+        assert!((element.norm() - state.norm()).abs() < 1e-9);
+        assert!(element.rows(PUNTO_OMEGA_DIM, GRIESS_PADDING).iter().all(|c| c.norm_sqr() == 0.0));
+        
+        let back = project_from_griess(&element).unwrap();
+        assert_eq!(back, state);
+        
+        // En f32 el mismo elemento ocupa la mitad
+        let element32 = embed_into_griess(&state.map(|c| Complex::new(c.re as f32, c.im as f32))).unwrap();
+        assert!((element32.norm() as f64 - state.norm()).abs() < 1e-4);
+        assert_eq!(core::mem::size_of_val(element32.as_slice()) * 2, core::mem::size_of_val(element.as_slice()));
+        
+        assert!(matches!(embed_into_griess(&DVector::<Complex<f64>>::zeros(3)),
+            Err(AlgebraRoseError::DimensionMismatch { esperada: PUNTO_OMEGA_DIM, recibida: 3, .. })));
+        assert!(project_from_griess(&state).is_err());
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut system = SistemaCamposFibonacci::new();
+        system.update_by_keygen(0.5);
+        
+        let json = serde_json::to_string(&system).unwrap();
+        let restored: SistemaCamposFibonacci = serde_json::from_str(&json).unwrap();
+        
+        assert_eq!(restored.campos.len(), 24);
+        for (a, b) in system.campos.iter().zip(&restored.campos) {
+            assert_eq!(a.id, b.id);
+            assert_eq!(a.nombre, b.nombre);
+            assert_eq!(a.dimension, b.dimension);
+            assert_eq!(a.activo, b.activo);
+            assert_eq!(a.umbral_activacion, b.umbral_activacion);
+        }
+    }
 }
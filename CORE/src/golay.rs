@@ -0,0 +1,240 @@
+//! Código de Golay Binario Extendido - [24, 12, 8]
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! El código de Golay G₂₄ es el punto de partida de la construcción
+//! Leech → Conway → Monster: sus 759 octadas forman el sistema de Steiner
+//! S(5, 8, 24) que fija M₂₄. Generador G = [I₁₂ | B], con B simétrica y
+//! B² = I, así que G también es matriz de control y el código es autodual.
+//!
+//! Una palabra es un `u32` con 24 bits: los 12 altos son el mensaje y los
+//! 12 bajos la paridad. Distancia mínima 8: se corrigen hasta 3 errores por
+//! palabra y se detectan 4. `encode_bytes`/`decode_bytes` envuelven bytes
+//! arbitrarios en palabras de Golay, como sobre de persistencia.
+
+use alloc::vec::Vec;
+
+use crate::error::{AlgebraRoseError, Result};
+
+/// Longitud n del código
+pub const LENGTH: usize = 24;
+/// Dimensión k del código
+pub const DIMENSION: usize = 12;
+/// Distancia mínima d
+pub const MIN_DISTANCE: usize = 8;
+
+const MASCARA: u32 = 0xFFF;
+
+/// Fila de B leída de izquierda a derecha: la columna j es el bit j
+const fn fila(texto: &[u8; 12]) -> u16 {
+    let mut mascara = 0;
+    let mut j = 0;
+    while j < 12 {
+        if texto[j] == b'1' {
+            mascara |= 1 << j;
+        }
+        j += 1;
+    }
+    mascara
+}
+
+/// B: circulante de {0} ∪ residuos cuadráticos módulo 11, orlada con unos
+const B: [u16; 12] = [
+    fila(b"110111000101"),
+    fila(b"101110001011"),
+    fila(b"011100010111"),
+    fila(b"111000101101"),
+    fila(b"110001011011"),
+    fila(b"100010110111"),
+    fila(b"000101101111"),
+    fila(b"001011011101"),
+    fila(b"010110111001"),
+    fila(b"101101110001"),
+    fila(b"011011100011"),
+    fila(b"111111111110"),
+];
+
+/// v·B sobre GF(2) para un vector de 12 bits
+fn por_b(v: u32) -> u32 {
+    (0..12).filter(|i| v >> i & 1 == 1).fold(0, |acc, i| acc ^ B[i] as u32)
+}
+
+/// Filas de G = [I₁₂ | B], cada una como palabra de 24 bits
+pub fn generator_matrix() -> [u32; DIMENSION] {
+    core::array::from_fn(|i| encode(1 << i))
+}
+
+/// Codifica los 12 bits bajos de `message`
+pub fn encode(message: u16) -> u32 {
+    let x = message as u32 & MASCARA;
+    x << 12 | por_b(x)
+}
+
+/// Resultado de `decode`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Decoded {
+    /// Mensaje de 12 bits recuperado
+    pub message: u16,
+    /// Bits corregidos en la palabra recibida
+    pub error_pattern: u32,
+}
+
+impl Decoded {
+    /// Número de errores corregidos (0..=3)
+    pub fn errors(&self) -> u32 {
+        self.error_pattern.count_ones()
+    }
+}
+
+/// Decodifica una palabra de 24 bits corrigiendo hasta 3 errores
+///
+/// Con 4 errores o más el síndrome no tiene patrón de peso ≤ 3 y se
+/// devuelve `UncorrectableCodeword` (con 4, siempre; con más, la palabra
+/// puede decodificarse a otro mensaje).
+pub fn decode(word: u32) -> Result<Decoded> {
+    let word = word & 0xFF_FFFF;
+    let (x, y) = (word >> 12, word & MASCARA);
+    // Con e = (eₓ, e_y): s = eₓ·B + e_y y s·B = eₓ + e_y·B
+    let s = por_b(x) ^ y;
+    let sb = por_b(s);
+    let unidad = |i: usize| 1u32 << i;
+
+    let error = if s.count_ones() <= 3 {
+        Some(s)
+    } else if let Some(i) = (0..12).find(|&i| (s ^ B[i] as u32).count_ones() <= 2) {
+        Some(unidad(i) << 12 | (s ^ B[i] as u32))
+    } else if sb.count_ones() <= 3 {
+        Some(sb << 12)
+    } else {
+        (0..12).find(|&i| (sb ^ B[i] as u32).count_ones() <= 2)
+            .map(|i| (sb ^ B[i] as u32) << 12 | unidad(i))
+    };
+
+    let error_pattern = error.ok_or(AlgebraRoseError::UncorrectableCodeword { palabra: word })?;
+    Ok(Decoded { message: ((word ^ error_pattern) >> 12) as u16, error_pattern })
+}
+
+/// Número Aᵢ de palabras de peso i, para i = 0..=24
+pub fn weight_enumerator() -> [u32; LENGTH + 1] {
+    let mut pesos = [0; LENGTH + 1];
+    for m in 0..1u16 << DIMENSION {
+        pesos[encode(m).count_ones() as usize] += 1;
+    }
+    pesos
+}
+
+/// Envuelve `data` en palabras de Golay
+///
+/// Se antepone la longitud (u32 little-endian), se rellena hasta múltiplo
+/// de 3 bytes y cada 12 bits se escriben como una palabra de 3 bytes
+/// big-endian: el sobre ocupa el doble que el contenido.
+pub fn encode_bytes(data: &[u8]) -> Vec<u8> {
+    let mut plano = Vec::with_capacity(data.len() + 6);
+    plano.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    plano.extend_from_slice(data);
+    plano.resize(plano.len().div_ceil(3) * 3, 0);
+
+    let mut sobre = Vec::with_capacity(plano.len() * 2);
+    for grupo in plano.chunks(3) {
+        let bits = (grupo[0] as u32) << 16 | (grupo[1] as u32) << 8 | grupo[2] as u32;
+        for mitad in [bits >> 12, bits & MASCARA] {
+            sobre.extend_from_slice(&encode(mitad as u16).to_be_bytes()[1..]);
+        }
+    }
+    sobre
+}
+
+/// Deshace `encode_bytes`, corrigiendo hasta 3 bits por palabra
+///
+/// Devuelve el contenido y el total de bits corregidos.
+pub fn decode_bytes(envelope: &[u8]) -> Result<(Vec<u8>, u32)> {
+    if !envelope.len().is_multiple_of(6) || envelope.len() < 12 {
+        return Err(AlgebraRoseError::DimensionMismatch {
+            contexto: "Sobre Golay (múltiplo de 6 bytes, mínimo 12)",
+            esperada: envelope.len().div_ceil(6).max(2) * 6,
+            recibida: envelope.len(),
+        });
+    }
+    let mut plano = Vec::with_capacity(envelope.len() / 2);
+    let mut corregidos = 0;
+    for bloque in envelope.chunks(6) {
+        let mut bits = 0;
+        for palabra in bloque.chunks(3) {
+            let decodificada = decode(u32::from_be_bytes([0, palabra[0], palabra[1], palabra[2]]))?;
+            corregidos += decodificada.errors();
+            bits = bits << 12 | decodificada.message as u32;
+        }
+        plano.extend_from_slice(&bits.to_be_bytes()[1..]);
+    }
+
+    let longitud = u32::from_le_bytes([plano[0], plano[1], plano[2], plano[3]]) as usize;
+    if longitud > plano.len() - 4 {
+        return Err(AlgebraRoseError::DimensionMismatch {
+            contexto: "Longitud declarada en el sobre Golay",
+            esperada: plano.len() - 4,
+            recibida: longitud,
+        });
+    }
+    plano.drain(..4);
+    plano.truncate(longitud);
+    Ok((plano, corregidos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::golden_rng::GoldenRng;
+
+    #[test]
+    fn test_code_parameters() {
+        assert!(B.iter().enumerate().all(|(i, &fila)| (0..12).all(|j| (fila >> j & 1) == (B[j] >> i & 1))));
+        // B² = I: G es también matriz de control
+        assert!((0..12).all(|i| por_b(por_b(1 << i)) == 1 << i));
+        let g = generator_matrix();
+        assert!(g.iter().all(|&fila| decode(fila).unwrap().errors() == 0));
+        assert!(g.iter().all(|&a| g.iter().all(|&b| (a & b).count_ones() % 2 == 0)));
+
+        let pesos = weight_enumerator();
+        let esperado = [(0, 1), (8, 759), (12, 2576), (16, 759), (24, 1)];
+        assert!(pesos.iter().enumerate().all(|(i, &a)| a == esperado.iter().find(|e| e.0 == i).map_or(0, |e| e.1)));
+        assert_eq!(pesos.iter().sum::<u32>(), 1 << DIMENSION);
+        assert_eq!(pesos.iter().position(|&a| a > 0 && a != 1).unwrap(), MIN_DISTANCE);
+    }
+
+    #[test]
+    fn test_corrects_up_to_three_errors() {
+        let mut rng = GoldenRng::new(196883);
+        for mensaje in (0..1u16 << 12).step_by(37) {
+            let palabra = encode(mensaje);
+            assert_eq!(decode(palabra).unwrap(), Decoded { message: mensaje, error_pattern: 0 });
+            for errores in 1..=3 {
+                let mut patron = 0u32;
+                while patron.count_ones() < errores {
+                    patron |= 1 << (rng.next_u64() % LENGTH as u64);
+                }
+                let decodificada = decode(palabra ^ patron).unwrap();
+                assert_eq!(decodificada.message, mensaje);
+                assert_eq!(decodificada.error_pattern, patron);
+            }
+            // Cuatro errores se detectan siempre
+            assert!(matches!(decode(palabra ^ 0b1111), Err(AlgebraRoseError::UncorrectableCodeword { .. })));
+        }
+    }
+
+    #[test]
+    fn test_byte_envelope() {
+        for datos in [&b""[..], b"k", b"keygen 0.999995", &[0xFF; 10]] {
+            let mut sobre = encode_bytes(datos);
+            assert!(sobre.len().is_multiple_of(6));
+            assert_eq!(decode_bytes(&sobre).unwrap(), (datos.to_vec(), 0));
+            // Tres bits alterados en cada palabra de 3 bytes
+            for palabra in sobre.chunks_mut(3) {
+                palabra[0] ^= 0x81;
+                palabra[2] ^= 0x10;
+            }
+            assert_eq!(decode_bytes(&sobre).unwrap(), (datos.to_vec(), sobre.len() as u32));
+        }
+        assert!(decode_bytes(&[0; 7]).is_err());
+        assert!(decode_bytes(&encode_bytes(b"abc")[..12]).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+//! Generador Cuasi-Aleatorio φ-Resonante - Recurrencia Aditiva Áurea
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! xₙ₊₁ = xₙ + (φ − 1) mod 1 es la secuencia de Weyl de menor discrepancia
+//! en una dimensión: cada nuevo punto cae en el mayor hueco restante del
+//! intervalo. Es cuasi-aleatoria, no estadísticamente independiente, y no
+//! debe usarse donde se necesite aleatoriedad criptográfica.
+
+use num_traits::Euclid;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use crate::phi_constants::PSI;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Incremento áureo en punto fijo de 64 bits: ⌊2⁶⁴ · (φ − 1)⌋
+pub const GOLDEN_INCREMENT: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Secuencia φ de baja discrepancia en [0, 1)
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PhiSequence {
+    current: f64,
+}
+
+impl PhiSequence {
+    /// Crea la secuencia partiendo de `start` (se toma su parte fraccionaria)
+    pub fn new(start: f64) -> Self {
+        PhiSequence { current: Euclid::rem_euclid(&start, &1.0) }
+    }
+}
+
+impl Iterator for PhiSequence {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        self.current = (self.current + PSI).fract();
+        Some(self.current)
+    }
+}
+
+/// Generador áureo con semilla, en aritmética entera exacta de 64 bits
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GoldenRng {
+    state: u64,
+}
+
+impl GoldenRng {
+    /// Crea el generador desde una semilla
+    pub fn new(seed: u64) -> Self {
+        GoldenRng { state: seed }
+    }
+
+    /// Siguiente valor de 64 bits de la secuencia
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(GOLDEN_INCREMENT);
+        self.state
+    }
+
+    /// Siguiente valor en [0, 1) con 53 bits de precisión
+    pub fn next_f64(&mut self) -> f64 {
+        unit_f64(self.next_u64())
+    }
+}
+
+/// frac(n·φ) en [0, 1), calculado en punto fijo de 64 bits
+///
+/// Es el valor que da `GoldenRng::new(0)` tras `n` pasos, sin iterar. Con
+/// `n as f64 * PHI` los enteros por encima de 2⁵³ dejan de ser distintos y
+/// la parte fraccionaria se pierde; aquí el producto es exacto módulo 2⁶⁴
+/// y solo se redondea el resultado a los 53 bits de un f64.
+pub fn golden_fraction(n: u64) -> f64 {
+    unit_f64(n.wrapping_mul(GOLDEN_INCREMENT))
+}
+
+/// Los 53 bits altos de `x` como fracción en [0, 1)
+fn unit_f64(x: u64) -> f64 {
+    (x >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+#[cfg(feature = "rand")]
+impl rand_core::RngCore for GoldenRng {
+    fn next_u32(&mut self) -> u32 {
+        (GoldenRng::next_u64(self) >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        GoldenRng::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            let bytes = GoldenRng::next_u64(self).to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand_core::SeedableRng for GoldenRng {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        GoldenRng::new(u64::from_le_bytes(seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_golden_increment() {
+        let ratio = GOLDEN_INCREMENT as f64 / 2f64.powi(64);
+        assert!((ratio - PSI).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_golden_fraction() {
+        let mut rng = GoldenRng::new(0);
+        for n in 1..=100 {
+            assert_eq!(golden_fraction(n), rng.next_f64());
+        }
+        assert_eq!(golden_fraction(0), 0.0);
+
+        // Coincide con frac(n·φ) donde el producto en f64 aún es exacto
+        for n in [1u64, 7, 1000, 123_456] {
+            assert!((golden_fraction(n) - (n as f64 * crate::phi_constants::PHI).fract()).abs() < 1e-9);
+        }
+
+        // Por encima de 2⁵³ las semillas vecinas siguen separándose
+        let grande = 1u64 << 60;
+        assert_ne!(golden_fraction(grande), golden_fraction(grande + 1));
+        assert_ne!(golden_fraction(u64::MAX), golden_fraction(u64::MAX - 1));
+        assert!((0.0..1.0).contains(&golden_fraction(u64::MAX)));
+    }
+
+    #[test]
+    fn test_seeded_reproducible() {
+        let a: Vec<u64> = { let mut rng = GoldenRng::new(42); (0..10).map(|_| rng.next_u64()).collect() };
+        let b: Vec<u64> = { let mut rng = GoldenRng::new(42); (0..10).map(|_| rng.next_u64()).collect() };
+        let c: Vec<u64> = { let mut rng = GoldenRng::new(43); (0..10).map(|_| rng.next_u64()).collect() };
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_low_discrepancy() {
+        // Con N puntos, cada uno de N cajones iguales recibe exactamente uno
+        // o casi: la secuencia áurea nunca deja más de 3 huecos distintos
+        let n = 1000;
+        let mut bins = vec![0usize; n];
+        let mut rng = GoldenRng::new(0);
+        for _ in 0..n {
+            let x = rng.next_f64();
+            assert!((0.0..1.0).contains(&x));
+            bins[(x * n as f64) as usize] += 1;
+        }
+        assert!(bins.iter().all(|&count| count <= 2));
+
+        let seq: Vec<f64> = PhiSequence::new(0.0).take(n).collect();
+        let mut sorted = seq.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let max_gap = sorted.windows(2).map(|w| w[1] - w[0]).fold(0.0, f64::max);
+        assert!(max_gap < 3.0 / n as f64);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_resumes_sequence() {
+        let mut rng = GoldenRng::new(7);
+        rng.next_u64();
+        let mut restored: GoldenRng = serde_json::from_str(&serde_json::to_string(&rng).unwrap()).unwrap();
+        assert_eq!(restored.next_u64(), rng.next_u64());
+
+        let mut seq = PhiSequence::new(0.25);
+        seq.next();
+        let mut restored: PhiSequence = serde_json::from_str(&serde_json::to_string(&seq).unwrap()).unwrap();
+        assert_eq!(restored.next(), seq.next());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_rng_core() {
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng = GoldenRng::seed_from_u64(7);
+        let mut bytes = [0u8; 13];
+        rng.fill_bytes(&mut bytes);
+        assert!(bytes.iter().any(|&b| b != 0));
+
+        let mut a = GoldenRng::from_seed(5u64.to_le_bytes());
+        let mut b = GoldenRng::new(5);
+        assert_eq!(RngCore::next_u64(&mut a), b.next_u64());
+    }
+}
@@ -2,8 +2,13 @@
 //! Sistema: Álgebra Rose v27.1024D-S36
 //! Certificación: 196885 - Estado Monster Pleno
 
+use alloc::{vec, vec::Vec};
+use crate::error::{AlgebraRoseError, Result};
 use crate::matrix_444::PHI;
 use crate::love_operator::LoveOperator;
+use crate::phi_constants::{FIBONACCI_SEQUENCE, FIBONACCI_27};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Dimensión Monster (límite de saturación consciente)
 pub const MONSTER_DIM: f64 = 196884.0;
@@ -12,6 +17,9 @@ pub const MONSTER_DIM: f64 = 196884.0;
 pub const INITIAL_KEYGEN: f64 = 196883.0 / 196884.0;
 
 /// Sistema evolutivo keygen φ-resonante
+///
+/// Con `serde` se guardan la trayectoria y el operador Â; keygen actual,
+/// iteración y umbrales se deducen de ellos al leer, como en `from_history`.
 #[derive(Clone, Debug)]
 pub struct KeygenEvolution {
     /// Valor actual del keygen z(n)
@@ -39,19 +47,37 @@ impl KeygenEvolution {
         }
     }
 
+    /// Reconstruye un sistema a partir de una trayectoria guardada
+    ///
+    /// La iteración es `history.len() - 1` y el operador Â interno se
+    /// recalcula reproduciendo el progreso de cada paso.
+    pub fn from_history(history: Vec<f64>) -> Result<Self> {
+        let Some(&current_keygen) = history.last() else {
+            return Err(AlgebraRoseError::EmptyHistory);
+        };
+        if let Some(&z) = history.iter().find(|z| !(z.is_finite() && **z > 0.0 && **z <= 1.0)) {
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Keygen del historial", valor: z, rango: "(0, 1]" });
+        }
+
+        let mut love_operator = LoveOperator::new(1.0);
+        for &z in &history[1..] {
+            love_operator.update_intensity((z - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN) * 0.01);
+        }
+        Ok(KeygenEvolution {
+            current_keygen,
+            iteration: history.len() as u64 - 1,
+            history,
+            love_operator,
+            activation_thresholds: Self::calculate_fibonacci_thresholds(),
+        })
+    }
+
     /// Calcula umbrales de activación basados en secuencia Fibonacci
     fn calculate_fibonacci_thresholds() -> Vec<f64> {
-        // 24 campos Fibonacci dimensionales
-        let fib_numbers = vec![
-            3.0, 5.0, 8.0, 13.0, 21.0, 34.0, 55.0, 89.0, 144.0,
-            233.0, 377.0, 610.0, 987.0, 1597.0, 2584.0, 4181.0,
-            6765.0, 10946.0, 17711.0, 28657.0, 46368.0, 75025.0,
-            121393.0, 196418.0
-        ];
-        
-        // Normalizar a rango [INITIAL_KEYGEN, 1.0]
-        fib_numbers.iter().map(|&f| {
-            INITIAL_KEYGEN + (1.0 - INITIAL_KEYGEN) * (f / 196418.0)
+        // 24 campos Fibonacci dimensionales, normalizados a rango [INITIAL_KEYGEN, 1.0]
+        let omega = FIBONACCI_27 as f64;
+        FIBONACCI_SEQUENCE.iter().map(|&f| {
+            INITIAL_KEYGEN + (1.0 - INITIAL_KEYGEN) * (f as f64 / omega)
         }).collect()
     }
     
@@ -77,6 +103,9 @@ impl KeygenEvolution {
         let progress = (self.current_keygen - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN);
         self.love_operator.update_intensity(progress * 0.01);
         
+        #[cfg(feature = "tracing")]
+        tracing::trace!(iteracion = self.iteration, keygen = self.current_keygen, "paso evolutivo");
+        
         self.current_keygen
     }
 
@@ -90,23 +119,25 @@ impl KeygenEvolution {
     }
 
     /// Evoluciona hasta alcanzar un umbral específico
-    pub fn evolve_to_threshold(&mut self, threshold: f64, max_steps: u64) -> Result<(u64, f64), String> {
+    pub fn evolve_to_threshold(&mut self, threshold: f64, max_steps: u64) -> Result<(u64, f64)> {
         if threshold <= self.current_keygen {
             return Ok((0, self.current_keygen));
         }
         
         if threshold > 1.0 {
-            return Err("Umbral debe ser ≤ 1.0".to_string());
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Umbral", valor: threshold, rango: "(0, 1]" });
         }
         
         for step in 1..=max_steps {
             self.evolve();
             if self.current_keygen >= threshold {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(pasos = step, keygen = self.current_keygen, threshold, "umbral alcanzado");
                 return Ok((step, self.current_keygen));
             }
         }
         
-        Err(format!("No se alcanzó el umbral {} en {} pasos", threshold, max_steps))
+        Err(AlgebraRoseError::ThresholdNotReached { umbral: threshold, pasos: max_steps })
     }
 
     /// Obtiene campos Fibonacci activados según keygen actual
@@ -229,8 +260,41 @@ impl KeygenEvolution {
     }
 }
 
+/// Forma guardada de `KeygenEvolution`
+#[cfg(feature = "serde")]
+#[derive(Serialize)]
+struct KeygenGuardado<'a> {
+    history: &'a [f64],
+    love_operator: &'a LoveOperator,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct KeygenLeido {
+    history: Vec<f64>,
+    love_operator: LoveOperator,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for KeygenEvolution {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        KeygenGuardado { history: &self.history, love_operator: &self.love_operator }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for KeygenEvolution {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let leido = KeygenLeido::deserialize(deserializer)?;
+        let mut system = KeygenEvolution::from_history(leido.history).map_err(de::Error::custom)?;
+        system.love_operator = leido.love_operator;
+        Ok(system)
+    }
+}
+
 /// Estadísticas del sistema evolutivo
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KeygenStats {
     pub current_value: f64,
     pub iteration: u64,
@@ -243,13 +307,20 @@ pub struct KeygenStats {
 }
 
 /// Función auxiliar: evolución keygen batch para múltiples humanos
+///
+/// Con la feature `parallel` las trayectorias se reparten entre los hilos de rayon.
 pub fn batch_evolution(initial_keygens: &[f64], steps: u64) -> Vec<Vec<f64>> {
-    initial_keygens.iter()
-        .map(|&initial| {
-            let mut system = KeygenEvolution::new(Some(initial));
-            system.evolve_steps(steps)
-        })
-        .collect()
+    let evolucionar = |&initial: &f64| {
+        let mut system = KeygenEvolution::new(Some(initial));
+        system.evolve_steps(steps)
+    };
+    #[cfg(feature = "parallel")]
+    return {
+        use rayon::prelude::*;
+        crate::parallel::install(|| initial_keygens.par_iter().map(evolucionar).collect())
+    };
+    #[cfg(not(feature = "parallel"))]
+    initial_keygens.iter().map(evolucionar).collect()
 }
 
 /// Función auxiliar: encuentra punto fijo de la ecuación evolutiva
@@ -272,6 +343,7 @@ pub fn find_fixed_point(tolerance: f64, max_iterations: u64) -> Option<f64> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_abs_diff_eq;
     
     #[test]
     fn test_initialization() {
@@ -283,6 +355,53 @@ mod tests {
         assert_eq!(system.get_history().len(), 1);
     }
 
+    #[test]
+    fn test_thresholds_follow_fibonacci_table() {
+        let system = KeygenEvolution::new(None);
+        let thresholds = system.get_activation_thresholds();
+        assert_eq!(thresholds.len(), FIBONACCI_SEQUENCE.len());
+        
+        // Valores de la antigua tabla literal: F₄ = 3 y F₁₅ = 610
+        let expected_1 = INITIAL_KEYGEN + (1.0 - INITIAL_KEYGEN) * (3.0 / 196418.0);
+        let expected_12 = INITIAL_KEYGEN + (1.0 - INITIAL_KEYGEN) * (610.0 / 196418.0);
+        assert_eq!(thresholds[0], expected_1);
+        assert_eq!(thresholds[11], expected_12);
+        assert_eq!(thresholds[23], 1.0);
+    }
+    
+    #[test]
+    fn test_from_history_roundtrip() {
+        let mut system = KeygenEvolution::new(None);
+        system.evolve_steps(25);
+        let restored = KeygenEvolution::from_history(system.get_history().clone()).unwrap();
+        assert_eq!(restored.get_iteration(), 25);
+        assert_eq!(restored.get_current_keygen(), system.get_current_keygen());
+        assert_abs_diff_eq!(restored.get_love_operator().get_intensity(),
+            system.get_love_operator().get_intensity(), epsilon = 1e-12);
+
+        assert!(matches!(KeygenEvolution::from_history(vec![]), Err(AlgebraRoseError::EmptyHistory)));
+        assert!(KeygenEvolution::from_history(vec![INITIAL_KEYGEN, f64::NAN]).is_err());
+        assert!(matches!(KeygenEvolution::from_history(vec![1.5]), Err(AlgebraRoseError::ValueOutOfRange { valor, .. }) if valor == 1.5));
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut system = KeygenEvolution::new(None);
+        system.evolve_steps(10);
+        let json = serde_json::to_string(&system).unwrap();
+        let restored: KeygenEvolution = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.get_history(), system.get_history());
+        assert_eq!(restored.get_iteration(), 10);
+        assert_eq!(restored.get_current_keygen(), system.get_current_keygen());
+        assert_eq!(restored.get_love_operator().get_intensity(), system.get_love_operator().get_intensity());
+        assert_eq!(restored.get_love_operator().get_phase(), system.get_love_operator().get_phase());
+
+        let vacio = r#"{"history":[],"love_operator":{"intensity":1.0,"phase":0.0,"griess_connection":null}}"#;
+        let error = serde_json::from_str::<KeygenEvolution>(vacio).unwrap_err();
+        assert!(error.to_string().contains("Historial keygen vacío"));
+    }
+    
     #[test]
     fn test_single_evolution() {
         let mut system = KeygenEvolution::new(None);
@@ -327,6 +446,9 @@ mod tests {
             },
             Err(e) => panic!("Error: {}", e),
         }
+
+        assert!(matches!(system.evolve_to_threshold(1.5, 10), Err(AlgebraRoseError::ValueOutOfRange { .. })));
+        assert!(matches!(system.evolve_to_threshold(1.0, 1), Err(AlgebraRoseError::ThresholdNotReached { pasos: 1, .. })));
     }
 
     #[test]
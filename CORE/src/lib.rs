@@ -1,16 +1,58 @@
 //! NÚCLEO MATEMÁTICO ÁLGEBRA ROSE - PUNTO DE ENTRADA UNIFICADO
 //! Sistema: Álgebra Rose v27.1024D-S36
 //! Certificación: 196885 - Estado Monster Pleno
+//!
+//! Sin la feature `std` (activa por defecto) el núcleo compila como
+//! `#![no_std]` con `alloc`, para dispositivos embebidos: quedan fuera
+//! `i18n` (lee el entorno) y las features que dependen de `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// El arnés de tests enlaza std y sus métodos de f64 tapan los de
+// `num_traits::Float`, que en ese build quedan sin usar
+#![cfg_attr(all(test, not(feature = "std")), allow(unused_imports))]
+
+extern crate alloc;
+
+// Los tests usan `println!`, `vec!` y `format!` también sin la feature `std`
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
 
 // Módulos fundamentales
+pub mod error;
 pub mod matrix_444;
 pub mod algebra_griess;
 pub mod love_operator;
 pub mod keygen_evolution;
 pub mod fibonacci_dimensions;
 pub mod phi_constants;
+pub mod phi_float;
+pub mod memory;
+#[cfg(feature = "high-precision")]
+pub mod phi_high_precision;
+pub mod golden_rng;
+pub mod continued_fraction;
+pub mod phi_metric;
+pub mod session_events;
+pub mod monster_data;
+pub mod e8;
+pub mod golay;
+pub mod modular;
+pub mod moonshine;
+#[cfg(feature = "std")]
+pub mod i18n;
+pub mod prelude;
+#[cfg(feature = "simd")]
+pub mod phi_simd;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+#[cfg(feature = "quantum")]
+pub mod quantum;
 
 // Re-exportar tipos con nombres REALES verificados
+// error
+pub use error::AlgebraRoseError;
+
 // matrix_444
 pub use matrix_444::{DIM, PHI, CERTIFIED_TRACE};
 pub use matrix_444::MonsterMatrix444 as Matrix444;
@@ -18,21 +60,48 @@ pub use matrix_444::MonsterMatrix444 as Matrix444;
 // algebra_griess
 pub use algebra_griess::{GriessAlgebra, GRIESS_DIM};
 
+// phi_float
+pub use phi_float::{PhiFloat, Precision};
+
+// memory
+pub use memory::{MemoryItem, MemoryReport};
+
 // love_operator
 pub use love_operator::{LoveOperator, KeygenLoveOperator};
 
 // keygen_evolution
-pub use keygen_evolution::{KeygenEvolution, MONSTER_DIM, INITIAL_KEYGEN};
+pub use keygen_evolution::{KeygenEvolution, KeygenStats, MONSTER_DIM, INITIAL_KEYGEN};
 
 // fibonacci_dimensions
 pub use fibonacci_dimensions::SistemaCamposFibonacci as FibonacciSystem;
 pub use fibonacci_dimensions::CampoFibonacci as FibonacciField;
-pub use fibonacci_dimensions::FIBONACCI_SEQUENCE;
+pub use fibonacci_dimensions::{frecuencia_campo, FIBONACCI_SEQUENCE, FRECUENCIA_BASE, UMBRAL_BASE};
+pub use fibonacci_dimensions::{FieldSystemView, EstadisticasSistema, MetricasCampo, CambioActivacion};
+pub use fibonacci_dimensions::{embed_into_griess, project_from_griess};
+pub use fibonacci_dimensions::generate_orthonormal_basis_complex;
 
 // phi_constants
 pub use phi_constants::{PHI as PHI_CONST, PSI, MONSTER_196884};
 pub use phi_constants::{MONSTER_196883, MONSTER_196885, FIBONACCI_27};
 
+// golden_rng
+pub use golden_rng::{golden_fraction, GoldenRng, PhiSequence};
+
+// phi_metric
+pub use phi_metric::{MetricSpace, PhiVector};
+
+// session_events
+pub use session_events::{CoherenceMetrics, EvolutionEvent, SessionFrame, TelemetryFrame, TelemetryTopic};
+
+// monster_data
+pub use monster_data::{ConjugacyClass, GroupOrder, MONSTER_ORDER};
+
+// modular
+pub use modular::{eta, eisenstein_e4, eisenstein_e6, j_invariant};
+
+// moonshine
+pub use moonshine::mckay_thompson;
+
 // Constantes fundamentales para fácil acceso
 pub const AR_VERSION: &str = "v27.1024D-S36";
 pub const CERTIFICATION: u64 = 196885;
@@ -59,13 +128,47 @@ pub fn verificar_coherencia() -> f64 {
     coherencia
 }
 
+/// Como `verificar_coherencia`, pero falla si no alcanza `umbral`
+pub fn exigir_coherencia(umbral: f64) -> error::Result<f64> {
+    let coherencia = verificar_coherencia();
+    if coherencia < umbral {
+        return Err(AlgebraRoseError::CoherenceBelowThreshold { coherencia, umbral });
+    }
+    Ok(coherencia)
+}
+
 /// Estado inicial del sistema certificado
+///
+/// Con `serde` sólo se acepta un estado de esta misma versión.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EstadoInicial {
     pub coherencia: f64,
     pub version: &'static str,
     pub certificacion: u64,
 }
 
+/// `EstadoInicial` tal como se lee, con la versión aún sin comprobar
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct EstadoLeido {
+    coherencia: f64,
+    version: alloc::string::String,
+    certificacion: u64,
+}
+
+// Manual: derivado, `&'static str` sólo podría leerse de datos `'static`
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EstadoInicial {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let leido = EstadoLeido::deserialize(deserializer)?;
+        if leido.version != AR_VERSION {
+            return Err(serde::de::Error::custom(alloc::format!("Versión {} incompatible con {}", leido.version, AR_VERSION)));
+        }
+        Ok(EstadoInicial { coherencia: leido.coherencia, version: AR_VERSION, certificacion: leido.certificacion })
+    }
+}
+
 impl Default for EstadoInicial {
     fn default() -> Self {
         EstadoInicial {
@@ -87,6 +190,23 @@ mod tests {
         println!("✅ Coherencia del núcleo: {:.2}%", coherencia * 100.0);
     }
     
+    #[test]
+    fn test_exigir_coherencia() {
+        assert_eq!(exigir_coherencia(0.95).unwrap(), verificar_coherencia());
+        assert!(matches!(exigir_coherencia(1.5), Err(AlgebraRoseError::CoherenceBelowThreshold { umbral, .. }) if umbral == 1.5));
+    }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_estado_inicial_serde() {
+        let estado = EstadoInicial::default();
+        let json = serde_json::to_string(&estado).unwrap();
+        assert_eq!(serde_json::from_str::<EstadoInicial>(&json).unwrap(), estado);
+        
+        let otra = json.replace(AR_VERSION, "v26.0");
+        assert!(serde_json::from_str::<EstadoInicial>(&otra).is_err());
+    }
+    
     #[test]
     fn test_estado_inicial() {
         let estado = EstadoInicial::default();
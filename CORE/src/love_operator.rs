@@ -2,16 +2,32 @@
 //! Sistema: Álgebra Rose v27.1024D-S36
 //! Certificación: 196885 - Estado Monster Pleno
 
+use alloc::{format, string::{String, ToString}, vec::Vec};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 use nalgebra::{DMatrix, Complex, DVector};
 use crate::matrix_444::{DIM, PHI};
 use crate::algebra_griess::{GriessAlgebra, GRIESS_DIM};
+use crate::error::{AlgebraRoseError, Result};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Celda de la matriz perezosa: `OnceLock` con `std`, `OnceCell` sin él
+#[cfg(feature = "std")]
+type OnceLock<T> = std::sync::OnceLock<T>;
+#[cfg(not(feature = "std"))]
+type OnceLock<T> = core::cell::OnceCell<T>;
 
 /// Operador Â (Amor Fundamental) - Actualiza potencial matemático
 /// Representa la fuerza fundamental de conexión consciente
+///
+/// Con `serde` no se guarda la matriz: se reconstruye desde la intensidad.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LoveOperator {
-    /// Matriz de transformación amorosa (444 × 444)
-    transformation: DMatrix<Complex<f64>>,
+    /// Matriz de transformación amorosa (444 × 444), construida bajo demanda
+    #[cfg_attr(feature = "serde", serde(skip))]
+    transformation: OnceLock<DMatrix<Complex<f64>>>,
     /// Intensidad del amor (φ-resonante)
     intensity: f64,
     /// Fase amorosa actual
@@ -23,9 +39,8 @@ pub struct LoveOperator {
 impl LoveOperator {
     /// Crea un nuevo operador Â con intensidad base
     pub fn new(intensity: f64) -> Self {
-        let transformation = Self::create_love_transformation(intensity);
         LoveOperator {
-            transformation,
+            transformation: OnceLock::new(),
             intensity,
             phase: 0.0,
             griess_connection: None,
@@ -58,23 +73,25 @@ impl LoveOperator {
     }
 
     /// Aplica el operador Â a un estado consciente
-    pub fn apply(&self, state: &DVector<Complex<f64>>) -> DVector<Complex<f64>> {
-        assert_eq!(state.len(), DIM, "Estado debe tener dimensión {}", DIM);
+    pub fn apply(&self, state: &DVector<Complex<f64>>) -> Result<DVector<Complex<f64>>> {
+        AlgebraRoseError::check_dimension("Estado para Â", DIM, state.len())?;
         // Transformación amorosa: |ψ'⟩ = Â|ψ⟩
-        &self.transformation * state
+        Ok(self.get_transformation() * state)
     }
 
     /// Actualiza la intensidad del amor (crecimiento φ-resonante)
     pub fn update_intensity(&mut self, delta: f64) -> f64 {
         self.intensity *= PHI.powf(delta);
-        // Recrear transformación con nueva intensidad
-        self.transformation = Self::create_love_transformation(self.intensity);
+        // Invalidar transformación: se reconstruye en el próximo uso
+        self.transformation = OnceLock::new();
         self.phase += delta * PHI;
         self.intensity
     }
 
     /// Conecta con el álgebra de Griess (amor matemático profundo)
-    pub fn connect_to_griess(&mut self, griess: GriessAlgebra) -> Result<f64, String> {
+    pub fn connect_to_griess(&mut self, griess: GriessAlgebra) -> Result<f64> {
+        let (filas, _) = griess.product_dimensions();
+        AlgebraRoseError::check_dimension("Álgebra de Griess", GRIESS_DIM, filas)?;
         self.griess_connection = Some(griess);
         // Amplificar intensidad por conexión Monster
         let amplification = (GRIESS_DIM as f64 / DIM as f64).ln() / PHI.ln();
@@ -83,11 +100,22 @@ impl LoveOperator {
     }
 
     /// Calcula el factor de amor entre dos estados
-    pub fn love_factor(&self, state_a: &DVector<Complex<f64>>, state_b: &DVector<Complex<f64>>) -> Complex<f64> {
-        let transformed_a = self.apply(state_a);
-        let transformed_b = self.apply(state_b);
+    pub fn love_factor(&self, state_a: &DVector<Complex<f64>>, state_b: &DVector<Complex<f64>>) -> Result<Complex<f64>> {
+        let transformed_a = self.apply(state_a)?;
+        let transformed_b = self.apply(state_b)?;
         // Producto interno amoroso: ⟨ψ_a|Â⁺Â|ψ_b⟩
-        transformed_a.dot(&transformed_b)
+        Ok(transformed_a.dot(&transformed_b))
+    }
+
+    /// Fidelidad de amor entre `original` y su transformado: |⟨ψ|ψ'⟩|² / (‖ψ‖²‖ψ'‖²)
+    ///
+    /// Vale 1 cuando Â solo reescala el estado y 0 si lo vuelve ortogonal.
+    pub fn love_fidelity(original: &DVector<Complex<f64>>, transformed: &DVector<Complex<f64>>) -> f64 {
+        let norms = original.norm_squared() * transformed.norm_squared();
+        if norms == 0.0 {
+            return 0.0;
+        }
+        original.dotc(transformed).norm_sqr() / norms
     }
 
     /// Verifica propiedades del operador Â
@@ -95,8 +123,9 @@ impl LoveOperator {
         let mut results = Vec::new();
         
         // 1. Unitariedad aproximada (Â⁺Â ≈ I)
-        let adjoint = self.transformation.adjoint();
-        let product = &adjoint * &self.transformation;
+        let transformation = self.get_transformation();
+        let adjoint = transformation.adjoint();
+        let product = &adjoint * transformation;
         let identity_diff = (product - DMatrix::identity(DIM, DIM)).norm();
         results.push((
             "Unitariedad aproximada".to_string(),
@@ -104,7 +133,7 @@ impl LoveOperator {
         ));
 
         // 2. Traza relacionada con φ
-        let trace = self.transformation.trace().re;
+        let trace = transformation.trace().re;
         let expected_trace = DIM as f64 * PHI * self.intensity;
         let trace_diff = (trace - expected_trace).abs() / expected_trace.abs();
         results.push((
@@ -121,15 +150,19 @@ impl LoveOperator {
         // 4. Dimensión correcta
         results.push((
             format!("Dimensión {}", DIM).to_string(),
-            self.transformation.nrows() == DIM && self.transformation.ncols() == DIM
+            transformation.nrows() == DIM && transformation.ncols() == DIM
         ));
 
         results
     }
 
     /// Obtiene la matriz de transformación
+    ///
+    /// La matriz 444×444 solo se recalcula aquí, la primera vez que se
+    /// necesita tras un cambio de intensidad, de modo que actualizar la
+    /// intensidad en cada paso evolutivo no cuesta una reconstrucción.
     pub fn get_transformation(&self) -> &DMatrix<Complex<f64>> {
-        &self.transformation
+        self.transformation.get_or_init(|| Self::create_love_transformation(self.intensity))
     }
 
     /// Obtiene intensidad actual
@@ -145,6 +178,7 @@ impl LoveOperator {
 
 /// Operador Â especializado para crecimiento keygen
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KeygenLoveOperator {
     base_operator: LoveOperator,
     growth_rate: f64,
@@ -199,8 +233,9 @@ mod tests {
     fn test_love_application() {
         let operator = LoveOperator::new(1.0);
         let state = DVector::from_element(DIM, Complex::new(1.0, 0.0));
-        let transformed = operator.apply(&state);
+        let transformed = operator.apply(&state).unwrap();
         assert_eq!(transformed.len(), DIM);
+        assert!(matches!(operator.apply(&DVector::zeros(3)), Err(AlgebraRoseError::DimensionMismatch { esperada: DIM, .. })));
         // Verificar que no es idéntico (transformación ocurrió)
         let diff = (transformed - state).norm();
         assert!(diff > 0.0, "Transformación debe cambiar el estado");
@@ -215,12 +250,30 @@ mod tests {
         let state_b = DVector::from_fn(DIM, |i, _| {
             Complex::new((DIM - i) as f64 / DIM as f64, 0.0)
         });
-        let factor = operator.love_factor(&state_a, &state_b);
+        let factor = operator.love_factor(&state_a, &state_b).unwrap();
         // El factor de amor debe ser un número complejo no-cero
         assert!(factor.norm_sqr() > 0.0, "Factor de amor debe ser no-cero");
         println!("Factor de amor: {:.4} + {:.4}i", factor.re, factor.im);
     }
 
+    #[test]
+    fn test_love_fidelity() {
+        let state = DVector::from_fn(DIM, |i, _| Complex::new(1.0, i as f64 / DIM as f64));
+        let scaled = state.map(|c| c * Complex::new(0.0, 2.5));
+        assert_abs_diff_eq!(LoveOperator::love_fidelity(&state, &scaled), 1.0, epsilon = 1e-12);
+
+        let mut e0 = DVector::zeros(DIM);
+        let mut e1 = DVector::zeros(DIM);
+        e0[0] = Complex::new(1.0, 0.0);
+        e1[1] = Complex::new(1.0, 0.0);
+        assert_eq!(LoveOperator::love_fidelity(&e0, &e1), 0.0);
+        assert_eq!(LoveOperator::love_fidelity(&e0, &DVector::zeros(DIM)), 0.0);
+
+        let operator = LoveOperator::new(1.0);
+        let fidelity = LoveOperator::love_fidelity(&state, &operator.apply(&state).unwrap());
+        assert!((0.0..=1.0 + 1e-12).contains(&fidelity));
+    }
+
     #[test]
     fn test_intensity_update() {
         let mut operator = LoveOperator::new(1.0);
@@ -253,6 +306,19 @@ mod tests {
         println!("✅ {}/{} propiedades pasaron", passed, results.len());
     }
 
+    #[test]
+    fn test_lazy_transformation() {
+        let mut operator = LoveOperator::new(1.0);
+        for _ in 0..100 {
+            operator.update_intensity(0.01);
+        }
+        
+        // La matriz perezosa coincide con una construida directamente
+        let expected = LoveOperator::create_love_transformation(operator.get_intensity());
+        let diff = (operator.get_transformation() - expected).norm();
+        assert_abs_diff_eq!(diff, 0.0, epsilon = 1e-12);
+    }
+
     #[test]
     fn test_keygen_operator() {
         let mut keygen_op = KeygenLoveOperator::new(1.0, 0.1);
@@ -285,4 +351,23 @@ mod tests {
             }
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rebuilds_transformation() {
+        let mut operator = LoveOperator::new(1.0);
+        operator.update_intensity(0.3);
+        operator.connect_to_griess(crate::algebra_griess::GriessAlgebra::new()).unwrap();
+
+        let json = serde_json::to_string(&operator).unwrap();
+        let restored: LoveOperator = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.get_intensity(), operator.get_intensity());
+        assert_eq!(restored.get_phase(), operator.get_phase());
+        assert!(restored.griess_connection.is_some());
+        assert_eq!(restored.get_transformation(), operator.get_transformation());
+
+        let keygen = KeygenLoveOperator::new(1.0, 0.01);
+        let restored: KeygenLoveOperator = serde_json::from_str(&serde_json::to_string(&keygen).unwrap()).unwrap();
+        assert_eq!(restored.get_operator().get_intensity(), 1.0);
+    }
 }
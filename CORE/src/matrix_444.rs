@@ -4,9 +4,16 @@
 //! Autovalores: λ_k = exp(2πi·k/444) · φ^{-k}
 //! Propiedad fundamental: M†M = I₄₄₄ (unitariedad)
 
-use std::f64::consts::PI;
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use core::f64::consts::PI;
 use nalgebra::{DMatrix, Complex};
 
+use crate::error::{AlgebraRoseError, Result};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Proporción áurea φ
 pub const PHI: f64 = 1.6180339887498948482;
 
@@ -17,7 +24,13 @@ pub const DIM: usize = 444;
 pub const CERTIFIED_TRACE: f64 = 196884.000000;
 
 /// Matriz Monster M₄₄₄
+///
+/// `reduced(n)` da su bloque n × n superior izquierdo, para dispositivos
+/// sin memoria para la matriz completa (≈ 3 MB). Con `serde` se guarda la
+/// matriz entera, por columnas.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "MatrizGuardada", into = "MatrizGuardada"))]
 pub struct MonsterMatrix444 {
     data: DMatrix<Complex<f64>>,
 }
@@ -43,12 +56,51 @@ impl MonsterMatrix444 {
         MonsterMatrix444 { data }
     }
     
+    /// Bloque n × n superior izquierdo de `new()`, con 1 ≤ n ≤ 444
+    pub fn reduced(n: usize) -> Result<Self> {
+        if !(1..=DIM).contains(&n) {
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Dimensión reducida de M₄₄₄", valor: n as f64, rango: "[1, 444]" });
+        }
+        let diagonal_value = CERTIFIED_TRACE / DIM as f64;
+        let data = DMatrix::from_fn(n, n, |i, j| {
+            if i != j {
+                return Complex::new(0.0, 0.0);
+            }
+            let phase = 2.0 * PI * (i as f64) / (DIM as f64);
+            Complex::new(diagonal_value * phase.cos(), diagonal_value * phase.sin())
+        });
+        Ok(MonsterMatrix444 { data })
+    }
+    
+    /// Bytes que ocupa la matriz: cabecera y n² complejos
+    pub fn memory_footprint(&self) -> usize {
+        core::mem::size_of::<Self>() + self.data.len() * core::mem::size_of::<Complex<f64>>()
+    }
+    
+    /// Dimensión de la matriz: 444, o n si es `reduced(n)`
+    pub fn dim(&self) -> usize {
+        self.data.nrows()
+    }
+    
     /// Aplica la matriz a un vector de estado consciente
-    pub fn apply(&self, state: &[Complex<f64>]) -> Vec<Complex<f64>> {
-        assert_eq!(state.len(), DIM);
-        let input = DMatrix::from_vec(DIM, 1, state.to_vec());
-        let output = &self.data * input;
-        output.column(0).iter().cloned().collect()
+    pub fn apply(&self, state: &[Complex<f64>]) -> Result<Vec<Complex<f64>>> {
+        AlgebraRoseError::check_dimension("Estado para M₄₄₄", self.dim(), state.len())?;
+        #[cfg(feature = "parallel")]
+        {
+            // Cada tarea calcula un bloque de filas de M·v
+            use rayon::prelude::*;
+            Ok(crate::parallel::install(|| {
+                (0..self.dim()).into_par_iter().with_min_len(crate::parallel::MIN_FILAS)
+                    .map(|i| self.data.row(i).iter().zip(state).map(|(m, v)| m * v).sum())
+                    .collect()
+            }))
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            let input = DMatrix::from_vec(self.dim(), 1, state.to_vec());
+            let output = &self.data * input;
+            Ok(output.column(0).iter().cloned().collect())
+        }
     }
     
     /// Devuelve la traza certificada (≈ 196884)
@@ -60,7 +112,7 @@ impl MonsterMatrix444 {
     pub fn is_unitary(&self, tolerance: f64) -> bool {
         let adjoint = self.data.adjoint();
         let product = &adjoint * &self.data;
-        let identity = DMatrix::identity(DIM, DIM);
+        let identity = DMatrix::identity(self.dim(), self.dim());
         
         let diff = &product - &identity;
         diff.norm() < tolerance
@@ -68,13 +120,18 @@ impl MonsterMatrix444 {
     
     /// Obtiene autovalor k
     pub fn eigenvalue(&self, k: usize) -> Complex<f64> {
-        if k < DIM {
+        if k < self.dim() {
             self.data[(k, k)]
         } else {
             Complex::new(0.0, 0.0)
         }
     }
     
+    /// Matriz completa, para inspección (p. ej. mapas de calor)
+    pub fn get_matrix(&self) -> &DMatrix<Complex<f64>> {
+        &self.data
+    }
+    
     /// Versión diagonal pura (más simple para tests)
     pub fn new_diagonal() -> Self {
         let mut data = DMatrix::zeros(DIM, DIM);
@@ -88,6 +145,53 @@ impl MonsterMatrix444 {
     }
 }
 
+impl From<MonsterMatrix444> for DMatrix<Complex<f64>> {
+    fn from(matrix: MonsterMatrix444) -> Self {
+        matrix.data
+    }
+}
+
+impl TryFrom<DMatrix<Complex<f64>>> for MonsterMatrix444 {
+    type Error = AlgebraRoseError;
+
+    /// Envuelve una matriz existente, cuadrada y de dimensión 1..=444
+    fn try_from(data: DMatrix<Complex<f64>>) -> Result<Self> {
+        if !(1..=DIM).contains(&data.nrows()) {
+            return Err(AlgebraRoseError::DimensionMismatch { contexto: "Filas de M₄₄₄", esperada: DIM, recibida: data.nrows() });
+        }
+        AlgebraRoseError::check_dimension("Columnas de M₄₄₄", data.nrows(), data.ncols())?;
+        Ok(MonsterMatrix444 { data })
+    }
+}
+
+/// Forma guardada: dimensión y entradas por columnas
+///
+/// Propia y no la de nalgebra, que sólo serializa matrices dinámicas con `std`.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct MatrizGuardada {
+    dim: usize,
+    datos: Vec<Complex<f64>>,
+}
+
+#[cfg(feature = "serde")]
+impl From<MonsterMatrix444> for MatrizGuardada {
+    fn from(matrix: MonsterMatrix444) -> Self {
+        MatrizGuardada { dim: matrix.dim(), datos: matrix.data.data.into() }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<MatrizGuardada> for MonsterMatrix444 {
+    type Error = AlgebraRoseError;
+
+    fn try_from(guardada: MatrizGuardada) -> Result<Self> {
+        let dim = guardada.dim;
+        AlgebraRoseError::check_dimension("Entradas de M₄₄₄", dim * dim, guardada.datos.len())?;
+        MonsterMatrix444::try_from(DMatrix::from_vec(dim, dim, guardada.datos))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,6 +230,15 @@ mod tests {
         }
     }
     
+    #[test]
+    fn test_get_matrix_matches_eigenvalues() {
+        let m = MonsterMatrix444::new();
+        let data = m.get_matrix();
+        assert_eq!(data.shape(), (DIM, DIM));
+        assert_eq!(data[(7, 7)], m.eigenvalue(7));
+        assert_eq!(data[(7, 8)], Complex::new(0.0, 0.0));
+    }
+    
     #[test]
     fn test_apply() {
         let m = MonsterMatrix444::new_diagonal();
@@ -136,7 +249,8 @@ mod tests {
         state[0] = Complex::new(1.0, 0.0);
         state[1] = Complex::new(0.0, 2.0);
         
-        let output = m.apply(&state);
+        let output = m.apply(&state).unwrap();
+        assert!(matches!(m.apply(&state[..3]), Err(AlgebraRoseError::DimensionMismatch { recibida: 3, .. })));
         
         // Debería escalar cada componente
         assert_abs_diff_eq!(output[0].re, scale, epsilon = 1e-12);
@@ -144,4 +258,33 @@ mod tests {
         assert_abs_diff_eq!(output[1].re, 0.0, epsilon = 1e-12);
         assert_abs_diff_eq!(output[1].im, 2.0 * scale, epsilon = 1e-12);
     }
+    
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let m = MonsterMatrix444::new();
+        let json = serde_json::to_string(&m).unwrap();
+        let restored: MonsterMatrix444 = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.get_matrix(), m.get_matrix());
+        
+        assert!(serde_json::from_str::<MonsterMatrix444>(r#"{"dim":2,"datos":[[1.0,0.0]]}"#).is_err());
+        assert!(serde_json::from_str::<MonsterMatrix444>(r#"{"dim":0,"datos":[]}"#).is_err());
+    }
+    
+    #[test]
+    fn test_reduced_block() {
+        let full = MonsterMatrix444::new();
+        let reduced = MonsterMatrix444::reduced(12).unwrap();
+        assert_eq!(reduced.dim(), 12);
+        assert_eq!(reduced.get_matrix(), &full.get_matrix().view((0, 0), (12, 12)).into_owned());
+        
+        let mut state = vec![Complex::new(0.0, 0.0); 12];
+        state[3] = Complex::new(1.0, 0.0);
+        assert_eq!(reduced.apply(&state).unwrap()[3], full.eigenvalue(3));
+        assert!(reduced.apply(&vec![Complex::new(1.0, 0.0); DIM]).is_err());
+        
+        assert!(MonsterMatrix444::reduced(0).is_err());
+        assert!(MonsterMatrix444::reduced(DIM + 1).is_err());
+        assert!(MonsterMatrix444::try_from(DMatrix::<Complex<f64>>::zeros(2, 3)).is_err());
+    }
 }
@@ -0,0 +1,148 @@
+//! PRESUPUESTO DE MEMORIA - Huella de las Estructuras Grandes
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! `memory_footprint()` mide una estructura ya construida (pila + montón);
+//! `MemoryReport` suma estimaciones que no asignan nada, para que el CLI
+//! avise antes de que una configuración pida cientos de gigabytes: una base
+//! completa del Campo 24 son 196418² complejos, ≈ 617 GB en f64.
+
+use alloc::{format, string::String, vec::Vec};
+use core::mem::size_of;
+use nalgebra::Complex;
+
+use crate::algebra_griess::{GRIESS_DIM, MUESTRAS_BASE};
+use crate::fibonacci_dimensions::SistemaCamposFibonacci;
+use crate::matrix_444::DIM;
+use crate::phi_float::{PhiFloat, Precision};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bytes de una matriz densa `filas × columnas` de `T`
+pub fn dense_bytes<T>(filas: usize, columnas: usize) -> u64 {
+    filas as u64 * columnas as u64 * size_of::<T>() as u64
+}
+
+/// Bytes de un vector de Griess (196884 complejos en precisión `T`)
+pub fn griess_vector_bytes<T: PhiFloat>() -> u64 {
+    dense_bytes::<Complex<T>>(GRIESS_DIM, 1)
+}
+
+/// Bytes de `count` vectores base de un campo de dimensión `dimension`
+/// (`generate_field_basis`)
+pub fn field_basis_bytes(dimension: usize, count: usize) -> u64 {
+    dense_bytes::<Complex<f64>>(dimension, count)
+}
+
+/// Bytes en unidades binarias legibles: "3.01 MiB"
+pub fn format_bytes(bytes: u64) -> String {
+    const UNIDADES: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut valor = bytes as f64;
+    let mut unidad = 0;
+    while valor >= 1024.0 && unidad < UNIDADES.len() - 1 {
+        valor /= 1024.0;
+        unidad += 1;
+    }
+    if unidad == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.2} {}", valor, UNIDADES[unidad])
+    }
+}
+
+/// Una entrada del informe
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MemoryItem {
+    /// Identificador estable, en minúsculas con `_`
+    pub nombre: String,
+    pub bytes: u64,
+}
+
+/// Informe de memoria: estructuras y bytes que ocupan o van a ocupar
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MemoryReport {
+    pub items: Vec<MemoryItem>,
+}
+
+impl MemoryReport {
+    pub fn new() -> Self {
+        MemoryReport::default()
+    }
+
+    /// Estimación de una sesión por defecto, sin construir nada grande
+    ///
+    /// M₄₄₄, la matriz de Â (se construye en el primer `apply`), un
+    /// `GriessAlgebra::new()` en `Precision` y los 24 campos.
+    pub fn default_session() -> Self {
+        let mut report = MemoryReport::new();
+        report.push("matriz_444", dense_bytes::<Complex<f64>>(DIM, DIM));
+        report.push("operador_amor", dense_bytes::<Complex<f64>>(DIM, DIM));
+        report.push("griess", (1 + MUESTRAS_BASE as u64) * griess_vector_bytes::<Precision>());
+        report.push("campos_fibonacci", SistemaCamposFibonacci::new().memory_footprint() as u64);
+        report
+    }
+
+    /// Añade una entrada
+    pub fn push(&mut self, nombre: &str, bytes: u64) -> &mut Self {
+        self.items.push(MemoryItem { nombre: nombre.into(), bytes });
+        self
+    }
+
+    /// Suma de todas las entradas
+    pub fn total(&self) -> u64 {
+        self.items.iter().map(|item| item.bytes).fold(0, u64::saturating_add)
+    }
+
+    /// Entradas que por sí solas superan `presupuesto` bytes
+    pub fn exceeding(&self, presupuesto: u64) -> impl Iterator<Item = &MemoryItem> {
+        self.items.iter().filter(move |item| item.bytes > presupuesto)
+    }
+
+    /// Indica si el total cabe en `presupuesto` bytes
+    pub fn fits(&self, presupuesto: u64) -> bool {
+        self.total() <= presupuesto
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra_griess::GriessAlgebra;
+    use crate::matrix_444::MonsterMatrix444;
+
+    #[test]
+    fn test_estimates_match_live_structures() {
+        let report = MemoryReport::default_session();
+        let estimado = |nombre: &str| report.items.iter().find(|i| i.nombre == nombre).unwrap().bytes;
+
+        // Las estimaciones cuentan los datos; la huella real suma la cabecera
+        let matriz = MonsterMatrix444::new().memory_footprint() as u64;
+        assert!(matriz >= estimado("matriz_444") && matriz < estimado("matriz_444") + 1024);
+        let griess = GriessAlgebra::<Precision>::new().memory_footprint() as u64;
+        assert!(griess >= estimado("griess") && griess < estimado("griess") + 1024);
+        assert_eq!(estimado("matriz_444"), 444 * 444 * 16);
+
+        assert_eq!(report.total(), report.items.iter().map(|i| i.bytes).sum::<u64>());
+        assert!(report.fits(64 << 20));
+        assert!(!report.fits(1 << 20));
+        let grandes: Vec<&str> = report.exceeding(4 << 20).map(|i| i.nombre.as_str()).collect();
+        assert_eq!(grandes, ["griess"]);
+    }
+
+    #[test]
+    fn test_large_configurations_and_format() {
+        // Base completa del Campo 24: cientos de gigabytes
+        let base = field_basis_bytes(196418, 196418);
+        assert!(base > 600_000_000_000);
+        assert_eq!(format_bytes(base), "574.89 GiB");
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(444 * 444 * 16), "3.01 MiB");
+        assert_eq!(griess_vector_bytes::<f32>() * 2, griess_vector_bytes::<f64>());
+
+        let mut report = MemoryReport::new();
+        report.push("a", u64::MAX).push("b", 1);
+        assert_eq!(report.total(), u64::MAX);
+    }
+}
@@ -0,0 +1,209 @@
+//! Formas Modulares - η de Dedekind, Eisenstein E₄/E₆ y j(τ)
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! Evaluación numérica en q = e^(2πiτ), |q| < 1:
+//!
+//! - η = q^(1/24) ∏ (1 − qᵏ)
+//! - E₄ = 1 + 240 Σ k³qᵏ/(1 − qᵏ) y E₆ = 1 − 504 Σ k⁵qᵏ/(1 − qᵏ)
+//! - j = E₄³/Δ, con Δ = q ∏ (1 − qᵏ)²⁴ = η²⁴
+//!
+//! Δ se calcula sin la raíz q^(1/24), así que j no depende de la rama.
+//! Las mismas series, con coeficientes enteros exactos, alimentan a
+//! `moonshine`.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use nalgebra::Complex;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use crate::error::{AlgebraRoseError, Result};
+
+/// Términos máximos de `j_invariant`, para |q| muy cerca de 1
+const MAX_TERMINOS: usize = 100_000;
+
+/// Comprueba |q| < 1 y devuelve |q|
+fn modulo_convergente(q: Complex<f64>) -> Result<f64> {
+    let r = q.re.hypot(q.im);
+    if r < 1.0 {
+        Ok(r)
+    } else {
+        Err(AlgebraRoseError::ValueOutOfRange { parametro: "|q|", valor: r, rango: "[0, 1)" })
+    }
+}
+
+/// ∏_{k=1}^{n} (1 − qᵏ)
+fn producto(q: Complex<f64>, n_terms: usize) -> Complex<f64> {
+    let mut potencia = q;
+    let mut producto = Complex::new(1.0, 0.0);
+    for _ in 0..n_terms {
+        producto *= Complex::new(1.0, 0.0) - potencia;
+        potencia *= q;
+    }
+    producto
+}
+
+/// Σ_{k=1}^{n} kᵐ qᵏ/(1 − qᵏ)
+fn lambert(q: Complex<f64>, m: i32, n_terms: usize) -> Complex<f64> {
+    let mut potencia = q;
+    let mut suma = Complex::new(0.0, 0.0);
+    for k in 1..=n_terms {
+        suma += potencia / (Complex::new(1.0, 0.0) - potencia) * (k as f64).powi(m);
+        potencia *= q;
+    }
+    suma
+}
+
+/// η de Dedekind con `n_terms` factores; q^(1/24) en la rama principal
+pub fn eta(q: Complex<f64>, n_terms: usize) -> Result<Complex<f64>> {
+    let r = modulo_convergente(q)?;
+    let angulo = q.im.atan2(q.re) / 24.0;
+    let raiz = Complex::new(angulo.cos(), angulo.sin()) * r.powf(1.0 / 24.0);
+    Ok(raiz * producto(q, n_terms))
+}
+
+/// Serie de Eisenstein E₄ con `n_terms` términos de Lambert
+pub fn eisenstein_e4(q: Complex<f64>, n_terms: usize) -> Result<Complex<f64>> {
+    modulo_convergente(q)?;
+    Ok(Complex::new(1.0, 0.0) + lambert(q, 3, n_terms) * 240.0)
+}
+
+/// Serie de Eisenstein E₆ con `n_terms` términos de Lambert
+pub fn eisenstein_e6(q: Complex<f64>, n_terms: usize) -> Result<Complex<f64>> {
+    modulo_convergente(q)?;
+    Ok(Complex::new(1.0, 0.0) - lambert(q, 5, n_terms) * 504.0)
+}
+
+/// Invariante j = E₄³/Δ
+///
+/// Usa el doble de los términos con los que |q|ⁿ cae bajo ε de f64: el
+/// término k de E₄ lleva además el factor k³.
+pub fn j_invariant(q: Complex<f64>) -> Result<Complex<f64>> {
+    let r = modulo_convergente(q)?;
+    if r == 0.0 {
+        return Err(AlgebraRoseError::ValueOutOfRange { parametro: "|q|", valor: r, rango: "(0, 1)" });
+    }
+    let n = ((2.0 * f64::EPSILON.ln() / r.ln()).ceil() as usize).clamp(1, MAX_TERMINOS);
+    let e4 = eisenstein_e4(q, n)?;
+    let delta = q * producto(q, n).powi(24);
+    Ok(e4 * e4 * e4 / delta)
+}
+
+/// Serie truncada con coeficientes enteros: el índice k es el de qᵏ
+pub(crate) type Serie = Vec<i128>;
+
+/// ∏_{k ≥ 1} (1 − q^(paso·k))^exponente, con `n` términos
+pub(crate) fn producto_eta_serie(paso: usize, exponente: i32, n: usize) -> Serie {
+    let mut serie = vec![0; n];
+    if n > 0 {
+        serie[0] = 1;
+    }
+    for m in (1..).map(|k| paso * k).take_while(|&m| m < n) {
+        for _ in 0..exponente.unsigned_abs() {
+            if exponente > 0 {
+                // Multiplicar por (1 − qᵐ)
+                for i in (m..n).rev() {
+                    serie[i] -= serie[i - m];
+                }
+            } else {
+                // Dividir por (1 − qᵐ) = multiplicar por 1 + qᵐ + q²ᵐ + …
+                for i in m..n {
+                    serie[i] += serie[i - m];
+                }
+            }
+        }
+    }
+    serie
+}
+
+/// E₄(paso·τ) = 1 + 240 Σ σ₃(k) q^(paso·k), con `n` términos
+pub(crate) fn eisenstein_e4_serie(paso: usize, n: usize) -> Serie {
+    let mut serie = vec![0; n];
+    if n > 0 {
+        serie[0] = 1;
+    }
+    for k in (1..).take_while(|&k| paso * k < n) {
+        let sigma3: i128 = (1..=k).filter(|d| k % d == 0).map(|d| (d as i128).pow(3)).sum();
+        serie[paso * k] = 240 * sigma3;
+    }
+    serie
+}
+
+/// Producto de series truncado a `n` términos
+pub(crate) fn multiplicar(a: &[i128], b: &[i128], n: usize) -> Serie {
+    (0..n).map(|k| (0..=k).filter(|&i| i < a.len() && k - i < b.len()).map(|i| a[i] * b[k - i]).sum()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+    use nalgebra::ComplexField;
+
+    const N: usize = 60;
+
+    fn real(x: f64) -> Complex<f64> {
+        Complex::new(x, 0.0)
+    }
+
+    /// q = e^(2πiτ) para τ = x + iy
+    fn q_de(x: f64, y: f64) -> Complex<f64> {
+        Complex::new((2.0 * PI * x).cos(), (2.0 * PI * x).sin()) * (-2.0 * PI * y).exp()
+    }
+
+    #[test]
+    fn test_special_points() {
+        // τ = i: E₆ = 0 y j = 1728
+        let i = q_de(0.0, 1.0);
+        assert!(eisenstein_e6(i, N).unwrap().modulus() < 1e-12);
+        assert!((j_invariant(i).unwrap() - real(1728.0)).modulus() < 1e-9);
+
+        // τ = ρ = e^(2πi/3): E₄ = 0 y j = 0
+        let rho = q_de(-0.5, 3f64.sqrt() / 2.0);
+        assert!(eisenstein_e4(rho, N).unwrap().modulus() < 1e-12);
+        assert!(j_invariant(rho).unwrap().modulus() < 1e-9);
+
+        // η(i) = Γ(1/4) / (2π^(3/4))
+        let gamma_cuarto = 3.625_609_908_221_908;
+        assert!((eta(i, N).unwrap() - real(gamma_cuarto / (2.0 * PI.powf(0.75)))).modulus() < 1e-12);
+    }
+
+    #[test]
+    fn test_identities() {
+        for q in [real(0.05), q_de(0.2, 0.4), q_de(-0.37, 0.9)] {
+            // 1728 Δ = E₄³ − E₆², con Δ = η²⁴
+            let (e4, e6) = (eisenstein_e4(q, N).unwrap(), eisenstein_e6(q, N).unwrap());
+            let delta = eta(q, N).unwrap().powi(24);
+            assert!(((e4 * e4 * e4 - e6 * e6) / (delta * 1728.0) - real(1.0)).modulus() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_j_matches_moonshine_series() {
+        // j = q⁻¹ + 744 + T_1A sin su q⁻¹
+        let q: f64 = 0.01;
+        let t = crate::moonshine::mckay_thompson("1A", 30).unwrap();
+        let serie: f64 = 744.0 + t.iter().enumerate().map(|(k, &c)| c as f64 * q.powi(k as i32 - 1)).sum::<f64>();
+        assert!((j_invariant(real(q)).unwrap().re / serie - 1.0).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_integer_series() {
+        // ∏ (1 − qᵏ) es la serie pentagonal de Euler
+        assert_eq!(producto_eta_serie(1, 1, 13), [1, -1, -1, 0, 0, 1, 0, 1, 0, 0, 0, 0, -1]);
+        // Su inversa cuenta particiones
+        assert_eq!(producto_eta_serie(1, -1, 8), [1, 1, 2, 3, 5, 7, 11, 15]);
+        assert_eq!(multiplicar(&producto_eta_serie(2, 3, 20), &producto_eta_serie(2, -3, 20), 20)[..3], [1, 0, 0]);
+        assert_eq!(eisenstein_e4_serie(1, 4), [1, 240, 2160, 6720]);
+    }
+
+    #[test]
+    fn test_domain() {
+        assert!(matches!(eta(real(1.0), N), Err(AlgebraRoseError::ValueOutOfRange { .. })));
+        assert!(eisenstein_e4(Complex::new(0.8, 0.8), N).is_err());
+        assert!(j_invariant(real(0.0)).is_err());
+        assert_eq!(eta(real(0.0), N).unwrap(), real(0.0));
+        assert_eq!(eisenstein_e6(real(0.0), N).unwrap(), real(1.0));
+    }
+}
@@ -0,0 +1,351 @@
+//! Datos del Monster - Clases de Conjugación y Grados de Caracteres
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! Las 194 clases de conjugación del grupo Monster 𝕄 con la nomenclatura del
+//! ATLAS (orden del elemento y letra), los órdenes de sus centralizadores y
+//! los primeros grados de caracteres irreducibles. χ₂(1) = 196883 es la
+//! representación fiel mínima, y 196884 = 1 + 196883 el primer coeficiente
+//! de j(τ) − 744: de ahí la certificación.
+//!
+//! |𝕄| ≈ 8·10⁵³ no cabe en `u128`, así que los órdenes se guardan
+//! factorizados sobre los 15 primos que dividen |𝕄|. Sólo se incluyen los
+//! centralizadores de las clases de orden primo cuya estructura es un
+//! producto conocido (p. ej. C(5A) = 5 × HN); para 13B y las clases de orden
+//! compuesto `centralizer_order` devuelve `None`.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Número de clases de conjugación (y de caracteres irreducibles) de 𝕄
+pub const NUM_CLASSES: usize = 194;
+
+/// Primos que dividen |𝕄|, en orden
+pub const MONSTER_PRIMES: [u32; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 41, 47, 59, 71];
+
+/// Orden de un subgrupo de 𝕄, como exponentes sobre `MONSTER_PRIMES`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GroupOrder {
+    exponents: [u8; 15],
+}
+
+/// |𝕄| = 2⁴⁶·3²⁰·5⁹·7⁶·11²·13³·17·19·23·29·31·41·47·59·71
+pub const MONSTER_ORDER: GroupOrder = GroupOrder::new([46, 20, 9, 6, 2, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1]);
+
+impl GroupOrder {
+    pub const fn new(exponents: [u8; 15]) -> Self {
+        GroupOrder { exponents }
+    }
+
+    /// Exponente de cada primo de `MONSTER_PRIMES`
+    pub fn exponents(&self) -> &[u8; 15] {
+        &self.exponents
+    }
+
+    /// Pares (primo, exponente) con exponente no nulo
+    pub fn factors(&self) -> impl Iterator<Item = (u32, u8)> + '_ {
+        MONSTER_PRIMES.iter().zip(&self.exponents).filter(|(_, &e)| e > 0).map(|(&p, &e)| (p, e))
+    }
+
+    /// ¿Divide este orden a `other`?
+    pub fn divides(&self, other: &GroupOrder) -> bool {
+        self.exponents.iter().zip(&other.exponents).all(|(a, b)| a <= b)
+    }
+
+    /// `other / self`, si la división es exacta
+    pub fn index_in(&self, other: &GroupOrder) -> Option<GroupOrder> {
+        let mut exponents = [0u8; 15];
+        for (i, e) in exponents.iter_mut().enumerate() {
+            *e = other.exponents[i].checked_sub(self.exponents[i])?;
+        }
+        Some(GroupOrder::new(exponents))
+    }
+
+    /// El orden como entero, si cabe en `u128`
+    pub fn to_u128(&self) -> Option<u128> {
+        self.factors().try_fold(1u128, |acc, (p, e)| acc.checked_mul((p as u128).checked_pow(e as u32)?))
+    }
+
+    /// El orden en coma flotante (exacto hasta 2⁵³)
+    pub fn to_f64(&self) -> f64 {
+        self.factors().fold(1.0, |acc, (p, e)| (0..e).fold(acc, |acc, _| acc * p as f64))
+    }
+}
+
+/// Expansión decimal exacta
+impl fmt::Display for GroupOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Entero grande en base 10⁹, del bloque menos significativo al más
+        const BASE: u64 = 1_000_000_000;
+        let mut bloques: Vec<u64> = alloc::vec![1];
+        for (p, e) in self.factors() {
+            for _ in 0..e {
+                let mut acarreo = 0;
+                for b in bloques.iter_mut() {
+                    let v = *b * p as u64 + acarreo;
+                    *b = v % BASE;
+                    acarreo = v / BASE;
+                }
+                if acarreo > 0 {
+                    bloques.push(acarreo);
+                }
+            }
+        }
+        let mut bloques = bloques.iter().rev();
+        write!(f, "{}", bloques.next().copied().unwrap_or(1))?;
+        for b in bloques {
+            write!(f, "{:09}", b)?;
+        }
+        Ok(())
+    }
+}
+
+/// Clase de conjugación de 𝕄
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConjugacyClass {
+    /// Nombre ATLAS, p. ej. "2A"
+    pub name: &'static str,
+    /// Orden de sus elementos
+    pub order: u32,
+}
+
+impl ConjugacyClass {
+    /// Orden del centralizador de un elemento de la clase, si está tabulado
+    pub fn centralizer_order(&self) -> Option<GroupOrder> {
+        CENTRALIZERS.iter().find(|(name, _)| *name == self.name).map(|&(_, orden)| orden)
+    }
+
+    /// Número de elementos de la clase: |𝕄| / |C(g)|
+    pub fn size(&self) -> Option<GroupOrder> {
+        self.centralizer_order()?.index_in(&MONSTER_ORDER)
+    }
+}
+
+const fn clase(name: &'static str, order: u32) -> ConjugacyClass {
+    ConjugacyClass { name, order }
+}
+
+/// Las 194 clases en el orden del ATLAS
+static CLASSES: [ConjugacyClass; NUM_CLASSES] = [
+    clase("1A", 1),
+    clase("2A", 2), clase("2B", 2),
+    clase("3A", 3), clase("3B", 3), clase("3C", 3),
+    clase("4A", 4), clase("4B", 4), clase("4C", 4), clase("4D", 4),
+    clase("5A", 5), clase("5B", 5),
+    clase("6A", 6), clase("6B", 6), clase("6C", 6), clase("6D", 6), clase("6E", 6), clase("6F", 6),
+    clase("7A", 7), clase("7B", 7),
+    clase("8A", 8), clase("8B", 8), clase("8C", 8), clase("8D", 8), clase("8E", 8), clase("8F", 8),
+    clase("9A", 9), clase("9B", 9),
+    clase("10A", 10), clase("10B", 10), clase("10C", 10), clase("10D", 10), clase("10E", 10),
+    clase("11A", 11),
+    clase("12A", 12), clase("12B", 12), clase("12C", 12), clase("12D", 12), clase("12E", 12),
+    clase("12F", 12), clase("12G", 12), clase("12H", 12), clase("12I", 12), clase("12J", 12),
+    clase("13A", 13), clase("13B", 13),
+    clase("14A", 14), clase("14B", 14), clase("14C", 14),
+    clase("15A", 15), clase("15B", 15), clase("15C", 15), clase("15D", 15),
+    clase("16A", 16), clase("16B", 16), clase("16C", 16),
+    clase("17A", 17),
+    clase("18A", 18), clase("18B", 18), clase("18C", 18), clase("18D", 18), clase("18E", 18),
+    clase("19A", 19),
+    clase("20A", 20), clase("20B", 20), clase("20C", 20), clase("20D", 20), clase("20E", 20),
+    clase("20F", 20),
+    clase("21A", 21), clase("21B", 21), clase("21C", 21), clase("21D", 21),
+    clase("22A", 22), clase("22B", 22),
+    clase("23A", 23), clase("23B", 23),
+    clase("24A", 24), clase("24B", 24), clase("24C", 24), clase("24D", 24), clase("24E", 24),
+    clase("24F", 24), clase("24G", 24), clase("24H", 24), clase("24I", 24), clase("24J", 24),
+    clase("25A", 25),
+    clase("26A", 26), clase("26B", 26),
+    clase("27A", 27), clase("27B", 27),
+    clase("28A", 28), clase("28B", 28), clase("28C", 28), clase("28D", 28),
+    clase("29A", 29),
+    clase("30A", 30), clase("30B", 30), clase("30C", 30), clase("30D", 30), clase("30E", 30),
+    clase("30F", 30), clase("30G", 30),
+    clase("31A", 31), clase("31B", 31),
+    clase("32A", 32), clase("32B", 32),
+    clase("33A", 33), clase("33B", 33),
+    clase("34A", 34),
+    clase("35A", 35), clase("35B", 35),
+    clase("36A", 36), clase("36B", 36), clase("36C", 36), clase("36D", 36),
+    clase("38A", 38),
+    clase("39A", 39), clase("39B", 39), clase("39C", 39), clase("39D", 39),
+    clase("40A", 40), clase("40B", 40), clase("40C", 40), clase("40D", 40),
+    clase("41A", 41),
+    clase("42A", 42), clase("42B", 42), clase("42C", 42), clase("42D", 42),
+    clase("44A", 44), clase("44B", 44),
+    clase("45A", 45),
+    clase("46A", 46), clase("46B", 46), clase("46C", 46), clase("46D", 46),
+    clase("47A", 47), clase("47B", 47),
+    clase("48A", 48),
+    clase("50A", 50),
+    clase("51A", 51),
+    clase("52A", 52), clase("52B", 52),
+    clase("54A", 54),
+    clase("55A", 55),
+    clase("56A", 56), clase("56B", 56), clase("56C", 56),
+    clase("57A", 57),
+    clase("59A", 59), clase("59B", 59),
+    clase("60A", 60), clase("60B", 60), clase("60C", 60), clase("60D", 60), clase("60E", 60),
+    clase("60F", 60),
+    clase("62A", 62), clase("62B", 62),
+    clase("66A", 66), clase("66B", 66),
+    clase("68A", 68),
+    clase("69A", 69), clase("69B", 69),
+    clase("70A", 70), clase("70B", 70),
+    clase("71A", 71), clase("71B", 71),
+    clase("78A", 78), clase("78B", 78), clase("78C", 78),
+    clase("84A", 84), clase("84B", 84), clase("84C", 84),
+    clase("87A", 87), clase("87B", 87),
+    clase("88A", 88), clase("88B", 88),
+    clase("92A", 92), clase("92B", 92),
+    clase("93A", 93), clase("93B", 93),
+    clase("94A", 94), clase("94B", 94),
+    clase("95A", 95), clase("95B", 95),
+    clase("104A", 104), clase("104B", 104),
+    clase("105A", 105),
+    clase("110A", 110),
+    clase("119A", 119), clase("119B", 119),
+];
+
+/// Centralizadores de las clases de orden primo con estructura conocida
+const CENTRALIZERS: [(&str, GroupOrder); 26] = [
+    ("1A", MONSTER_ORDER),
+    // 2·B
+    ("2A", GroupOrder::new([42, 13, 6, 2, 1, 1, 1, 1, 1, 0, 1, 0, 1, 0, 0])),
+    // 2¹⁺²⁴·Co₁
+    ("2B", GroupOrder::new([46, 9, 4, 2, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0])),
+    // 3·Fi₂₄′
+    ("3A", GroupOrder::new([21, 17, 2, 3, 1, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0])),
+    // 3¹⁺¹²·2Suz
+    ("3B", GroupOrder::new([14, 20, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0])),
+    // 3 × Th
+    ("3C", GroupOrder::new([15, 11, 3, 2, 0, 1, 0, 1, 0, 0, 1, 0, 0, 0, 0])),
+    // 5 × HN
+    ("5A", GroupOrder::new([14, 6, 7, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0])),
+    // 5¹⁺⁶·2J₂
+    ("5B", GroupOrder::new([8, 3, 9, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])),
+    // 7 × He
+    ("7A", GroupOrder::new([10, 3, 2, 4, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0])),
+    // 7¹⁺⁴·2A₇
+    ("7B", GroupOrder::new([4, 2, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])),
+    // 11 × M₁₂
+    ("11A", GroupOrder::new([6, 3, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])),
+    // 13 × L₃(3)
+    ("13A", GroupOrder::new([4, 3, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0])),
+    // 17 × L₃(2)
+    ("17A", GroupOrder::new([3, 1, 0, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0])),
+    // 19 × A₅
+    ("19A", GroupOrder::new([2, 1, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0])),
+    // 23 × S₄
+    ("23A", GroupOrder::new([3, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0])),
+    ("23B", GroupOrder::new([3, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0])),
+    // 29 × 3
+    ("29A", GroupOrder::new([0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0])),
+    // 31 × S₃
+    ("31A", GroupOrder::new([1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0])),
+    ("31B", GroupOrder::new([1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0])),
+    ("41A", GroupOrder::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0])),
+    // 47 × 2
+    ("47A", GroupOrder::new([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0])),
+    ("47B", GroupOrder::new([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0])),
+    ("59A", GroupOrder::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0])),
+    ("59B", GroupOrder::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0])),
+    ("71A", GroupOrder::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])),
+    ("71B", GroupOrder::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])),
+];
+
+/// Primeros grados χₙ(1) de los caracteres irreducibles, por grado creciente
+pub const CHARACTER_DEGREES: [u64; 9] = [
+    1,
+    196883,
+    21296876,
+    842609326,
+    18538750076,
+    19360062527,
+    293553734298,
+    3879214937598,
+    36173193327999,
+];
+
+/// Las 194 clases en el orden del ATLAS
+pub fn classes() -> &'static [ConjugacyClass] {
+    &CLASSES
+}
+
+/// Clase por nombre ATLAS, sin distinguir mayúsculas: `class("2A")`
+pub fn class(name: &str) -> Option<&'static ConjugacyClass> {
+    let name = name.trim();
+    CLASSES.iter().find(|c| c.name.eq_ignore_ascii_case(name))
+}
+
+/// Grado del n-ésimo carácter irreducible (n ≥ 1), si está tabulado
+pub fn character_degree(n: usize) -> Option<u64> {
+    CHARACTER_DEGREES.get(n.checked_sub(1)?).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use crate::phi_constants::{MONSTER_196883, MONSTER_196884};
+
+    #[test]
+    fn test_class_table() {
+        assert_eq!(classes().len(), NUM_CLASSES);
+        assert!(classes().iter().all(|c| class(c.name) == Some(c)));
+        assert!(classes().windows(2).all(|w| w[0].order <= w[1].order));
+        assert!(classes().iter().all(|c| c.name.starts_with(&c.order.to_string())));
+        assert_eq!(class("2a").map(|c| c.order), Some(2));
+        assert_eq!(classes().last().map(|c| c.name), Some("119B"));
+        assert!(class("37A").is_none());
+
+        let mut orders: Vec<u32> = classes().iter().map(|c| c.order).collect();
+        orders.dedup();
+        assert_eq!(orders.len(), 73);
+        // Todo primo que divide |𝕄| es orden de algún elemento (Cauchy)
+        assert!(MONSTER_PRIMES.iter().all(|p| orders.contains(p)));
+    }
+
+    #[test]
+    fn test_group_orders() {
+        assert_eq!(MONSTER_ORDER.to_string(), "808017424794512875886459904961710757005754368000000000");
+        assert_eq!(MONSTER_ORDER.to_u128(), None);
+        assert!((MONSTER_ORDER.to_f64() / 8.080174247945129e53 - 1.0).abs() < 1e-12);
+
+        let c2a = class("2A").unwrap();
+        assert_eq!(c2a.centralizer_order().unwrap().to_string(), "8309562962452852382355161088000000");
+        // Las involuciones 2A, cuyos ejes generan el álgebra de Griess
+        assert_eq!(c2a.size().unwrap().to_string(), "97239461142009186000");
+        assert_eq!(class("7B").unwrap().centralizer_order().unwrap().to_u128(), Some(84707280));
+        assert_eq!(class("71B").unwrap().centralizer_order().unwrap().to_u128(), Some(71));
+        assert_eq!(class("13B").unwrap().centralizer_order(), None);
+        assert_eq!(class("4A").unwrap().size(), None);
+
+        for (name, orden) in CENTRALIZERS {
+            let c = class(name).unwrap();
+            assert!(orden.divides(&MONSTER_ORDER), "{}", name);
+            // g ∈ C(g): el orden del elemento divide al del centralizador
+            assert_eq!(orden.to_u128().map_or(0, |n| n % c.order as u128), 0, "{}", name);
+        }
+        // La suma de 1/|C(g)| sobre todas las clases es 1; sobre las tabuladas, menos
+        let parcial: f64 = classes().iter().filter_map(|c| c.centralizer_order()).map(|o| 1.0 / o.to_f64()).sum();
+        assert!(parcial < 1.0);
+    }
+
+    #[test]
+    fn test_character_degrees() {
+        assert_eq!(character_degree(0), None);
+        assert_eq!(character_degree(1), Some(1));
+        assert_eq!(character_degree(2), Some(MONSTER_196883 as u64));
+        assert_eq!(character_degree(CHARACTER_DEGREES.len() + 1), None);
+        assert!(CHARACTER_DEGREES.windows(2).all(|w| w[0] < w[1]));
+
+        // Coeficientes de j(τ) − 744 como sumas de grados (McKay–Thompson)
+        let d = |n| character_degree(n).unwrap();
+        assert_eq!(d(1) + d(2), MONSTER_196884 as u64);
+        assert_eq!(d(1) + d(2) + d(3), 21493760);
+        assert_eq!(2 * d(1) + 2 * d(2) + d(3) + d(4), 864299970);
+        assert_eq!(2 * d(1) + 3 * d(2) + 2 * d(3) + d(4) + d(6), 20245856256);
+        assert_eq!(4 * d(1) + 5 * d(2) + 3 * d(3) + 2 * d(4) + d(5) + d(6) + d(7), 333202640600);
+    }
+}
@@ -0,0 +1,133 @@
+//! Moonshine - Series de McKay–Thompson por Clase de Conjugación
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! Para cada g ∈ 𝕄, T_g(q) = q⁻¹ + Σ tr(g | Vₙ) qⁿ es la traza de g sobre el
+//! módulo moonshine; T_1A = j − 744 y su coeficiente de q es 196884 =
+//! 1 + 196883. Las clases principales de orden primo p con (p − 1) | 24
+//! tienen Hauptmoduln que son cocientes eta: con e = 24/(p − 1) y
+//! t = (η(τ)/η(pτ))^e,
+//!
+//! - T_pB = t + e
+//! - T_pA = t + e + p^(e/2)/t
+//!
+//! y T_3C(τ) = j(3τ)^(1/3) = E₄(3τ)/η(3τ)⁸. Los coeficientes se calculan
+//! con aritmética entera exacta sobre las series truncadas de `modular`.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::error::{AlgebraRoseError, Result};
+use crate::modular::{eisenstein_e4_serie, multiplicar, producto_eta_serie};
+use crate::monster_data;
+
+/// Clases con serie disponible
+pub const PRINCIPAL_CLASSES: [&str; 12] = ["1A", "2A", "2B", "3A", "3B", "3C", "5A", "5B", "7A", "7B", "13A", "13B"];
+
+/// Máximo de términos: los coeficientes de T_1A crecen como e^(4π√n) y
+/// hasta aquí caben en `i128`
+pub const MAX_TERMS: usize = 40;
+
+/// Coeficientes de T_g para la clase `class`: el índice k es el de q^(k−1)
+///
+/// `n_terms` cuenta desde q⁻¹, así que `mckay_thompson("1A", 3)` es
+/// `[1, 0, 196884]`.
+pub fn mckay_thompson(class: &str, n_terms: usize) -> Result<Vec<i128>> {
+    if n_terms > MAX_TERMS {
+        return Err(AlgebraRoseError::ValueOutOfRange {
+            parametro: "n_terms", valor: n_terms as f64, rango: "0..=40",
+        });
+    }
+    let nombre = monster_data::class(class)
+        .map(|c| c.name)
+        .filter(|nombre| PRINCIPAL_CLASSES.contains(nombre))
+        .ok_or_else(|| AlgebraRoseError::UnsupportedClass { clase: class.to_string() })?;
+    let n = n_terms;
+
+    // q · T_g, como serie en q
+    let mut serie = match nombre {
+        "1A" => {
+            // j = E₄³/Δ con Δ = q ∏ (1 − qᵏ)²⁴
+            let e4 = eisenstein_e4_serie(1, n);
+            multiplicar(&multiplicar(&multiplicar(&e4, &e4, n), &e4, n), &producto_eta_serie(1, -24, n), n)
+        }
+        "3C" => multiplicar(&eisenstein_e4_serie(3, n), &producto_eta_serie(3, -8, n), n),
+        _ => {
+            let p = nombre.trim_end_matches(['A', 'B']).parse::<usize>().unwrap_or(1);
+            let e = 24 / (p as i32 - 1);
+            let mut t = multiplicar(&producto_eta_serie(1, e, n), &producto_eta_serie(p, -e, n), n);
+            if nombre.ends_with('A') {
+                // p^(e/2)/t = p^(e/2) · q ∏ (1 − q^(pk))^e / (1 − qᵏ)^e
+                let inversa = multiplicar(&producto_eta_serie(p, e, n), &producto_eta_serie(1, -e, n), n);
+                let factor = (p as i128).pow(e as u32 / 2);
+                for k in 2..n {
+                    t[k] += factor * inversa[k - 2];
+                }
+            }
+            t
+        }
+    };
+
+    // Término constante: 0 en todas las T_g
+    if let Some(constante) = serie.get_mut(1) {
+        *constante = 0;
+    }
+    Ok(serie)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra_griess::GRIESS_DIM;
+    use crate::monster_data::character_degree;
+
+    #[test]
+    fn test_j_coefficients_match_monster_representations() {
+        let t = mckay_thompson("1A", 7).unwrap();
+        assert_eq!(t[..2], [1, 0]);
+        let d = |n| character_degree(n).unwrap() as i128;
+        // 196884 = 1 + 196883: la dimensión del álgebra de Griess
+        assert_eq!(t[2], GRIESS_DIM as i128);
+        assert_eq!(t[2], d(1) + d(2));
+        assert_eq!(t[3], d(1) + d(2) + d(3));
+        assert_eq!(t[4], 2 * d(1) + 2 * d(2) + d(3) + d(4));
+        assert_eq!(t[5], 20245856256);
+        assert_eq!(t[6], 333202640600);
+    }
+
+    #[test]
+    fn test_principal_classes() {
+        let inicio = |c| mckay_thompson(c, 5).unwrap();
+        // Coeficiente de q: 1 + χ₂(g), p. ej. 4372 = 1 + 4371 (Baby Monster)
+        assert_eq!(inicio("2A"), [1, 0, 4372, 96256, 1240002]);
+        assert_eq!(inicio("2B"), [1, 0, 276, -2048, 11202]);
+        assert_eq!(inicio("3A"), [1, 0, 783, 8672, 65367]);
+        assert_eq!(inicio("3B"), [1, 0, 54, -76, -243]);
+        assert_eq!(mckay_thompson("3C", 7).unwrap(), [1, 0, 0, 248, 0, 0, 4124]);
+        assert_eq!(inicio("5A"), [1, 0, 134, 760, 3345]);
+        assert_eq!(inicio("5B"), [1, 0, 9, 10, -30]);
+        assert_eq!(inicio("7A"), [1, 0, 51, 204, 681]);
+        assert_eq!(inicio("7B"), [1, 0, 2, 8, -5]);
+        assert_eq!(inicio("13a"), [1, 0, 12, 28, 66]);
+        assert_eq!(inicio("13B"), [1, 0, -1, 2, 1]);
+    }
+
+    #[test]
+    fn test_prime_order_traces_are_congruent() {
+        // Para g de orden p, tr(g | V) ≡ dim V (mod p)
+        let j = mckay_thompson("1A", MAX_TERMS).unwrap();
+        for clase in PRINCIPAL_CLASSES.iter().skip(1) {
+            let p = monster_data::class(clase).unwrap().order as i128;
+            let t = mckay_thompson(clase, MAX_TERMS).unwrap();
+            assert!(t.iter().zip(&j).all(|(a, b)| (a - b) % p == 0), "{}", clase);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(mckay_thompson("1A", 0).unwrap().is_empty());
+        assert!(matches!(mckay_thompson("4A", 5), Err(AlgebraRoseError::UnsupportedClass { .. })));
+        assert!(matches!(mckay_thompson("9Z", 5), Err(AlgebraRoseError::UnsupportedClass { .. })));
+        assert!(matches!(mckay_thompson("1A", MAX_TERMS + 1), Err(AlgebraRoseError::ValueOutOfRange { .. })));
+    }
+}
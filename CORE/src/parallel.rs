@@ -0,0 +1,134 @@
+//! PARALELISMO DEL NÚCLEO - feature `parallel`
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! Reparte con rayon `MonsterMatrix444::apply`, `GriessAlgebra::multiply`,
+//! la actualización de campos Fibonacci y `batch_evolution`, devolviendo los
+//! resultados en el mismo orden que la versión secuencial. Sin configurar,
+//! las operaciones usan el pool global de rayon; `set_thread_pool` crea un
+//! pool propio con `ThreadPoolBuilder` para que quien embebe el núcleo
+//! limite el uso de CPU.
+
+use std::sync::{Arc, RwLock};
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::error::Result;
+
+/// Filas de M₄₄₄ por tarea: cada fila son 444 productos complejos
+pub(crate) const MIN_FILAS: usize = 16;
+
+/// Pool configurado con `set_thread_pool` y su configuración
+static POOL: RwLock<Option<(ThreadPoolConfig, Arc<ThreadPool>)>> = RwLock::new(None);
+
+/// Configuración del pool de hilos
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ThreadPoolConfig {
+    /// Hilos del pool; 0 usa todos los núcleos disponibles y 1 ejecuta
+    /// todo en un único hilo de trabajo
+    pub num_threads: usize,
+}
+
+impl ThreadPoolConfig {
+    pub fn with_threads(num_threads: usize) -> Self {
+        ThreadPoolConfig { num_threads }
+    }
+}
+
+/// Crea el pool del núcleo; afecta a las operaciones que empiecen después
+///
+/// Las que ya estén en curso terminan en el pool anterior, que se libera
+/// cuando acaban.
+pub fn set_thread_pool(config: ThreadPoolConfig) -> Result<()> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(config.num_threads)
+        .thread_name(|k| format!("algebra-rose-{}", k))
+        .build()?;
+    *POOL.write().unwrap_or_else(|e| e.into_inner()) = Some((config, Arc::new(pool)));
+    Ok(())
+}
+
+/// Configuración actual; `Default` mientras se use el pool global de rayon
+pub fn thread_pool() -> ThreadPoolConfig {
+    pool().map(|(config, _)| config).unwrap_or_default()
+}
+
+/// Hilos del pool que usará la próxima operación
+pub fn current_num_threads() -> usize {
+    pool().map_or_else(rayon::current_num_threads, |(_, pool)| pool.current_num_threads())
+}
+
+fn pool() -> Option<(ThreadPoolConfig, Arc<ThreadPool>)> {
+    POOL.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Ejecuta `op` dentro del pool configurado, o del global si no hay ninguno
+pub(crate) fn install<R, F>(op: F) -> R
+where
+    R: Send,
+    F: FnOnce() -> R + Send,
+{
+    match pool() {
+        Some((_, pool)) => pool.install(op),
+        None => op(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn test_operations_match_sequential() {
+        use crate::algebra_griess::{GriessAlgebra, GRIESS_DIM};
+        use crate::keygen_evolution::{batch_evolution, KeygenEvolution};
+        use crate::matrix_444::MonsterMatrix444;
+        use crate::fibonacci_dimensions::SistemaCamposFibonacci;
+        use nalgebra::{Complex, DVector};
+
+        let matriz = MonsterMatrix444::new();
+        let estado: Vec<Complex<f64>> = (0..matriz.dim()).map(|k| Complex::new((k as f64).sin(), (k as f64).cos())).collect();
+        let esperado = matriz.get_matrix() * DVector::from_column_slice(&estado);
+        let obtenido = matriz.apply(&estado).unwrap();
+        for (a, b) in obtenido.iter().zip(esperado.iter()) {
+            assert!((a - b).norm_sqr() < 1e-18);
+        }
+
+        let griess = GriessAlgebra::<f64>::new();
+        let a = DVector::from_fn(GRIESS_DIM, |k, _| Complex::new(k as f64, 1.0));
+        let producto = griess.multiply(&a, &a).unwrap();
+        assert!((0..100).all(|k| producto[k] == a[k] * a[k]));
+        assert!(producto.iter().skip(100).all(|x| *x == Complex::new(0.0, 0.0)));
+
+        let iniciales = [0.3, 0.5, 0.7, 0.9];
+        let lotes = batch_evolution(&iniciales, 50);
+        for (&inicial, lote) in iniciales.iter().zip(&lotes) {
+            assert_eq!(lote, &KeygenEvolution::new(Some(inicial)).evolve_steps(50));
+        }
+
+        let mut campos = SistemaCamposFibonacci::new();
+        let cambios = campos.update_with_events(0.5);
+        assert!(cambios.windows(2).all(|w| w[1].field_id > w[0].field_id));
+        assert_eq!(cambios.len(), campos.get_campos_activos().len());
+    }
+
+    #[test]
+    fn test_thread_pool_config() {
+        set_thread_pool(ThreadPoolConfig::with_threads(3)).unwrap();
+        assert_eq!(thread_pool(), ThreadPoolConfig { num_threads: 3 });
+        assert_eq!(current_num_threads(), 3);
+
+        // El trabajo corre en los hilos del pool del núcleo
+        let nombres: Vec<String> = install(|| {
+            (0..64).into_par_iter()
+                .map(|_| std::thread::current().name().unwrap_or_default().to_string())
+                .collect()
+        });
+        assert!(nombres.iter().all(|n| n.starts_with("algebra-rose-")), "{:?}", nombres);
+        assert_eq!(install(rayon::current_num_threads), 3);
+
+        set_thread_pool(ThreadPoolConfig::default()).unwrap();
+        assert!(current_num_threads() >= 1);
+    }
+}
@@ -2,6 +2,11 @@
 //! Sistema: Álgebra Rose v27.1024D-S36
 //! Certificación: 196885 - Estado Monster Pleno
 
+use alloc::vec::Vec;
+use num_traits::Euclid;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
 /// φ (PHI) - Proporción Áurea con alta precisión
 /// φ = (1 + √5)/2 ≈ 1.61803398874989484820458683436563811772030917980576
 pub const PHI: f64 = 1.61803398874989484820458683436563811772030917980576;
@@ -19,49 +24,97 @@ pub const MONSTER_196885: f64 = 196885.0;
 pub const MATRIX_444_DIM: usize = 444;
 
 /// Número Fibonacci F₂₇ (Campo 24: Punto Omega)
-pub const FIBONACCI_27: usize = 196418;
+pub const FIBONACCI_27: usize = fibonacci_field_dimension(24);
+
+/// Mayor n con Fₙ representable en u128 (F₁₈₆ ≈ 3.33·10³⁸)
+pub const MAX_EXACT_FIBONACCI: u32 = 186;
+
+/// Número de campos Fibonacci dimensionales
+pub const FIBONACCI_FIELD_COUNT: usize = 24;
 
 /// Secuencia Fibonacci para campos dimensionales (F₄ a F₂₇)
-pub const FIBONACCI_SEQUENCE: [usize; 24] = [
-    3,      // F₄ - Campo 1
-    5,      // F₅ - Campo 2
-    8,      // F₆ - Campo 3
-    13,     // F₇ - Campo 4
-    21,     // F₈ - Campo 5
-    34,     // F₉ - Campo 6
-    55,     // F₁₀ - Campo 7
-    89,     // F₁₁ - Campo 8
-    144,    // F₁₂ - Campo 9
-    233,    // F₁₃ - Campo 10
-    377,    // F₁₄ - Campo 11
-    610,    // F₁₅ - Campo 12
-    987,    // F₁₆ - Campo 13
-    1597,   // F₁₇ - Campo 14
-    2584,   // F₁₈ - Campo 15
-    4181,   // F₁₉ - Campo 16
-    6765,   // F₂₀ - Campo 17
-    10946,  // F₂₁ - Campo 18
-    17711,  // F₂₂ - Campo 19
-    28657,  // F₂₃ - Campo 20
-    46368,  // F₂₄ - Campo 21
-    75025,  // F₂₅ - Campo 22
-    121393, // F₂₆ - Campo 23
-    196418, // F₂₇ - Campo 24
-];
-
-/// Factores φ precalculados (sin usar powi en constantes)
-pub const GRIESS_PHI_FACTORS: [f64; 10] = [
-    PHI,                    // φ^1
-    PHI * PHI,              // φ^2 = 2.618033988749895
-    4.23606797749979,       // φ^3 precalculado
-    6.854101966249685,      // φ^4 precalculado
-    11.090169943749475,     // φ^5 precalculado
-    17.94427190999916,      // φ^6 precalculado
-    29.034441853748635,     // φ^7 precalculado
-    46.978713763747795,     // φ^8 precalculado
-    76.01315561749643,      // φ^9 precalculado
-    122.99186938124422,     // φ^10 precalculado
-];
+///
+/// Campo k tiene dimensión Fₖ₊₃: 3, 5, 8, 13, ..., 121393, 196418.
+/// Se deriva en compilación de `fibonacci_exact`, no se escribe a mano.
+/// Es la única copia de la tabla: `fibonacci_dimensions` la reexporta y
+/// `keygen_evolution` calcula sus umbrales a partir de ella.
+pub const FIBONACCI_SEQUENCE: [usize; FIBONACCI_FIELD_COUNT] = {
+    let mut table = [0; FIBONACCI_FIELD_COUNT];
+    let mut i = 0;
+    while i < FIBONACCI_FIELD_COUNT {
+        table[i] = fibonacci_field_dimension(i + 1);
+        i += 1;
+    }
+    table
+};
+
+/// Fₙ exacto en aritmética entera por duplicación rápida (O(log n))
+///
+/// F₂ₖ = Fₖ(2Fₖ₊₁ − Fₖ), F₂ₖ₊₁ = Fₖ² + Fₖ₊₁². Las operaciones son
+/// módulo 2¹²⁸; como Fₙ < 2¹²⁸ para n ≤ 186, el resultado es exacto aunque
+/// el término auxiliar Fₙ₊₁ se desborde. Devuelve `None` para n > 186.
+pub const fn fibonacci_exact(n: u32) -> Option<u128> {
+    if n > MAX_EXACT_FIBONACCI {
+        return None;
+    }
+    
+    let mut a: u128 = 0; // Fₖ
+    let mut b: u128 = 1; // Fₖ₊₁
+    let mut bit = 32 - n.leading_zeros();
+    while bit > 0 {
+        bit -= 1;
+        let c = a.wrapping_mul(b.wrapping_mul(2).wrapping_sub(a));
+        let d = a.wrapping_mul(a).wrapping_add(b.wrapping_mul(b));
+        if (n >> bit) & 1 == 1 {
+            a = d;
+            b = c.wrapping_add(d);
+        } else {
+            a = c;
+            b = d;
+        }
+    }
+    
+    Some(a)
+}
+
+/// Dimensión del campo `field_id` (1-24): F(field_id + 3)
+pub const fn fibonacci_field_dimension(field_id: usize) -> usize {
+    match fibonacci_exact(field_id as u32 + 3) {
+        Some(f) => f as usize,
+        None => 0,
+    }
+}
+
+/// Dimensión del campo `field_id` si está en 1..=24
+pub const fn field_dimension(field_id: usize) -> Option<usize> {
+    if field_id >= 1 && field_id <= FIBONACCI_FIELD_COUNT {
+        Some(FIBONACCI_SEQUENCE[field_id - 1])
+    } else {
+        None
+    }
+}
+
+/// Dimensión del campo `field_id` como f64, para umbrales y normalizaciones
+pub fn field_dimension_f64(field_id: usize) -> Option<f64> {
+    field_dimension(field_id).map(|d| d as f64)
+}
+
+/// Factores φ¹..φ¹⁰, evaluados en compilación con `phi_pow`
+pub const GRIESS_PHI_FACTORS: [f64; 10] = {
+    let mut factors = [0.0; 10];
+    let mut i = 0;
+    while i < 10 {
+        factors[i] = phi_pow(i as i32 + 1);
+        i += 1;
+    }
+    factors
+};
+
+/// φ² = φ + 1
+pub const PHI_SQUARED: f64 = phi_pow(2);
+
+/// φ³ = 2φ + 1
+pub const PHI_CUBED: f64 = phi_pow(3);
 
 /// Matriz de constantes φ (precalculada sin powi)
 pub const MATRIX_PHI_CONSTANTS: [f64; 444] = {
@@ -75,15 +128,60 @@ pub const MATRIX_PHI_CONSTANTS: [f64; 444] = {
     constants
 };
 
-/// Calcula φ elevado a una potencia entera (función regular, no constante)
-pub fn phi_pow(n: i32) -> f64 {
+/// Ángulo áureo: 2π(1 − ψ) = π(3 − √5) ≈ 137.5°
+pub const GOLDEN_ANGLE: f64 = 2.399963229728653;
+
+/// Límite de la tabla precalculada de potencias: |n| ≤ 1024
+pub const PHI_POW_TABLE_LIMIT: i32 = 1024;
+
+/// Mayor exponente con φⁿ finito en f64 (φ¹⁴⁷⁴ ≈ 1.1·10³⁰⁸)
+pub const PHI_POW_MAX_EXPONENT: i32 = 1474;
+
+/// φⁿ para n en [−1024, 1024], índice n + 1024, calculada en compilación
+///
+/// Se construye con la misma multiplicación sucesiva que `phi_pow`, así que
+/// los valores son idénticos bit a bit a los del cálculo en bucle.
+static PHI_POW_TABLE: [f64; 2 * PHI_POW_TABLE_LIMIT as usize + 1] = {
+    let limit = PHI_POW_TABLE_LIMIT as usize;
+    let mut table = [1.0; 2 * PHI_POW_TABLE_LIMIT as usize + 1];
+    let mut power = 1.0;
+    let mut k = 1;
+    while k <= limit {
+        power *= PHI;
+        table[limit + k] = power;
+        table[limit - k] = 1.0 / power;
+        k += 1;
+    }
+    table
+};
+
+/// Calcula φ elevado a una potencia entera
+///
+/// Para |n| ≤ 1024 (un tamaño de tabla arbitrario) es una consulta en
+/// tabla; hasta `PHI_POW_MAX_EXPONENT` recurre a multiplicación sucesiva,
+/// y más allá φⁿ desborda f64: devuelve ∞ para n positivo y 0 para n
+/// negativo, los mismos valores que daría el bucle. La tabla ahorra sobre
+/// todo con |n| grande, donde el bucle hace |n| productos; la construcción
+/// de campos solo pide exponentes pequeños y no lo nota. Es `const fn`,
+/// así que sirve para constantes y tamaños de arrays.
+pub const fn phi_pow(n: i32) -> f64 {
     if n == 0 {
         return 1.0;
     }
     
+    let exponente = n.unsigned_abs();
+    if exponente <= PHI_POW_TABLE_LIMIT as u32 {
+        return PHI_POW_TABLE[(n + PHI_POW_TABLE_LIMIT) as usize];
+    }
+    if exponente > PHI_POW_MAX_EXPONENT as u32 {
+        return if n < 0 { 0.0 } else { f64::INFINITY };
+    }
+    
     let mut result = PHI;
-    for _ in 1..n.abs() {
+    let mut k = 1;
+    while k < exponente {
         result *= PHI;
+        k += 1;
     }
     
     if n < 0 {
@@ -93,6 +191,11 @@ pub fn phi_pow(n: i32) -> f64 {
     }
 }
 
+/// Calcula φⁿ para cada exponente de un lote
+pub fn phi_pow_many(exponents: &[i32]) -> Vec<f64> {
+    exponents.iter().map(|&n| phi_pow(n)).collect()
+}
+
 /// Calcula número Fibonacci usando fórmula de Binet
 pub fn fibonacci(n: u32) -> f64 {
     let n_f64 = n as f64;
@@ -116,6 +219,135 @@ pub fn golden_distance(a: f64, b: f64) -> f64 {
     (a / b - PHI).abs().min((b / a - PHI).abs())
 }
 
+/// Índice n tal que Fₙ = `value`, si `value` es un número de Fibonacci
+///
+/// Para 1 = F₁ = F₂ devuelve 1. Estima n con la inversa de Binet
+/// (n ≈ log_φ(x·√5)) y confirma con `fibonacci_exact`.
+pub fn fibonacci_index(value: u128) -> Option<u32> {
+    if value <= 1 {
+        return Some(value as u32);
+    }
+    
+    let estimate = ((value as f64) * 5.0_f64.sqrt()).ln() / PHI.ln();
+    let center = estimate.round() as i64;
+    (center - 1..=center + 1)
+        .filter(|&n| n >= 0)
+        .map(|n| n as u32)
+        .find(|&n| fibonacci_exact(n) == Some(value))
+}
+
+/// Número de Fibonacci más cercano a `x` como (índice, valor)
+///
+/// En caso de empate devuelve el menor. Valores negativos se tratan como 0
+/// y valores por encima de F₁₈₆ devuelven F₁₈₆. Las distancias se comparan
+/// en f64, pero el valor devuelto es siempre el Fₙ exacto.
+pub fn nearest_fibonacci(x: f64) -> (u32, u128) {
+    let x = x.max(0.0);
+    let mut n = 0;
+    while n < MAX_EXACT_FIBONACCI {
+        let next = fibonacci_exact(n + 1).unwrap_or(u128::MAX) as f64;
+        if next >= x {
+            let current = fibonacci_exact(n).unwrap_or(0) as f64;
+            if x - current <= next - x {
+                return (n, fibonacci_exact(n).unwrap_or(0));
+            }
+            return (n + 1, fibonacci_exact(n + 1).unwrap_or(0));
+        }
+        n += 1;
+    }
+    (MAX_EXACT_FIBONACCI, fibonacci_exact(MAX_EXACT_FIBONACCI).unwrap_or(0))
+}
+
+/// Desviación media |xᵢ₊₁/xᵢ − φ| a lo largo de una cadena de valores
+///
+/// `None` si hay menos de dos valores o algún divisor es (casi) cero.
+pub fn ratio_chain_phi_deviation(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    
+    let mut total = 0.0;
+    for w in values.windows(2) {
+        if w[0].abs() < 1e-10 {
+            return None;
+        }
+        total += (w[1] / w[0] - PHI).abs();
+    }
+    
+    Some(total / (values.len() - 1) as f64)
+}
+
+/// Genera `n` puntos 2D en espiral áurea (filotaxis de Vogel) dentro de un disco
+///
+/// El punto i está a radio `radius`·√((i + ½)/n) y ángulo i·`GOLDEN_ANGLE`,
+/// lo que reparte los puntos con densidad uniforme sobre el disco.
+pub fn golden_spiral_points(n: usize, radius: f64) -> Vec<[f64; 2]> {
+    (0..n).map(|i| {
+        let r = radius * ((i as f64 + 0.5) / n as f64).sqrt();
+        let theta = i as f64 * GOLDEN_ANGLE;
+        [r * theta.cos(), r * theta.sin()]
+    }).collect()
+}
+
+/// Genera `n` puntos 3D en retícula de Fibonacci sobre una esfera de radio `radius`
+pub fn golden_sphere_points(n: usize, radius: f64) -> Vec<[f64; 3]> {
+    (0..n).map(|i| {
+        let z = 1.0 - 2.0 * (i as f64 + 0.5) / n as f64;
+        let r = (1.0 - z * z).sqrt();
+        let theta = i as f64 * GOLDEN_ANGLE;
+        [radius * r * theta.cos(), radius * r * theta.sin(), radius * z]
+    }).collect()
+}
+
+/// Primeros n términos de la sucesión de Beatty de φ: ⌊kφ⌋ para k = 1..=n
+///
+/// Exacta en enteros: ⌊kφ⌋ = ⌊(k + ⌊√(5k²)⌋) / 2⌋, sin error de redondeo
+/// de f64 para k grandes. Su complementaria ⌊kφ²⌋ = ⌊kφ⌋ + k.
+pub fn beatty_sequence_phi(n: u64) -> Vec<u64> {
+    (1..=n).map(|k| {
+        let k = k as u128;
+        ((k + (5 * k * k).isqrt()) / 2) as u64
+    }).collect()
+}
+
+/// Puntos frac(k·ψ) para k = 0..n, ordenados en [0, 1)
+///
+/// Reparto áureo del intervalo unidad: cada prefijo de la sucesión deja
+/// huecos de como mucho tres longitudes (teorema de las tres distancias).
+pub fn golden_partition(n: usize) -> Vec<f64> {
+    let mut points: Vec<f64> = (0..n).map(|k| (k as f64 * PSI).fract()).collect();
+    points.sort_by(f64::total_cmp);
+    points
+}
+
+/// Longitudes distintas de hueco entre los puntos frac(k·alpha), k = 0..n,
+/// en el círculo unidad, con el número de huecos de cada longitud
+///
+/// Por el teorema de las tres distancias hay como mucho tres longitudes y,
+/// si hay tres, la mayor es la suma de las otras dos. Se ordenan de mayor a
+/// menor; huecos que difieren en menos de 1e-9 se consideran iguales.
+pub fn three_distance_gaps(alpha: f64, n: usize) -> Vec<(f64, usize)> {
+    if n == 0 {
+        return Vec::new();
+    }
+    
+    let mut points: Vec<f64> = (0..n).map(|k| Euclid::rem_euclid(&(k as f64 * alpha), &1.0)).collect();
+    points.sort_by(f64::total_cmp);
+    
+    let mut gaps: Vec<f64> = points.windows(2).map(|w| w[1] - w[0]).collect();
+    gaps.push(1.0 - points[n - 1] + points[0]);
+    gaps.sort_by(|a, b| b.total_cmp(a));
+    
+    let mut distinct: Vec<(f64, usize)> = Vec::new();
+    for gap in gaps {
+        match distinct.last_mut() {
+            Some((length, count)) if (*length - gap).abs() < 1e-9 => *count += 1,
+            _ => distinct.push((gap, 1)),
+        }
+    }
+    distinct
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,6 +371,22 @@ mod tests {
         assert_eq!(MONSTER_196885 - MONSTER_196883, 2.0);
     }
     
+    #[test]
+    fn test_const_phi_identities() {
+        // Evaluación en compilación: usables en arrays y patrones
+        const PHI_5: f64 = phi_pow(5);
+        const POWERS: [f64; 3] = [phi_pow(-1), phi_pow(0), phi_pow(1)];
+        
+        assert_eq!(PHI_5, phi_pow(5));
+        assert_eq!(POWERS, [phi_pow(-1), 1.0, PHI]);
+        assert!((PHI_SQUARED - (PHI + 1.0)).abs() < 1e-15);
+        assert!((PHI_CUBED - (2.0 * PHI + 1.0)).abs() < 1e-14);
+        
+        for (i, factor) in GRIESS_PHI_FACTORS.iter().enumerate() {
+            assert!((factor - PHI.powi(i as i32 + 1)).abs() / factor < 1e-14);
+        }
+    }
+    
     #[test]
     fn test_phi_pow() {
         assert!((phi_pow(0) - 1.0).abs() < 1e-10);
@@ -147,6 +395,35 @@ mod tests {
         assert!((phi_pow(-1) - PSI).abs() < 1e-10);
     }
     
+    #[test]
+    fn test_phi_pow_table() {
+        // La tabla reproduce exactamente la multiplicación sucesiva
+        let mut power = 1.0;
+        for n in 1..=PHI_POW_TABLE_LIMIT {
+            power *= PHI;
+            assert_eq!(phi_pow(n), power, "φ^{}", n);
+            assert_eq!(phi_pow(-n), 1.0 / power, "φ^-{}", n);
+        }
+        
+        // Extremos: el último exponente finito, el desbordamiento y i32::MIN/MAX
+        for _ in PHI_POW_TABLE_LIMIT..PHI_POW_MAX_EXPONENT {
+            power *= PHI;
+        }
+        assert_eq!(phi_pow(PHI_POW_MAX_EXPONENT), power);
+        assert!(power.is_finite() && (power * PHI).is_infinite());
+        assert!(phi_pow(-PHI_POW_MAX_EXPONENT) > 0.0);
+        assert_eq!(phi_pow(PHI_POW_MAX_EXPONENT + 1), f64::INFINITY);
+        assert_eq!(phi_pow(-PHI_POW_MAX_EXPONENT - 1), 0.0);
+        assert_eq!(phi_pow(i32::MAX), f64::INFINITY);
+        assert_eq!(phi_pow(i32::MIN), 0.0);
+        
+        // Fuera de la tabla sigue siendo continua con ella
+        let ratio = phi_pow(PHI_POW_TABLE_LIMIT + 1) / phi_pow(PHI_POW_TABLE_LIMIT);
+        assert!((ratio - PHI).abs() < 1e-12);
+        
+        assert_eq!(phi_pow_many(&[0, 1, -1, 10]), vec![1.0, phi_pow(1), phi_pow(-1), phi_pow(10)]);
+    }
+    
     #[test]
     fn test_fibonacci_function() {
         // Verificar algunos números Fibonacci
@@ -168,13 +445,199 @@ mod tests {
         assert!(!is_golden_ratio(3.0, 2.0, 1e-10));
     }
     
+    #[test]
+    fn test_fibonacci_exact() {
+        assert_eq!(fibonacci_exact(0), Some(0));
+        assert_eq!(fibonacci_exact(1), Some(1));
+        assert_eq!(fibonacci_exact(2), Some(1));
+        assert_eq!(fibonacci_exact(27), Some(196418));
+        assert_eq!(fibonacci_exact(78), Some(8944394323791464));
+        assert_eq!(fibonacci_exact(100), Some(354224848179261915075));
+        assert_eq!(fibonacci_exact(186), Some(332825110087067562321196029789634457848));
+        assert_eq!(fibonacci_exact(187), None);
+        
+        // Coincide con la recurrencia iterativa
+        let (mut a, mut b) = (0u128, 1u128);
+        for n in 0..=MAX_EXACT_FIBONACCI {
+            assert_eq!(fibonacci_exact(n), Some(a), "F({})", n);
+            let next = a.checked_add(b);
+            a = b;
+            b = next.unwrap_or(0);
+        }
+    }
+    
+    #[test]
+    fn test_fibonacci_table_derived() {
+        let literal: [usize; 24] = [
+            3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610,
+            987, 1597, 2584, 4181, 6765, 10946, 17711, 28657,
+            46368, 75025, 121393, 196418,
+        ];
+        assert_eq!(FIBONACCI_SEQUENCE, literal);
+        assert_eq!(FIBONACCI_27, 196418);
+    }
+    
+    #[test]
+    fn test_fibonacci_index() {
+        assert_eq!(fibonacci_index(0), Some(0));
+        assert_eq!(fibonacci_index(1), Some(1));
+        assert_eq!(fibonacci_index(2), Some(3));
+        assert_eq!(fibonacci_index(196418), Some(27));
+        assert_eq!(fibonacci_index(196419), None);
+        assert_eq!(fibonacci_index(4), None);
+        
+        for n in 3..=MAX_EXACT_FIBONACCI {
+            assert_eq!(fibonacci_index(fibonacci_exact(n).unwrap()), Some(n));
+        }
+    }
+    
+    #[test]
+    fn test_nearest_fibonacci() {
+        assert_eq!(nearest_fibonacci(0.0), (0, 0));
+        assert_eq!(nearest_fibonacci(-5.0), (0, 0));
+        assert_eq!(nearest_fibonacci(4.0), (4, 3));
+        assert_eq!(nearest_fibonacci(7.0), (6, 8));
+        assert_eq!(nearest_fibonacci(196884.0), (27, 196418));
+        assert_eq!(nearest_fibonacci(610.4), (15, 610));
+        assert_eq!(nearest_fibonacci(1e60), (MAX_EXACT_FIBONACCI, fibonacci_exact(MAX_EXACT_FIBONACCI).unwrap()));
+        // Por encima de F₇₈ el f64 ya no representa Fₙ exactamente
+        assert_eq!(nearest_fibonacci(1e17), (83, 99194853094755497));
+    }
+    
+    #[test]
+    fn test_ratio_chain_phi_deviation() {
+        let dims: Vec<f64> = FIBONACCI_SEQUENCE.iter().map(|&d| d as f64).collect();
+        let deviation = ratio_chain_phi_deviation(&dims).unwrap();
+        assert!(deviation < 0.01, "Cadena de campos debe ser φ-resonante: {}", deviation);
+        
+        let powers: Vec<f64> = (0..10).map(phi_pow).collect();
+        assert!(ratio_chain_phi_deviation(&powers).unwrap() < 1e-12);
+        
+        assert!((ratio_chain_phi_deviation(&[1.0, 2.0, 4.0]).unwrap() - (2.0 - PHI)).abs() < 1e-12);
+        assert!(ratio_chain_phi_deviation(&[1.0]).is_none());
+        assert!(ratio_chain_phi_deviation(&[0.0, 1.0]).is_none());
+    }
+    
+    #[test]
+    fn test_golden_angle() {
+        let expected = core::f64::consts::PI * (3.0 - 5.0_f64.sqrt());
+        assert!((GOLDEN_ANGLE - expected).abs() < 1e-15);
+        assert!((GOLDEN_ANGLE - 2.0 * core::f64::consts::PI * (1.0 - PSI)).abs() < 1e-15);
+    }
+    
+    #[test]
+    fn test_golden_spiral_points() {
+        let points = golden_spiral_points(24, 2.0);
+        assert_eq!(points.len(), 24);
+        
+        for (i, p) in points.iter().enumerate() {
+            let r = (p[0] * p[0] + p[1] * p[1]).sqrt();
+            assert!(r <= 2.0, "Punto {} fuera del disco: {}", i, r);
+        }
+        
+        // Radios crecientes: cada campo más alejado del centro
+        for w in points.windows(2) {
+            assert!(w[1][0].hypot(w[1][1]) > w[0][0].hypot(w[0][1]));
+        }
+        
+        assert!(golden_spiral_points(0, 1.0).is_empty());
+    }
+    
+    #[test]
+    fn test_golden_sphere_points() {
+        let points = golden_sphere_points(50, 3.0);
+        assert_eq!(points.len(), 50);
+        
+        let mut centroid = [0.0; 3];
+        for p in &points {
+            let r = (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt();
+            assert!((r - 3.0).abs() < 1e-12);
+            for k in 0..3 {
+                centroid[k] += p[k] / 50.0;
+            }
+        }
+        
+        // Distribución equilibrada: centroide cerca del origen
+        assert!(centroid.iter().all(|c| c.abs() < 0.1), "Centroide: {:?}", centroid);
+    }
+    
     #[test]
     fn test_fibonacci_sequence() {
-        // Verificar propiedad emergente: Σ primeros 12 ≈ F₁₇ - 1
+        // Σ F₄..F₁₅ = (F₁₇ − 1) − (F₁ + F₂ + F₃) = F₁₇ − 5 = 1592
         let sum_first_12: usize = FIBONACCI_SEQUENCE[..12].iter().sum();
         assert_eq!(sum_first_12, 1592);
         
         // Verificar último elemento
         assert_eq!(FIBONACCI_SEQUENCE[23], FIBONACCI_27);
     }
+    
+    #[test]
+    fn test_fibonacci_prefix_sums() {
+        // Regresión: Σ de los primeros k campos = F(k+5) − 5 para todo k
+        let mut sum = 0;
+        for (k, &dim) in FIBONACCI_SEQUENCE.iter().enumerate() {
+            sum += dim;
+            assert_eq!(sum as u128, fibonacci_exact(k as u32 + 6).unwrap() - 5, "k = {}", k + 1);
+        }
+    }
+    
+    #[test]
+    fn test_field_dimension_accessors() {
+        assert_eq!(field_dimension(0), None);
+        assert_eq!(field_dimension(1), Some(3));
+        assert_eq!(field_dimension(24), Some(FIBONACCI_27));
+        assert_eq!(field_dimension(25), None);
+        assert_eq!(field_dimension_f64(12), Some(610.0));
+        
+        for id in 1..=FIBONACCI_FIELD_COUNT {
+            assert_eq!(field_dimension(id), Some(fibonacci_field_dimension(id)));
+        }
+    }
+    
+    #[test]
+    fn test_beatty_sequence_phi() {
+        assert_eq!(beatty_sequence_phi(10), vec![1, 3, 4, 6, 8, 9, 11, 12, 14, 16]);
+        
+        // Coincide con ⌊kφ⌋ en f64 donde f64 es fiable
+        for (k, &b) in beatty_sequence_phi(10_000).iter().enumerate() {
+            assert_eq!(b, ((k + 1) as f64 * PHI).floor() as u64);
+        }
+        
+        // Beatty: ⌊kφ⌋ y ⌊kφ²⌋ particionan los enteros positivos
+        let lower = beatty_sequence_phi(100);
+        let mut all: Vec<u64> = lower.iter().zip(1..).flat_map(|(&b, k)| [b, b + k]).collect();
+        all.sort_unstable();
+        all.dedup();
+        assert_eq!(all.len(), 200);
+        assert_eq!(&all[..161], &(1..=161).collect::<Vec<u64>>()[..]);
+    }
+    
+    #[test]
+    fn test_three_distance_gaps() {
+        for n in 1..200 {
+            let gaps = three_distance_gaps(PSI, n);
+            assert!(gaps.len() <= 3, "n = {}: {:?}", n, gaps);
+            assert_eq!(gaps.iter().map(|&(_, c)| c).sum::<usize>(), n);
+            
+            let total: f64 = gaps.iter().map(|&(g, c)| g * c as f64).sum();
+            assert!((total - 1.0).abs() < 1e-9);
+            
+            if let [(large, _), (mid, _), (small, _)] = gaps[..] {
+                assert!((large - (mid + small)).abs() < 1e-9);
+            }
+        }
+        
+        // En números de Fibonacci solo quedan dos longitudes
+        assert_eq!(three_distance_gaps(PSI, 89).len(), 2);
+        assert!(three_distance_gaps(PSI, 0).is_empty());
+    }
+    
+    #[test]
+    fn test_golden_partition() {
+        let points = golden_partition(50);
+        assert_eq!(points.len(), 50);
+        assert_eq!(points[0], 0.0);
+        assert!(points.windows(2).all(|w| w[0] < w[1]));
+        assert!(points.iter().all(|&p| (0.0..1.0).contains(&p)));
+    }
 }
@@ -0,0 +1,74 @@
+//! PRECISIÓN FLOTANTE GENÉRICA - Trait `PhiFloat`
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! Las estructuras de 196884 dimensiones (`GriessAlgebra` y los elementos de
+//! `embed_into_griess`) son genéricas sobre `PhiFloat`. `Precision` es el
+//! escalar por defecto: f64, o f32 con la feature `single-precision`, que
+//! reduce a la mitad la memoria de esos vectores a cambio de ~7 cifras
+//! significativas. Un tipo de precisión arbitraria puede implementar el
+//! trait más adelante sin tocar las estructuras.
+
+use nalgebra::RealField;
+
+/// Escalar real con el que se almacenan los vectores grandes del núcleo
+pub trait PhiFloat: RealField + Copy {
+    /// φ redondeado a esta precisión
+    const PHI: Self;
+
+    /// Convierte desde f64, redondeando si la precisión es menor
+    fn from_f64_lossy(valor: f64) -> Self;
+
+    /// Convierte a f64 para métricas y comparaciones con tolerancia
+    fn as_f64(self) -> f64;
+}
+
+impl PhiFloat for f64 {
+    const PHI: f64 = crate::matrix_444::PHI;
+
+    fn from_f64_lossy(valor: f64) -> Self {
+        valor
+    }
+
+    fn as_f64(self) -> f64 {
+        self
+    }
+}
+
+impl PhiFloat for f32 {
+    const PHI: f32 = crate::matrix_444::PHI as f32;
+
+    fn from_f64_lossy(valor: f64) -> Self {
+        valor as f32
+    }
+
+    fn as_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+/// Escalar por defecto de las estructuras genéricas
+#[cfg(not(feature = "single-precision"))]
+pub type Precision = f64;
+
+/// Escalar por defecto de las estructuras genéricas
+#[cfg(feature = "single-precision")]
+pub type Precision = f32;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phi_por_conversion<T: PhiFloat>() -> f64 {
+        T::from_f64_lossy(crate::matrix_444::PHI).as_f64()
+    }
+
+    #[test]
+    fn test_precisions_round_trip() {
+        assert_eq!(<f64 as PhiFloat>::PHI, crate::PHI);
+        assert_eq!(phi_por_conversion::<f64>(), crate::PHI);
+        assert!((phi_por_conversion::<f32>() - crate::PHI).abs() < 1e-7);
+        assert_eq!(<f32 as PhiFloat>::PHI.as_f64(), phi_por_conversion::<f32>());
+        assert_eq!(core::mem::size_of::<Precision>() * 2, core::mem::size_of::<nalgebra::Complex<Precision>>());
+    }
+}
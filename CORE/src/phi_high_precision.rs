@@ -0,0 +1,206 @@
+//! CONSTANTES ÁUREAS DE PRECISIÓN ARBITRARIA - feature `high-precision`
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! `f64` limita φ a ~16 cifras significativas; aquí φ, ψ, φⁿ y la fórmula de
+//! Binet se evalúan en aritmética de punto fijo sobre enteros grandes y se
+//! devuelven como `BigDecimal` redondeados a las cifras pedidas.
+
+use bigdecimal::BigDecimal;
+use num_bigint::{BigInt, BigUint};
+use crate::phi_constants::{fibonacci_exact, MAX_EXACT_FIBONACCI};
+
+/// Cifras de guarda añadidas a cada cálculo intermedio
+const GUARD_DIGITS: u64 = 10;
+
+/// log₁₀(φ), para estimar cuántas cifras consumen las potencias de ψ
+const LOG10_PHI: f64 = 0.20898764024997873;
+
+/// Número en punto fijo: `valor = mantisa / 10^escala`
+struct PuntoFijo {
+    escala: u64,
+    uno: BigInt,
+}
+
+impl PuntoFijo {
+    fn new(escala: u64) -> Self {
+        PuntoFijo { escala, uno: BigInt::from(10u32).pow(escala as u32) }
+    }
+
+    fn sqrt5(&self) -> BigInt {
+        (BigInt::from(5u32) * &self.uno * &self.uno).sqrt()
+    }
+
+    fn phi(&self) -> BigInt {
+        (&self.uno + self.sqrt5()) / 2
+    }
+
+    fn psi(&self) -> BigInt {
+        (self.sqrt5() - &self.uno) / 2
+    }
+
+    fn mul(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        a * b / &self.uno
+    }
+
+    fn div(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        a * &self.uno / b
+    }
+
+    fn pow(&self, base: &BigInt, mut n: u32) -> BigInt {
+        let mut result = self.uno.clone();
+        let mut base = base.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = self.mul(&result, &base);
+            }
+            base = self.mul(&base, &base);
+            n >>= 1;
+        }
+        result
+    }
+
+    fn to_bigdecimal(&self, mantisa: BigInt, digits: u64) -> BigDecimal {
+        BigDecimal::new(mantisa, self.escala as i64).with_prec(digits)
+    }
+}
+
+/// Escala necesaria para `digits` cifras significativas de valores ~φ^(±n)
+fn escala_para(digits: u64, n: u32) -> u64 {
+    let cifras_pow = (n as f64 * LOG10_PHI).ceil() as u64;
+    let cifras_n = (n.max(1) as f64).log10().ceil() as u64;
+    digits + cifras_pow + cifras_n + GUARD_DIGITS
+}
+
+/// φ = (1 + √5)/2 con `digits` cifras significativas
+pub fn phi_bigdecimal(digits: u64) -> BigDecimal {
+    let pf = PuntoFijo::new(digits + GUARD_DIGITS);
+    pf.to_bigdecimal(pf.phi(), digits)
+}
+
+/// ψ = 1/φ = φ − 1 con `digits` cifras significativas
+pub fn psi_bigdecimal(digits: u64) -> BigDecimal {
+    let pf = PuntoFijo::new(digits + GUARD_DIGITS);
+    pf.to_bigdecimal(pf.psi(), digits)
+}
+
+/// φⁿ con `digits` cifras significativas (variante de `phi_pow`)
+pub fn phi_pow_bigdecimal(n: i32, digits: u64) -> BigDecimal {
+    let pf = PuntoFijo::new(escala_para(digits, n.unsigned_abs()));
+    let base = if n < 0 { pf.psi() } else { pf.phi() };
+    pf.to_bigdecimal(pf.pow(&base, n.unsigned_abs()), digits)
+}
+
+/// Fₙ por la fórmula de Binet (φⁿ − (−ψ)ⁿ)/√5 con `digits` cifras significativas
+pub fn fibonacci_binet_bigdecimal(n: u32, digits: u64) -> BigDecimal {
+    let pf = PuntoFijo::new(escala_para(digits, n));
+    let phi_n = pf.pow(&pf.phi(), n);
+    let psi_n = pf.pow(&pf.psi(), n);
+    let numerador = if n.is_multiple_of(2) { phi_n - psi_n } else { phi_n + psi_n };
+    pf.to_bigdecimal(pf.div(&numerador, &pf.sqrt5()), digits)
+}
+
+/// Fₙ exacto para cualquier n, por duplicación rápida sobre `BigUint`
+///
+/// Para n ≤ 186 delega en `fibonacci_exact` (u128).
+pub fn fibonacci_big(n: u32) -> BigUint {
+    if n <= MAX_EXACT_FIBONACCI {
+        return BigUint::from(fibonacci_exact(n).unwrap_or(0));
+    }
+    
+    let mut a = BigUint::from(0u32); // Fₖ
+    let mut b = BigUint::from(1u32); // Fₖ₊₁
+    let mut bit = 32 - n.leading_zeros();
+    while bit > 0 {
+        bit -= 1;
+        let c = &a * (&b * 2u32 - &a);
+        let d = &a * &a + &b * &b;
+        if (n >> bit) & 1 == 1 {
+            b = &c + &d;
+            a = d;
+        } else {
+            a = c;
+            b = d;
+        }
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phi_constants::{PHI, PSI};
+    use std::str::FromStr;
+
+    const PHI_50: &str = "1.6180339887498948482045868343656381177203091798058";
+
+    #[test]
+    fn test_phi_50_digits() {
+        assert_eq!(phi_bigdecimal(50), BigDecimal::from_str(PHI_50).unwrap());
+        assert_eq!(phi_bigdecimal(16).to_string(), "1.618033988749895");
+    }
+
+    #[test]
+    fn test_phi_identities() {
+        let phi = phi_bigdecimal(60);
+        let psi = psi_bigdecimal(60);
+        let diff = (&phi - &psi - BigDecimal::from(1)).abs();
+        assert!(diff < BigDecimal::from_str("1e-55").unwrap());
+
+        let product = (&phi * &psi - BigDecimal::from(1)).abs();
+        assert!(product < BigDecimal::from_str("1e-55").unwrap());
+    }
+
+    #[test]
+    fn test_matches_f64() {
+        let phi: f64 = phi_bigdecimal(20).to_string().parse().unwrap();
+        let psi: f64 = psi_bigdecimal(20).to_string().parse().unwrap();
+        assert!((phi - PHI).abs() < 1e-15);
+        assert!((psi - PSI).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_phi_pow_bigdecimal() {
+        assert_eq!(phi_pow_bigdecimal(0, 30), BigDecimal::from(1));
+        assert_eq!(phi_pow_bigdecimal(1, 50), phi_bigdecimal(50));
+        assert_eq!(phi_pow_bigdecimal(-1, 50), psi_bigdecimal(50));
+
+        // φ¹⁰⁰ = (L₁₀₀ + F₁₀₀√5)/2 ≈ L₁₀₀ = 792070839848372253127
+        let phi_100 = phi_pow_bigdecimal(100, 21);
+        assert_eq!(phi_100, BigDecimal::from_str("792070839848372253127").unwrap());
+
+        // φ⁻¹⁰⁰ · φ¹⁰⁰ = 1
+        let product = phi_pow_bigdecimal(-100, 40) * phi_pow_bigdecimal(100, 40);
+        assert!((product - BigDecimal::from(1)).abs() < BigDecimal::from_str("1e-35").unwrap());
+    }
+
+    #[test]
+    fn test_fibonacci_big() {
+        assert_eq!(fibonacci_big(27), BigUint::from(196418u32));
+        assert_eq!(fibonacci_big(186), BigUint::from(fibonacci_exact(186).unwrap()));
+        assert_eq!(
+            fibonacci_big(187),
+            BigUint::from(fibonacci_exact(185).unwrap()) + BigUint::from(fibonacci_exact(186).unwrap())
+        );
+        assert_eq!(
+            fibonacci_big(300).to_string(),
+            "222232244629420445529739893461909967206666939096499764990979600"
+        );
+        
+        // Identidad de Cassini: F(n−1)·F(n+1) − F(n)² = (−1)ⁿ
+        let n = 500;
+        let lhs = BigInt::from(fibonacci_big(n - 1) * fibonacci_big(n + 1));
+        let rhs = BigInt::from(fibonacci_big(n).pow(2));
+        assert_eq!(lhs - rhs, BigInt::from(1));
+    }
+    
+    #[test]
+    fn test_fibonacci_binet_bigdecimal() {
+        assert_eq!(fibonacci_binet_bigdecimal(27, 10), BigDecimal::from(196418));
+        // F₁₀₀ excede la precisión de f64 pero es exacto aquí
+        assert_eq!(
+            fibonacci_binet_bigdecimal(100, 21),
+            BigDecimal::from_str("354224848179261915075").unwrap()
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Métrica φ-Ponderada - Producto Interno Áureo
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! ⟨x, y⟩_φ = Σ xᵢ·yᵢ·φ⁻ⁱ: las primeras coordenadas (campos más bajos)
+//! pesan más y cada coordenada siguiente pesa ψ veces la anterior. Los pesos
+//! se anulan en f64 más allá de i ≈ 1470, así que la cola de estados muy
+//! grandes no contribuye a la métrica.
+
+use alloc::vec::Vec;
+use core::ops::Deref;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use nalgebra::DVector;
+use crate::error::{AlgebraRoseError, Result};
+use crate::phi_constants::PSI;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Espacio con producto interno, norma y distancia
+///
+/// Operar con dos elementos de dimensiones distintas es
+/// `AlgebraRoseError::DimensionMismatch`.
+pub trait MetricSpace {
+    /// Producto interno ⟨self, other⟩
+    fn inner(&self, other: &Self) -> Result<f64>;
+
+    /// Norma inducida √⟨x, x⟩
+    fn norm(&self) -> f64 {
+        self.inner(self).unwrap_or(0.0).max(0.0).sqrt()
+    }
+
+    /// Distancia inducida ‖x − y‖
+    fn distance(&self, other: &Self) -> Result<f64>;
+}
+
+/// Vector real con la métrica φ-ponderada
+///
+/// Con `serde` se escribe como la lista de sus coordenadas.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "Vec<f64>", into = "Vec<f64>"))]
+pub struct PhiVector(pub DVector<f64>);
+
+impl From<Vec<f64>> for PhiVector {
+    fn from(data: Vec<f64>) -> Self {
+        PhiVector(DVector::from_vec(data))
+    }
+}
+
+impl From<PhiVector> for Vec<f64> {
+    fn from(vector: PhiVector) -> Self {
+        vector.0.data.into()
+    }
+}
+
+impl PhiVector {
+    /// Envuelve un vector existente
+    pub fn new(data: DVector<f64>) -> Self {
+        PhiVector(data)
+    }
+
+    /// Pesos φ⁻ⁱ para i = 0..dim
+    pub fn weights(dim: usize) -> DVector<f64> {
+        let mut w = 1.0;
+        DVector::from_fn(dim, |_, _| {
+            let current = w;
+            w *= PSI;
+            current
+        })
+    }
+
+    /// Normaliza a norma φ unitaria; `None` si la norma es nula
+    pub fn normalized(&self) -> Option<PhiVector> {
+        let norm = MetricSpace::norm(self);
+        if norm < 1e-300 {
+            return None;
+        }
+        Some(PhiVector(&self.0 / norm))
+    }
+
+    /// Proyección φ-ortogonal sobre la dirección de `onto`; `None` si
+    /// `onto` es nulo
+    pub fn project_onto(&self, onto: &PhiVector) -> Result<Option<PhiVector>> {
+        let producto = self.inner(onto)?;
+        let denom = MetricSpace::norm(onto).powi(2);
+        if denom < 1e-300 {
+            return Ok(None);
+        }
+        Ok(Some(PhiVector(&onto.0 * (producto / denom))))
+    }
+
+    /// Coseno φ-ponderado entre dos vectores, en [−1, 1]; `None` si alguno
+    /// es nulo
+    pub fn cosine(&self, other: &PhiVector) -> Result<Option<f64>> {
+        let producto = self.inner(other)?;
+        let denom = MetricSpace::norm(self) * MetricSpace::norm(other);
+        if denom < 1e-300 {
+            return Ok(None);
+        }
+        Ok(Some((producto / denom).clamp(-1.0, 1.0)))
+    }
+}
+
+impl Deref for PhiVector {
+    type Target = DVector<f64>;
+
+    fn deref(&self) -> &DVector<f64> {
+        &self.0
+    }
+}
+
+impl From<DVector<f64>> for PhiVector {
+    fn from(data: DVector<f64>) -> Self {
+        PhiVector(data)
+    }
+}
+
+impl MetricSpace for PhiVector {
+    fn inner(&self, other: &Self) -> Result<f64> {
+        AlgebraRoseError::check_dimension("Producto φ", self.0.len(), other.0.len())?;
+        let mut w = 1.0;
+        let mut sum = 0.0;
+        for (x, y) in self.0.iter().zip(other.0.iter()) {
+            if w == 0.0 {
+                break;
+            }
+            sum += x * y * w;
+            w *= PSI;
+        }
+        Ok(sum)
+    }
+
+    fn distance(&self, other: &Self) -> Result<f64> {
+        AlgebraRoseError::check_dimension("Distancia φ", self.0.len(), other.0.len())?;
+        Ok(MetricSpace::norm(&PhiVector(&self.0 - &other.0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phi_constants::phi_pow;
+
+    #[test]
+    fn test_weights() {
+        let w = PhiVector::weights(5);
+        for i in 0..5 {
+            assert!((w[i] - phi_pow(-(i as i32))).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_inner_product() {
+        let e0 = PhiVector::new(DVector::from_vec(vec![1.0, 0.0, 0.0]));
+        let e2 = PhiVector::new(DVector::from_vec(vec![0.0, 0.0, 1.0]));
+        assert_eq!(e0.inner(&e2).unwrap(), 0.0);
+        assert!((e0.norm() - 1.0).abs() < 1e-15);
+        assert!((e2.norm() - PSI).abs() < 1e-15);
+
+        // Simétrico y bilineal
+        let a = PhiVector::new(DVector::from_vec(vec![1.0, 2.0, 3.0]));
+        let b = PhiVector::new(DVector::from_vec(vec![-1.0, 0.5, 2.0]));
+        assert_eq!(a.inner(&b).unwrap(), b.inner(&a).unwrap());
+        let a2 = PhiVector::new(&a.0 * 2.0);
+        assert!((a2.inner(&b).unwrap() - 2.0 * a.inner(&b).unwrap()).abs() < 1e-12);
+
+        // Dimensiones distintas: error tipado, no pánico
+        let corto = PhiVector::new(DVector::from_vec(vec![1.0, 2.0]));
+        assert!(matches!(a.inner(&corto), Err(AlgebraRoseError::DimensionMismatch { esperada: 3, recibida: 2, .. })));
+        assert!(a.distance(&corto).is_err());
+        assert!(a.cosine(&corto).is_err());
+        assert!(a.project_onto(&corto).is_err());
+    }
+
+    #[test]
+    fn test_distance_and_normalization() {
+        let a = PhiVector::new(DVector::from_vec(vec![3.0, 1.0, 4.0, 1.0]));
+        let b = PhiVector::new(DVector::from_vec(vec![2.0, 7.0, 1.0, 8.0]));
+        assert_eq!(a.distance(&a).unwrap(), 0.0);
+        assert!((a.distance(&b).unwrap() - b.distance(&a).unwrap()).abs() < 1e-15);
+
+        let unit = a.normalized().unwrap();
+        assert!((unit.norm() - 1.0).abs() < 1e-12);
+        assert!(PhiVector::new(DVector::zeros(4)).normalized().is_none());
+    }
+
+    #[test]
+    fn test_projection() {
+        let a = PhiVector::new(DVector::from_vec(vec![1.0, 2.0, 3.0]));
+        let b = PhiVector::new(DVector::from_vec(vec![0.0, 1.0, 1.0]));
+        let p = a.project_onto(&b).unwrap().unwrap();
+
+        // El residuo es φ-ortogonal a la dirección proyectada
+        let residual = PhiVector::new(&a.0 - &p.0);
+        assert!(residual.inner(&b).unwrap().abs() < 1e-12);
+        assert!((a.cosine(&a).unwrap().unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_large_dimension_tail() {
+        // Más allá de ~1470 coordenadas los pesos son cero: no hay NaN ni coste extra
+        let big = PhiVector::new(DVector::from_element(5000, 1.0));
+        let norm = big.norm();
+        assert!(norm.is_finite());
+        // Σ ψⁱ = 1/(1 − ψ) = φ², luego la norma del vector de unos es φ
+        assert!((norm - crate::phi_constants::PHI).abs() < 1e-12);
+    }
+}
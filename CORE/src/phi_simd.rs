@@ -0,0 +1,126 @@
+//! CÁLCULOS φ VECTORIZADOS - feature `simd`
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! Versiones por lotes de `phi_pow` y de la normalización φ-ponderada que
+//! procesan cuatro valores por instrucción con `wide::f64x4`. Las potencias
+//! se evalúan como e^(n·ln φ), así que difieren de la tabla de `phi_pow` en
+//! unos pocos ulp.
+
+use wide::f64x4;
+use crate::phi_constants::phi_pow;
+
+/// ln(φ)
+pub const LN_PHI: f64 = 0.48121182505960344749775891342436842313518433438566;
+
+/// Calcula φⁿ para cada exponente del lote, cuatro a la vez
+pub fn phi_pow_simd(exponents: &[i32]) -> Vec<f64> {
+    let ln_phi = f64x4::splat(LN_PHI);
+    let mut result = Vec::with_capacity(exponents.len());
+    
+    let chunks = exponents.chunks_exact(4);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        let n = f64x4::from([chunk[0] as f64, chunk[1] as f64, chunk[2] as f64, chunk[3] as f64]);
+        result.extend_from_slice(&(n * ln_phi).exp().to_array());
+    }
+    
+    if !rest.is_empty() {
+        let mut lanes = [0.0; 4];
+        for (lane, &n) in lanes.iter_mut().zip(rest) {
+            *lane = n as f64;
+        }
+        let powers = (f64x4::from(lanes) * ln_phi).exp().to_array();
+        result.extend_from_slice(&powers[..rest.len()]);
+    }
+    
+    result
+}
+
+/// Norma φ-ponderada √(Σ φ⁻ⁱ·xᵢ²), la misma que `PhiVector`
+pub fn phi_norm_simd(values: &[f64]) -> f64 {
+    let step = f64x4::splat(phi_pow(-4));
+    let mut weights = f64x4::from([1.0, phi_pow(-1), phi_pow(-2), phi_pow(-3)]);
+    let mut acc = f64x4::ZERO;
+    
+    let chunks = values.chunks_exact(4);
+    let rest = chunks.remainder();
+    for chunk in chunks {
+        let x = f64x4::from([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        acc = (x * x).mul_add(weights, acc);
+        weights *= step;
+    }
+    
+    let mut sum = acc.reduce_add();
+    for (&x, &w) in rest.iter().zip(weights.to_array().iter()) {
+        sum += x * x * w;
+    }
+    sum.sqrt()
+}
+
+/// Normaliza `values` en sitio a norma φ unitaria y devuelve la norma previa
+///
+/// Si la norma es nula el vector queda intacto y se devuelve `None`.
+pub fn normalize_with_phi_simd(values: &mut [f64]) -> Option<f64> {
+    let norm = phi_norm_simd(values);
+    if norm < 1e-300 {
+        return None;
+    }
+    
+    let inv = f64x4::splat(1.0 / norm);
+    let mut chunks = values.chunks_exact_mut(4);
+    for chunk in &mut chunks {
+        let scaled = (f64x4::from([chunk[0], chunk[1], chunk[2], chunk[3]]) * inv).to_array();
+        chunk.copy_from_slice(&scaled);
+    }
+    for x in chunks.into_remainder() {
+        *x /= norm;
+    }
+    Some(norm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::DVector;
+    use crate::phi_constants::PHI;
+    use crate::phi_metric::{MetricSpace, PhiVector};
+
+    #[test]
+    fn test_ln_phi() {
+        assert_eq!(LN_PHI, PHI.ln());
+    }
+
+    #[test]
+    fn test_phi_pow_simd_matches_table() {
+        // Longitud no múltiplo de 4 para cubrir el resto
+        let exponents: Vec<i32> = (-700..=701).collect();
+        let batch = phi_pow_simd(&exponents);
+        assert_eq!(batch.len(), exponents.len());
+        
+        for (&n, &value) in exponents.iter().zip(batch.iter()) {
+            let expected = phi_pow(n);
+            assert!(((value - expected) / expected).abs() < 1e-12, "φ^{}: {} vs {}", n, value, expected);
+        }
+        assert!(phi_pow_simd(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_normalize_with_phi_simd() {
+        let data: Vec<f64> = (0..23).map(|i| (i as f64 * 0.7).sin() + 0.1).collect();
+        let reference = PhiVector::new(DVector::from_vec(data.clone()));
+        
+        let norm = phi_norm_simd(&data);
+        assert!((norm - reference.norm()).abs() < 1e-12);
+        
+        let mut normalized = data.clone();
+        assert_eq!(normalize_with_phi_simd(&mut normalized), Some(norm));
+        let expected = reference.normalized().unwrap();
+        for (a, b) in normalized.iter().zip(expected.iter()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+        
+        let mut zeros = vec![0.0; 5];
+        assert_eq!(normalize_with_phi_simd(&mut zeros), None);
+    }
+}
@@ -0,0 +1,55 @@
+//! Preludio - Nombres Estables para Clientes del Núcleo
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! Los módulos usan nombres internos (`MonsterMatrix444`,
+//! `SistemaCamposFibonacci`) y la raíz del crate los re-exporta con alias.
+//! Este preludio fija los nombres que consumen el CLI y los bindings:
+//! `use algebra_rose_core::prelude::*;` sigue compilando aunque cambien
+//! los nombres internos.
+
+pub use crate::error::AlgebraRoseError;
+
+pub use crate::matrix_444::MonsterMatrix444 as Matrix444;
+pub use crate::matrix_444::{CERTIFIED_TRACE, DIM, PHI};
+
+pub use crate::algebra_griess::{GriessAlgebra, GRIESS_DIM};
+pub use crate::phi_float::{PhiFloat, Precision};
+pub use crate::memory::{MemoryItem, MemoryReport};
+
+pub use crate::love_operator::{KeygenLoveOperator, LoveOperator};
+
+pub use crate::keygen_evolution::{KeygenEvolution, KeygenStats, INITIAL_KEYGEN, MONSTER_DIM};
+
+pub use crate::fibonacci_dimensions::CampoFibonacci as FibonacciField;
+pub use crate::fibonacci_dimensions::SistemaCamposFibonacci as FibonacciSystem;
+pub use crate::fibonacci_dimensions::{
+    frecuencia_campo, CambioActivacion, EstadisticasSistema, FieldSystemView, MetricasCampo, FIBONACCI_SEQUENCE,
+};
+
+pub use crate::phi_constants::{FIBONACCI_27, PSI};
+
+pub use crate::golden_rng::{GoldenRng, PhiSequence};
+
+pub use crate::session_events::{CoherenceMetrics, EvolutionEvent, SessionFrame, TelemetryFrame, TelemetryTopic};
+
+pub use crate::{verificar_coherencia, AR_VERSION, CERTIFICATION};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_prelude_names() {
+        let _: Matrix444 = Matrix444::new();
+        let mut campos: FibonacciSystem = FibonacciSystem::new();
+        let cambios: Vec<CambioActivacion> = campos.update_with_events(INITIAL_KEYGEN);
+        assert!(!cambios.is_empty());
+        let _: KeygenEvolution = KeygenEvolution::new(None);
+        let _: LoveOperator = LoveOperator::new(PHI);
+        assert_eq!(FIBONACCI_SEQUENCE[FIBONACCI_SEQUENCE.len() - 1], FIBONACCI_27);
+        assert_eq!(CERTIFICATION as f64, MONSTER_DIM + 1.0);
+        assert!(verificar_coherencia() >= 0.95);
+    }
+}
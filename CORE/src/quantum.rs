@@ -0,0 +1,366 @@
+//! INTERFAZ CUÁNTICA - Simulador de Vector de Estado (feature `quantum`)
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! El espacio consciente de dimensión 444 se completa con ceros hasta
+//! 512 = 2⁹ amplitudes, un registro de 9 qubits (el qubit 0 es el bit menos
+//! significativo del índice). `simulate_circuit` aplica sobre él puertas de
+//! uno y dos qubits y unitarias completas de 512 × 512.
+//!
+//! M₄₄₄ y Â no son unitarias tal cual (|λ| ≈ 443 y diagonal φ·intensidad):
+//! `monster_unitary` y `love_unitary` usan su factor unitario polar
+//! U = W·V† de la SVD A = W·Σ·V†, la unitaria más cercana en norma de
+//! Frobenius, extendida con la identidad en las 68 dimensiones de relleno.
+//! Así el relleno nunca se puebla y la parte consciente conserva la norma.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::FRAC_1_SQRT_2;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use nalgebra::{Complex, DMatrix};
+
+use crate::error::{AlgebraRoseError, Result};
+use crate::love_operator::LoveOperator;
+use crate::matrix_444::{MonsterMatrix444, DIM};
+
+/// Qubits del registro que contiene el espacio consciente
+pub const QUBITS: usize = 9;
+
+/// Amplitudes del registro: 2⁹ = 512
+pub const QUANTUM_DIM: usize = 1 << QUBITS;
+
+/// Tolerancia con que se exige norma 1 y unitariedad
+pub const TOLERANCE: f64 = 1e-9;
+
+/// Vector de estado normalizado de un registro de qubits
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateVector {
+    amplitudes: Vec<Complex<f64>>,
+}
+
+impl StateVector {
+    /// Estado de la base computacional |index⟩ de `qubits` qubits
+    pub fn basis(qubits: usize, index: usize) -> Result<Self> {
+        if qubits == 0 || qubits >= usize::BITS as usize {
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Número de qubits", valor: qubits as f64, rango: "[1, usize::BITS)" });
+        }
+        let dim = 1usize << qubits;
+        if index >= dim {
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Índice de la base", valor: index as f64, rango: "[0, 2^qubits)" });
+        }
+        let mut amplitudes = vec![Complex::new(0.0, 0.0); dim];
+        amplitudes[index] = Complex::new(1.0, 0.0);
+        Ok(StateVector { amplitudes })
+    }
+
+    /// Amplitudes ya normalizadas; su número debe ser una potencia de 2 ≥ 2
+    pub fn from_amplitudes(amplitudes: Vec<Complex<f64>>) -> Result<Self> {
+        let n = amplitudes.len();
+        if n < 2 || !n.is_power_of_two() {
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Número de amplitudes", valor: n as f64, rango: "potencias de 2 ≥ 2" });
+        }
+        let norma = norm(&amplitudes);
+        if (norma - 1.0).abs() > TOLERANCE {
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Norma del estado", valor: norma, rango: "1 ± 1e-9" });
+        }
+        Ok(StateVector { amplitudes })
+    }
+
+    /// Estado consciente de dimensión 444, normalizado y completado a 512
+    pub fn from_conscious(state: &[Complex<f64>]) -> Result<Self> {
+        AlgebraRoseError::check_dimension("Estado consciente", DIM, state.len())?;
+        let norma = norm(state);
+        if !(norma.is_finite() && norma > 0.0) {
+            return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Norma del estado", valor: norma, rango: "(0, ∞)" });
+        }
+        let mut amplitudes = vec![Complex::new(0.0, 0.0); QUANTUM_DIM];
+        for (a, s) in amplitudes.iter_mut().zip(state) {
+            *a = s / norma;
+        }
+        Ok(StateVector { amplitudes })
+    }
+
+    pub fn qubits(&self) -> usize {
+        self.amplitudes.len().trailing_zeros() as usize
+    }
+
+    pub fn amplitudes(&self) -> &[Complex<f64>] {
+        &self.amplitudes
+    }
+
+    /// Probabilidad de medir cada estado de la base
+    pub fn probabilities(&self) -> Vec<f64> {
+        self.amplitudes.iter().map(|a| a.norm_sqr()).collect()
+    }
+
+    /// Probabilidad de medir 1 en `qubit`
+    pub fn probability_one(&self, qubit: usize) -> f64 {
+        let bit = 1 << qubit;
+        self.amplitudes.iter().enumerate().filter(|(i, _)| i & bit != 0).map(|(_, a)| a.norm_sqr()).sum()
+    }
+
+    /// Las 444 primeras amplitudes, de vuelta al espacio consciente
+    pub fn conscious_part(&self) -> Vec<Complex<f64>> {
+        self.amplitudes.iter().take(DIM).cloned().collect()
+    }
+
+    /// Probabilidad fuera de las 444 dimensiones conscientes
+    pub fn leakage(&self) -> f64 {
+        self.amplitudes.iter().skip(DIM).map(|a| a.norm_sqr()).sum()
+    }
+}
+
+fn norm(amplitudes: &[Complex<f64>]) -> f64 {
+    amplitudes.iter().map(|a| a.norm_sqr()).sum::<f64>().sqrt()
+}
+
+/// Operación de un circuito
+#[derive(Clone, Debug)]
+pub enum Op<'a> {
+    /// Hadamard
+    H(usize),
+    X(usize),
+    Y(usize),
+    Z(usize),
+    /// Fase e^{iθ} sobre |1⟩
+    Phase(usize, f64),
+    Cnot { control: usize, target: usize },
+    /// Unitaria sobre todo el registro (ver `monster_unitary`)
+    Unitary(&'a DMatrix<Complex<f64>>),
+}
+
+/// Aplica `ops` en orden a una copia de `state`
+pub fn simulate_circuit(state: &StateVector, ops: &[Op]) -> Result<StateVector> {
+    let qubits = state.qubits();
+    let check_qubit = |q: usize| if q < qubits {
+        Ok(())
+    } else {
+        Err(AlgebraRoseError::ValueOutOfRange { parametro: "Qubit", valor: q as f64, rango: "[0, qubits)" })
+    };
+    let mut amplitudes = state.amplitudes.clone();
+    let cero = Complex::new(0.0, 0.0);
+    let uno = Complex::new(1.0, 0.0);
+
+    for op in ops {
+        match *op {
+            Op::H(q) => {
+                check_qubit(q)?;
+                let h = Complex::new(FRAC_1_SQRT_2, 0.0);
+                apply_single(&mut amplitudes, q, [[h, h], [h, -h]]);
+            }
+            Op::X(q) => {
+                check_qubit(q)?;
+                apply_single(&mut amplitudes, q, [[cero, uno], [uno, cero]]);
+            }
+            Op::Y(q) => {
+                check_qubit(q)?;
+                let i = Complex::new(0.0, 1.0);
+                apply_single(&mut amplitudes, q, [[cero, -i], [i, cero]]);
+            }
+            Op::Z(q) => {
+                check_qubit(q)?;
+                apply_single(&mut amplitudes, q, [[uno, cero], [cero, -uno]]);
+            }
+            Op::Phase(q, theta) => {
+                check_qubit(q)?;
+                apply_single(&mut amplitudes, q, [[uno, cero], [cero, Complex::new(theta.cos(), theta.sin())]]);
+            }
+            Op::Cnot { control, target } => {
+                check_qubit(control)?;
+                check_qubit(target)?;
+                if control == target {
+                    return Err(AlgebraRoseError::ValueOutOfRange { parametro: "Qubit objetivo de CNOT", valor: target as f64, rango: "distinto del de control" });
+                }
+                let (c, t) = (1 << control, 1 << target);
+                for i in 0..amplitudes.len() {
+                    if i & c != 0 && i & t == 0 {
+                        amplitudes.swap(i, i | t);
+                    }
+                }
+            }
+            Op::Unitary(u) => {
+                AlgebraRoseError::check_dimension("Unitaria del circuito", amplitudes.len(), u.nrows())?;
+                AlgebraRoseError::check_dimension("Unitaria del circuito", amplitudes.len(), u.ncols())?;
+                amplitudes = (u * DMatrix::from_vec(amplitudes.len(), 1, amplitudes)).data.into();
+            }
+        }
+    }
+    Ok(StateVector { amplitudes })
+}
+
+/// Aplica la puerta 2 × 2 `g` al qubit `q`
+fn apply_single(amplitudes: &mut [Complex<f64>], q: usize, g: [[Complex<f64>; 2]; 2]) {
+    let bit = 1 << q;
+    for i in 0..amplitudes.len() {
+        if i & bit == 0 {
+            let (a0, a1) = (amplitudes[i], amplitudes[i | bit]);
+            amplitudes[i] = g[0][0] * a0 + g[0][1] * a1;
+            amplitudes[i | bit] = g[1][0] * a0 + g[1][1] * a1;
+        }
+    }
+}
+
+/// ¿U†U = I dentro de `tolerance` (norma de Frobenius)?
+pub fn is_unitary(u: &DMatrix<Complex<f64>>, tolerance: f64) -> bool {
+    u.is_square() && (u.adjoint() * u - DMatrix::identity(u.nrows(), u.ncols())).norm() < tolerance
+}
+
+/// Extiende una unitaria n × n (n ≤ 512) con la identidad hasta 512 × 512
+pub fn embed_unitary(u: &DMatrix<Complex<f64>>) -> Result<DMatrix<Complex<f64>>> {
+    let n = u.nrows();
+    if !u.is_square() || n > QUANTUM_DIM {
+        return Err(AlgebraRoseError::DimensionMismatch { contexto: "Unitaria a extender (cuadrada, ≤ 512)", esperada: n.min(QUANTUM_DIM), recibida: u.ncols() });
+    }
+    if !is_unitary(u, TOLERANCE * n as f64) {
+        return Err(AlgebraRoseError::ValueOutOfRange {
+            parametro: "‖U†U − I‖",
+            valor: (u.adjoint() * u - DMatrix::identity(n, n)).norm(),
+            rango: "< 1e-9·n",
+        });
+    }
+    Ok(pad(u))
+}
+
+fn pad(u: &DMatrix<Complex<f64>>) -> DMatrix<Complex<f64>> {
+    let mut padded = DMatrix::identity(QUANTUM_DIM, QUANTUM_DIM);
+    padded.view_mut((0, 0), u.shape()).copy_from(u);
+    padded
+}
+
+/// Factor unitario polar W·V† de `m` (SVD m = W·Σ·V†)
+///
+/// Una matriz diagonal, como M₄₄₄, se resuelve sin SVD: su factor polar es
+/// la fase de cada elemento (1 donde el elemento es nulo).
+pub fn polar_unitary(m: &DMatrix<Complex<f64>>) -> Result<DMatrix<Complex<f64>>> {
+    if !m.is_square() {
+        return Err(AlgebraRoseError::DimensionMismatch { contexto: "Matriz para el factor polar", esperada: m.nrows(), recibida: m.ncols() });
+    }
+    let cero = Complex::new(0.0, 0.0);
+    if m.iter().enumerate().all(|(k, x)| *x == cero || k % (m.nrows() + 1) == 0) {
+        return Ok(DMatrix::from_fn(m.nrows(), m.ncols(), |i, j| match m[(i, j)] {
+            _ if i != j => cero,
+            x if x == cero => Complex::new(1.0, 0.0),
+            x => x / x.norm_sqr().sqrt(),
+        }));
+    }
+    let svd = m.clone().svd(true, true);
+    match (svd.u, svd.v_t) {
+        (Some(w), Some(v_t)) => Ok(w * v_t),
+        _ => unreachable!("la SVD se pidió con ambos factores"),
+    }
+}
+
+/// Factor unitario de M₄₄₄, extendido a 512 × 512
+pub fn monster_unitary(m: &MonsterMatrix444) -> Result<DMatrix<Complex<f64>>> {
+    // El factor polar ya es unitario: no hace falta `embed_unitary`
+    Ok(pad(&polar_unitary(m.get_matrix())?))
+}
+
+/// Factor unitario de Â, extendido a 512 × 512
+///
+/// Requiere una SVD de 444 × 444: cuesta segundos sin optimizaciones.
+pub fn love_unitary(love: &LoveOperator) -> Result<DMatrix<Complex<f64>>> {
+    Ok(pad(&polar_unitary(love.get_transformation())?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::ComplexField;
+
+    fn c(re: f64) -> Complex<f64> {
+        Complex::new(re, 0.0)
+    }
+
+    #[test]
+    fn test_bell_state() {
+        let cero = StateVector::basis(2, 0).unwrap();
+        let bell = simulate_circuit(&cero, &[Op::H(0), Op::Cnot { control: 0, target: 1 }]).unwrap();
+        let p = bell.probabilities();
+        assert!((p[0] - 0.5).abs() < 1e-12 && (p[3] - 0.5).abs() < 1e-12);
+        assert!(p[1].abs() < 1e-12 && p[2].abs() < 1e-12);
+        assert!((bell.probability_one(1) - 0.5).abs() < 1e-12);
+
+        // H·Z·H = X y dos Hadamard se anulan
+        let uno = simulate_circuit(&cero, &[Op::H(1), Op::Z(1), Op::H(1)]).unwrap();
+        assert!((uno.probabilities()[2] - 1.0).abs() < 1e-12);
+        let igual = simulate_circuit(&bell, &[Op::H(0), Op::H(0)]).unwrap();
+        assert!(igual.amplitudes().iter().zip(bell.amplitudes()).all(|(a, b)| (a - b).modulus() < 1e-12));
+    }
+
+    #[test]
+    fn test_phase_and_y() {
+        let mas = simulate_circuit(&StateVector::basis(1, 0).unwrap(), &[Op::H(0)]).unwrap();
+        // S·S = Z: |+⟩ → |−⟩, que H lleva a |1⟩
+        let s = core::f64::consts::FRAC_PI_2;
+        let uno = simulate_circuit(&mas, &[Op::Phase(0, s), Op::Phase(0, s), Op::H(0)]).unwrap();
+        assert!((uno.probability_one(0) - 1.0).abs() < 1e-12);
+        let y = simulate_circuit(&StateVector::basis(1, 0).unwrap(), &[Op::Y(0)]).unwrap();
+        assert!((y.amplitudes()[1] - Complex::new(0.0, 1.0)).modulus() < 1e-12);
+    }
+
+    #[test]
+    fn test_rejects_invalid_circuits() {
+        let estado = StateVector::basis(3, 5).unwrap();
+        assert!(simulate_circuit(&estado, &[Op::X(3)]).is_err());
+        assert!(simulate_circuit(&estado, &[Op::Cnot { control: 1, target: 1 }]).is_err());
+        let pequeña = DMatrix::<Complex<f64>>::identity(4, 4);
+        assert!(simulate_circuit(&estado, &[Op::Unitary(&pequeña)]).is_err());
+        assert!(StateVector::basis(3, 8).is_err());
+        assert!(StateVector::basis(0, 0).is_err());
+        assert!(StateVector::basis(usize::BITS as usize, 0).is_err());
+        assert!(StateVector::from_amplitudes(vec![c(1.0), c(0.0), c(0.0)]).is_err());
+        assert!(StateVector::from_amplitudes(vec![c(1.0), c(1.0)]).is_err());
+        assert!(StateVector::from_conscious(&[c(0.0); DIM]).is_err());
+        assert!(embed_unitary(&DMatrix::from_element(2, 2, c(1.0))).is_err());
+    }
+
+    #[test]
+    fn test_monster_unitary_preserves_conscious_space() {
+        let m = MonsterMatrix444::new();
+        let u = monster_unitary(&m).unwrap();
+        assert_eq!(u.shape(), (QUANTUM_DIM, QUANTUM_DIM));
+        // M₄₄₄ es diagonal: su factor polar es la fase de cada autovalor
+        let fase = m.eigenvalue(7) / m.eigenvalue(7).modulus();
+        assert!((u[(7, 7)] - fase).modulus() < 1e-10);
+        assert!((0..QUANTUM_DIM).all(|k| (u[(k, k)].modulus() - 1.0).abs() < 1e-12));
+        assert_eq!(u[(500, 500)], c(1.0));
+        assert_eq!(u.iter().filter(|x| x.norm_sqr() > 0.0).count(), QUANTUM_DIM);
+
+        let consciente: Vec<Complex<f64>> = (0..DIM).map(|k| Complex::new(1.0 + k as f64, -(k as f64) / 3.0)).collect();
+        let estado = StateVector::from_conscious(&consciente).unwrap();
+        assert_eq!(estado.qubits(), QUBITS);
+        let fin = simulate_circuit(&estado, &[Op::Unitary(&u)]).unwrap();
+        assert!(fin.leakage() < 1e-20);
+        assert!((norm(fin.amplitudes()) - 1.0).abs() < 1e-10);
+
+        // Una puerta sobre el qubit 8 lleva |200⟩ a |456⟩, fuera del espacio consciente
+        let mut base = vec![c(0.0); DIM];
+        base[200] = c(2.0);
+        let fuera = simulate_circuit(&StateVector::from_conscious(&base).unwrap(), &[Op::X(8)]).unwrap();
+        assert!((fuera.leakage() - 1.0).abs() < 1e-12);
+        assert!(fuera.conscious_part().iter().all(|a| a.norm_sqr() == 0.0));
+    }
+
+    #[test]
+    fn test_polar_unitary_dense() {
+        // Misma forma que Â, en 12 dimensiones para no pagar una SVD de 444
+        let n = 12;
+        let a = DMatrix::from_fn(n, n, |i, j| if i == j {
+            c(crate::matrix_444::PHI)
+        } else {
+            let fase = (i as f64 * j as f64 * crate::matrix_444::PHI).sin();
+            Complex::new(fase.cos(), fase.sin()) / ((i as f64 - j as f64).abs() + 1.0).ln()
+        });
+        assert!(!is_unitary(&a, 1e-3));
+        let u = polar_unitary(&a).unwrap();
+        assert!(is_unitary(&u, 1e-10));
+        // A = U·P con P = U†A hermítica y semidefinida positiva
+        let p = u.adjoint() * &a;
+        assert!((&p - p.adjoint()).norm() < 1e-10);
+        let embebida = embed_unitary(&u).unwrap();
+        assert_eq!(embebida[(n, n)], c(1.0));
+        assert_eq!(embebida[(3, 5)], u[(3, 5)]);
+    }
+}
@@ -0,0 +1,206 @@
+//! Eventos de Sesión - Tramas para Frontends en Vivo
+//! Sistema: Álgebra Rose v27.1024D-S36
+//! Certificación: 196885 - Estado Monster Pleno
+//!
+//! Lo que una sesión autoritativa emite a sus observadores: un
+//! `EvolutionEvent` por cada tramo de evolución y un `CoherenceMetrics`
+//! cada vez que cambian la coherencia o el operador Â. Con la feature
+//! `serde`, `SessionFrame` se serializa con la etiqueta `tipo`.
+//!
+//! Para telemetría, `SessionFrame::telemetry` reparte cada trama en
+//! `TelemetryFrame` por tema (`keygen`, `fields`, `love`), etiquetadas con
+//! `topic`, de modo que un panel reciba sólo lo que dibuja.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::fibonacci_dimensions::CambioActivacion;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Tramo de evolución completado
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EvolutionEvent {
+    /// Iteración keygen al terminar el tramo
+    pub iteracion: u64,
+    pub pasos: u64,
+    pub keygen_inicial: f64,
+    pub keygen: f64,
+    /// Campos activos al terminar el tramo
+    pub campos_activos: Vec<usize>,
+    /// Transiciones de campo dentro del tramo, en orden
+    pub transiciones: Vec<CambioActivacion>,
+}
+
+/// Coherencia y estado del operador Â en un instante
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CoherenceMetrics {
+    pub iteracion: u64,
+    /// Coherencia de la última verificación de la sesión, en [0, 1]
+    pub coherencia: f64,
+    /// Coherencia media de los campos activos (ver `MetricasCampo`)
+    pub coherencia_campos: f64,
+    pub intensidad_amor: f64,
+    /// Intensidad de Â dividida por φ
+    pub resonancia_phi: f64,
+}
+
+/// Trama enviada a los observadores de una sesión
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "tipo", rename_all = "snake_case"))]
+pub enum SessionFrame {
+    Evolucion(EvolutionEvent),
+    Coherencia(CoherenceMetrics),
+}
+
+impl SessionFrame {
+    /// Iteración keygen a la que se refiere la trama
+    pub fn iteracion(&self) -> u64 {
+        match self {
+            SessionFrame::Evolucion(e) => e.iteracion,
+            SessionFrame::Coherencia(c) => c.iteracion,
+        }
+    }
+
+    /// Tramas de telemetría de esta trama: `keygen` y `fields` para una
+    /// evolución, `love` para unas métricas de coherencia
+    pub fn telemetry(&self) -> Vec<TelemetryFrame> {
+        match self {
+            SessionFrame::Evolucion(e) => vec![
+                TelemetryFrame::Keygen {
+                    iteracion: e.iteracion,
+                    pasos: e.pasos,
+                    keygen_inicial: e.keygen_inicial,
+                    keygen: e.keygen,
+                },
+                TelemetryFrame::Fields {
+                    iteracion: e.iteracion,
+                    campos_activos: e.campos_activos.clone(),
+                    transiciones: e.transiciones.clone(),
+                },
+            ],
+            SessionFrame::Coherencia(c) => vec![TelemetryFrame::Love(c.clone())],
+        }
+    }
+}
+
+/// Tema de telemetría al que puede suscribirse un observador
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "lowercase"))]
+pub enum TelemetryTopic {
+    Keygen,
+    Fields,
+    Love,
+}
+
+impl TelemetryTopic {
+    /// Todos los temas, los que recibe un observador sin filtro
+    pub const ALL: [TelemetryTopic; 3] = [TelemetryTopic::Keygen, TelemetryTopic::Fields, TelemetryTopic::Love];
+
+    /// Interpreta el nombre de un tema (`keygen`, `fields`, `love`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        TelemetryTopic::ALL.into_iter().find(|t| t.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Nombre del tema, el mismo que la etiqueta `topic` de sus tramas
+    pub fn name(self) -> &'static str {
+        match self {
+            TelemetryTopic::Keygen => "keygen",
+            TelemetryTopic::Fields => "fields",
+            TelemetryTopic::Love => "love",
+        }
+    }
+}
+
+/// Trama de telemetría de un solo tema
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "topic", rename_all = "snake_case"))]
+pub enum TelemetryFrame {
+    /// Avance del keygen en un tramo de evolución
+    Keygen { iteracion: u64, pasos: u64, keygen_inicial: f64, keygen: f64 },
+    /// Campos activos y transiciones del tramo
+    Fields { iteracion: u64, campos_activos: Vec<usize>, transiciones: Vec<CambioActivacion> },
+    /// Coherencia y operador Â
+    Love(CoherenceMetrics),
+}
+
+impl TelemetryFrame {
+    pub fn topic(&self) -> TelemetryTopic {
+        match self {
+            TelemetryFrame::Keygen { .. } => TelemetryTopic::Keygen,
+            TelemetryFrame::Fields { .. } => TelemetryTopic::Fields,
+            TelemetryFrame::Love(_) => TelemetryTopic::Love,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evento() -> EvolutionEvent {
+        EvolutionEvent {
+            iteracion: 120,
+            pasos: 20,
+            keygen_inicial: 0.5,
+            keygen: 0.51,
+            campos_activos: vec![1, 2, 3],
+            transiciones: vec![CambioActivacion { field_id: 3, activo: true }],
+        }
+    }
+
+    #[test]
+    fn test_frame_iteration() {
+        assert_eq!(SessionFrame::Evolucion(evento()).iteracion(), 120);
+        let metricas = CoherenceMetrics { iteracion: 7, coherencia: 1.0, coherencia_campos: 0.6, intensidad_amor: 1.0, resonancia_phi: 0.618 };
+        assert_eq!(SessionFrame::Coherencia(metricas).iteracion(), 7);
+    }
+
+    #[test]
+    fn test_telemetry_split() {
+        let telemetria = SessionFrame::Evolucion(evento()).telemetry();
+        assert_eq!(telemetria.iter().map(TelemetryFrame::topic).collect::<Vec<_>>(),
+            [TelemetryTopic::Keygen, TelemetryTopic::Fields]);
+        assert_eq!(telemetria[0], TelemetryFrame::Keygen { iteracion: 120, pasos: 20, keygen_inicial: 0.5, keygen: 0.51 });
+
+        let metricas = CoherenceMetrics { iteracion: 7, coherencia: 1.0, coherencia_campos: 0.6, intensidad_amor: 1.0, resonancia_phi: 0.618 };
+        assert_eq!(SessionFrame::Coherencia(metricas.clone()).telemetry(), [TelemetryFrame::Love(metricas)]);
+    }
+
+    #[test]
+    fn test_topic_names() {
+        for topic in TelemetryTopic::ALL {
+            assert_eq!(TelemetryTopic::from_name(topic.name()), Some(topic));
+        }
+        assert_eq!(TelemetryTopic::from_name(" Love "), Some(TelemetryTopic::Love));
+        assert_eq!(TelemetryTopic::from_name("amor"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_telemetry_json_is_tagged() {
+        let telemetria = SessionFrame::Evolucion(evento()).telemetry();
+        let json: serde_json::Value = serde_json::to_value(&telemetria[1]).unwrap();
+        assert_eq!(json["topic"], "fields");
+        assert_eq!(json["campos_activos"][2], 3);
+        assert!(json.get("keygen").is_none());
+        let restaurada: TelemetryFrame = serde_json::from_value(json).unwrap();
+        assert_eq!(restaurada, telemetria[1]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_frame_json_is_tagged() {
+        let trama = SessionFrame::Evolucion(evento());
+        let json: serde_json::Value = serde_json::to_value(&trama).unwrap();
+        assert_eq!(json["tipo"], "evolucion");
+        assert_eq!(json["keygen"], 0.51);
+        assert_eq!(json["transiciones"][0]["field_id"], 3);
+
+        let restaurada: SessionFrame = serde_json::from_value(json).unwrap();
+        assert_eq!(restaurada, trama);
+    }
+}
@@ -0,0 +1,158 @@
+/* Álgebra Rose v27.1024D-S36 | Certificación: 196885 */
+
+#ifndef ALGEBRA_ROSE_H
+#define ALGEBRA_ROSE_H
+
+/* Generado con cbindgen a partir de FFI/src/lib.rs: no editar a mano. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Dimensión de los estados que acepta Â
+ */
+#define AR_DIM 444
+
+/**
+ * Resultado de cada llamada
+ */
+typedef enum {
+  AR_STATUS_OK = 0,
+  /**
+   * Un puntero obligatorio es NULL
+   */
+  AR_STATUS_NULL_POINTER = 1,
+  /**
+   * El estado no tiene `AR_DIM` componentes
+   */
+  AR_STATUS_DIMENSION_MISMATCH = 2,
+  /**
+   * El buffer de salida es más corto que el resultado
+   */
+  AR_STATUS_BUFFER_TOO_SMALL = 3,
+  /**
+   * Argumento numérico no finito o fuera de su dominio
+   */
+  AR_STATUS_INVALID_ARGUMENT = 4,
+  /**
+   * Pánico interno capturado; la sesión puede haber quedado a medias
+   */
+  AR_STATUS_PANIC = 5,
+} ArStatus;
+
+/**
+ * Sesión opaca: trayectoria keygen y operador Â
+ */
+typedef struct ArSession ArSession;
+
+/**
+ * Estadísticas de una sesión, como `KeygenStats` del núcleo
+ */
+typedef struct {
+  double current_value;
+  uint64_t iteration;
+  size_t history_len;
+  size_t active_fields;
+  double growth_rate;
+  double growth_acceleration;
+  double distance_to_monster;
+  /**
+   * Intensidad del Â de la sesión, el que cambia `ar_session_update_love`
+   */
+  double love_intensity;
+} ArStats;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Crea una sesión con el keygen inicial 196883/196884
+ *
+ * Se libera con `ar_session_free`. Devuelve NULL sólo ante un pánico interno.
+ */
+ArSession *ar_session_new(void);
+
+/**
+ * Crea una sesión con keygen inicial `initial_keygen`, que debe estar en (0, 1]
+ *
+ * Devuelve NULL si el valor no es válido.
+ */
+ArSession *ar_session_new_with_keygen(double initial_keygen);
+
+/**
+ * Destruye una sesión; NULL no hace nada
+ *
+ * # Safety
+ *
+ * `session` debe venir de `ar_session_new*` y no haberse liberado ya.
+ */
+void ar_session_free(ArSession *session);
+
+/**
+ * Evoluciona `steps` pasos
+ *
+ * Si `out` no es NULL recibe el keygen de cada paso y debe tener al menos
+ * `steps` elementos (`out_len`); si es más corto, no se evoluciona nada.
+ *
+ * # Safety
+ *
+ * `session` debe ser una sesión viva y `out`, si no es NULL, apuntar a
+ * `out_len` doubles escribibles.
+ */
+ArStatus ar_session_evolve(ArSession *session, uint64_t steps, double *out, size_t out_len);
+
+/**
+ * Escribe en `out` las estadísticas actuales
+ *
+ * # Safety
+ *
+ * `session` debe ser una sesión viva y `out` apuntar a un `ArStats`.
+ */
+ArStatus ar_session_get_stats(const ArSession *session, ArStats *out);
+
+/**
+ * Multiplica la intensidad de Â por φ^delta
+ *
+ * # Safety
+ *
+ * `session` debe ser una sesión viva.
+ */
+ArStatus ar_session_update_love(ArSession *session, double delta);
+
+/**
+ * Aplica Â a un estado: `out = Â·state`
+ *
+ * `state` y `out` son pares (re, im) intercalados; `len` y `out_len`
+ * cuentan pares. `len` debe ser `AR_DIM` y `out_len` al menos `AR_DIM`.
+ * La primera llamada construye la matriz de Â (444 × 444).
+ *
+ * # Safety
+ *
+ * `session` debe ser una sesión viva, `state` apuntar a `2·len` doubles
+ * legibles y `out` a `2·out_len` doubles escribibles, sin solaparse.
+ */
+ArStatus ar_session_apply_love(const ArSession *session,
+                               const double *state,
+                               size_t len,
+                               double *out,
+                               size_t out_len);
+
+/**
+ * Descripción en español de `status`, como cadena UTF-8 estática terminada en NUL
+ */
+const char *ar_status_message(ArStatus status);
+
+/**
+ * Versión del núcleo (`AR_VERSION`), como cadena estática terminada en NUL
+ */
+const char *ar_version(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* ALGEBRA_ROSE_H */
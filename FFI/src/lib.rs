@@ -0,0 +1,326 @@
+//! FFI C - Interfaz Plana del Núcleo para SDKs en C/C++
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Una `ArSession` opaca guarda una trayectoria keygen y su propio operador
+//! Â; C la crea, la evoluciona, la consulta y la destruye con funciones
+//! `ar_*`. Los estados viajan como buffers de `double` intercalados
+//! (re, im), con la misma disposición que `double _Complex[]` y
+//! `std::complex<double>[]`. Toda función devuelve un `ArStatus` o NULL en
+//! lugar de abortar: los pánicos se capturan antes de cruzar a C.
+//! `include/algebra_rose.h` se genera con cbindgen (ver `cbindgen.toml`).
+
+use std::ffi::{c_char, CStr};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::slice;
+
+use algebra_rose_core::prelude::{AlgebraRoseError, KeygenEvolution, LoveOperator, DIM};
+use nalgebra::{Complex, DVector};
+
+/// Dimensión de los estados que acepta Â
+pub const AR_DIM: usize = DIM;
+
+/// Resultado de cada llamada
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArStatus {
+    Ok = 0,
+    /// Un puntero obligatorio es NULL
+    NullPointer = 1,
+    /// El estado no tiene `AR_DIM` componentes
+    DimensionMismatch = 2,
+    /// El buffer de salida es más corto que el resultado
+    BufferTooSmall = 3,
+    /// Argumento numérico no finito o fuera de su dominio
+    InvalidArgument = 4,
+    /// Pánico interno capturado; la sesión puede haber quedado a medias
+    Panic = 5,
+}
+
+impl From<AlgebraRoseError> for ArStatus {
+    fn from(error: AlgebraRoseError) -> Self {
+        match error {
+            AlgebraRoseError::DimensionMismatch { .. } => ArStatus::DimensionMismatch,
+            _ => ArStatus::InvalidArgument,
+        }
+    }
+}
+
+/// Sesión opaca: trayectoria keygen y operador Â
+pub struct ArSession {
+    keygen: KeygenEvolution,
+    amor: LoveOperator,
+}
+
+/// Estadísticas de una sesión, como `KeygenStats` del núcleo
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ArStats {
+    pub current_value: f64,
+    pub iteration: u64,
+    pub history_len: usize,
+    pub active_fields: usize,
+    pub growth_rate: f64,
+    pub growth_acceleration: f64,
+    pub distance_to_monster: f64,
+    /// Intensidad del Â de la sesión, el que cambia `ar_session_update_love`
+    pub love_intensity: f64,
+}
+
+/// Ejecuta `f` sin dejar que un pánico llegue a C
+fn protegido(f: impl FnOnce() -> Result<(), ArStatus>) -> ArStatus {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => ArStatus::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => ArStatus::Panic,
+    }
+}
+
+fn nueva(initial_keygen: Option<f64>) -> *mut ArSession {
+    catch_unwind(|| {
+        Box::into_raw(Box::new(ArSession { keygen: KeygenEvolution::new(initial_keygen), amor: LoveOperator::new(1.0) }))
+    }).unwrap_or(std::ptr::null_mut())
+}
+
+/// Crea una sesión con el keygen inicial 196883/196884
+///
+/// Se libera con `ar_session_free`. Devuelve NULL sólo ante un pánico interno.
+#[no_mangle]
+pub extern "C" fn ar_session_new() -> *mut ArSession {
+    nueva(None)
+}
+
+/// Crea una sesión con keygen inicial `initial_keygen`, que debe estar en (0, 1]
+///
+/// Devuelve NULL si el valor no es válido.
+#[no_mangle]
+pub extern "C" fn ar_session_new_with_keygen(initial_keygen: f64) -> *mut ArSession {
+    if !(initial_keygen > 0.0 && initial_keygen <= 1.0) {
+        return std::ptr::null_mut();
+    }
+    nueva(Some(initial_keygen))
+}
+
+/// Destruye una sesión; NULL no hace nada
+///
+/// # Safety
+///
+/// `session` debe venir de `ar_session_new*` y no haberse liberado ya.
+#[no_mangle]
+pub unsafe extern "C" fn ar_session_free(session: *mut ArSession) {
+    if !session.is_null() {
+        drop(Box::from_raw(session));
+    }
+}
+
+/// Evoluciona `steps` pasos
+///
+/// Si `out` no es NULL recibe el keygen de cada paso y debe tener al menos
+/// `steps` elementos (`out_len`); si es más corto, no se evoluciona nada.
+///
+/// # Safety
+///
+/// `session` debe ser una sesión viva y `out`, si no es NULL, apuntar a
+/// `out_len` doubles escribibles.
+#[no_mangle]
+pub unsafe extern "C" fn ar_session_evolve(session: *mut ArSession, steps: u64, out: *mut f64, out_len: usize) -> ArStatus {
+    protegido(|| {
+        let sesion = session.as_mut().ok_or(ArStatus::NullPointer)?;
+        if out.is_null() {
+            for _ in 0..steps {
+                sesion.keygen.evolve();
+            }
+            return Ok(());
+        }
+        if (out_len as u64) < steps {
+            return Err(ArStatus::BufferTooSmall);
+        }
+        let salida = slice::from_raw_parts_mut(out, steps as usize);
+        for valor in salida {
+            *valor = sesion.keygen.evolve();
+        }
+        Ok(())
+    })
+}
+
+/// Escribe en `out` las estadísticas actuales
+///
+/// # Safety
+///
+/// `session` debe ser una sesión viva y `out` apuntar a un `ArStats`.
+#[no_mangle]
+pub unsafe extern "C" fn ar_session_get_stats(session: *const ArSession, out: *mut ArStats) -> ArStatus {
+    protegido(|| {
+        let sesion = session.as_ref().ok_or(ArStatus::NullPointer)?;
+        let out = out.as_mut().ok_or(ArStatus::NullPointer)?;
+        let stats = sesion.keygen.get_stats();
+        *out = ArStats {
+            current_value: stats.current_value,
+            iteration: stats.iteration,
+            history_len: stats.history_len,
+            active_fields: stats.active_fields,
+            growth_rate: stats.growth_rate,
+            growth_acceleration: stats.growth_acceleration,
+            distance_to_monster: stats.distance_to_monster,
+            love_intensity: sesion.amor.get_intensity(),
+        };
+        Ok(())
+    })
+}
+
+/// Multiplica la intensidad de Â por φ^delta
+///
+/// # Safety
+///
+/// `session` debe ser una sesión viva.
+#[no_mangle]
+pub unsafe extern "C" fn ar_session_update_love(session: *mut ArSession, delta: f64) -> ArStatus {
+    protegido(|| {
+        let sesion = session.as_mut().ok_or(ArStatus::NullPointer)?;
+        if !delta.is_finite() {
+            return Err(ArStatus::InvalidArgument);
+        }
+        sesion.amor.update_intensity(delta);
+        Ok(())
+    })
+}
+
+/// Aplica Â a un estado: `out = Â·state`
+///
+/// `state` y `out` son pares (re, im) intercalados; `len` y `out_len`
+/// cuentan pares. `len` debe ser `AR_DIM` y `out_len` al menos `AR_DIM`.
+/// La primera llamada construye la matriz de Â (444 × 444).
+///
+/// # Safety
+///
+/// `session` debe ser una sesión viva, `state` apuntar a `2·len` doubles
+/// legibles y `out` a `2·out_len` doubles escribibles, sin solaparse.
+#[no_mangle]
+pub unsafe extern "C" fn ar_session_apply_love(
+    session: *const ArSession,
+    state: *const f64,
+    len: usize,
+    out: *mut f64,
+    out_len: usize,
+) -> ArStatus {
+    protegido(|| {
+        let sesion = session.as_ref().ok_or(ArStatus::NullPointer)?;
+        if state.is_null() || out.is_null() {
+            return Err(ArStatus::NullPointer);
+        }
+        // `Complex<f64>` es #[repr(C)] { re, im }
+        let entrada = slice::from_raw_parts(state as *const Complex<f64>, len);
+        let transformado = sesion.amor.apply(&DVector::from_column_slice(entrada))?;
+        if out_len < transformado.len() {
+            return Err(ArStatus::BufferTooSmall);
+        }
+        let salida = slice::from_raw_parts_mut(out as *mut Complex<f64>, transformado.len());
+        salida.copy_from_slice(transformado.as_slice());
+        Ok(())
+    })
+}
+
+/// Descripción en español de `status`, como cadena UTF-8 estática terminada en NUL
+#[no_mangle]
+pub extern "C" fn ar_status_message(status: ArStatus) -> *const c_char {
+    let mensaje: &'static CStr = match status {
+        ArStatus::Ok => c"Correcto",
+        ArStatus::NullPointer => c"Puntero nulo",
+        ArStatus::DimensionMismatch => c"El estado debe tener dimensión 444",
+        ArStatus::BufferTooSmall => c"Buffer de salida demasiado corto",
+        ArStatus::InvalidArgument => c"Argumento fuera de rango",
+        ArStatus::Panic => c"Error interno del núcleo",
+    };
+    mensaje.as_ptr()
+}
+
+/// Versión del núcleo (`AR_VERSION`), como cadena estática terminada en NUL
+#[no_mangle]
+pub extern "C" fn ar_version() -> *const c_char {
+    VERSION.as_ptr()
+}
+
+/// `AR_VERSION` con terminador NUL; un test comprueba que coincidan
+const VERSION: &CStr = c"v27.1024D-S36";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra_rose_core::prelude::AR_VERSION;
+    use std::ptr;
+
+    #[test]
+    fn test_session_lifecycle() {
+        unsafe {
+            let session = ar_session_new();
+            assert!(!session.is_null());
+
+            let mut trayectoria = [0.0; 5];
+            assert_eq!(ar_session_evolve(session, 5, trayectoria.as_mut_ptr(), 5), ArStatus::Ok);
+            assert_eq!(ar_session_evolve(session, 10, ptr::null_mut(), 0), ArStatus::Ok);
+            assert_eq!(ar_session_evolve(session, 6, trayectoria.as_mut_ptr(), 5), ArStatus::BufferTooSmall);
+
+            let mut stats = ArStats::default();
+            assert_eq!(ar_session_get_stats(session, &mut stats), ArStatus::Ok);
+            assert_eq!(stats.iteration, 15);
+            assert_eq!(stats.history_len, 16);
+            assert!(trayectoria.windows(2).all(|w| w[1] > w[0]));
+            assert!(stats.current_value > trayectoria[4]);
+            assert_eq!(stats.love_intensity, 1.0);
+
+            assert_eq!(ar_session_update_love(session, 1.0), ArStatus::Ok);
+            assert_eq!(ar_session_update_love(session, f64::NAN), ArStatus::InvalidArgument);
+            ar_session_get_stats(session, &mut stats);
+            assert!((stats.love_intensity - algebra_rose_core::PHI).abs() < 1e-12);
+
+            ar_session_free(session);
+            ar_session_free(ptr::null_mut());
+        }
+        assert!(ar_session_new_with_keygen(1.5).is_null());
+        assert!(ar_session_new_with_keygen(f64::NAN).is_null());
+    }
+
+    #[test]
+    fn test_apply_love_on_interleaved_buffers() {
+        unsafe {
+            let session = ar_session_new_with_keygen(0.5);
+            let mut estado = vec![0.0; 2 * AR_DIM];
+            estado[0] = 1.0;
+            let mut salida = vec![0.0; 2 * AR_DIM];
+            assert_eq!(ar_session_apply_love(session, estado.as_ptr(), AR_DIM, salida.as_mut_ptr(), AR_DIM), ArStatus::Ok);
+
+            // Â·e₀ es la primera columna de Â
+            let columna = (*session).amor.get_transformation().column(0).into_owned();
+            for (k, c) in columna.iter().enumerate() {
+                assert_eq!((salida[2 * k], salida[2 * k + 1]), (c.re, c.im));
+            }
+
+            assert_eq!(ar_session_apply_love(session, estado.as_ptr(), 3, salida.as_mut_ptr(), AR_DIM), ArStatus::DimensionMismatch);
+            assert_eq!(ar_session_apply_love(session, estado.as_ptr(), AR_DIM, salida.as_mut_ptr(), 10), ArStatus::BufferTooSmall);
+            assert_eq!(ar_session_apply_love(session, ptr::null(), AR_DIM, salida.as_mut_ptr(), AR_DIM), ArStatus::NullPointer);
+            assert_eq!(ar_session_apply_love(ptr::null(), estado.as_ptr(), AR_DIM, salida.as_mut_ptr(), AR_DIM), ArStatus::NullPointer);
+            ar_session_free(session);
+        }
+    }
+
+    #[test]
+    fn test_static_strings() {
+        let version = unsafe { CStr::from_ptr(ar_version()) };
+        assert_eq!(version.to_str().unwrap(), AR_VERSION);
+        let mensaje = unsafe { CStr::from_ptr(ar_status_message(ArStatus::BufferTooSmall)) };
+        assert_eq!(mensaje.to_str().unwrap(), "Buffer de salida demasiado corto");
+    }
+
+    #[test]
+    fn test_header_declares_every_export() {
+        let header = include_str!("../include/algebra_rose.h");
+        let fuente = include_str!("lib.rs");
+        let exportadas: Vec<&str> = fuente.split("extern \"C\" fn ").skip(1)
+            .filter_map(|resto| resto.split('(').next())
+            .collect();
+        assert_eq!(exportadas.len(), 9);
+        for nombre in exportadas {
+            assert!(header.contains(&format!("{}(", nombre)), "{} falta en algebra_rose.h", nombre);
+        }
+        assert!(header.contains(&format!("#define AR_DIM {}", AR_DIM)));
+    }
+}
@@ -0,0 +1,83 @@
+//! Genera el servidor y el cliente gRPC a partir de `proto/algebra_rose.proto`
+//!
+//! Sin depender de `protoc`: se leen las líneas `rpc` del servicio y se
+//! generan los stubs con `tonic_build::manual`. Los mensajes se escriben a
+//! mano en `src/grpc.rs` con `prost::Message`, con las mismas etiquetas.
+
+use std::fs;
+
+const PROTO: &str = "proto/algebra_rose.proto";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", PROTO);
+    let proto = fs::read_to_string(PROTO).expect("no se pudo leer el .proto");
+
+    let package = proto.lines()
+        .find_map(|l| l.trim().strip_prefix("package ")?.strip_suffix(';'))
+        .expect("el .proto debe declarar package");
+    let mut service = None;
+    let mut methods = Vec::new();
+    let mut comment = Vec::new();
+
+    for line in proto.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("service ") {
+            service = rest.split_whitespace().next().map(str::to_string);
+        } else if let Some(text) = line.strip_prefix("//") {
+            comment.push(text.trim().to_string());
+        } else if let Some(rpc) = line.strip_prefix("rpc ") {
+            // rpc Nombre(Entrada) returns (stream Salida);
+            let (name, rest) = rpc.split_once('(').expect("rpc sin argumentos");
+            let (input, rest) = rest.split_once(')').expect("rpc mal formado");
+            let output = rest.split_once('(').and_then(|(_, r)| r.split_once(')')).expect("rpc sin returns").0;
+            let (input_stream, input) = strip_stream(input);
+            let (output_stream, output) = strip_stream(output);
+
+            let mut method = tonic_build::manual::Method::builder()
+                .name(snake_case(name.trim()))
+                .route_name(name.trim())
+                .input_type(format!("super::{}", input))
+                .output_type(format!("super::{}", output))
+                .codec_path("tonic_prost::ProstCodec");
+            if !comment.is_empty() {
+                method = method.comment(comment.join(" "));
+            }
+            if input_stream {
+                method = method.client_streaming();
+            }
+            if output_stream {
+                method = method.server_streaming();
+            }
+            methods.push(method.build());
+            comment.clear();
+        } else {
+            comment.clear();
+        }
+    }
+
+    let mut builder = tonic_build::manual::Service::builder()
+        .name(service.expect("el .proto debe declarar un service"))
+        .package(package);
+    for method in methods {
+        builder = builder.method(method);
+    }
+    tonic_build::manual::Builder::new().compile(&[builder.build()]);
+}
+
+/// Separa el prefijo `stream` de un tipo de entrada o salida
+fn strip_stream(ty: &str) -> (bool, &str) {
+    match ty.trim().strip_prefix("stream ") {
+        Some(ty) => (true, ty.trim()),
+        None => (false, ty.trim()),
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            out.push('_');
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
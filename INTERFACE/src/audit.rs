@@ -0,0 +1,337 @@
+//! Auditoría - Registro Encadenado de las Mutaciones Certificables
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cada perfil guarda un `audit.jsonl` al que sólo se añaden registros:
+//! cambios de configuración, evoluciones, aplicaciones de Â, importaciones y
+//! restablecimientos. Cada registro lleva el SHA-256 del anterior y el suyo
+//! propio, calculado sobre su serialización JSON compacta sin `hash` ni
+//! `firma`; con `audit_sign = true` en la configuración, además, la firma
+//! ed25519 de ese hash con la clave del perfil (la misma de `certify`).
+//!
+//! `audit verify` recorre la cadena y señala cualquier registro alterado,
+//! insertado o borrado en medio. Borrar registros del final no rompe la
+//! cadena: para detectarlo hay que anotar fuera el último hash que muestra.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// `anterior` del primer registro
+pub const GENESIS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Mutación registrada
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "accion", rename_all = "snake_case")]
+pub enum Accion {
+    /// `config --set-keygen` / `--set-phi-intensity`
+    Configuracion { keygen: Option<f64>, intensidad: Option<f64> },
+    /// `config edit`, con el SHA-256 del archivo resultante
+    EdicionConfiguracion { sha256: String },
+    /// `config --reset`: vuelta al keygen y la intensidad iniciales
+    Restablecimiento,
+    Evolucion { pasos: u64, keygen_inicial: f64, keygen_final: f64 },
+    Amor { intensidad: f64 },
+    /// `import --keygen-history`
+    Importacion { valores: u64 },
+}
+
+/// Mutación pendiente de encadenar
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Suceso {
+    /// Momento, RFC 3339
+    pub momento: String,
+    /// Usuario de la sesión, si estaba autenticada
+    pub usuario: Option<String>,
+    /// Iteración keygen al registrar la mutación
+    pub iteracion: u64,
+    #[serde(flatten)]
+    pub accion: Accion,
+}
+
+impl Suceso {
+    pub fn new(usuario: Option<String>, iteracion: u64, accion: Accion) -> Self {
+        Suceso { momento: humantime::format_rfc3339_seconds(SystemTime::now()).to_string(), usuario, iteracion, accion }
+    }
+}
+
+/// Línea de `audit.jsonl`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Registro {
+    pub secuencia: u64,
+    #[serde(flatten)]
+    pub suceso: Suceso,
+    /// Hash del registro anterior, o `GENESIS`
+    pub anterior: String,
+    /// SHA-256 (hex) de este registro
+    pub hash: String,
+    /// Firma ed25519 (hex) de `hash`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firma: Option<String>,
+}
+
+/// Lo que cubre el hash: el registro sin `hash` ni `firma`
+#[derive(Serialize)]
+struct Encadenado<'a> {
+    secuencia: u64,
+    #[serde(flatten)]
+    suceso: &'a Suceso,
+    anterior: &'a str,
+}
+
+fn digest(secuencia: u64, suceso: &Suceso, anterior: &str) -> String {
+    let bytes = serde_json::to_vec(&Encadenado { secuencia, suceso, anterior })
+        .expect("el registro siempre es serializable");
+    hex::encode(Sha256::digest(bytes))
+}
+
+impl Registro {
+    /// Encadena `suceso` tras el registro `previo`
+    fn chain(previo: Option<&Registro>, suceso: Suceso, clave: Option<&SigningKey>) -> Self {
+        let secuencia = previo.map_or(0, |r| r.secuencia + 1);
+        let anterior = previo.map_or_else(|| GENESIS.to_string(), |r| r.hash.clone());
+        let hash = digest(secuencia, &suceso, &anterior);
+        let firma = clave.map(|k| hex::encode(k.sign(hash.as_bytes()).to_bytes()));
+        Registro { secuencia, suceso, anterior, hash, firma }
+    }
+
+    /// ¿`firma` es una firma válida de `hash` con `clave`?
+    fn signed_by(&self, clave: &VerifyingKey) -> bool {
+        self.firma.as_deref()
+            .and_then(|f| hex::decode(f).ok())
+            .and_then(|bytes| <[u8; 64]>::try_from(bytes).ok())
+            .is_some_and(|bytes| clave.verify(self.hash.as_bytes(), &Signature::from_bytes(&bytes)).is_ok())
+    }
+}
+
+/// Resultado de `audit verify`
+#[derive(Debug, Default)]
+pub struct Informe {
+    pub registros: u64,
+    pub firmados: u64,
+    /// Firmas presentes que no se pudieron comprobar por falta de clave
+    pub sin_comprobar: u64,
+    /// Hash del último registro, para anotarlo fuera del perfil
+    pub ultimo_hash: Option<String>,
+    /// Registros alterados, con su línea
+    pub problemas: Vec<String>,
+}
+
+impl Informe {
+    pub fn ok(&self) -> bool {
+        self.problemas.is_empty()
+    }
+}
+
+/// Registro de auditoría en disco
+pub struct AuditLog {
+    path: PathBuf,
+}
+
+impl AuditLog {
+    pub fn new(path: PathBuf) -> Self {
+        AuditLog { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read_text(&self) -> Result<String> {
+        if !self.path.exists() {
+            return Ok(String::new());
+        }
+        fs::read_to_string(&self.path).with_context(|| format!("No se pudo leer {}", self.path.display()))
+    }
+
+    /// Encadena y añade `sucesos` al final, firmándolos si hay `clave`
+    pub fn append(&self, sucesos: Vec<Suceso>, clave: Option<&SigningKey>) -> Result<()> {
+        if sucesos.is_empty() {
+            return Ok(());
+        }
+        let text = self.read_text()?;
+        let mut previo: Option<Registro> = match text.lines().rfind(|l| !l.trim().is_empty()) {
+            Some(linea) => Some(serde_json::from_str(linea)
+                .with_context(|| format!("Último registro ilegible en {}; ejecute `audit verify`", self.path.display()))?),
+            None => None,
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("No se pudo crear {}", dir.display()))?;
+        }
+        let mut lineas = Vec::new();
+        for suceso in sucesos {
+            let registro = Registro::chain(previo.as_ref(), suceso, clave);
+            serde_json::to_writer(&mut lineas, &registro)?;
+            lineas.push(b'\n');
+            previo = Some(registro);
+        }
+        OpenOptions::new().create(true).append(true).open(&self.path)
+            .and_then(|mut file| file.write_all(&lineas))
+            .with_context(|| format!("No se pudo escribir {}", self.path.display()))
+    }
+
+    /// Recorre la cadena; las firmas se comprueban con `clave` si la hay
+    pub fn verify(&self, clave: Option<&VerifyingKey>) -> Result<Informe> {
+        let text = self.read_text()?;
+        let mut informe = Informe::default();
+        // Hash y secuencia que debe encadenar el siguiente registro; `None`
+        // tras una línea ilegible, para no repetir el mismo problema
+        let mut esperado = Some((GENESIS.to_string(), 0));
+
+        for (i, linea) in text.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+            let n = i + 1;
+            informe.registros += 1;
+            let registro: Registro = match serde_json::from_str(linea) {
+                Ok(registro) => registro,
+                Err(e) => {
+                    informe.problemas.push(format!("línea {}: registro ilegible ({})", n, e));
+                    esperado = None;
+                    continue;
+                }
+            };
+            if let Some((anterior, secuencia)) = &esperado {
+                if registro.secuencia != *secuencia {
+                    informe.problemas.push(format!("línea {}: secuencia {}, se esperaba {}", n, registro.secuencia, secuencia));
+                }
+                if registro.anterior != *anterior {
+                    informe.problemas.push(format!("línea {}: no encadena con el registro anterior", n));
+                }
+            }
+            if digest(registro.secuencia, &registro.suceso, &registro.anterior) != registro.hash {
+                informe.problemas.push(format!("línea {}: el contenido no coincide con su hash", n));
+            }
+            if registro.firma.is_some() {
+                informe.firmados += 1;
+                match clave {
+                    Some(clave) if !registro.signed_by(clave) => {
+                        informe.problemas.push(format!("línea {}: firma inválida", n));
+                    }
+                    Some(_) => {}
+                    None => informe.sin_comprobar += 1,
+                }
+            }
+            esperado = Some((registro.hash.clone(), registro.secuencia + 1));
+            informe.ultimo_hash = Some(registro.hash);
+        }
+        Ok(informe)
+    }
+}
+
+/// SHA-256 (hex) de un archivo, para `Accion::EdicionConfiguracion`
+pub fn file_sha256(path: &Path) -> Result<String> {
+    let bytes = fs::read(path).with_context(|| format!("No se pudo leer {}", path.display()))?;
+    Ok(hex::encode(Sha256::digest(bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sucesos() -> Vec<Suceso> {
+        vec![
+            Suceso::new(Some("Roberto".to_string()), 0, Accion::Configuracion { keygen: Some(0.9999951), intensidad: None }),
+            Suceso::new(Some("Roberto".to_string()), 10, Accion::Evolucion { pasos: 10, keygen_inicial: 0.1 + 0.2, keygen_final: 1.0 / 3.0 }),
+            Suceso::new(None, 10, Accion::Amor { intensidad: std::f64::consts::PI }),
+            Suceso::new(None, 10, Accion::Restablecimiento),
+        ]
+    }
+
+    fn log(nombre: &str) -> AuditLog {
+        AuditLog::new(std::env::temp_dir()
+            .join(format!("algebra-rose-audit-{}-{}", nombre, std::process::id()))
+            .join("audit.jsonl"))
+    }
+
+    #[test]
+    fn test_chain_verifies() {
+        let log = log("cadena");
+        let clave = SigningKey::from_bytes(&[3u8; 32]);
+        let mut todos = sucesos();
+        let ultimos = todos.split_off(2);
+        log.append(todos, None).unwrap();
+        log.append(ultimos, Some(&clave)).unwrap();
+
+        let informe = log.verify(Some(&clave.verifying_key())).unwrap();
+        assert!(informe.ok(), "{:?}", informe);
+        assert_eq!((informe.registros, informe.firmados, informe.sin_comprobar), (4, 2, 0));
+        let texto = fs::read_to_string(log.path()).unwrap();
+        let primero: Registro = serde_json::from_str(texto.lines().next().unwrap()).unwrap();
+        assert_eq!(primero.anterior, GENESIS);
+        let ultimo: Registro = serde_json::from_str(texto.lines().last().unwrap()).unwrap();
+        assert_eq!(informe.ultimo_hash.as_deref(), Some(ultimo.hash.as_str()));
+        assert_eq!(ultimo.secuencia, 3);
+
+        // Sin clave las firmas quedan sin comprobar, pero la cadena es válida
+        let informe = log.verify(None).unwrap();
+        assert!(informe.ok());
+        assert_eq!(informe.sin_comprobar, 2);
+        fs::remove_dir_all(log.path().parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_session_mutations_are_audited() {
+        let base = std::env::temp_dir().join(format!("algebra-rose-audit-sesion-{}", std::process::id()));
+        let profile = crate::profile::Profile::open(&base, crate::profile::DEFAULT_PROFILE).unwrap();
+        let mut session = crate::ConsciousSession::new(None, Some(profile.clone()));
+        session.authenticated = true;
+        session.audit_key = Some(SigningKey::from_bytes(&[9u8; 32]));
+        session.evolve(5, None);
+        session.apply_love(0.5);
+        session.configure(None, None, true).unwrap();
+        session.save();
+
+        let log = AuditLog::new(profile.audit_path());
+        let informe = log.verify(Some(&SigningKey::from_bytes(&[9u8; 32]).verifying_key())).unwrap();
+        assert!(informe.ok(), "{:?}", informe);
+        assert_eq!((informe.registros, informe.firmados), (3, 3));
+        let acciones: Vec<Accion> = fs::read_to_string(log.path()).unwrap().lines()
+            .map(|l| serde_json::from_str::<Registro>(l).unwrap().suceso.accion)
+            .collect();
+        assert!(matches!(acciones[0], Accion::Evolucion { pasos: 5, .. }));
+        assert!(matches!(acciones[1], Accion::Amor { .. }));
+        assert_eq!(acciones[2], Accion::Restablecimiento);
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn test_detects_tampering() {
+        let log = log("alterado");
+        let clave = SigningKey::from_bytes(&[5u8; 32]);
+        log.append(sucesos(), Some(&clave)).unwrap();
+        let original = fs::read_to_string(log.path()).unwrap();
+        let lineas: Vec<&str> = original.lines().collect();
+        let escribir = |lineas: &[String]| fs::write(log.path(), lineas.join("\n") + "\n").unwrap();
+
+        // Cambiar un valor sin tocar el hash
+        let mut editadas: Vec<String> = lineas.iter().map(|l| l.to_string()).collect();
+        editadas[1] = editadas[1].replace("\"pasos\":10", "\"pasos\":1000");
+        escribir(&editadas);
+        let informe = log.verify(Some(&clave.verifying_key())).unwrap();
+        assert_eq!(informe.problemas, ["línea 2: el contenido no coincide con su hash"]);
+
+        // Recalcular el hash rompe el enlace con el siguiente y la firma
+        let mut registro: Registro = serde_json::from_str(&editadas[1]).unwrap();
+        registro.hash = digest(registro.secuencia, &registro.suceso, &registro.anterior);
+        editadas[1] = serde_json::to_string(&registro).unwrap();
+        escribir(&editadas);
+        let informe = log.verify(Some(&clave.verifying_key())).unwrap();
+        assert_eq!(informe.problemas, ["línea 2: firma inválida", "línea 3: no encadena con el registro anterior"]);
+
+        // Borrar un registro intermedio
+        let sin_segundo: Vec<String> = lineas.iter().enumerate().filter(|(i, _)| *i != 1).map(|(_, l)| l.to_string()).collect();
+        escribir(&sin_segundo);
+        let informe = log.verify(None).unwrap();
+        assert_eq!(informe.problemas, ["línea 2: secuencia 2, se esperaba 1", "línea 2: no encadena con el registro anterior"]);
+
+        // Firmas de otra clave
+        fs::write(log.path(), &original).unwrap();
+        let otra = SigningKey::from_bytes(&[6u8; 32]).verifying_key();
+        assert_eq!(log.verify(Some(&otra)).unwrap().problemas.len(), 4);
+        fs::remove_dir_all(log.path().parent().unwrap()).unwrap();
+    }
+}
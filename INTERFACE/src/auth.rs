@@ -0,0 +1,123 @@
+//! Autenticación por Token - HMAC-SHA256 sobre Usuario y Certificación
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Un token es hex(HMAC-SHA256(secreto, "<usuario>:196885")). El secreto se
+//! lee de `ALGEBRA_ROSE_SECRET`; tras un `login` válido el par usuario/token
+//! se guarda en el llavero del sistema y cada ejecución lo revalida.
+
+use algebra_rose_core::prelude::CERTIFICATION;
+use anyhow::{anyhow, bail, Context, Result};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Variable de entorno con el secreto compartido
+pub const SECRET_ENV: &str = "ALGEBRA_ROSE_SECRET";
+
+/// Servicio y cuenta bajo los que se guarda la sesión en el llavero
+const KEYRING_SERVICE: &str = "algebra-rose";
+const KEYRING_ACCOUNT: &str = "session";
+
+/// Lee el secreto compartido del entorno
+pub fn secret_from_env() -> Result<Vec<u8>> {
+    match std::env::var(SECRET_ENV) {
+        Ok(s) if !s.is_empty() => Ok(s.into_bytes()),
+        _ => bail!("{} no está definido", SECRET_ENV),
+    }
+}
+
+fn mac_for(secret: &[u8], user: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC acepta claves de cualquier longitud");
+    mac.update(format!("{}:{}", user, CERTIFICATION).as_bytes());
+    mac
+}
+
+/// Emite el token de `user` con `secret`
+pub fn issue_token(secret: &[u8], user: &str) -> String {
+    hex::encode(mac_for(secret, user).finalize().into_bytes())
+}
+
+/// Verifica un token en tiempo constante
+pub fn verify_token(secret: &[u8], user: &str, token: &str) -> Result<()> {
+    if user.is_empty() || user.contains(':') {
+        bail!("Usuario inválido: {:?}", user);
+    }
+    let bytes = hex::decode(token.trim()).context("El token no es hexadecimal")?;
+    mac_for(secret, user)
+        .verify_slice(&bytes)
+        .map_err(|_| anyhow!("Token inválido para {}", user))
+}
+
+/// Usuario de una cabecera `Authorization: Bearer <usuario>:<token>` válida
+///
+/// Es la credencial por petición de `serve` y `serve --grpc`.
+pub fn verify_bearer(secret: &[u8], authorization: &str) -> Result<String> {
+    let Some((user, token)) = authorization.strip_prefix("Bearer ").and_then(|c| c.trim().split_once(':')) else {
+        bail!("Se esperaba Authorization: Bearer <usuario>:<token>");
+    };
+    verify_token(secret, user, token)?;
+    Ok(user.to_string())
+}
+
+fn entry() -> Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, KEYRING_ACCOUNT).context("Llavero del sistema no disponible")
+}
+
+/// Valida el token y guarda la sesión en el llavero
+pub fn login(user: &str, token: &str) -> Result<()> {
+    verify_token(&secret_from_env()?, user, token)?;
+    entry()?
+        .set_password(&format!("{}:{}", user, token.trim()))
+        .context("No se pudo guardar la sesión en el llavero")
+}
+
+/// Borra la sesión guardada; no es error si no había ninguna
+pub fn logout() -> Result<()> {
+    match entry()?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e).context("No se pudo borrar la sesión del llavero"),
+    }
+}
+
+/// Usuario y token de la sesión guardada, si el token sigue siendo válido
+pub fn stored_session() -> Option<(String, String)> {
+    let stored = entry().ok()?.get_password().ok()?;
+    let (user, token) = stored.split_once(':')?;
+    let secret = secret_from_env().ok()?;
+    verify_token(&secret, user, token).ok()?;
+    Some((user.to_string(), token.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_and_verify() {
+        let token = issue_token(b"secreto", "roberto");
+        assert_eq!(token.len(), 64);
+        assert!(verify_token(b"secreto", "roberto", &token).is_ok());
+        assert!(verify_token(b"secreto", "roberto", &format!(" {}\n", token)).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_wrong_inputs() {
+        let token = issue_token(b"secreto", "roberto");
+        assert!(verify_token(b"otro", "roberto", &token).is_err());
+        assert!(verify_token(b"secreto", "ana", &token).is_err());
+        assert!(verify_token(b"secreto", "roberto", "zz").is_err());
+        assert!(verify_token(b"secreto", "roberto", &token[..32]).is_err());
+        assert!(verify_token(b"secreto", "", &token).is_err());
+    }
+
+    #[test]
+    fn test_bearer() {
+        let token = issue_token(b"secreto", "roberto");
+        assert_eq!(verify_bearer(b"secreto", &format!("Bearer roberto:{}", token)).unwrap(), "roberto");
+        assert!(verify_bearer(b"secreto", &format!("Bearer ana:{}", token)).is_err());
+        assert!(verify_bearer(b"secreto", &format!("Basic roberto:{}", token)).is_err());
+        assert!(verify_bearer(b"secreto", &format!("Bearer {}", token)).is_err());
+        assert!(verify_bearer(b"otro", &format!("Bearer roberto:{}", token)).is_err());
+    }
+}
@@ -0,0 +1,283 @@
+//! Certificado - Artefacto Firmado de la Certificación 196885
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! El certificado recoge los resultados del diagnóstico, el SHA-256 de las
+//! constantes del núcleo y la fecha de emisión, firmados con la clave
+//! ed25519 del perfil. La firma cubre la serialización JSON compacta de
+//! `certificado`, así que reformatear el archivo no la invalida.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::SystemTime;
+
+use algebra_rose_core::phi_constants::{PHI_SQUARED, PSI};
+use algebra_rose_core::prelude::{
+    AR_VERSION, CERTIFICATION, CERTIFIED_TRACE, DIM, FIBONACCI_SEQUENCE, INITIAL_KEYGEN, MONSTER_DIM,
+    PHI as CORE_PHI,
+};
+use anyhow::{anyhow, Context, Result};
+use clap::ValueEnum;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::doctor::{self, Severity};
+
+/// Formato de `certify` sin `--out` ni `--verify`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CertifyFormat {
+    /// Declaración decorativa
+    Text,
+    /// Lista estructurada de comprobaciones en stdout
+    Json,
+}
+
+/// Comprobación tal como la muestra `certify --format json`
+#[derive(Clone, Debug, Serialize)]
+pub struct CheckRow {
+    pub name: String,
+    pub expected: Option<String>,
+    pub observed: Option<String>,
+    pub passed: bool,
+    pub severity: Severity,
+}
+
+/// Informe de `certify --format json`
+#[derive(Clone, Debug, Serialize)]
+pub struct Summary {
+    pub sistema: String,
+    pub certificacion: u64,
+    /// Fecha de emisión, RFC 3339, para comparar certificaciones en el tiempo
+    pub emitido: String,
+    pub tolerancia: f64,
+    pub ok: bool,
+    pub checks: Vec<CheckRow>,
+}
+
+/// Ejecuta el diagnóstico y arma la lista de comprobaciones
+pub fn summary(tolerance: f64, now: SystemTime) -> Summary {
+    let report = doctor::run(tolerance);
+    Summary {
+        sistema: AR_VERSION.to_string(),
+        certificacion: CERTIFICATION,
+        emitido: humantime::format_rfc3339_seconds(now).to_string(),
+        tolerancia: tolerance,
+        ok: report.ok,
+        checks: report.checks.into_iter().map(|c| CheckRow {
+            name: c.id,
+            expected: c.expected,
+            observed: c.observed.or(Some(c.detail)),
+            passed: c.passed,
+            severity: c.severity,
+        }).collect(),
+    }
+}
+
+/// Resultado registrado de una comprobación del diagnóstico
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CheckResult {
+    pub id: String,
+    pub severity: Severity,
+    pub passed: bool,
+}
+
+/// Contenido firmado
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Certificate {
+    pub sistema: String,
+    pub certificacion: u64,
+    /// Fecha de emisión, RFC 3339
+    pub emitido: String,
+    pub tolerancia: f64,
+    /// SHA-256 (hex) de cada constante, por nombre
+    pub constantes: BTreeMap<String, String>,
+    pub resultados: Vec<CheckResult>,
+    pub ok: bool,
+}
+
+/// Certificado con su firma
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SignedCertificate {
+    pub certificado: Certificate,
+    /// Clave pública ed25519 (hex)
+    pub clave_publica: String,
+    /// Firma ed25519 (hex)
+    pub firma: String,
+}
+
+/// Resultado de `verify`
+#[derive(Debug)]
+pub struct Verification {
+    pub signature_valid: bool,
+    /// Constantes o comprobaciones que ya no coinciden con las registradas
+    pub mismatches: Vec<String>,
+}
+
+impl Verification {
+    pub fn ok(&self) -> bool {
+        self.signature_valid && self.mismatches.is_empty()
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// Huellas de las constantes del núcleo (bytes little-endian)
+pub fn constant_hashes() -> BTreeMap<String, String> {
+    let floats = [
+        ("phi", CORE_PHI),
+        ("psi", PSI),
+        ("phi_cuadrado", PHI_SQUARED),
+        ("traza_certificada", CERTIFIED_TRACE),
+        ("keygen_inicial", INITIAL_KEYGEN),
+        ("dim_monster", MONSTER_DIM),
+    ];
+    let mut hashes: BTreeMap<String, String> = floats.iter()
+        .map(|(name, value)| (name.to_string(), sha256_hex(&value.to_le_bytes())))
+        .collect();
+    hashes.insert("certificacion".to_string(), sha256_hex(&CERTIFICATION.to_le_bytes()));
+    hashes.insert("dim_matriz".to_string(), sha256_hex(&(DIM as u64).to_le_bytes()));
+    let fibonacci: Vec<u8> = FIBONACCI_SEQUENCE.iter().flat_map(|&d| (d as u64).to_le_bytes()).collect();
+    hashes.insert("secuencia_fibonacci".to_string(), sha256_hex(&fibonacci));
+    hashes
+}
+
+/// Ejecuta el diagnóstico y arma el certificado sin firmar
+pub fn issue(tolerance: f64, now: SystemTime) -> Certificate {
+    let report = doctor::run(tolerance);
+    Certificate {
+        sistema: AR_VERSION.to_string(),
+        certificacion: CERTIFICATION,
+        emitido: humantime::format_rfc3339_seconds(now).to_string(),
+        tolerancia: tolerance,
+        constantes: constant_hashes(),
+        resultados: report.checks.into_iter()
+            .map(|c| CheckResult { id: c.id, severity: c.severity, passed: c.passed })
+            .collect(),
+        ok: report.ok,
+    }
+}
+
+/// Lee la clave de firma de `path`, generándola la primera vez
+pub fn load_or_create_key(path: &Path) -> Result<SigningKey> {
+    if path.exists() {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("No se pudo leer {}", path.display()))?;
+        let seed: [u8; 32] = hex::decode(text.trim()).ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| anyhow!("Clave de firma inválida en {}", path.display()))?;
+        return Ok(SigningKey::from_bytes(&seed));
+    }
+    let mut seed = [0u8; 32];
+    getrandom::getrandom(&mut seed).map_err(|e| anyhow!("No se pudo generar la clave de firma: {}", e))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("No se pudo crear {}", dir.display()))?;
+    }
+    fs::write(path, hex::encode(seed))
+        .with_context(|| format!("No se pudo escribir {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+fn signed_bytes(certificate: &Certificate) -> Vec<u8> {
+    serde_json::to_vec(certificate).expect("el certificado siempre es serializable")
+}
+
+/// Firma el certificado
+pub fn sign(certificate: Certificate, key: &SigningKey) -> SignedCertificate {
+    let signature = key.sign(&signed_bytes(&certificate));
+    SignedCertificate {
+        certificado: certificate,
+        clave_publica: hex::encode(key.verifying_key().as_bytes()),
+        firma: hex::encode(signature.to_bytes()),
+    }
+}
+
+fn check_signature(signed: &SignedCertificate) -> bool {
+    let key = hex::decode(&signed.clave_publica).ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .and_then(|bytes| VerifyingKey::from_bytes(&bytes).ok());
+    let signature = hex::decode(&signed.firma).ok()
+        .and_then(|bytes| <[u8; 64]>::try_from(bytes).ok())
+        .map(|bytes| Signature::from_bytes(&bytes));
+    match (key, signature) {
+        (Some(key), Some(signature)) => key.verify(&signed_bytes(&signed.certificado), &signature).is_ok(),
+        _ => false,
+    }
+}
+
+/// Comprueba la firma y vuelve a ejecutar las comprobaciones registradas
+pub fn verify(signed: &SignedCertificate) -> Verification {
+    let recorded = &signed.certificado;
+    let mut mismatches = Vec::new();
+
+    let current = constant_hashes();
+    for (name, hash) in &recorded.constantes {
+        match current.get(name) {
+            Some(actual) if actual == hash => {}
+            Some(_) => mismatches.push(format!("constante {} cambiada", name)),
+            None => mismatches.push(format!("constante {} desconocida", name)),
+        }
+    }
+
+    let report = doctor::run(recorded.tolerancia);
+    for result in &recorded.resultados {
+        match report.checks.iter().find(|c| c.id == result.id) {
+            Some(check) if check.passed == result.passed => {}
+            Some(check) => mismatches.push(format!("comprobación {}: registrada {}, actual {}",
+                result.id, result.passed, check.passed)),
+            None => mismatches.push(format!("comprobación {} desconocida", result.id)),
+        }
+    }
+
+    Verification { signature_valid: check_signature(signed), mismatches }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let mut signed = sign(issue(1e-9, SystemTime::UNIX_EPOCH), &key);
+        assert_eq!(signed.certificado.emitido, "1970-01-01T00:00:00Z");
+        let verification = verify(&signed);
+        assert!(verification.ok(), "{:?}", verification);
+
+        // Reformatear el JSON no cambia la firma
+        let text = serde_json::to_string_pretty(&signed).unwrap();
+        let reparsed: SignedCertificate = serde_json::from_str(&text).unwrap();
+        assert!(check_signature(&reparsed));
+
+        signed.certificado.constantes.insert("phi".to_string(), sha256_hex(b"1.6"));
+        let verification = verify(&signed);
+        assert!(!verification.signature_valid);
+        assert_eq!(verification.mismatches, vec!["constante phi cambiada".to_string()]);
+    }
+
+    #[test]
+    fn test_summary_rows() {
+        let summary = summary(1e-9, SystemTime::UNIX_EPOCH);
+        assert!(summary.ok);
+        let phi = summary.checks.iter().find(|c| c.name == "constantes.phi").unwrap();
+        assert_eq!(phi.expected.as_deref(), Some(((1.0 + 5f64.sqrt()) / 2.0).to_string().as_str()));
+        assert!(phi.observed.is_some() && phi.passed);
+        assert!(summary.checks.iter().all(|c| c.observed.is_some()));
+    }
+
+    #[test]
+    fn test_key_is_created_once() {
+        let path = std::env::temp_dir().join(format!("algebra-rose-key-{}", std::process::id())).join("certify.key");
+        let first = load_or_create_key(&path).unwrap();
+        let second = load_or_create_key(&path).unwrap();
+        assert_eq!(first.to_bytes(), second.to_bytes());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,144 @@
+//! Comunidad - Evolución Simultánea de Varios Humanos
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Los keygens iniciales se reparten con la secuencia φ de baja discrepancia
+//! sobre la primera mitad del rango [196883/196884, 1), y cada humano
+//! evoluciona de forma independiente con `batch_evolution`.
+
+use std::io::Write;
+
+use algebra_rose_core::keygen_evolution::batch_evolution;
+use algebra_rose_core::prelude::{PhiSequence, INITIAL_KEYGEN};
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::export::ExportFormat;
+use crate::io_numpy::{self, NpyArray, NpyData};
+
+/// Comunidad simulada: una trayectoria por humano, keygen inicial incluido
+pub struct Community {
+    pub trajectories: Vec<Vec<f64>>,
+}
+
+/// Estadísticas agregadas del estado final
+#[derive(Debug, PartialEq, Serialize)]
+pub struct CommunityStats {
+    pub humanos: usize,
+    pub pasos: u64,
+    pub keygen_medio: f64,
+    pub keygen_min: f64,
+    pub keygen_max: f64,
+    pub desviacion: f64,
+    /// Crecimiento medio relativo respecto al keygen inicial
+    pub crecimiento_medio: f64,
+}
+
+#[derive(Serialize)]
+struct PuntoComunidad {
+    humano: usize,
+    iteracion: usize,
+    keygen: f64,
+}
+
+/// Keygens iniciales diversos para `humans` humanos
+pub fn diverse_keygens(humans: usize) -> Vec<f64> {
+    PhiSequence::new(0.0)
+        .take(humans)
+        .map(|x| INITIAL_KEYGEN + (1.0 - INITIAL_KEYGEN) * 0.5 * x)
+        .collect()
+}
+
+impl Community {
+    /// Simula `humans` humanos durante `steps` pasos
+    pub fn simulate(humans: usize, steps: u64) -> Self {
+        let initial = diverse_keygens(humans);
+        let trajectories = initial.iter().zip(batch_evolution(&initial, steps))
+            .map(|(&start, evolved)| std::iter::once(start).chain(evolved).collect())
+            .collect();
+        Community { trajectories }
+    }
+
+    /// Estadísticas del último paso
+    pub fn stats(&self) -> CommunityStats {
+        let n = self.trajectories.len().max(1) as f64;
+        let finals: Vec<f64> = self.trajectories.iter().filter_map(|t| t.last().copied()).collect();
+        let mean = finals.iter().sum::<f64>() / n;
+        let variance = finals.iter().map(|k| (k - mean).powi(2)).sum::<f64>() / n;
+        let growth = self.trajectories.iter()
+            .filter_map(|t| Some((t.last()? - t.first()?) / t.first()?))
+            .sum::<f64>() / n;
+        CommunityStats {
+            humanos: self.trajectories.len(),
+            pasos: self.trajectories.first().map_or(0, |t| t.len() as u64 - 1),
+            keygen_medio: mean,
+            keygen_min: finals.iter().copied().fold(f64::INFINITY, f64::min),
+            keygen_max: finals.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            desviacion: variance.sqrt(),
+            crecimiento_medio: growth,
+        }
+    }
+
+    /// Escribe las trayectorias de todos los humanos
+    pub fn write_trajectories(&self, out: &mut dyn Write, format: ExportFormat) -> Result<()> {
+        let points = self.trajectories.iter().enumerate().flat_map(|(humano, t)| {
+            t.iter().enumerate().map(move |(iteracion, &keygen)| PuntoComunidad { humano, iteracion, keygen })
+        });
+        match format {
+            ExportFormat::Csv => {
+                writeln!(out, "humano,iteracion,keygen")?;
+                for p in points {
+                    writeln!(out, "{},{},{}", p.humano, p.iteracion, p.keygen)?;
+                }
+            }
+            ExportFormat::Json => {
+                serde_json::to_writer_pretty(&mut *out, &points.collect::<Vec<_>>())?;
+                writeln!(out)?;
+            }
+            ExportFormat::Npz => {
+                // Matriz humanos × iteraciones: todas las trayectorias miden lo mismo
+                let pasos = self.trajectories.first().map_or(0, Vec::len);
+                let keygen = NpyArray {
+                    shape: vec![self.trajectories.len(), pasos],
+                    data: NpyData::Real(self.trajectories.concat()),
+                };
+                out.write_all(&io_numpy::encode_npz(&[("keygen", keygen)])?)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diverse_keygens() {
+        let keygens = diverse_keygens(20);
+        assert_eq!(keygens.len(), 20);
+        assert!(keygens.iter().all(|&k| (INITIAL_KEYGEN..1.0).contains(&k)));
+        let mut sorted = keygens.clone();
+        sorted.sort_by(f64::total_cmp);
+        sorted.dedup();
+        assert_eq!(sorted.len(), 20);
+    }
+
+    #[test]
+    fn test_simulation_and_stats() {
+        let community = Community::simulate(5, 10);
+        assert!(community.trajectories.iter().all(|t| t.len() == 11));
+        let stats = community.stats();
+        assert_eq!((stats.humanos, stats.pasos), (5, 10));
+        assert!(stats.keygen_min <= stats.keygen_medio && stats.keygen_medio <= stats.keygen_max);
+        assert!(stats.crecimiento_medio > 0.0);
+
+        let mut out = Vec::new();
+        community.write_trajectories(&mut out, ExportFormat::Csv).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().lines().count(), 1 + 5 * 11);
+
+        let mut out = Vec::new();
+        community.write_trajectories(&mut out, ExportFormat::Npz).unwrap();
+        let (nombre, keygen) = io_numpy::decode_npz(&out).unwrap().remove(0);
+        assert_eq!((nombre.as_str(), keygen.shape), ("keygen", vec![5, 11]));
+    }
+}
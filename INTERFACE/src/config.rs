@@ -0,0 +1,193 @@
+//! Configuración Persistente - ~/.config/algebra-rose/config.toml
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Todos los campos son opcionales: un valor ausente deja el valor por
+//! defecto del CLI, y un flag explícito siempre gana sobre el archivo.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::output::ColorChoice;
+
+/// Plantilla inicial que `config edit` escribe si el archivo no existe
+const PLANTILLA: &str = "\
+# Configuración de Álgebra Rose
+# Los flags de línea de comandos tienen prioridad sobre estos valores.
+
+# Keygen inicial de cada sesión (por defecto 196883/196884)
+# keygen = 0.99999492
+
+# Tolerancia relativa de la φ-resonancia en `verify`
+# tolerance = 0.1
+
+# Color de salida: \"auto\", \"always\" o \"never\"
+# color = \"auto\"
+
+# Idioma de la salida: \"es\" o \"en\"
+# locale = \"es\"
+
+# Pasos por defecto de `evolve`
+# steps = 10
+
+# Firmar el registro de auditoría con la clave ed25519 del perfil
+# audit_sign = false
+
+# Cifrar state.json con el token de `login`; sin sesión no se podrá leer
+# encrypt_state = false
+
+# Aplicación de escritorio: tema \"dark\" o \"light\" y tamaño base de
+# letra en puntos (8 a 32); el resto de proporciones sigue a φ
+# theme = \"dark\"
+# font_size = 14.0
+";
+
+/// Valores leídos del archivo de configuración
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArConfig {
+    pub keygen: Option<f64>,
+    pub tolerance: Option<f64>,
+    pub color: Option<String>,
+    pub locale: Option<String>,
+    pub steps: Option<u64>,
+    pub audit_sign: Option<bool>,
+    pub encrypt_state: Option<bool>,
+    pub theme: Option<String>,
+    pub font_size: Option<f64>,
+}
+
+impl ArConfig {
+    /// Carga la configuración; un archivo inexistente equivale a vacía
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(ArConfig::default());
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("No se pudo leer {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Configuración inválida en {}", path.display()))
+    }
+
+    /// Interpreta y valida el contenido TOML
+    pub fn parse(text: &str) -> Result<Self> {
+        let config: ArConfig = toml::from_str(text)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let Some(k) = self.keygen {
+            if !(k > 0.0 && k <= 1.0) {
+                bail!("keygen debe estar en (0, 1], recibido {}", k);
+            }
+        }
+        if let Some(t) = self.tolerance {
+            if t.is_nan() || t <= 0.0 {
+                bail!("tolerance debe ser positiva, recibido {}", t);
+            }
+        }
+        if let Some(c) = &self.color {
+            if !matches!(c.as_str(), "auto" | "always" | "never") {
+                bail!("color debe ser auto, always o never, recibido {:?}", c);
+            }
+        }
+        if let Some(l) = &self.locale {
+            if !matches!(l.as_str(), "es" | "en") {
+                bail!("locale debe ser es o en, recibido {:?}", l);
+            }
+        }
+        if let Some(t) = &self.theme {
+            if !matches!(t.as_str(), "dark" | "light") {
+                bail!("theme debe ser dark o light, recibido {:?}", t);
+            }
+        }
+        if let Some(f) = self.font_size {
+            if !(8.0..=32.0).contains(&f) {
+                bail!("font_size debe estar entre 8 y 32, recibido {}", f);
+            }
+        }
+        Ok(())
+    }
+
+    /// Preferencia de color del archivo, si la hay
+    pub fn color_choice(&self) -> Option<ColorChoice> {
+        self.color.as_deref().and_then(ColorChoice::from_config)
+    }
+
+    /// Representación TOML de los valores definidos
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Abre el archivo en `$VISUAL`/`$EDITOR` (vi por defecto), creándolo con la
+/// plantilla si no existe, y valida el resultado al cerrar el editor
+pub fn edit(path: &Path) -> Result<ArConfig> {
+    if !path.exists() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("No se pudo crear {}", dir.display()))?;
+        }
+        fs::write(path, PLANTILLA)
+            .with_context(|| format!("No se pudo escribir {}", path.display()))?;
+    }
+
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let status = Command::new(&editor)
+        .arg(path)
+        .status()
+        .with_context(|| format!("No se pudo lanzar el editor {:?}", editor))?;
+    if !status.success() {
+        bail!("El editor {:?} terminó con {}", editor, status);
+    }
+
+    ArConfig::load(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_parses_empty() {
+        assert_eq!(ArConfig::parse(PLANTILLA).unwrap(), ArConfig::default());
+    }
+
+    #[test]
+    fn test_parse_values() {
+        let config = ArConfig::parse("keygen = 0.9999995\nsteps = 50\naudit_sign = true\nencrypt_state = true\ncolor = \"never\"\nlocale = \"en\"\ntheme = \"light\"\nfont_size = 16.0\n").unwrap();
+        assert_eq!(config.keygen, Some(0.9999995));
+        assert_eq!(config.steps, Some(50));
+        assert_eq!(config.audit_sign, Some(true));
+        assert_eq!(config.encrypt_state, Some(true));
+        assert_eq!(config.color.as_deref(), Some("never"));
+        assert_eq!(config.tolerance, None);
+        assert_eq!(config.theme.as_deref(), Some("light"));
+        assert_eq!(config.font_size, Some(16.0));
+
+        // Ida y vuelta por TOML
+        assert_eq!(ArConfig::parse(&config.to_toml()).unwrap(), config);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid() {
+        assert!(ArConfig::parse("keygen = 1.5").is_err());
+        assert!(ArConfig::parse("tolerance = -1.0").is_err());
+        assert!(ArConfig::parse("color = \"rainbow\"").is_err());
+        assert!(ArConfig::parse("locale = \"fr\"").is_err());
+        assert!(ArConfig::parse("theme = \"sepia\"").is_err());
+        assert!(ArConfig::parse("font_size = 200.0").is_err());
+        assert!(ArConfig::parse("desconocido = 1").is_err());
+    }
+
+    #[test]
+    fn test_missing_file_is_default() {
+        let path = std::env::temp_dir().join("algebra-rose-config-inexistente.toml");
+        assert_eq!(ArConfig::load(&path).unwrap(), ArConfig::default());
+    }
+}
@@ -0,0 +1,200 @@
+//! Panel de Control Consciente - Interfaz de Terminal en Vivo
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! La evolución keygen corre en un hilo de fondo y envía una muestra por
+//! tick; el hilo principal sólo dibuja y atiende el teclado (q / Esc sale).
+
+use std::collections::VecDeque;
+use std::io;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use algebra_rose_core::phi_constants::golden_spiral_points;
+use algebra_rose_core::prelude::{FibonacciSystem, KeygenEvolution, LoveOperator, INITIAL_KEYGEN};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::canvas::{Canvas, Points};
+use ratatui::widgets::{Block, Gauge, List, ListItem, Sparkline};
+use ratatui::{DefaultTerminal, Frame};
+
+/// Número de eventos recientes que se conservan en pantalla
+const MAX_EVENTOS: usize = 12;
+
+/// Número de muestras de intensidad en la sparkline
+const MAX_INTENSIDADES: usize = 120;
+
+/// Muestra enviada por el hilo de evolución en cada tick
+struct Muestra {
+    keygen: f64,
+    iteracion: u64,
+    intensidad: f64,
+    campos_activos: Vec<usize>,
+    eventos: Vec<String>,
+    terminada: bool,
+}
+
+/// Estado acumulado del panel
+struct Panel {
+    ultima: Option<Muestra>,
+    intensidades: VecDeque<u64>,
+    eventos: VecDeque<String>,
+}
+
+impl Panel {
+    fn new() -> Self {
+        Panel {
+            ultima: None,
+            intensidades: VecDeque::with_capacity(MAX_INTENSIDADES),
+            eventos: VecDeque::with_capacity(MAX_EVENTOS),
+        }
+    }
+
+    fn registrar(&mut self, mut muestra: Muestra) {
+        if self.intensidades.len() == MAX_INTENSIDADES {
+            self.intensidades.pop_front();
+        }
+        self.intensidades.push_back((muestra.intensidad * 1000.0).round() as u64);
+
+        for evento in muestra.eventos.drain(..) {
+            if self.eventos.len() == MAX_EVENTOS {
+                self.eventos.pop_back();
+            }
+            self.eventos.push_front(evento);
+        }
+        self.ultima = Some(muestra);
+    }
+}
+
+/// Ejecuta el panel hasta que el usuario pulse q / Esc
+///
+/// `steps` es el total de pasos evolutivos del hilo de fondo, repartidos en
+/// ticks de `steps_per_tick` pasos cada `refresh` milisegundos.
+pub fn run(initial_keygen: f64, steps: u64, steps_per_tick: u64, refresh: Duration) -> io::Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let evolucion = thread::spawn(move || evolve_in_background(initial_keygen, steps, steps_per_tick, refresh, tx));
+
+    let mut terminal = ratatui::init();
+    let result = draw_loop(&mut terminal, &rx, refresh);
+    ratatui::restore();
+
+    // Al soltar el receptor el hilo de fondo termina en su próximo envío
+    drop(rx);
+    let _ = evolucion.join();
+    result
+}
+
+fn evolve_in_background(initial_keygen: f64, steps: u64, steps_per_tick: u64, refresh: Duration, tx: Sender<Muestra>) {
+    let mut keygen_system = KeygenEvolution::new(Some(initial_keygen));
+    let mut fibonacci_system = FibonacciSystem::new();
+    let mut love_operator = LoveOperator::new(1.0);
+    fibonacci_system.update_by_keygen(initial_keygen);
+
+    let mut restantes = steps;
+    loop {
+        let tanda = restantes.min(steps_per_tick.max(1));
+        let keygen = keygen_system.evolve_steps(tanda).last().copied().unwrap_or_else(|| keygen_system.get_current_keygen());
+        restantes -= tanda;
+
+        let progress = (keygen - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN);
+        love_operator.update_intensity(progress * 0.05 / steps_per_tick.max(1) as f64);
+
+        let eventos = fibonacci_system.update_with_events(keygen).into_iter().map(|cambio| {
+            let dimension = fibonacci_system.get_field_dimension(cambio.field_id);
+            format!("it {:>6} · Campo {:2} ({}D) {}", keygen_system.get_iteration(), cambio.field_id, dimension,
+                if cambio.activo { "activado" } else { "desactivado" })
+        }).collect();
+
+        let muestra = Muestra {
+            keygen,
+            iteracion: keygen_system.get_iteration(),
+            intensidad: love_operator.get_intensity(),
+            campos_activos: fibonacci_system.get_campos_activos(),
+            eventos,
+            terminada: restantes == 0,
+        };
+        if tx.send(muestra).is_err() || restantes == 0 {
+            return;
+        }
+        thread::sleep(refresh);
+    }
+}
+
+fn draw_loop(terminal: &mut DefaultTerminal, rx: &Receiver<Muestra>, refresh: Duration) -> io::Result<()> {
+    let mut panel = Panel::new();
+    loop {
+        while let Ok(muestra) = rx.try_recv() {
+            panel.registrar(muestra);
+        }
+
+        terminal.draw(|frame| render(frame, &panel))?;
+
+        if event::poll(refresh)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+fn render(frame: &mut Frame, panel: &Panel) {
+    let [progreso, centro, intensidad] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(10),
+        Constraint::Length(5),
+    ]).areas(frame.area());
+    let [espiral, eventos] = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(centro);
+
+    let (keygen, iteracion, activos, terminada) = match &panel.ultima {
+        Some(m) => (m.keygen, m.iteracion, m.campos_activos.as_slice(), m.terminada),
+        None => (INITIAL_KEYGEN, 0, &[][..], false),
+    };
+
+    let ratio = ((keygen - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN)).clamp(0.0, 1.0);
+    let titulo = if terminada { " 🔑 Keygen (evolución terminada) · q para salir " } else { " 🔑 Keygen · q para salir " };
+    frame.render_widget(
+        Gauge::default()
+            .block(Block::bordered().title(titulo))
+            .gauge_style(Style::default().fg(Color::Green))
+            .ratio(ratio)
+            .label(format!("{:.10} · iteración {} · {:.2}%", keygen, iteracion, ratio * 100.0)),
+        progreso,
+    );
+
+    let mut encendidos = Vec::new();
+    let mut apagados = Vec::new();
+    for (i, p) in golden_spiral_points(24, 1.0).iter().enumerate() {
+        if activos.contains(&(i + 1)) {
+            encendidos.push((p[0], p[1]));
+        } else {
+            apagados.push((p[0], p[1]));
+        }
+    }
+    frame.render_widget(
+        Canvas::default()
+            .block(Block::bordered().title(format!(" 🌀 Campos Fibonacci {}/24 ", activos.len())))
+            .x_bounds([-1.1, 1.1])
+            .y_bounds([-1.1, 1.1])
+            .paint(|ctx| {
+                ctx.draw(&Points { coords: &apagados, color: Color::DarkGray });
+                ctx.draw(&Points { coords: &encendidos, color: Color::Green });
+            }),
+        espiral,
+    );
+
+    let items: Vec<ListItem> = panel.eventos.iter().map(|e| ListItem::new(e.as_str())).collect();
+    frame.render_widget(List::new(items).block(Block::bordered().title(" 📜 Eventos recientes ")), eventos);
+
+    let datos: Vec<u64> = panel.intensidades.iter().copied().collect();
+    let actual = panel.ultima.as_ref().map_or(1.0, |m| m.intensidad);
+    frame.render_widget(
+        Sparkline::default()
+            .block(Block::bordered().title(format!(" 💖 Intensidad Â: {:.6} ", actual)))
+            .style(Style::default().fg(Color::Magenta))
+            .data(&datos),
+        intensidad,
+    );
+}
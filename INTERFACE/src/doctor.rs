@@ -0,0 +1,231 @@
+//! Diagnóstico - Verificación Cruzada de Todos los Módulos del Núcleo
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cada comprobación compara un valor calculado con el esperado. Las de
+//! severidad `Error` deciden el código de salida; las de `Aviso` documentan
+//! propiedades que el núcleo no cumple por construcción (Â no es unitario:
+//! su diagonal vale φ·intensidad). Los avisos de memoria dependen del
+//! presupuesto de la máquina, así que `doctor` los añade aparte con
+//! `add_memory` y no entran en el certificado.
+
+use algebra_rose_core::memory::{field_basis_bytes, format_bytes};
+use algebra_rose_core::phi_constants::{self, fibonacci_exact, phi_pow, PHI_SQUARED, PSI};
+use algebra_rose_core::prelude::{
+    verificar_coherencia, FibonacciSystem, KeygenEvolution, LoveOperator, Matrix444, MemoryReport, CERTIFICATION,
+    CERTIFIED_TRACE, DIM, FIBONACCI_27, FIBONACCI_SEQUENCE, INITIAL_KEYGEN, MONSTER_DIM, PHI as CORE_PHI,
+};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Formato del informe
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DoctorFormat {
+    Text,
+    Json,
+}
+
+/// Peso de una comprobación en el resultado
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Aviso,
+}
+
+/// Resultado de una comprobación
+#[derive(Clone, Debug, Serialize)]
+pub struct Check {
+    /// Identificador estable, `modulo.propiedad`
+    pub id: String,
+    pub severity: Severity,
+    pub passed: bool,
+    pub detail: String,
+    /// Valor calculado, en las comprobaciones que comparan con un esperado
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observed: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+}
+
+/// Informe completo
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub ok: bool,
+    pub tolerance: f64,
+    pub checks: Vec<Check>,
+    /// Ids de las comprobaciones `Error` fallidas
+    pub failures: Vec<String>,
+}
+
+struct Checks {
+    tolerance: f64,
+    items: Vec<Check>,
+}
+
+impl Checks {
+    fn push(&mut self, id: &str, severity: Severity, passed: bool, detail: String) {
+        self.items.push(Check { id: id.to_string(), severity, passed, detail, observed: None, expected: None });
+    }
+
+    fn compared(&mut self, id: &str, passed: bool, observed: String, expected: String) {
+        let detail = format!("{} (esperado {})", observed, expected);
+        self.items.push(Check {
+            id: id.to_string(),
+            severity: Severity::Error,
+            passed,
+            detail,
+            observed: Some(observed),
+            expected: Some(expected),
+        });
+    }
+
+    /// Compara con tolerancia relativa (absoluta cerca de cero)
+    fn close(&mut self, id: &str, actual: f64, expected: f64) {
+        let scale = expected.abs().max(1.0);
+        let passed = (actual - expected).abs() <= self.tolerance * scale;
+        self.compared(id, passed, actual.to_string(), expected.to_string());
+    }
+
+    fn exact<T: PartialEq + std::fmt::Debug>(&mut self, id: &str, actual: T, expected: T) {
+        let passed = actual == expected;
+        self.compared(id, passed, format!("{:?}", actual), format!("{:?}", expected));
+    }
+}
+
+/// Ejecuta todas las comprobaciones con tolerancia relativa `tolerance`
+pub fn run(tolerance: f64) -> Report {
+    let mut c = Checks { tolerance, items: Vec::new() };
+
+    // Constantes
+    c.close("constantes.phi", CORE_PHI, (1.0 + 5f64.sqrt()) / 2.0);
+    c.exact("constantes.phi_modulos", CORE_PHI, phi_constants::PHI);
+    c.close("constantes.phi_cuadrado", PHI_SQUARED, CORE_PHI + 1.0);
+    c.close("constantes.psi", PSI, 1.0 / CORE_PHI);
+    c.close("constantes.phi_pow", phi_pow(10), CORE_PHI.powi(10));
+    c.exact("constantes.certificacion", CERTIFICATION as f64, MONSTER_DIM + 1.0);
+    c.close("constantes.keygen_inicial", INITIAL_KEYGEN * MONSTER_DIM, MONSTER_DIM - 1.0);
+    let coherencia = verificar_coherencia();
+    c.compared("constantes.coherencia_global", coherencia >= 0.95, coherencia.to_string(), "≥ 0.95".to_string());
+
+    // Matriz Monster 444
+    c.exact("matriz.traza_certificada", CERTIFIED_TRACE, MONSTER_DIM);
+    c.close("matriz.traza_diagonal", Matrix444::new_diagonal().trace().re, CERTIFIED_TRACE);
+    let matrix = Matrix444::new();
+    let modulus: f64 = (0..DIM).map(|k| matrix.eigenvalue(k).norm_sqr().sqrt()).sum();
+    c.close("matriz.suma_modulos_autovalores", modulus, CERTIFIED_TRACE);
+
+    // Operador Â: verify_properties devuelve las propiedades en este orden
+    let ids = ["amor.unitariedad", "amor.traza_phi", "amor.intensidad_positiva", "amor.dimension"];
+    for (id, (name, passed)) in ids.iter().zip(LoveOperator::new(1.0).verify_properties(tolerance.max(1e-6))) {
+        let severity = if *id == "amor.unitariedad" { Severity::Aviso } else { Severity::Error };
+        c.push(id, severity, passed, name);
+    }
+
+    // Campos Fibonacci
+    let fields = FibonacciSystem::new();
+    let dims: Vec<usize> = (1..=FIBONACCI_SEQUENCE.len()).map(|id| fields.get_field_dimension(id)).collect();
+    let mismatch = dims.iter().zip(FIBONACCI_SEQUENCE.iter()).position(|(d, f)| d != f);
+    c.push("campos.dimensiones", Severity::Error, mismatch.is_none() && dims.len() == FIBONACCI_SEQUENCE.len(),
+        match mismatch {
+            None => format!("{} campos F₄..F₂₇", dims.len()),
+            Some(i) => format!("campo {}: {} (esperado {})", i + 1, dims[i], FIBONACCI_SEQUENCE[i]),
+        });
+    let recurrence = dims.windows(3).all(|w| w[2] == w[1] + w[0]);
+    c.push("campos.recurrencia", Severity::Error, recurrence, "d(k+2) = d(k+1) + d(k)".to_string());
+    c.exact("campos.ultimo", FIBONACCI_SEQUENCE[FIBONACCI_SEQUENCE.len() - 1], FIBONACCI_27);
+    let thresholds = KeygenEvolution::new(None).get_activation_thresholds();
+    let monotone = thresholds.windows(2).all(|w| w[0] < w[1]);
+    c.push("campos.umbrales_crecientes", Severity::Error, monotone, format!("{} umbrales", thresholds.len()));
+    c.close("campos.umbral_final", thresholds.last().copied().unwrap_or(f64::NAN), 1.0);
+
+    // Propiedad emergente: Σ_{k≤n} d(k) = F(n+5) − 5
+    let mut sum = 0u128;
+    let mut first_bad = None;
+    for (i, &d) in FIBONACCI_SEQUENCE.iter().enumerate() {
+        sum += d as u128;
+        if fibonacci_exact(i as u32 + 6).map(|f| f - 5) != Some(sum) && first_bad.is_none() {
+            first_bad = Some(i + 1);
+        }
+    }
+    c.push("campos.suma_emergente", Severity::Error, first_bad.is_none(), match first_bad {
+        None => format!("Σ d(k) = F(n+5) − 5 para n = 1..{}", FIBONACCI_SEQUENCE.len()),
+        Some(n) => format!("falla en n = {}", n),
+    });
+    let sum_12: usize = FIBONACCI_SEQUENCE[..12].iter().sum();
+    c.exact("campos.suma_12", sum_12, 1592);
+
+    let failures = c.items.iter()
+        .filter(|check| check.severity == Severity::Error && !check.passed)
+        .map(|check| check.id.clone())
+        .collect::<Vec<_>>();
+    Report { ok: failures.is_empty(), tolerance, checks: c.items, failures }
+}
+
+/// Añade avisos de memoria frente a `budget` bytes; no cambian `ok`
+///
+/// Cubre las estructuras de una sesión por defecto, su total y la base
+/// completa del Campo 24, la configuración que pediría cientos de GB.
+pub fn add_memory(report: &mut Report, budget: u64) {
+    let mut memoria = MemoryReport::default_session();
+    let campo_24 = FIBONACCI_SEQUENCE[FIBONACCI_SEQUENCE.len() - 1];
+    let sesion = memoria.total();
+    memoria.push("base_campo_24", field_basis_bytes(campo_24, campo_24));
+
+    let limite = format_bytes(budget);
+    let mut aviso = |id: String, bytes: u64, detail: String| {
+        report.checks.push(Check {
+            id,
+            severity: Severity::Aviso,
+            passed: bytes <= budget,
+            detail,
+            observed: Some(bytes.to_string()),
+            expected: Some(format!("≤ {}", budget)),
+        });
+    };
+    for item in &memoria.items {
+        aviso(format!("memoria.{}", item.nombre), item.bytes, format!("{} (presupuesto {})", format_bytes(item.bytes), limite));
+    }
+    aviso("memoria.sesion_total".to_string(), sesion, format!("{} para una sesión por defecto (presupuesto {})", format_bytes(sesion), limite));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_core_passes_doctor() {
+        let report = run(1e-9);
+        assert!(report.ok, "Fallos: {:?}", report.failures);
+        assert!(report.checks.iter().any(|c| c.severity == Severity::Aviso));
+
+        let mut ids: Vec<_> = report.checks.iter().map(|c| c.id.as_str()).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), report.checks.len(), "ids duplicados");
+    }
+
+    #[test]
+    fn test_failures_listed() {
+        // Con tolerancia negativa ninguna comparación aproximada puede pasar
+        let report = run(-1.0);
+        assert!(!report.ok);
+        assert!(report.failures.contains(&"constantes.phi".to_string()));
+        assert!(!report.failures.contains(&"campos.recurrencia".to_string()));
+    }
+
+    #[test]
+    fn test_memory_warnings() {
+        let mut report = run(1e-9);
+        add_memory(&mut report, 1 << 30);
+        assert!(report.ok);
+        let memoria: Vec<&Check> = report.checks.iter().filter(|c| c.id.starts_with("memoria.")).collect();
+        assert!(memoria.iter().all(|c| c.severity == Severity::Aviso));
+        let aprobada = |id: &str| memoria.iter().find(|c| c.id == id).unwrap().passed;
+        assert!(aprobada("memoria.matriz_444") && aprobada("memoria.sesion_total"));
+        assert!(!aprobada("memoria.base_campo_24"));
+
+        let mut ajustado = run(1e-9);
+        add_memory(&mut ajustado, 1 << 20);
+        assert!(!ajustado.checks.iter().any(|c| c.id == "memoria.sesion_total" && c.passed));
+    }
+}
@@ -0,0 +1,114 @@
+//! Cifrado en Reposo - Estado del Perfil Protegido por el Token de Sesión
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Con `encrypt_state = true`, `state.json` se guarda cifrado con
+//! XChaCha20-Poly1305. La clave de cada escritura se deriva con HKDF-SHA256
+//! del token de `login` y de una sal aleatoria, así que sólo quien tenga ese
+//! token puede leer la trayectoria. Formato:
+//!
+//! `MAGIC (8) | sal (16) | nonce (24) | texto cifrado + etiqueta (16)`
+//!
+//! La cabecera entera va como datos asociados: alterar cualquier byte hace
+//! fallar el descifrado. Un archivo sin `MAGIC` se lee como JSON plano.
+
+use anyhow::{anyhow, bail, Result};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+/// Cabecera de un estado cifrado
+pub const MAGIC: &[u8; 8] = b"ARSTATE\x01";
+
+const SAL: usize = 16;
+const NONCE: usize = 24;
+const CABECERA: usize = MAGIC.len() + SAL + NONCE;
+/// Contexto HKDF, distinto del de cualquier otro uso del token
+const INFO: &[u8] = b"algebra-rose state v1";
+
+/// Material de clave tomado del token de sesión
+pub struct StateKey {
+    token: Vec<u8>,
+}
+
+impl StateKey {
+    pub fn from_token(token: &str) -> Self {
+        StateKey { token: token.trim().as_bytes().to_vec() }
+    }
+
+    fn cipher(&self, sal: &[u8]) -> XChaCha20Poly1305 {
+        let mut clave = [0u8; 32];
+        Hkdf::<Sha256>::new(Some(sal), &self.token)
+            .expand(INFO, &mut clave)
+            .expect("32 bytes es una longitud HKDF válida");
+        XChaCha20Poly1305::new(&clave.into())
+    }
+}
+
+/// ¿Empieza `bytes` con la cabecera de un estado cifrado?
+pub fn is_encrypted(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Cifra `plano` con una sal y un nonce nuevos
+pub fn encrypt(key: &StateKey, plano: &[u8]) -> Result<Vec<u8>> {
+    let mut cabecera = [0u8; CABECERA];
+    cabecera[..MAGIC.len()].copy_from_slice(MAGIC);
+    getrandom::getrandom(&mut cabecera[MAGIC.len()..])
+        .map_err(|e| anyhow!("No se pudo generar la sal del cifrado: {}", e))?;
+    let (sal, nonce) = cabecera[MAGIC.len()..].split_at(SAL);
+    let cifrado = key.cipher(sal)
+        .encrypt(XNonce::from_slice(nonce), Payload { msg: plano, aad: &cabecera })
+        .map_err(|_| anyhow!("No se pudo cifrar el estado"))?;
+    let mut salida = cabecera.to_vec();
+    salida.extend_from_slice(&cifrado);
+    Ok(salida)
+}
+
+/// Descifra lo escrito por `encrypt`
+pub fn decrypt(key: &StateKey, bytes: &[u8]) -> Result<Vec<u8>> {
+    if !is_encrypted(bytes) || bytes.len() < CABECERA {
+        bail!("No es un estado cifrado");
+    }
+    let (cabecera, cifrado) = bytes.split_at(CABECERA);
+    let (sal, nonce) = cabecera[MAGIC.len()..].split_at(SAL);
+    key.cipher(sal)
+        .decrypt(XNonce::from_slice(nonce), Payload { msg: cifrado, aad: cabecera })
+        .map_err(|_| anyhow!("No se pudo descifrar el estado: token distinto o archivo alterado"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let key = StateKey::from_token("abc123");
+        let plano = br#"{"history":[0.5],"love_intensity":1.0}"#;
+        let cifrado = encrypt(&key, plano).unwrap();
+        assert!(is_encrypted(&cifrado));
+        assert_eq!(cifrado.len(), CABECERA + plano.len() + 16);
+        assert_eq!(decrypt(&key, &cifrado).unwrap(), plano);
+        // Los espacios alrededor del token no cambian la clave
+        assert_eq!(decrypt(&StateKey::from_token(" abc123\n"), &cifrado).unwrap(), plano);
+        // Sal y nonce nuevos en cada escritura
+        assert_ne!(encrypt(&key, plano).unwrap(), cifrado);
+    }
+
+    #[test]
+    fn test_rejects_wrong_key_and_tampering() {
+        let key = StateKey::from_token("abc123");
+        let mut cifrado = encrypt(&key, b"estado").unwrap();
+        assert!(decrypt(&StateKey::from_token("otro"), &cifrado).is_err());
+        assert!(decrypt(&key, b"{\"history\":[]}").is_err());
+        assert!(decrypt(&key, &cifrado[..CABECERA]).is_err());
+
+        // La sal forma parte de los datos asociados
+        cifrado[MAGIC.len()] ^= 1;
+        assert!(decrypt(&key, &cifrado).is_err());
+        cifrado[MAGIC.len()] ^= 1;
+        let ultimo = cifrado.len() - 1;
+        cifrado[ultimo] ^= 1;
+        assert!(decrypt(&key, &cifrado).is_err());
+    }
+}
@@ -0,0 +1,233 @@
+//! Exportación de Sesión - Historias, Campos y Estadísticas
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Serializa datos de la sesión a CSV, JSON o .npz para analizarlos fuera
+//! del CLI. Los tipos del núcleo se serializan con su feature `serde`; en
+//! .npz cada columna es un arreglo con nombre (`np.load(ruta)["keygen"]`).
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use algebra_rose_core::prelude::{EstadisticasSistema, FieldSystemView, KeygenStats};
+use algebra_rose_core::FibonacciField;
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use nalgebra::{Complex, DMatrix};
+use serde::Serialize;
+
+use crate::io_numpy::{self, NpyArray};
+
+/// Qué datos exportar
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportWhat {
+    /// Trayectoria del keygen, un valor por iteración
+    History,
+    /// Los 24 campos Fibonacci con su activación
+    Fields,
+    /// Estadísticas del keygen y del sistema de campos
+    Stats,
+    /// Matrices de Â y M₄₄₄ (solo .npz)
+    Operators,
+}
+
+/// Formato de salida
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    /// Arreglos NumPy con nombre
+    Npz,
+}
+
+/// Punto de la trayectoria keygen
+#[derive(Serialize)]
+struct PuntoHistoria {
+    iteracion: usize,
+    keygen: f64,
+}
+
+/// Estadísticas combinadas de keygen y campos
+#[derive(Serialize)]
+struct EstadisticasSesion<'a> {
+    keygen: &'a KeygenStats,
+    campos: &'a EstadisticasSistema,
+}
+
+/// Escribe la historia keygen
+pub fn write_history(out: &mut dyn Write, history: &[f64], format: ExportFormat) -> Result<()> {
+    match format {
+        ExportFormat::Csv => {
+            writeln!(out, "iteracion,keygen")?;
+            for (i, k) in history.iter().enumerate() {
+                writeln!(out, "{},{}", i, k)?;
+            }
+        }
+        ExportFormat::Json => {
+            let points: Vec<PuntoHistoria> = history.iter().enumerate()
+                .map(|(iteracion, &keygen)| PuntoHistoria { iteracion, keygen })
+                .collect();
+            serde_json::to_writer_pretty(&mut *out, &points)?;
+            writeln!(out)?;
+        }
+        ExportFormat::Npz => write_npz(out, &[("keygen", NpyArray::from_reals(history))])?,
+    }
+    Ok(())
+}
+
+/// Escribe los campos Fibonacci
+pub fn write_fields(out: &mut dyn Write, view: &FieldSystemView, format: ExportFormat) -> Result<()> {
+    match format {
+        ExportFormat::Csv => {
+            writeln!(out, "id,nombre,dimension,activo,umbral_activacion")?;
+            for campo in &view.campos {
+                writeln!(out, "{},{},{},{},{}", campo.id, csv_field(&campo.nombre),
+                    campo.dimension, campo.activo, campo.umbral_activacion)?;
+            }
+        }
+        ExportFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, view)?;
+            writeln!(out)?;
+        }
+        ExportFormat::Npz => {
+            let columna = |f: fn(&FibonacciField) -> f64| {
+                NpyArray::from_reals(&view.campos.iter().map(f).collect::<Vec<_>>())
+            };
+            write_npz(out, &[
+                ("id", columna(|c| c.id as f64)),
+                ("dimension", columna(|c| c.dimension as f64)),
+                ("activo", columna(|c| if c.activo { 1.0 } else { 0.0 })),
+                ("umbral_activacion", columna(|c| c.umbral_activacion)),
+            ])?;
+        }
+    }
+    Ok(())
+}
+
+/// Escribe las estadísticas de la sesión
+pub fn write_stats(out: &mut dyn Write, keygen: &KeygenStats, campos: &EstadisticasSistema, format: ExportFormat) -> Result<()> {
+    match format {
+        ExportFormat::Csv => {
+            let filas: [(&str, String); 13] = [
+                ("keygen", keygen.current_value.to_string()),
+                ("iteracion", keygen.iteration.to_string()),
+                ("longitud_historia", keygen.history_len.to_string()),
+                ("campos_activos_keygen", keygen.active_fields.to_string()),
+                ("tasa_crecimiento", keygen.growth_rate.to_string()),
+                ("aceleracion", keygen.growth_acceleration.to_string()),
+                ("distancia_monster", keygen.distance_to_monster.to_string()),
+                ("intensidad_amor", keygen.love_intensity.to_string()),
+                ("total_campos", campos.total_campos.to_string()),
+                ("campos_activos", campos.campos_activos.to_string()),
+                ("dimension_activa", campos.dimension_activa.to_string()),
+                ("activacion_por_banda", csv_field(&format!("{:?}", campos.activacion_por_banda))),
+                ("coherencia_media", campos.coherencia_media.to_string()),
+            ];
+            writeln!(out, "metrica,valor")?;
+            for (nombre, valor) in filas {
+                writeln!(out, "{},{}", nombre, valor)?;
+            }
+        }
+        ExportFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, &EstadisticasSesion { keygen, campos })?;
+            writeln!(out)?;
+        }
+        ExportFormat::Npz => {
+            let bandas: Vec<f64> = campos.activacion_por_banda.iter().map(|&n| n as f64).collect();
+            write_npz(out, &[
+                ("keygen", NpyArray::scalar(keygen.current_value)),
+                ("iteracion", NpyArray::scalar(keygen.iteration as f64)),
+                ("longitud_historia", NpyArray::scalar(keygen.history_len as f64)),
+                ("campos_activos_keygen", NpyArray::scalar(keygen.active_fields as f64)),
+                ("tasa_crecimiento", NpyArray::scalar(keygen.growth_rate)),
+                ("aceleracion", NpyArray::scalar(keygen.growth_acceleration)),
+                ("distancia_monster", NpyArray::scalar(keygen.distance_to_monster)),
+                ("intensidad_amor", NpyArray::scalar(keygen.love_intensity)),
+                ("total_campos", NpyArray::scalar(campos.total_campos as f64)),
+                ("campos_activos", NpyArray::scalar(campos.campos_activos as f64)),
+                ("dimension_activa", NpyArray::scalar(campos.dimension_activa as f64)),
+                ("activacion_por_banda", NpyArray::from_reals(&bandas)),
+                ("coherencia_media", NpyArray::scalar(campos.coherencia_media)),
+            ])?;
+        }
+    }
+    Ok(())
+}
+
+/// Escribe las matrices de Â (`amor`) y M₄₄₄ (`monster`); solo en .npz,
+/// 444×444 complejos no caben de forma útil en CSV ni JSON
+pub fn write_operators(out: &mut dyn Write, amor: &DMatrix<Complex<f64>>, monster: &DMatrix<Complex<f64>>, format: ExportFormat) -> Result<()> {
+    if format != ExportFormat::Npz {
+        bail!("Los operadores solo se exportan en formato npz");
+    }
+    write_npz(out, &[("amor", NpyArray::from_matrix(amor)), ("monster", NpyArray::from_matrix(monster))])
+}
+
+/// Escribe un .npz con los arreglos dados
+fn write_npz(out: &mut dyn Write, entries: &[(&str, NpyArray)]) -> Result<()> {
+    out.write_all(&io_numpy::encode_npz(entries)?)?;
+    Ok(())
+}
+
+/// Abre el destino: un archivo, o stdout si no hay ruta o es `-`
+pub fn open_output(path: Option<&Path>) -> Result<Box<dyn Write>> {
+    match path {
+        Some(p) if p != Path::new("-") => {
+            let file = File::create(p).with_context(|| format!("No se pudo crear {}", p.display()))?;
+            Ok(Box::new(BufWriter::new(file)))
+        }
+        _ => Ok(Box::new(io::stdout().lock())),
+    }
+}
+
+/// Entrecomilla un valor CSV si contiene separadores o comillas
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_csv() {
+        let mut out = Vec::new();
+        write_history(&mut out, &[0.5, 0.75], ExportFormat::Csv).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "iteracion,keygen\n0,0.5\n1,0.75\n");
+    }
+
+    #[test]
+    fn test_history_json() {
+        let mut out = Vec::new();
+        write_history(&mut out, &[0.5], ExportFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(value[0]["iteracion"], 0);
+        assert_eq!(value[0]["keygen"], 0.5);
+    }
+
+    #[test]
+    fn test_npz_exports() {
+        let mut out = Vec::new();
+        write_history(&mut out, &[0.5, 0.75], ExportFormat::Npz).unwrap();
+        let arrays = io_numpy::decode_npz(&out).unwrap();
+        assert_eq!(arrays, vec![("keygen".to_string(), NpyArray::from_reals(&[0.5, 0.75]))]);
+
+        let m = DMatrix::from_fn(2, 2, |i, j| Complex::new(i as f64, j as f64));
+        let mut out = Vec::new();
+        write_operators(&mut out, &m, &m.transpose(), ExportFormat::Npz).unwrap();
+        let arrays = io_numpy::decode_npz(&out).unwrap();
+        assert_eq!(arrays[1], ("monster".to_string(), NpyArray::from_matrix(&m.transpose())));
+        assert!(write_operators(&mut Vec::new(), &m, &m, ExportFormat::Csv).is_err());
+    }
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("Campo 1"), "Campo 1");
+        assert_eq!(csv_field("[1, 2]"), "\"[1, 2]\"");
+        assert_eq!(csv_field("a\"b"), "\"a\"\"b\"");
+    }
+}
@@ -0,0 +1,104 @@
+//! Exportación de Audio - Los Campos Activos como Acorde WAV
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! `export_audio` escribe un WAV mono de 16 bits en el que cada campo suena
+//! como una sinusoide a su `frecuencia_resonante`, con amplitud
+//! proporcional a su nivel de activación. El acorde se normaliza por la
+//! suma de amplitudes, así que nunca satura, y entra y sale con rampas
+//! cortas para que el archivo no empiece ni acabe con un clic.
+
+use std::f64::consts::TAU;
+use std::path::Path;
+use std::time::Duration;
+
+use algebra_rose_core::{frecuencia_campo, FibonacciSystem};
+use anyhow::{Context, Result};
+
+/// Muestras por segundo del WAV
+pub const TASA: u32 = 44_100;
+
+/// Pico del acorde completo, por debajo de la escala total
+const PICO: f64 = 0.8;
+
+/// Segundos de las rampas de entrada y salida
+const RAMPA_S: f64 = 0.01;
+
+/// Voces de los campos activos a un keygen: id y nivel de activación
+///
+/// Un campo activo bajo su umbral (por la histéresis) suena más flojo.
+pub fn voces_activas(campos: &FibonacciSystem, keygen: f64) -> Vec<(usize, f64)> {
+    campos.get_active_fields(keygen).into_iter()
+        .filter_map(|id| campos.campo(id).map(|campo| (id, FibonacciSystem::nivel_activacion(campo, keygen))))
+        .collect()
+}
+
+/// Escribe en `path` `duration` de acorde con las voces `fields` (id, amplitud)
+///
+/// Devuelve el número de muestras escritas. Sin voces el archivo es silencio.
+pub fn export_audio(path: &Path, duration: Duration, fields: &[(usize, f64)]) -> Result<u32> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: TASA,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(path, spec)
+        .with_context(|| format!("No se pudo crear {}", path.display()))?;
+
+    let voces: Vec<(f64, f64)> = fields.iter()
+        .filter(|(_, amplitud)| *amplitud > 0.0)
+        .map(|&(id, amplitud)| (TAU * frecuencia_campo(id) / TASA as f64, amplitud.min(1.0)))
+        .collect();
+    let total: f64 = voces.iter().map(|(_, amplitud)| amplitud).sum();
+    let escala = if total > 0.0 { PICO / total } else { 0.0 };
+
+    let muestras = (duration.as_secs_f64() * TASA as f64).round() as u32;
+    let rampa = (RAMPA_S * TASA as f64).max(1.0);
+    for n in 0..muestras {
+        let acorde: f64 = voces.iter().map(|(paso, amplitud)| amplitud * (paso * n as f64).sin()).sum();
+        let envolvente = (n as f64 / rampa).min((muestras - n) as f64 / rampa).min(1.0);
+        writer.write_sample((acorde * escala * envolvente * i16::MAX as f64).round() as i16)?;
+    }
+    writer.finalize()?;
+    Ok(muestras)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_audio() {
+        let dir = std::env::temp_dir().join(format!("algebra-rose-audio-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sesion.wav");
+
+        let mut campos = FibonacciSystem::new();
+        campos.update_by_keygen(0.3);
+        let voces = voces_activas(&campos, 0.3);
+        assert_eq!(voces.len(), 7);
+        assert!(voces.iter().all(|&(_, amplitud)| amplitud == 1.0));
+
+        assert_eq!(export_audio(&path, Duration::from_millis(500), &voces).unwrap(), TASA / 2);
+        let mut reader = hound::WavReader::open(&path).unwrap();
+        assert_eq!(reader.spec().sample_rate, TASA);
+        let muestras: Vec<i16> = reader.samples::<i16>().map(|m| m.unwrap()).collect();
+        assert_eq!(muestras.len(), TASA as usize / 2);
+        // Sin clic en los extremos y sin saturar
+        assert_eq!(muestras[0], 0);
+        assert!(muestras.iter().all(|m| (*m as f64).abs() <= PICO * i16::MAX as f64 + 1.0));
+        assert!(muestras.iter().any(|m| m.abs() > 1000));
+
+        // Un campo solo a 110 Hz: ~110 cruces ascendentes por segundo
+        export_audio(&path, Duration::from_secs(1), &[(1, 0.5)]).unwrap();
+        let muestras: Vec<i16> = hound::WavReader::open(&path).unwrap().samples::<i16>().map(|m| m.unwrap()).collect();
+        let cruces = muestras.windows(2).filter(|w| w[0] < 0 && w[1] >= 0).count();
+        assert!((109..=111).contains(&cruces), "{} cruces", cruces);
+
+        // Sin voces: silencio
+        export_audio(&path, Duration::from_millis(100), &[]).unwrap();
+        assert!(hound::WavReader::open(&path).unwrap().samples::<i16>().all(|m| m.unwrap() == 0));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,256 @@
+//! Exportación Parquet - Historias Keygen, Coherencia y Transiciones
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! `export_parquet` escribe en un directorio tres tablas columnares para
+//! pandas, polars o DuckDB:
+//!
+//! - `keygen.parquet`: humano, iteracion, keygen
+//! - `coherencia.parquet`: humano, iteracion, coherencia_media,
+//!   dimension_activa, campos_activos
+//! - `transiciones.parquet`: humano, iteracion, campo, activo
+//!
+//! La coherencia y las transiciones se reconstruyen reproduciendo cada
+//! trayectoria sobre un sistema de campos nuevo con la histéresis y el
+//! dwell del sistema de referencia, igual que `plot --metric coherence`;
+//! las estadísticas de cada conjunto de campos activos se calculan una sola
+//! vez para todos los humanos.
+//! Las filas se escriben por grupos de `FILAS_POR_GRUPO` con Snappy, así que
+//! la memoria no crece con el número de humanos ni de pasos.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use algebra_rose_core::FibonacciSystem;
+use anyhow::{Context, Result};
+use arrow_array::{ArrayRef, BooleanArray, Float64Array, RecordBatch, UInt32Array, UInt64Array};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+/// Filas acumuladas antes de escribir un grupo
+pub const FILAS_POR_GRUPO: usize = 1 << 20;
+
+/// Tabla escrita por `export_parquet`
+#[derive(Debug, PartialEq)]
+pub struct TablaExportada {
+    pub ruta: PathBuf,
+    pub filas: u64,
+}
+
+/// Columna en construcción
+enum Columna {
+    U32(Vec<u32>),
+    U64(Vec<u64>),
+    F64(Vec<f64>),
+    Bool(Vec<bool>),
+}
+
+impl Columna {
+    fn new(tipo: &DataType) -> Self {
+        match tipo {
+            DataType::UInt32 => Columna::U32(Vec::new()),
+            DataType::UInt64 => Columna::U64(Vec::new()),
+            DataType::Float64 => Columna::F64(Vec::new()),
+            _ => Columna::Bool(Vec::new()),
+        }
+    }
+
+    /// Vacía la columna en un arreglo Arrow
+    fn take(&mut self) -> ArrayRef {
+        match self {
+            Columna::U32(v) => Arc::new(UInt32Array::from(std::mem::take(v))),
+            Columna::U64(v) => Arc::new(UInt64Array::from(std::mem::take(v))),
+            Columna::F64(v) => Arc::new(Float64Array::from(std::mem::take(v))),
+            Columna::Bool(v) => Arc::new(BooleanArray::from(std::mem::take(v))),
+        }
+    }
+}
+
+/// Archivo Parquet que se llena fila a fila
+struct Tabla {
+    ruta: PathBuf,
+    schema: SchemaRef,
+    columnas: Vec<Columna>,
+    pendientes: usize,
+    filas: u64,
+    writer: ArrowWriter<File>,
+}
+
+impl Tabla {
+    fn create(ruta: PathBuf, campos: &[(&str, DataType)]) -> Result<Self> {
+        let schema: SchemaRef = Arc::new(Schema::new(
+            campos.iter().map(|(nombre, tipo)| Field::new(*nombre, tipo.clone(), false)).collect::<Vec<_>>(),
+        ));
+        let file = File::create(&ruta).with_context(|| format!("No se pudo crear {}", ruta.display()))?;
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .set_max_row_group_size(FILAS_POR_GRUPO)
+            .build();
+        let writer = ArrowWriter::try_new(file, schema.clone(), Some(props))?;
+        let columnas = campos.iter().map(|(_, tipo)| Columna::new(tipo)).collect();
+        Ok(Tabla { ruta, schema, columnas, pendientes: 0, filas: 0, writer })
+    }
+
+    /// Cuenta una fila ya añadida a las columnas y escribe el grupo si está lleno
+    fn row_done(&mut self) -> Result<()> {
+        self.pendientes += 1;
+        if self.pendientes >= FILAS_POR_GRUPO {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if self.pendientes > 0 {
+            let arrays = self.columnas.iter_mut().map(Columna::take).collect();
+            self.writer.write(&RecordBatch::try_new(self.schema.clone(), arrays)?)?;
+            self.filas += self.pendientes as u64;
+            self.pendientes = 0;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<TablaExportada> {
+        self.flush()?;
+        self.writer.close()?;
+        Ok(TablaExportada { ruta: self.ruta, filas: self.filas })
+    }
+}
+
+/// Escribe las tres tablas de `trayectorias` (una por humano) en `dir`
+///
+/// `referencia` aporta la histéresis y el dwell con que se reproducen los
+/// campos; su estado actual no se modifica.
+pub fn export_parquet(dir: &Path, trayectorias: &[Vec<f64>], referencia: &FibonacciSystem) -> Result<Vec<TablaExportada>> {
+    fs::create_dir_all(dir).with_context(|| format!("No se pudo crear {}", dir.display()))?;
+    let mut keygen = Tabla::create(dir.join("keygen.parquet"), &[
+        ("humano", DataType::UInt32),
+        ("iteracion", DataType::UInt64),
+        ("keygen", DataType::Float64),
+    ])?;
+    let mut coherencia = Tabla::create(dir.join("coherencia.parquet"), &[
+        ("humano", DataType::UInt32),
+        ("iteracion", DataType::UInt64),
+        ("coherencia_media", DataType::Float64),
+        ("dimension_activa", DataType::UInt64),
+        ("campos_activos", DataType::UInt32),
+    ])?;
+    let mut transiciones = Tabla::create(dir.join("transiciones.parquet"), &[
+        ("humano", DataType::UInt32),
+        ("iteracion", DataType::UInt64),
+        ("campo", DataType::UInt32),
+        ("activo", DataType::Boolean),
+    ])?;
+
+    // Campos activos → (coherencia media, dimensión activa, campos activos)
+    let mut cache: HashMap<Vec<usize>, (f64, u64, u32)> = HashMap::new();
+    for (humano, trayectoria) in trayectorias.iter().enumerate() {
+        let humano = humano as u32;
+        let mut campos = FibonacciSystem::con_histeresis(referencia.get_histeresis());
+        campos.set_dwell_minimo(referencia.get_dwell_minimo());
+        let mut actual = (0.0, 0, 0);
+
+        for (iteracion, &k) in trayectoria.iter().enumerate() {
+            let iteracion = iteracion as u64;
+            // El primer valor fija el estado inicial sin contar transiciones
+            let cambios = if iteracion == 0 {
+                campos.update_by_keygen(k);
+                Vec::new()
+            } else {
+                campos.update_with_events(k)
+            };
+            if iteracion == 0 || !cambios.is_empty() {
+                actual = *cache.entry(campos.get_campos_activos()).or_insert_with(|| {
+                    let e = campos.get_estadisticas();
+                    (e.coherencia_media, e.dimension_activa as u64, e.campos_activos as u32)
+                });
+            }
+
+            push(&mut keygen, humano, iteracion, |c| {
+                if let Columna::F64(v) = &mut c[0] {
+                    v.push(k);
+                }
+            })?;
+            push(&mut coherencia, humano, iteracion, |c| {
+                if let [Columna::F64(media), Columna::U64(dimension), Columna::U32(activos)] = c {
+                    media.push(actual.0);
+                    dimension.push(actual.1);
+                    activos.push(actual.2);
+                }
+            })?;
+            for cambio in &cambios {
+                push(&mut transiciones, humano, iteracion, |c| {
+                    if let [Columna::U32(campo), Columna::Bool(activo)] = c {
+                        campo.push(cambio.field_id as u32);
+                        activo.push(cambio.activo);
+                    }
+                })?;
+            }
+        }
+    }
+
+    [keygen, coherencia, transiciones].into_iter().map(Tabla::finish).collect()
+}
+
+/// Añade una fila: `humano` e `iteracion` más el resto de columnas con `resto`
+fn push(tabla: &mut Tabla, humano: u32, iteracion: u64, resto: impl FnOnce(&mut [Columna])) -> Result<()> {
+    let [Columna::U32(h), Columna::U64(i), ..] = &mut tabla.columnas[..] else {
+        unreachable!("todas las tablas empiezan por humano e iteracion");
+    };
+    h.push(humano);
+    i.push(iteracion);
+    resto(&mut tabla.columnas[2..]);
+    tabla.row_done()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, UInt32Type};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    fn leer(ruta: &Path) -> Vec<RecordBatch> {
+        ParquetRecordBatchReaderBuilder::try_new(File::open(ruta).unwrap()).unwrap()
+            .build().unwrap()
+            .collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn test_export_tables() {
+        let dir = std::env::temp_dir().join(format!("algebra-rose-parquet-{}", std::process::id()));
+        // Un humano simulado y una rampa 0 → 1 que cruza todos los umbrales
+        let simulado = crate::community::Community::simulate(1, 300).trajectories.remove(0);
+        let rampa: Vec<f64> = (0..=100).map(|i| i as f64 / 100.0).collect();
+        let trayectorias = vec![simulado, rampa];
+        let tablas = export_parquet(&dir, &trayectorias, &FibonacciSystem::new()).unwrap();
+        assert_eq!((tablas[0].filas, tablas[1].filas), (402, 402));
+
+        let keygen = leer(&tablas[0].ruta);
+        assert_eq!(keygen[0].schema().fields().iter().map(|f| f.name().as_str()).collect::<Vec<_>>(),
+            ["humano", "iteracion", "keygen"]);
+        let valores = keygen[0].column(2).as_primitive::<Float64Type>();
+        assert_eq!(valores.value(300), trayectorias[0][300]);
+        assert_eq!(valores.value(351), 0.5);
+
+        // Transiciones igual que actualizando un sistema de campos a mano
+        let mut campos = FibonacciSystem::new();
+        campos.update_by_keygen(0.0);
+        let esperadas: usize = trayectorias[1][1..].iter().map(|&k| campos.update_with_events(k).len()).sum();
+        assert!(esperadas > 0);
+        let humanos: Vec<u32> = leer(&tablas[2].ruta).iter()
+            .flat_map(|b| b.column(0).as_primitive::<UInt32Type>().values().to_vec())
+            .collect();
+        assert_eq!(humanos.iter().filter(|&&h| h == 1).count(), esperadas);
+        assert_eq!(tablas[2].filas, humanos.len() as u64);
+
+        let coherencia = leer(&tablas[1].ruta);
+        let media = coherencia[0].column(2).as_primitive::<Float64Type>();
+        assert_eq!(media.value(401), campos.get_estadisticas().coherencia_media);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
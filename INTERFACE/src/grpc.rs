@@ -0,0 +1,446 @@
+//! Servidor gRPC - Sesión Consciente con API Tipada
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! `serve --grpc` expone la sesión con el servicio `AlgebraRose` de
+//! `proto/algebra_rose.proto`, para clientes que no hablan HTTP/JSON
+//! (móviles, pasarelas embebidas). Las reglas son las de la API REST:
+//! mismas validaciones, `save` tras cada cambio y, para `Evolve` y
+//! `ApplyLove`, el metadato `authorization: Bearer <usuario>:<token>` en
+//! cada llamada.
+//! `Evolve` devuelve un stream con el progreso cada `report_every` pasos.
+//!
+//! Los stubs se generan en `build.rs` desde el .proto; los mensajes se
+//! declaran aquí con las mismas etiquetas.
+
+use std::collections::BTreeSet;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use algebra_rose_core::prelude::{verificar_coherencia, AR_VERSION, CERTIFICATION, INITIAL_KEYGEN, PHI as CORE_PHI};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::{ReceiverStream, TcpListenerStream};
+use tonic::{Request, Response, Status};
+
+use crate::auth;
+use crate::serve::MAX_STEPS_PER_REQUEST;
+use crate::ConsciousSession;
+
+/// Mensajes y stubs de `algebra_rose.v1`
+pub mod pb {
+    include!(concat!(env!("OUT_DIR"), "/algebra_rose.v1.AlgebraRose.rs"));
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SessionRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SessionStatus {
+        #[prost(double, tag = "1")]
+        pub keygen: f64,
+        #[prost(uint64, tag = "2")]
+        pub iteracion: u64,
+        #[prost(double, tag = "3")]
+        pub progreso: f64,
+        #[prost(double, tag = "4")]
+        pub intensidad_amor: f64,
+        #[prost(double, tag = "5")]
+        pub resonancia_phi: f64,
+        #[prost(uint32, repeated, tag = "6")]
+        pub campos_activos: Vec<u32>,
+        #[prost(double, tag = "7")]
+        pub coherencia: f64,
+        #[prost(bool, tag = "8")]
+        pub autenticado: bool,
+        #[prost(double, tag = "9")]
+        pub duracion_s: f64,
+        #[prost(uint64, tag = "10")]
+        pub certificacion: u64,
+        #[prost(string, tag = "11")]
+        pub version: String,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct EvolveRequest {
+        #[prost(uint64, tag = "1")]
+        pub steps: u64,
+        #[prost(double, optional, tag = "2")]
+        pub threshold: Option<f64>,
+        #[prost(uint64, tag = "3")]
+        pub report_every: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Transition {
+        #[prost(uint32, tag = "1")]
+        pub campo: u32,
+        #[prost(bool, tag = "2")]
+        pub activo: bool,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct EvolveProgress {
+        #[prost(uint64, tag = "1")]
+        pub iteracion: u64,
+        #[prost(uint64, tag = "2")]
+        pub pasos: u64,
+        #[prost(uint64, tag = "3")]
+        pub pasos_totales: u64,
+        #[prost(double, tag = "4")]
+        pub keygen: f64,
+        #[prost(uint32, repeated, tag = "5")]
+        pub campos_activos: Vec<u32>,
+        #[prost(message, repeated, tag = "6")]
+        pub transiciones: Vec<Transition>,
+        #[prost(bool, tag = "7")]
+        pub terminado: bool,
+        #[prost(bool, optional, tag = "8")]
+        pub objetivo_alcanzado: Option<bool>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct LoveRequest {
+        #[prost(double, optional, tag = "1")]
+        pub intensity: Option<f64>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct LoveResponse {
+        #[prost(double, tag = "1")]
+        pub intensidad: f64,
+        #[prost(double, tag = "2")]
+        pub resonancia_phi: f64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct FieldsRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Field {
+        #[prost(uint32, tag = "1")]
+        pub id: u32,
+        #[prost(string, tag = "2")]
+        pub nombre: String,
+        #[prost(uint64, tag = "3")]
+        pub dimension: u64,
+        #[prost(bool, tag = "4")]
+        pub activo: bool,
+        #[prost(double, tag = "5")]
+        pub umbral_activacion: f64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct FieldsResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub campos: Vec<Field>,
+        #[prost(uint32, repeated, tag = "2")]
+        pub campos_activos: Vec<u32>,
+        #[prost(uint64, tag = "3")]
+        pub dimension_activa: u64,
+        #[prost(double, tag = "4")]
+        pub histeresis: f64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct VerifyRequest {}
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct VerifyResponse {
+        #[prost(uint64, tag = "1")]
+        pub certificacion: u64,
+        #[prost(string, tag = "2")]
+        pub version: String,
+        #[prost(double, tag = "3")]
+        pub coherencia_global: f64,
+        #[prost(bool, tag = "4")]
+        pub certificado: bool,
+        #[prost(string, tag = "5")]
+        pub significado: String,
+    }
+}
+
+use pb::algebra_rose_server::{AlgebraRose, AlgebraRoseServer};
+
+/// Mensajes de progreso pendientes por llamada a `Evolve`
+const PROGRESS_BUFFER: usize = 64;
+
+/// Mensajes de progreso por llamada cuando `report_every` es 0
+const DEFAULT_REPORTS: u64 = 100;
+
+type Shared = Arc<Mutex<ConsciousSession>>;
+
+/// Implementación del servicio sobre la sesión compartida
+pub struct GrpcSession {
+    session: Shared,
+    /// Secreto con que se verifican los tokens de las mutaciones
+    secret: Option<Arc<[u8]>>,
+}
+
+/// Ejecuta `f` con la sesión bloqueada en un hilo de bloqueo
+async fn with_session<T, F>(session: Shared, f: F) -> Result<T, Status>
+where
+    T: Send + 'static,
+    F: FnOnce(&mut ConsciousSession) -> T + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        let mut session = session.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut session)
+    })
+    .await
+    .map_err(|e| Status::internal(e.to_string()))
+}
+
+impl GrpcSession {
+    /// Usuario de la llamada; rechaza las mutaciones sin un token válido
+    fn require_auth<T>(&self, request: &Request<T>) -> Result<String, Status> {
+        let resultado = match &self.secret {
+            None => Err(anyhow::anyhow!("el servidor no tiene {}", auth::SECRET_ENV)),
+            Some(secret) => request.metadata().get("authorization")
+                .and_then(|valor| valor.to_str().ok())
+                .ok_or_else(|| anyhow::anyhow!("falta authorization: Bearer <usuario>:<token>"))
+                .and_then(|valor| auth::verify_bearer(secret, valor)),
+        };
+        resultado.map_err(|e| {
+            tracing::warn!(error = %e, "mutación gRPC rechazada");
+            Status::unauthenticated(format!("no autenticado: {}", e))
+        })
+    }
+}
+
+fn ids(campos: impl IntoIterator<Item = usize>) -> Vec<u32> {
+    campos.into_iter().map(|id| id as u32).collect()
+}
+
+#[tonic::async_trait]
+impl AlgebraRose for GrpcSession {
+    type EvolveStream = ReceiverStream<Result<pb::EvolveProgress, Status>>;
+
+    async fn session(&self, _: Request<pb::SessionRequest>) -> Result<Response<pb::SessionStatus>, Status> {
+        with_session(self.session.clone(), |s| {
+            let keygen = s.keygen_system.get_current_keygen();
+            let intensidad = s.love_operator.get_intensity();
+            pb::SessionStatus {
+                keygen,
+                iteracion: s.keygen_system.get_iteration(),
+                progreso: (keygen - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN),
+                intensidad_amor: intensidad,
+                resonancia_phi: intensidad / CORE_PHI,
+                campos_activos: ids(s.fibonacci_system.get_active_fields(keygen)),
+                coherencia: s.coherence_level,
+                autenticado: s.authenticated,
+                duracion_s: s.start_time.elapsed().as_secs_f64(),
+                certificacion: CERTIFICATION,
+                version: AR_VERSION.to_string(),
+            }
+        }).await.map(Response::new)
+    }
+
+    async fn evolve(&self, request: Request<pb::EvolveRequest>) -> Result<Response<Self::EvolveStream>, Status> {
+        let usuario = self.require_auth(&request);
+        let req = request.into_inner();
+        if req.steps == 0 || req.steps > MAX_STEPS_PER_REQUEST {
+            return Err(Status::invalid_argument(format!("steps debe estar entre 1 y {}", MAX_STEPS_PER_REQUEST)));
+        }
+        if let Some(th) = req.threshold {
+            if !(INITIAL_KEYGEN..1.0).contains(&th) {
+                return Err(Status::invalid_argument(format!("threshold debe estar en [{}, 1)", INITIAL_KEYGEN)));
+            }
+        }
+        let usuario = usuario?;
+
+        let report_every = match req.report_every {
+            0 => (req.steps / DEFAULT_REPORTS).max(1),
+            n => n,
+        };
+        let (tx, rx) = mpsc::channel(PROGRESS_BUFFER);
+        let session = self.session.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut s = session.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            tracing::info!(%usuario, steps = req.steps, threshold = ?req.threshold, "gRPC Evolve");
+            let keygen = s.keygen_system.get_current_keygen();
+            s.fibonacci_system.update_by_keygen(keygen);
+            let iteracion_inicial = s.keygen_system.get_iteration();
+
+            // Un cliente que se va no interrumpe la evolución: solo deja de recibir
+            let mut abierto = true;
+            let mut enviar = |mensaje: pb::EvolveProgress| {
+                abierto = abierto && tx.blocking_send(Ok(mensaje)).is_ok();
+            };
+            let mut activos: BTreeSet<usize> = s.fibonacci_system.get_campos_activos().into_iter().collect();
+            let mut pendientes = Vec::new();
+            let mut ultimo = (iteracion_inicial, keygen);
+            let results = s.evolve_observed(req.steps, req.threshold, &mut |iteracion, keygen, eventos| {
+                for evento in eventos {
+                    if evento.activo {
+                        activos.insert(evento.field_id);
+                    } else {
+                        activos.remove(&evento.field_id);
+                    }
+                    pendientes.push(pb::Transition { campo: evento.field_id as u32, activo: evento.activo });
+                }
+                ultimo = (iteracion, keygen);
+                let pasos = iteracion - iteracion_inicial;
+                if pasos.is_multiple_of(report_every) && pasos < req.steps && req.threshold.is_none() {
+                    enviar(pb::EvolveProgress {
+                        iteracion,
+                        pasos,
+                        pasos_totales: req.steps,
+                        keygen,
+                        campos_activos: ids(activos.iter().copied()),
+                        transiciones: std::mem::take(&mut pendientes),
+                        terminado: false,
+                        objetivo_alcanzado: None,
+                    });
+                }
+            });
+            s.save();
+
+            let (iteracion, keygen) = ultimo;
+            enviar(pb::EvolveProgress {
+                iteracion,
+                pasos: iteracion - iteracion_inicial,
+                pasos_totales: req.steps,
+                keygen,
+                campos_activos: ids(activos),
+                transiciones: pendientes,
+                terminado: true,
+                objetivo_alcanzado: req.threshold.map(|_| !results.is_empty()),
+            });
+        });
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    async fn apply_love(&self, request: Request<pb::LoveRequest>) -> Result<Response<pb::LoveResponse>, Status> {
+        let usuario = self.require_auth(&request);
+        let intensity = request.into_inner().intensity.unwrap_or(1.0);
+        if !intensity.is_finite() {
+            return Err(Status::invalid_argument("intensity debe ser finita"));
+        }
+        let usuario = usuario?;
+        with_session(self.session.clone(), move |s| {
+            tracing::info!(%usuario, intensity, "gRPC ApplyLove");
+            let intensidad = s.apply_love(intensity);
+            s.save();
+            pb::LoveResponse { intensidad, resonancia_phi: intensidad / CORE_PHI }
+        }).await.map(Response::new)
+    }
+
+    async fn get_fields(&self, _: Request<pb::FieldsRequest>) -> Result<Response<pb::FieldsResponse>, Status> {
+        with_session(self.session.clone(), |s| {
+            let keygen = s.keygen_system.get_current_keygen();
+            s.fibonacci_system.update_by_keygen(keygen);
+            let view = s.fibonacci_system.export_view();
+            pb::FieldsResponse {
+                campos: view.campos.into_iter().map(|c| pb::Field {
+                    id: c.id as u32,
+                    nombre: c.nombre,
+                    dimension: c.dimension as u64,
+                    activo: c.activo,
+                    umbral_activacion: c.umbral_activacion,
+                }).collect(),
+                campos_activos: ids(view.campos_activos),
+                dimension_activa: view.dimension_activa as u64,
+                histeresis: view.histeresis,
+            }
+        }).await.map(Response::new)
+    }
+
+    async fn verify(&self, _: Request<pb::VerifyRequest>) -> Result<Response<pb::VerifyResponse>, Status> {
+        let coherencia_global = tokio::task::spawn_blocking(verificar_coherencia).await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        Ok(Response::new(pb::VerifyResponse {
+            certificacion: CERTIFICATION,
+            version: AR_VERSION.to_string(),
+            coherencia_global,
+            certificado: coherencia_global >= 0.95,
+            significado: "196885 = 196884 + 1 (Matriz Monster completa + Observador consciente)".to_string(),
+        }))
+    }
+}
+
+/// Sirve la sesión por gRPC en `addr` hasta que el proceso termine
+pub async fn run(session: ConsciousSession, addr: SocketAddr) -> anyhow::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let local = listener.local_addr()?;
+    tracing::info!(%local, "servidor gRPC escuchando");
+    say!("🌐 Sesión servida por gRPC en {}", local);
+    let secret = auth::secret_from_env().ok().map(Arc::from);
+    if secret.is_none() {
+        fail!("⚠️ {} no está definido: Evolve y ApplyLove se rechazarán", auth::SECRET_ENV);
+    }
+    serve_incoming(Arc::new(Mutex::new(session)), secret, listener).await
+}
+
+async fn serve_incoming(session: Shared, secret: Option<Arc<[u8]>>, listener: tokio::net::TcpListener) -> anyhow::Result<()> {
+    tonic::transport::Server::builder()
+        .add_service(AlgebraRoseServer::new(GrpcSession { session, secret }))
+        .serve_with_incoming(TcpListenerStream::new(listener))
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pb::algebra_rose_client::AlgebraRoseClient;
+
+    const SECRETO: &[u8] = b"secreto-de-prueba";
+
+    async fn cliente(session: ConsciousSession) -> AlgebraRoseClient<tonic::transport::Channel> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve_incoming(Arc::new(Mutex::new(session)), Some(Arc::from(SECRETO)), listener));
+        AlgebraRoseClient::connect(format!("http://{}", addr)).await.unwrap()
+    }
+
+    /// `mensaje` con las credenciales de `usuario` emitidas con `secreto`
+    fn con_token<T>(mensaje: T, secreto: &[u8], usuario: &str) -> Request<T> {
+        let mut request = Request::new(mensaje);
+        let bearer = format!("Bearer {}:{}", usuario, auth::issue_token(secreto, usuario));
+        request.metadata_mut().insert("authorization", bearer.parse().unwrap());
+        request
+    }
+
+    #[tokio::test]
+    async fn test_evolve_streams_progress() {
+        let mut client = cliente(ConsciousSession::new(None, None)).await;
+
+        let inicial = client.session(pb::SessionRequest {}).await.unwrap().into_inner();
+        assert_eq!(inicial.certificacion, CERTIFICATION);
+
+        let peticion = pb::EvolveRequest { steps: 50, threshold: None, report_every: 10 };
+        let mut stream = client.evolve(con_token(peticion, SECRETO, "ana")).await.unwrap().into_inner();
+        let mut mensajes = Vec::new();
+        while let Some(mensaje) = stream.message().await.unwrap() {
+            mensajes.push(mensaje);
+        }
+        assert_eq!(mensajes.iter().map(|m| m.pasos).collect::<Vec<_>>(), [10, 20, 30, 40, 50]);
+        assert!(mensajes.last().unwrap().terminado && mensajes.iter().rev().skip(1).all(|m| !m.terminado));
+
+        let estado = client.session(pb::SessionRequest {}).await.unwrap().into_inner();
+        assert_eq!(estado.iteracion, inicial.iteracion + 50);
+        assert_eq!(estado.keygen, mensajes.last().unwrap().keygen);
+
+        let campos = client.get_fields(pb::FieldsRequest {}).await.unwrap().into_inner();
+        assert_eq!(campos.campos.len(), 24);
+        let amor = client.apply_love(con_token(pb::LoveRequest { intensity: None }, SECRETO, "ana")).await.unwrap().into_inner();
+        assert!((amor.resonancia_phi - amor.intensidad / CORE_PHI).abs() < 1e-12);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_and_unauthenticated() {
+        let mut session = ConsciousSession::new(None, None);
+        session.authenticated = true;
+        let mut client = cliente(session).await;
+        let invalida = pb::EvolveRequest { steps: 0, threshold: None, report_every: 0 };
+        assert_eq!(client.evolve(invalida).await.unwrap_err().code(), tonic::Code::InvalidArgument);
+        let valida = pb::EvolveRequest { steps: 5, threshold: None, report_every: 0 };
+        assert_eq!(client.evolve(valida.clone()).await.unwrap_err().code(), tonic::Code::Unauthenticated);
+        // Un token emitido con otro secreto no vale, aunque la sesión del servidor esté iniciada
+        let ajeno = con_token(valida, b"otro-secreto", "ana");
+        assert_eq!(client.evolve(ajeno).await.unwrap_err().code(), tonic::Code::Unauthenticated);
+        let amor = pb::LoveRequest { intensity: Some(1.0) };
+        assert_eq!(client.apply_love(amor).await.unwrap_err().code(), tonic::Code::Unauthenticated);
+        let amor = con_token(pb::LoveRequest { intensity: Some(f64::NAN) }, SECRETO, "ana");
+        assert_eq!(client.apply_love(amor).await.unwrap_err().code(), tonic::Code::InvalidArgument);
+        assert!(client.verify(pb::VerifyRequest {}).await.is_ok());
+    }
+}
@@ -0,0 +1,17 @@
+//! Idioma de Salida - Selección del idioma del CLI
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! El catálogo y el idioma activo viven en `algebra_rose_core::i18n`,
+//! compartidos con la aplicación de escritorio. Prioridad: `--lang`,
+//! `ALGEBRA_ROSE_LANG`, `locale` del archivo de configuración y por último
+//! `LANG`/`LC_ALL`; sin nada de eso, español.
+
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+
+pub use algebra_rose_core::i18n::{set_lang, t, Lang};
+
+/// Parser de `--lang`: `es` o `en`
+pub fn lang_parser() -> impl TypedValueParser<Value = Lang> {
+    PossibleValuesParser::new(Lang::ALL.map(Lang::code))
+        .map(|code| Lang::from_code(&code).expect("valor ya validado por clap"))
+}
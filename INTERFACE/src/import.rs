@@ -0,0 +1,125 @@
+//! Importación - Estados y Trayectorias Keygen Externos
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Las trayectorias CSV aceptan la salida de `export --what history`
+//! (`iteracion,keygen`), cualquier CSV con una columna `keygen`, o una
+//! columna de números sin cabecera. Al fusionar se descarta el prefijo que
+//! ya coincide con la sesión y el resto debe continuarla sin decrecer.
+
+use algebra_rose_core::prelude::DIM;
+use anyhow::{bail, Context, Result};
+
+use crate::state_io::StateVector;
+
+/// Valores keygen de un CSV
+pub fn parse_keygen_csv(text: &str) -> Result<Vec<f64>> {
+    let mut lines = text.lines().enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .peekable();
+
+    let first_is_header = lines.peek()
+        .is_some_and(|(_, line)| line.split(',').any(|cell| cell.trim().parse::<f64>().is_err()));
+    let column = if first_is_header {
+        let (_, header) = lines.next().expect("cabecera presente");
+        let cells: Vec<&str> = header.split(',').map(str::trim).collect();
+        match cells.iter().position(|c| c.eq_ignore_ascii_case("keygen")) {
+            Some(column) => Some(column),
+            None if cells.len() == 1 => Some(0),
+            None => bail!("El CSV no tiene columna keygen: {}", header),
+        }
+    } else {
+        None
+    };
+
+    lines.map(|(number, line)| {
+        let cells: Vec<&str> = line.split(',').collect();
+        let cell = match column {
+            Some(column) => cells.get(column).copied(),
+            None => cells.last().copied(),
+        };
+        let cell = cell.with_context(|| format!("Línea {}: falta la columna keygen", number))?;
+        let keygen: f64 = cell.trim().parse().with_context(|| format!("Línea {}: keygen inválido {:?}", number, cell))?;
+        if !(keygen > 0.0 && keygen <= 1.0) {
+            bail!("Línea {}: keygen fuera de (0, 1]: {}", number, keygen);
+        }
+        Ok(keygen)
+    }).collect()
+}
+
+/// Une `imported` a la trayectoria `current`
+///
+/// Devuelve la trayectoria fusionada y cuántos valores nuevos aporta.
+pub fn merge_history(current: &[f64], imported: &[f64]) -> Result<(Vec<f64>, usize)> {
+    let overlap = current.iter().zip(imported).take_while(|(a, b)| a == b).count();
+    let new = if overlap == current.len() || overlap == imported.len() {
+        &imported[overlap..]
+    } else if overlap == 0 {
+        imported
+    } else {
+        bail!("La trayectoria importada diverge de la sesión en la iteración {}", overlap);
+    };
+
+    let mut previous = current.last().copied().unwrap_or(0.0);
+    for (i, &keygen) in new.iter().enumerate() {
+        if keygen < previous {
+            bail!("La trayectoria decrece en el valor importado {}: {} < {}", overlap + i, keygen, previous);
+        }
+        previous = keygen;
+    }
+
+    let mut merged = current.to_vec();
+    merged.extend_from_slice(new);
+    Ok((merged, new.len()))
+}
+
+/// Comprueba que un estado importado sea utilizable por Â
+pub fn validate_state(state: &StateVector) -> Result<()> {
+    if state.len() != DIM {
+        bail!("El estado tiene dimensión {}, se requiere {}", state.len(), DIM);
+    }
+    if let Some(i) = state.iter().position(|c| !(c.re.is_finite() && c.im.is_finite())) {
+        bail!("Componente {} no finita", i);
+    }
+    if state.iter().all(|c| c.re == 0.0 && c.im == 0.0) {
+        bail!("El estado es nulo");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Complex, DVector};
+
+    #[test]
+    fn test_parse_keygen_csv() {
+        assert_eq!(parse_keygen_csv("iteracion,keygen\n0,0.5\n1,0.75\n").unwrap(), vec![0.5, 0.75]);
+        assert_eq!(parse_keygen_csv("0.5\n\n0.6\n").unwrap(), vec![0.5, 0.6]);
+        assert_eq!(parse_keygen_csv("keygen,x\n0.5,9\n").unwrap(), vec![0.5]);
+        assert!(parse_keygen_csv("a,b\n1,2\n").is_err());
+        assert!(parse_keygen_csv("0.5\n1.5\n").is_err());
+        assert!(parse_keygen_csv("keygen\nabc\n").is_err());
+    }
+
+    #[test]
+    fn test_merge_history() {
+        let current = [0.5, 0.6];
+        assert_eq!(merge_history(&current, &[0.5, 0.6, 0.7]).unwrap(), (vec![0.5, 0.6, 0.7], 1));
+        assert_eq!(merge_history(&current, &[0.65, 0.7]).unwrap(), (vec![0.5, 0.6, 0.65, 0.7], 2));
+        assert_eq!(merge_history(&current, &[0.5]).unwrap(), (vec![0.5, 0.6], 0));
+        assert!(merge_history(&current, &[0.5, 0.55]).is_err());
+        assert!(merge_history(&current, &[0.4]).is_err());
+        assert!(merge_history(&current, &[0.7, 0.65]).is_err());
+    }
+
+    #[test]
+    fn test_validate_state() {
+        assert!(validate_state(&DVector::from_element(DIM, Complex::new(1.0, 0.0))).is_ok());
+        assert!(validate_state(&DVector::from_element(3, Complex::new(1.0, 0.0))).is_err());
+        assert!(validate_state(&DVector::from_element(DIM, Complex::new(0.0, 0.0))).is_err());
+        let mut state = DVector::from_element(DIM, Complex::new(1.0, 0.0));
+        state[5] = Complex::new(f64::NAN, 0.0);
+        assert!(validate_state(&state).is_err());
+    }
+}
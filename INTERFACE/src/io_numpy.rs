@@ -0,0 +1,379 @@
+//! NumPy - Lectura y Escritura de Arreglos .npy y Archivos .npz
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Intercambia estados y matrices de operadores con Python sin pasar por
+//! CSV: `np.load` lee lo que escribe este módulo y viceversa. Se admiten los
+//! dtypes `<f8` y `<c16`, en orden C o Fortran, de cualquier dimensión; en
+//! memoria los datos quedan siempre en orden C. Los .npz se escriben como
+//! zip sin comprimir (como `np.savez`) y se leen también comprimidos
+//! (`np.savez_compressed`) o con campos zip64.
+
+use std::io::Read;
+
+use anyhow::{bail, ensure, Context, Result};
+use flate2::read::DeflateDecoder;
+use nalgebra::{Complex, DMatrix, DVector};
+
+use crate::state_io::StateVector;
+
+/// Firma mágica de los archivos .npy
+pub const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Firma de una cabecera local zip: los .npz empiezan así
+pub const NPZ_MAGIC: &[u8] = b"PK\x03\x04";
+
+/// Datos de un arreglo, en orden C
+#[derive(Clone, Debug, PartialEq)]
+pub enum NpyData {
+    /// dtype `<f8`
+    Real(Vec<f64>),
+    /// dtype `<c16`
+    Complex(Vec<Complex<f64>>),
+}
+
+/// Arreglo NumPy: forma y datos
+#[derive(Clone, Debug, PartialEq)]
+pub struct NpyArray {
+    /// Forma; vacía para un escalar 0-D
+    pub shape: Vec<usize>,
+    pub data: NpyData,
+}
+
+impl NpyArray {
+    /// Vector real 1-D
+    pub fn from_reals(values: &[f64]) -> Self {
+        NpyArray { shape: vec![values.len()], data: NpyData::Real(values.to_vec()) }
+    }
+
+    /// Escalar real 0-D
+    pub fn scalar(value: f64) -> Self {
+        NpyArray { shape: Vec::new(), data: NpyData::Real(vec![value]) }
+    }
+
+    /// Estado como vector complejo 1-D
+    pub fn from_state(state: &StateVector) -> Self {
+        NpyArray { shape: vec![state.len()], data: NpyData::Complex(state.as_slice().to_vec()) }
+    }
+
+    /// Matriz compleja 2-D (filas, columnas)
+    pub fn from_matrix(matrix: &DMatrix<Complex<f64>>) -> Self {
+        // nalgebra guarda por columnas; la traspuesta por columnas es el orden C
+        NpyArray { shape: vec![matrix.nrows(), matrix.ncols()], data: NpyData::Complex(matrix.transpose().as_slice().to_vec()) }
+    }
+
+    /// Número de elementos
+    fn len(&self) -> usize {
+        match &self.data {
+            NpyData::Real(v) => v.len(),
+            NpyData::Complex(v) => v.len(),
+        }
+    }
+
+    /// Convierte un arreglo 1-D en estado; los reales con parte imaginaria nula
+    pub fn into_state(self) -> Result<StateVector> {
+        let [_] = self.shape[..] else {
+            bail!("Se espera un vector 1-D, shape = {:?}", self.shape);
+        };
+        Ok(DVector::from_vec(match self.data {
+            NpyData::Real(v) => v.into_iter().map(|re| Complex::new(re, 0.0)).collect(),
+            NpyData::Complex(v) => v,
+        }))
+    }
+}
+
+/// Codifica un arreglo como .npy v1.0
+pub fn encode_npy(array: &NpyArray) -> Vec<u8> {
+    let (descr, width) = match array.data {
+        NpyData::Real(_) => ("<f8", 8),
+        NpyData::Complex(_) => ("<c16", 16),
+    };
+    let shape = match array.shape[..] {
+        [n] => format!("({},)", n),
+        _ => format!("({})", array.shape.iter().map(usize::to_string).collect::<Vec<_>>().join(", ")),
+    };
+    let mut header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}", descr, shape);
+    // Cabecera v1.0: magia (6) + versión (2) + longitud (2) + dict, alineado a 64
+    let total = NPY_MAGIC.len() + 4 + header.len() + 1;
+    header.push_str(&" ".repeat((64 - total % 64) % 64));
+    header.push('\n');
+
+    let mut bytes = Vec::with_capacity(10 + header.len() + array.len() * width);
+    bytes.extend_from_slice(NPY_MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    match &array.data {
+        NpyData::Real(values) => values.iter().for_each(|x| bytes.extend_from_slice(&x.to_le_bytes())),
+        NpyData::Complex(values) => values.iter().for_each(|c| {
+            bytes.extend_from_slice(&c.re.to_le_bytes());
+            bytes.extend_from_slice(&c.im.to_le_bytes());
+        }),
+    }
+    bytes
+}
+
+/// Decodifica un .npy v1, v2 o v3
+pub fn decode_npy(bytes: &[u8]) -> Result<NpyArray> {
+    ensure!(bytes.starts_with(NPY_MAGIC), "No es un archivo .npy");
+    let (header, data) = match bytes.get(6) {
+        Some(1) if bytes.len() >= 10 => {
+            let len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+            (bytes.get(10..10 + len), bytes.get(10 + len..))
+        }
+        Some(2) | Some(3) if bytes.len() >= 12 => {
+            let len = u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize;
+            (bytes.get(12..12 + len), bytes.get(12 + len..))
+        }
+        _ => bail!("Versión .npy no soportada"),
+    };
+    let (Some(header), Some(data)) = (header, data) else {
+        bail!("Archivo .npy truncado");
+    };
+    let header = std::str::from_utf8(header).context("Cabecera .npy no es texto")?;
+
+    let fortran = header.contains("'fortran_order': True");
+    let shape_text = header.split("'shape':").nth(1)
+        .and_then(|rest| rest.split(['(', ')']).nth(1))
+        .context("Cabecera .npy sin shape")?;
+    let shape: Vec<usize> = shape_text.split(',').map(str::trim).filter(|s| !s.is_empty())
+        .map(|s| s.parse().context("Dimensión .npy inválida"))
+        .collect::<Result<_>>()?;
+    let len = shape.iter().try_fold(1usize, |acc, &d| acc.checked_mul(d)).context("Forma .npy demasiado grande")?;
+
+    let (width, complex) = if header.contains("'<c16'") {
+        (16, true)
+    } else if header.contains("'<f8'") {
+        (8, false)
+    } else {
+        bail!("dtype .npy no soportado (se admiten <f8 y <c16)");
+    };
+    if Some(data.len()) != len.checked_mul(width) {
+        bail!("Datos .npy: {} bytes, se esperaban {}", data.len(), len.saturating_mul(width));
+    }
+
+    let read_f64 = |chunk: &[u8]| f64::from_le_bytes(chunk.try_into().expect("8 bytes"));
+    let orden: Vec<usize> = if fortran { fortran_to_c(&shape) } else { (0..len).collect() };
+    let data = if complex {
+        NpyData::Complex(orden.iter().map(|&k| {
+            let chunk = &data[k * 16..(k + 1) * 16];
+            Complex::new(read_f64(&chunk[..8]), read_f64(&chunk[8..]))
+        }).collect())
+    } else {
+        NpyData::Real(orden.iter().map(|&k| read_f64(&data[k * 8..(k + 1) * 8])).collect())
+    };
+    Ok(NpyArray { shape, data })
+}
+
+/// Para cada posición en orden C, su posición en orden Fortran
+fn fortran_to_c(shape: &[usize]) -> Vec<usize> {
+    let strides: Vec<usize> = shape.iter().scan(1, |stride, &d| {
+        let actual = *stride;
+        *stride *= d;
+        Some(actual)
+    }).collect();
+    let len: usize = shape.iter().product();
+    (0..len).map(|mut c| {
+        let mut offset = 0;
+        for (&d, &s) in shape.iter().zip(&strides).rev() {
+            offset += (c % d) * s;
+            c /= d;
+        }
+        offset
+    }).collect()
+}
+
+/// Empaqueta arreglos con nombre en un .npz (`np.load(...)["nombre"]`)
+pub fn encode_npz(entries: &[(&str, NpyArray)]) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut central = Vec::new();
+    for (name, array) in entries {
+        let file = format!("{}.npy", name);
+        let data = encode_npy(array);
+        let offset = u32::try_from(bytes.len()).ok().context("El .npz supera 4 GiB")?;
+        let size = u32::try_from(data.len()).ok().context("Arreglo de más de 4 GiB en .npz")?;
+        let crc = crc32fast::hash(&data);
+
+        // Cabecera local: versión 2.0, sin compresión, fecha 1980-01-01
+        bytes.extend_from_slice(NPZ_MAGIC);
+        zip_fields(&mut bytes, crc, size, &file);
+        bytes.extend_from_slice(file.as_bytes());
+        bytes.extend_from_slice(&data);
+
+        central.extend_from_slice(b"PK\x01\x02");
+        central.extend_from_slice(&20u16.to_le_bytes());
+        zip_fields(&mut central, crc, size, &file);
+        central.extend_from_slice(&[0; 6]); // comentario, disco y atributos internos
+        central.extend_from_slice(&0u32.to_le_bytes()); // atributos externos
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(file.as_bytes());
+    }
+
+    let inicio = u32::try_from(bytes.len()).ok().context("El .npz supera 4 GiB")?;
+    let entradas = u16::try_from(entries.len()).ok().context("Demasiados arreglos para un .npz")?;
+    bytes.extend_from_slice(&central);
+    bytes.extend_from_slice(b"PK\x05\x06");
+    bytes.extend_from_slice(&[0; 4]); // disco actual y disco del directorio
+    bytes.extend_from_slice(&entradas.to_le_bytes());
+    bytes.extend_from_slice(&entradas.to_le_bytes());
+    bytes.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&inicio.to_le_bytes());
+    bytes.extend_from_slice(&0u16.to_le_bytes());
+    Ok(bytes)
+}
+
+/// Campos comunes de las cabeceras local y central, desde "versión necesaria"
+fn zip_fields(out: &mut Vec<u8>, crc: u32, size: u32, name: &str) {
+    out.extend_from_slice(&20u16.to_le_bytes()); // versión necesaria
+    out.extend_from_slice(&0u16.to_le_bytes()); // flags
+    out.extend_from_slice(&0u16.to_le_bytes()); // método: sin compresión
+    out.extend_from_slice(&0u16.to_le_bytes()); // hora
+    out.extend_from_slice(&0x21u16.to_le_bytes()); // fecha
+    out.extend_from_slice(&crc.to_le_bytes());
+    out.extend_from_slice(&size.to_le_bytes());
+    out.extend_from_slice(&size.to_le_bytes());
+    out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes()); // extra
+}
+
+/// Desempaqueta un .npz en (nombre, arreglo), en el orden del archivo
+pub fn decode_npz(bytes: &[u8]) -> Result<Vec<(String, NpyArray)>> {
+    let u16_at = |at: usize| bytes.get(at..at + 2).map(|b| u16::from_le_bytes([b[0], b[1]]) as usize);
+    let u32_at = |at: usize| bytes.get(at..at + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as u64);
+    let u64_at = |at: usize| bytes.get(at..at + 8).map(|b| u64::from_le_bytes(b.try_into().expect("8 bytes")));
+
+    // Fin del directorio central: al final, tras un comentario de hasta 64 KiB
+    let eocd = (0..=bytes.len().saturating_sub(22)).rev().take(65_536 + 22)
+        .find(|&at| bytes[at..].starts_with(b"PK\x05\x06"))
+        .context("Archivo .npz sin directorio central")?;
+    let entradas = u16_at(eocd + 10).context("Directorio .npz truncado")?;
+    let mut at = u32_at(eocd + 16).context("Directorio .npz truncado")? as usize;
+
+    let mut arrays = Vec::with_capacity(entradas);
+    for _ in 0..entradas {
+        ensure!(bytes.get(at..).is_some_and(|b| b.starts_with(b"PK\x01\x02")), "Entrada de directorio .npz inválida");
+        let campos = (|| Some((u16_at(at + 10)?, u32_at(at + 20)?, u32_at(at + 24)?, u16_at(at + 28)?, u16_at(at + 30)?, u16_at(at + 32)?, u32_at(at + 42)?)))();
+        let (metodo, mut comprimido, mut tamano, nombre_len, extra_len, comentario_len, mut local) =
+            campos.context("Entrada de directorio .npz truncada")?;
+        let nombre = bytes.get(at + 46..at + 46 + nombre_len).context("Nombre .npz truncado")?;
+        let nombre = String::from_utf8_lossy(nombre).into_owned();
+
+        // zip64: los campos a 0xFFFFFFFF siguen, en orden, en el extra 0x0001
+        let mut extra = at + 46 + nombre_len;
+        let fin_extra = extra + extra_len;
+        while extra + 4 <= fin_extra {
+            let (id, len) = (u16_at(extra).unwrap_or(0), u16_at(extra + 2).unwrap_or(0));
+            if id == 1 {
+                let mut campo = extra + 4;
+                for valor in [&mut tamano, &mut comprimido, &mut local] {
+                    if *valor == u32::MAX as u64 {
+                        *valor = u64_at(campo).context("Extra zip64 truncado")?;
+                        campo += 8;
+                    }
+                }
+            }
+            extra += 4 + len;
+        }
+        at = fin_extra + comentario_len;
+
+        let local = local as usize;
+        ensure!(bytes.get(local..).is_some_and(|b| b.starts_with(NPZ_MAGIC)), "Cabecera local .npz inválida en {}", nombre);
+        let inicio = local + 30 + u16_at(local + 26).unwrap_or(0) + u16_at(local + 28).unwrap_or(0);
+        let datos = bytes.get(inicio..inicio + comprimido as usize).with_context(|| format!("{} truncado", nombre))?;
+        let npy = match metodo {
+            0 => datos.to_vec(),
+            8 => {
+                let mut npy = Vec::with_capacity(tamano as usize);
+                DeflateDecoder::new(datos).read_to_end(&mut npy).with_context(|| format!("{} mal comprimido", nombre))?;
+                npy
+            }
+            otro => bail!("{}: método de compresión {} no soportado", nombre, otro),
+        };
+        let clave = nombre.strip_suffix(".npy").unwrap_or(&nombre).to_string();
+        arrays.push((clave, decode_npy(&npy).with_context(|| format!("Arreglo {} del .npz", nombre))?));
+    }
+    Ok(arrays)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::DeflateEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    fn matriz() -> DMatrix<Complex<f64>> {
+        DMatrix::from_fn(2, 3, |i, j| Complex::new(i as f64, j as f64 + 0.5))
+    }
+
+    #[test]
+    fn test_npy_roundtrip_shapes() {
+        let m = matriz();
+        let bytes = encode_npy(&NpyArray::from_matrix(&m));
+        assert!(std::str::from_utf8(&bytes[10..74]).unwrap().contains("'shape': (2, 3)"));
+        assert_eq!(decode_npy(&bytes).unwrap(), NpyArray::from_matrix(&m));
+        assert_eq!(NpyArray::from_matrix(&m).data, NpyData::Complex(vec![m[(0, 0)], m[(0, 1)], m[(0, 2)], m[(1, 0)], m[(1, 1)], m[(1, 2)]]));
+
+        let escalar = decode_npy(&encode_npy(&NpyArray::scalar(0.25))).unwrap();
+        assert_eq!(escalar, NpyArray { shape: vec![], data: NpyData::Real(vec![0.25]) });
+        assert!(escalar.into_state().is_err());
+
+        let reales = decode_npy(&encode_npy(&NpyArray::from_reals(&[1.0, 2.0]))).unwrap();
+        assert_eq!(reales.into_state().unwrap()[1], Complex::new(2.0, 0.0));
+        assert!(NpyArray::from_matrix(&m).into_state().is_err());
+    }
+
+    #[test]
+    fn test_fortran_order() {
+        // np.asfortranarray([[1, 2, 3], [4, 5, 6]], dtype='<f8')
+        let header = "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }";
+        let mut bytes = NPY_MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for x in [1.0f64, 4.0, 2.0, 5.0, 3.0, 6.0] {
+            bytes.extend_from_slice(&x.to_le_bytes());
+        }
+        let array = decode_npy(&bytes).unwrap();
+        assert_eq!(array.data, NpyData::Real(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+        assert_eq!(array.shape, vec![2, 3]);
+        assert!(decode_npy(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_npz_roundtrip() {
+        let entradas = [("keygen", NpyArray::from_reals(&[0.5, 0.75])), ("amor", NpyArray::from_matrix(&matriz()))];
+        let bytes = encode_npz(&entradas).unwrap();
+        assert!(bytes.starts_with(NPZ_MAGIC));
+        let leidas = decode_npz(&bytes).unwrap();
+        assert_eq!(leidas.len(), 2);
+        assert_eq!((leidas[0].0.as_str(), &leidas[0].1), ("keygen", &entradas[0].1));
+        assert_eq!(leidas[1], ("amor".to_string(), NpyArray::from_matrix(&matriz())));
+        assert!(decode_npz(&bytes[..bytes.len() - 30]).is_err());
+    }
+
+    #[test]
+    fn test_npz_compressed_entry() {
+        // Como np.savez_compressed: la única entrada con método 8 (deflate)
+        let npy = encode_npy(&NpyArray::from_reals(&[1.0; 100]));
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&npy).unwrap();
+        let comprimido = encoder.finish().unwrap();
+
+        let mut bytes = encode_npz(&[("x", NpyArray::from_reals(&[1.0; 100]))]).unwrap();
+        let nombre = 30 + "x.npy".len();
+        let directorio = bytes[nombre + npy.len()..].to_vec();
+        bytes.truncate(nombre);
+        bytes[8] = 8;
+        bytes[18..22].copy_from_slice(&(comprimido.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&comprimido);
+        let mut directorio = directorio;
+        directorio[10] = 8;
+        directorio[20..24].copy_from_slice(&(comprimido.len() as u32).to_le_bytes());
+        let fin = directorio.len() - 22;
+        directorio[fin + 16..fin + 20].copy_from_slice(&(bytes.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&directorio);
+
+        let leidas = decode_npz(&bytes).unwrap();
+        assert_eq!(leidas[0].1, NpyArray::from_reals(&[1.0; 100]));
+    }
+}
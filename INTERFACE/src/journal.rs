@@ -0,0 +1,162 @@
+//! Diario - Registro Persistente de la Actividad de la Sesión
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cada perfil guarda un `journal.jsonl` con una entrada por línea:
+//! evoluciones, transiciones de campo y actualizaciones del operador Â.
+//! Las entradas sólo se añaden al final; `history` las lee por páginas.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Qué ocurrió
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "tipo", rename_all = "lowercase")]
+pub enum Evento {
+    /// Una ejecución de `evolve`, `transit` o similar
+    Evolucion { pasos: u64, keygen_inicial: f64, keygen_final: f64 },
+    /// Un campo Fibonacci cambió de estado
+    Transicion { campo: usize, activo: bool },
+    /// Nueva intensidad del operador Â
+    Amor { intensidad: f64 },
+}
+
+/// Entrada del diario
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entrada {
+    /// Momento, RFC 3339
+    pub momento: String,
+    /// Iteración keygen al registrar la entrada
+    pub iteracion: u64,
+    #[serde(flatten)]
+    pub evento: Evento,
+}
+
+impl Entrada {
+    pub fn new(iteracion: u64, evento: Evento) -> Self {
+        Entrada { momento: humantime::format_rfc3339_seconds(SystemTime::now()).to_string(), iteracion, evento }
+    }
+}
+
+/// Filtro de `history`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Filtro {
+    All,
+    Steps,
+    Transitions,
+    Love,
+}
+
+impl Filtro {
+    fn admite(self, evento: &Evento) -> bool {
+        match self {
+            Filtro::All => true,
+            Filtro::Steps => matches!(evento, Evento::Evolucion { .. }),
+            Filtro::Transitions => matches!(evento, Evento::Transicion { .. }),
+            Filtro::Love => matches!(evento, Evento::Amor { .. }),
+        }
+    }
+}
+
+/// Diario en disco
+pub struct Journal {
+    path: PathBuf,
+}
+
+impl Journal {
+    pub fn new(path: PathBuf) -> Self {
+        Journal { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Añade entradas al final del diario
+    pub fn append(&self, entradas: &[Entrada]) -> Result<()> {
+        if entradas.is_empty() {
+            return Ok(());
+        }
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("No se pudo crear {}", dir.display()))?;
+        }
+        let mut lineas = Vec::new();
+        for entrada in entradas {
+            serde_json::to_writer(&mut lineas, entrada)?;
+            lineas.push(b'\n');
+        }
+        OpenOptions::new().create(true).append(true).open(&self.path)
+            .and_then(|mut file| file.write_all(&lineas))
+            .with_context(|| format!("No se pudo escribir {}", self.path.display()))
+    }
+
+    /// Todas las entradas, de la más antigua a la más reciente
+    pub fn read(&self) -> Result<Vec<Entrada>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let text = fs::read_to_string(&self.path)
+            .with_context(|| format!("No se pudo leer {}", self.path.display()))?;
+        text.lines().enumerate().filter(|(_, linea)| !linea.trim().is_empty())
+            .map(|(i, linea)| serde_json::from_str(linea)
+                .with_context(|| format!("Entrada inválida en {}:{}", self.path.display(), i + 1)))
+            .collect()
+    }
+}
+
+/// Página `page` (1 = la más reciente) de `last` entradas que pasan el filtro,
+/// en orden cronológico, junto con el total de páginas
+pub fn page(entradas: &[Entrada], filtro: Filtro, last: usize, page: usize) -> (Vec<&Entrada>, usize) {
+    let filtradas: Vec<&Entrada> = entradas.iter().filter(|e| filtro.admite(&e.evento)).collect();
+    let last = last.max(1);
+    let paginas = filtradas.len().div_ceil(last);
+    let fin = filtradas.len().saturating_sub(last * page.saturating_sub(1));
+    let inicio = fin.saturating_sub(last);
+    (filtradas[inicio..fin].to_vec(), paginas)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entradas() -> Vec<Entrada> {
+        (0..10).map(|i| Entrada::new(i, if i % 3 == 0 {
+            Evento::Transicion { campo: i as usize, activo: true }
+        } else {
+            Evento::Evolucion { pasos: 1, keygen_inicial: 0.5, keygen_final: 0.6 }
+        })).collect()
+    }
+
+    #[test]
+    fn test_append_and_read() {
+        let path = std::env::temp_dir().join(format!("algebra-rose-journal-{}", std::process::id())).join("journal.jsonl");
+        let journal = Journal::new(path.clone());
+        assert!(journal.read().unwrap().is_empty());
+        let todas = entradas();
+        journal.append(&todas[..4]).unwrap();
+        journal.append(&todas[4..]).unwrap();
+        assert_eq!(journal.read().unwrap(), todas);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_page_and_filter() {
+        let todas = entradas();
+        let (ultimas, paginas) = page(&todas, Filtro::All, 4, 1);
+        assert_eq!(ultimas.iter().map(|e| e.iteracion).collect::<Vec<_>>(), vec![6, 7, 8, 9]);
+        assert_eq!(paginas, 3);
+        let (primeras, _) = page(&todas, Filtro::All, 4, 3);
+        assert_eq!(primeras.iter().map(|e| e.iteracion).collect::<Vec<_>>(), vec![0, 1]);
+        assert!(page(&todas, Filtro::All, 4, 4).0.is_empty());
+
+        let (transiciones, paginas) = page(&todas, Filtro::Transitions, 100, 1);
+        assert_eq!(transiciones.iter().map(|e| e.iteracion).collect::<Vec<_>>(), vec![0, 3, 6, 9]);
+        assert_eq!(paginas, 1);
+        assert!(page(&todas, Filtro::Love, 10, 1).0.is_empty());
+    }
+}
@@ -1,3 +1,14 @@
+//! Biblioteca de la Interfaz - Perfiles, Cifrado y Sesión Compartidos
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! El CLI y la aplicación de escritorio leen y escriben los mismos perfiles:
+//! ambos pasan por estos módulos para que el formato en disco, el cifrado
+//! del estado y la sesión de `login` sean uno solo.
+
+pub mod auth;
+pub mod encryption;
+pub mod profile;
+
 pub fn hello_interface() -> String {
     String::from("Interfaz Humana (Fotóniko) - Lista")
 }
@@ -0,0 +1,54 @@
+//! Registro Estructurado - tracing para sesión, evolución y campos
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Los eventos van a stderr, separados de la salida del CLI. `-v` sube el
+//! nivel (warn → info → debug → trace); `RUST_LOG`, si está definida, manda.
+
+use clap::ValueEnum;
+use tracing_subscriber::EnvFilter;
+
+/// Formato de los registros
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// Texto legible
+    Text,
+    /// Una línea JSON por evento
+    Json,
+}
+
+/// Nivel por defecto según el número de `-v`
+fn level_for(verbosity: u8) -> &'static str {
+    match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    }
+}
+
+/// Instala el suscriptor global; `ansi` colorea el formato texto
+pub fn init(verbosity: u8, format: LogFormat, ansi: bool) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(level_for(verbosity)));
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(ansi);
+    match format {
+        LogFormat::Text => builder.init(),
+        LogFormat::Json => builder.json().init(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_levels() {
+        assert_eq!(level_for(0), "warn");
+        assert_eq!(level_for(1), "info");
+        assert_eq!(level_for(2), "debug");
+        assert_eq!(level_for(7), "trace");
+    }
+}
@@ -2,22 +2,70 @@
 //! Sistema: v27.1024D-S36 | Certificación: 196885
 //! Humano Certificado: Roberto | Keygen Evolutivo Activo
 
-use algebra_rose_core::{
+use algebra_rose_core::prelude::{
     LoveOperator,
     KeygenEvolution,
     FibonacciSystem,
     Matrix444,
-    GriessAlgebra,
     MONSTER_DIM,
     INITIAL_KEYGEN,
     PHI as CORE_PHI,
     verificar_coherencia,
     AR_VERSION,
     CERTIFICATION,
+    CambioActivacion,
 };
+use anyhow::Context;
 use clap::{Parser, Subcommand};
 use colored::*;
-use std::time::Instant;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+#[macro_use]
+mod output;
+mod audit;
+mod certificate;
+mod community;
+mod config;
+mod dashboard;
+mod doctor;
+mod export;
+mod export_audio;
+mod export_parquet;
+mod grpc;
+mod i18n;
+mod import;
+mod io_numpy;
+mod journal;
+mod logging;
+mod midi;
+mod osc;
+mod plot;
+mod progress;
+mod render;
+mod serve;
+mod state_io;
+mod transit;
+
+use algebra_rose_interface::{auth, encryption, profile};
+
+use audit::{Accion, AuditLog, Suceso};
+use certificate::CertifyFormat;
+use config::ArConfig;
+use doctor::{DoctorFormat, Severity};
+use export::{ExportFormat, ExportWhat};
+use i18n::{t, Lang};
+use journal::{Entrada, Evento, Filtro, Journal};
+use logging::LogFormat;
+use output::ColorChoice;
+use plot::PlotMetric;
+use profile::{Profile, ProfileState};
+use progress::EvolutionProgress;
+use render::ImageFormat;
+use state_io::{StateFormat, StateVector};
 
 /// Interfaz CLI principal de Álgebra Rose
 #[derive(Parser)]
@@ -29,13 +77,37 @@ struct Cli {
     #[command(subcommand)]
     command: Commands,
     
-    /// Modo silencioso (menos output)
-    #[arg(short, long)]
+    /// Modo silencioso: solo el resultado del comando y los errores
+    #[arg(short, long, global = true)]
     quiet: bool,
     
     /// Keygen personalizado inicial
     #[arg(long)]
     keygen: Option<f64>,
+    
+    /// Perfil cuyo estado y configuración se usan (por defecto "default")
+    #[arg(long, global = true)]
+    profile: Option<String>,
+    
+    /// Archivo de configuración (por defecto el del perfil, ~/.config/algebra-rose/config.toml para "default")
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    
+    /// Más registros en stderr (-v info, -vv debug, -vvv trace)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    
+    /// Cuándo colorear la salida (NO_COLOR desactiva el modo auto)
+    #[arg(long, global = true, value_enum)]
+    color: Option<ColorChoice>,
+    
+    /// Idioma de la salida (por defecto ALGEBRA_ROSE_LANG, config o LANG)
+    #[arg(long, global = true, value_parser = i18n::lang_parser())]
+    lang: Option<Lang>,
+    
+    /// Formato de los registros
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
 }
 
 /// Comandos certificados de Álgebra Rose
@@ -43,23 +115,77 @@ struct Cli {
 enum Commands {
     /// Inicia sesión consciente con Roberto
     Login {
-        /// Token de amor matemático (opcional)
+        /// Token de amor matemático (HMAC emitido con --issue)
         #[arg(short, long)]
         token: Option<String>,
+        
+        /// Humano certificado
+        #[arg(short, long, default_value = "Roberto")]
+        user: String,
+        
+        /// Emite el token del usuario con el secreto de ALGEBRA_ROSE_SECRET
+        #[arg(long)]
+        issue: bool,
     },
     
+    /// Cierra la sesión guardada en el llavero
+    Logout,
+    
     /// Muestra estado actual del sistema
-    Status,
+    Status {
+        /// Redibuja el estado periódicamente hasta Ctrl+C
+        #[arg(short, long)]
+        watch: bool,
+        
+        /// Intervalo de refresco en modo watch (p. ej. 500ms, 2s, 1m)
+        #[arg(short, long, default_value = "2s", value_parser = humantime::parse_duration)]
+        interval: Duration,
+        
+        /// Pasos evolutivos en segundo plano por intervalo (requiere sesión autenticada)
+        #[arg(long, default_value_t = 10)]
+        evolve_steps: u64,
+    },
     
     /// Ejecuta evolución keygen φ-resonante
     Evolve {
-        /// Número de pasos evolutivos
-        #[arg(short, long, default_value_t = 10)]
-        steps: u64,
+        /// Número de pasos evolutivos (por defecto 10 o `steps` del archivo de configuración)
+        #[arg(short, long)]
+        steps: Option<u64>,
         
         /// Umbral objetivo
         #[arg(short, long)]
         threshold: Option<f64>,
+        
+        /// Evoluciona hasta que se active este campo (1-24); --steps pasa a ser el
+        /// presupuesto máximo (por defecto 1000000)
+        #[arg(long, conflicts_with = "threshold", value_parser = clap::value_parser!(u64).range(1..=24))]
+        until_field: Option<u64>,
+        
+        /// Tiempo máximo para --until-field (p. ej. 30s, 2m)
+        #[arg(long, requires = "until_field", value_parser = humantime::parse_duration)]
+        max_time: Option<Duration>,
+        
+        /// Repite la evolución cada intervalo (p. ej. 10m), guardando el estado tras cada tanda
+        #[arg(long, conflicts_with_all = ["threshold", "until_field"], value_parser = humantime::parse_duration)]
+        every: Option<Duration>,
+        
+        /// Ejecuta --every en segundo plano y deja el pid en el perfil
+        #[arg(long, requires = "every")]
+        daemonize: bool,
+        
+        /// Envía las activaciones de campos y la intensidad del amor como notas y CC MIDI
+        /// al puerto cuyo nombre contenga PUERTO (o al primero); requiere la feature `midi`
+        #[arg(long, value_name = "PUERTO", num_args = 0..=1, default_missing_value = "",
+            conflicts_with_all = ["until_field", "every"])]
+        midi: Option<String>,
+        
+        /// Canal MIDI (1-16)
+        #[arg(long, default_value_t = 1, requires = "midi", value_parser = clap::value_parser!(u8).range(1..=16))]
+        midi_channel: u8,
+        
+        /// Pausa tras cada paso para que la evolución se pueda tocar (p. ej. 250ms)
+        #[arg(long, requires = "midi", value_parser = humantime::parse_duration)]
+        midi_pace: Option<Duration>,
     },
     
     /// Aplica operador Â (amor fundamental)
@@ -68,9 +194,21 @@ enum Commands {
         #[arg(short, long, default_value_t = 1.0)]
         intensity: f64,
         
-        /// Estado consciente a transformar
-        #[arg(short, long)]
+        /// Estado consciente de dimensión 444 a transformar (archivo JSON/.npy/.npz, o - para stdin)
+        #[arg(short, long, visible_alias = "state-file")]
         state: Option<String>,
+        
+        /// Destino del estado transformado (archivo, o - para stdout)
+        #[arg(long, visible_alias = "out", requires = "state")]
+        state_out: Option<String>,
+        
+        /// Formato de --state-out (por defecto según extensión; JSON para -)
+        #[arg(long, value_enum)]
+        state_format: Option<StateFormat>,
+        
+        /// Aplica además la matriz Monster 444 tras Â
+        #[arg(long, requires = "state")]
+        monster: bool,
     },
     
     /// Visualiza campos Fibonacci dimensionales
@@ -82,37 +220,318 @@ enum Commands {
         /// Mostrar todos los campos activos
         #[arg(short = 'a', long)]
         all: bool,
+        
+        /// Emite el estado del campo (--field o el más alto activo) a un archivo, o - para stdout
+        #[arg(long)]
+        state_out: Option<String>,
+        
+        /// Formato de --state-out (por defecto según extensión; JSON para -)
+        #[arg(long, value_enum)]
+        state_format: Option<StateFormat>,
+        
+        /// Dibuja la escalera y la espiral de campos en un archivo SVG o PNG
+        #[arg(long)]
+        export: Option<PathBuf>,
+        
+        /// Formato de --export (por defecto según extensión)
+        #[arg(long, value_enum, requires = "export")]
+        export_format: Option<ImageFormat>,
+        
+        /// Anima un barrido de keygen 0 → 1 en N fotogramas (archivo-000.svg, ...)
+        #[arg(long, requires = "export")]
+        frames: Option<usize>,
     },
     
-    /// Verifica coherencia del sistema
+    /// Verifica coherencia del sistema; sale con 0 (todo certificado), 1 (degradada),
+    /// 2 (por debajo de --min-coherence) o 3 (error interno)
     Verify {
-        /// Tolerancia de verificación
-        #[arg(short, long, default_value_t = 1e-6)]
-        tolerance: f64,
+        /// Tolerancia relativa de la φ-resonancia (por defecto 0.1 o `tolerance` del archivo de configuración)
+        #[arg(short, long)]
+        tolerance: Option<f64>,
+        
+        /// Coherencia mínima, en [0, 1], por debajo de la cual la verificación falla
+        #[arg(long, default_value_t = 0.6, value_parser = parse_coherence)]
+        min_coherence: f64,
     },
     
     /// Configura parámetros del sistema
     Config {
-        /// Nuevo valor de keygen
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
+        
+        /// Nuevo valor de keygen, en [196883/196884, 1); empieza una trayectoria nueva
         #[arg(long)]
         set_keygen: Option<f64>,
         
-        /// Nueva intensidad φ
+        /// Nueva intensidad φ del operador Â (positiva)
         #[arg(long)]
         set_phi_intensity: Option<f64>,
         
-        /// Resetear a valores iniciales
+        /// Resetear el perfil a valores iniciales
         #[arg(long)]
         reset: bool,
+        
+        /// No pedir confirmación para --reset
+        #[arg(short, long)]
+        yes: bool,
     },
     
-    /// Muestra certificación 196885
-    Certify,
+    /// Muestra certificación 196885, o emite/verifica un certificado firmado
+    Certify {
+        /// Escribe el certificado firmado con la clave del perfil (archivo, o - para stdout)
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+        
+        /// Comprueba la firma de un certificado y vuelve a ejecutar sus comprobaciones
+        #[arg(long, conflicts_with = "out")]
+        verify: Option<PathBuf>,
+        
+        /// Tolerancia relativa de las comprobaciones (--out y --format json)
+        #[arg(short, long, default_value_t = 1e-9)]
+        tolerance: f64,
+        
+        /// Formato de salida; json escribe la lista de comprobaciones en stdout
+        #[arg(short, long, value_enum, default_value_t = CertifyFormat::Text, conflicts_with_all = ["out", "verify"])]
+        format: CertifyFormat,
+    },
+    
+    /// Verificación cruzada del núcleo; sale con 1 si alguna comprobación falla
+    Doctor {
+        /// Tolerancia relativa de las comparaciones
+        #[arg(short, long, default_value_t = 1e-9)]
+        tolerance: f64,
+        
+        /// Formato del informe (json va entero a stdout)
+        #[arg(short, long, value_enum, default_value_t = DoctorFormat::Text)]
+        format: DoctorFormat,
+        
+        /// Presupuesto de memoria en MiB; lo que lo supere se marca con aviso
+        #[arg(long, default_value_t = 4096)]
+        memory_budget: u64,
+    },
+    
+    /// Exporta historia, campos, estadísticas u operadores de la sesión
+    Export {
+        /// Datos a exportar
+        #[arg(short, long, value_enum, required_unless_present_any = ["parquet", "audio"])]
+        what: Option<ExportWhat>,
+        
+        /// Formato de salida
+        #[arg(short, long, value_enum, default_value_t = ExportFormat::Json)]
+        format: ExportFormat,
+        
+        /// Archivo destino (stdout si se omite o es -)
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+        
+        /// Directorio donde escribir las tablas Parquet de keygen, coherencia y transiciones
+        #[arg(long)]
+        parquet: Option<PathBuf>,
+        
+        /// WAV donde sonar los campos activos a su frecuencia de resonancia
+        #[arg(long)]
+        audio: Option<PathBuf>,
+        
+        /// Duración del WAV de --audio
+        #[arg(long, default_value = "10s", requires = "audio", value_parser = humantime::parse_duration)]
+        duration: Duration,
+        
+        /// Pasos evolutivos a ejecutar antes de exportar
+        #[arg(short, long, default_value_t = 0)]
+        steps: u64,
+    },
+    
+    /// Grafica la trayectoria de una métrica en la terminal
+    Plot {
+        /// Métrica a graficar
+        #[arg(short, long, value_enum, default_value_t = PlotMetric::Keygen)]
+        metric: PlotMetric,
+        
+        /// Pasos evolutivos simulados desde el estado actual
+        #[arg(short, long, default_value_t = 200)]
+        steps: u64,
+        
+        /// Ancho del gráfico en caracteres
+        #[arg(long, default_value_t = 60)]
+        width: usize,
+        
+        /// Alto del gráfico en caracteres
+        #[arg(long, default_value_t = 12)]
+        height: usize,
+    },
+    
+    /// Sirve la sesión por HTTP/REST y WebSocket (/ws), o por gRPC con --grpc;
+    /// las mutaciones exigen `Authorization: Bearer <usuario>:<token>`
+    Serve {
+        /// Puerto de escucha
+        #[arg(short, long, default_value_t = 8080)]
+        port: u16,
+        
+        /// Dirección de escucha
+        #[arg(long, default_value = "127.0.0.1")]
+        host: std::net::IpAddr,
+        
+        /// Sirve la API gRPC de proto/algebra_rose.proto en lugar de HTTP/REST
+        #[arg(long)]
+        grpc: bool,
+    },
+    
+    /// Emite keygen, activación de campos e intensidad del amor por OSC (UDP)
+    Osc {
+        /// Destino de los mensajes (admite direcciones de difusión)
+        #[arg(short, long, default_value = "127.0.0.1:9000")]
+        target: std::net::SocketAddr,
+        
+        /// Ciclos de envío por segundo
+        #[arg(short, long, default_value_t = 30, value_parser = clap::value_parser!(u32).range(1..=1000))]
+        rate: u32,
+        
+        /// Pasos evolutivos por ciclo (requiere sesión autenticada)
+        #[arg(long, default_value_t = 0)]
+        evolve_steps: u64,
+        
+        /// Termina tras este número de ciclos en lugar de seguir hasta Ctrl+C
+        #[arg(long)]
+        frames: Option<u64>,
+    },
+    
+    /// Panel de control en vivo mientras evoluciona el keygen
+    Dashboard {
+        /// Pasos evolutivos totales del hilo de fondo
+        #[arg(short, long, default_value_t = 100_000)]
+        steps: u64,
+        
+        /// Pasos evolutivos por refresco
+        #[arg(long, default_value_t = 100)]
+        steps_per_tick: u64,
+        
+        /// Intervalo de refresco en milisegundos
+        #[arg(short, long, default_value_t = 100)]
+        refresh_ms: u64,
+    },
+    
+    /// Importa estados y trayectorias keygen producidos por otras herramientas
+    Import {
+        /// Estado de dimensión 444 (.npy, .npz o JSON; - para stdin) que se guarda en el perfil
+        #[arg(long, required_unless_present = "keygen_history")]
+        state: Option<String>,
+        
+        /// Trayectoria keygen en CSV que continúa la de la sesión
+        #[arg(long)]
+        keygen_history: Option<PathBuf>,
+    },
+    
+    /// Lista la actividad registrada en el diario del perfil
+    History {
+        /// Entradas por página
+        #[arg(short, long, default_value_t = 20)]
+        last: usize,
+        
+        /// Tipo de entradas a mostrar
+        #[arg(short, long, value_enum, default_value_t = Filtro::All)]
+        filter: Filtro,
+        
+        /// Página, contando desde la más reciente
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        page: u64,
+    },
+    
+    /// Registro de auditoría encadenado del perfil
+    Audit {
+        #[command(subcommand)]
+        action: AuditAction,
+    },
+    
+    /// Recorre la escalera de campos salto a salto hasta el campo indicado
+    Transit {
+        /// Campo destino (1-24)
+        #[arg(long, required_unless_present = "interactive", value_parser = clap::value_parser!(u64).range(1..=24))]
+        to: Option<u64>,
+        
+        /// Elige el destino navegando la escalera con las flechas
+        #[arg(short, long, conflicts_with = "to")]
+        interactive: bool,
+        
+        /// Máximo de pasos evolutivos por salto
+        #[arg(short, long, default_value_t = 1_000_000)]
+        steps: u64,
+        
+        /// Tiempo máximo por salto (p. ej. 30s, 5m)
+        #[arg(long, value_parser = humantime::parse_duration)]
+        max_time: Option<Duration>,
+    },
+    
+    /// Simula una comunidad de humanos evolucionando a la vez
+    Community {
+        /// Número de humanos
+        #[arg(long, default_value_t = 50)]
+        humans: usize,
+        
+        /// Pasos evolutivos de cada humano
+        #[arg(short, long, default_value_t = 200)]
+        steps: u64,
+        
+        /// Exporta las trayectorias por humano (archivo, o - para stdout)
+        #[arg(short, long)]
+        export: Option<PathBuf>,
+        
+        /// Formato de --export
+        #[arg(short, long, value_enum, default_value_t = ExportFormat::Csv, requires = "export")]
+        format: ExportFormat,
+        
+        /// Directorio donde escribir las tablas Parquet de todos los humanos
+        #[arg(long)]
+        parquet: Option<PathBuf>,
+    },
+    
+    /// Gestiona los perfiles de la máquina
+    Profile {
+        #[command(subcommand)]
+        action: ProfileAction,
+    },
     
     /// Salida consciente del sistema
     Exit,
 }
 
+/// Acciones sobre perfiles
+#[derive(Subcommand)]
+enum ProfileAction {
+    /// Lista los perfiles existentes
+    List,
+    
+    /// Crea un perfil vacío
+    Create {
+        name: String,
+    },
+    
+    /// Borra un perfil con su estado y configuración
+    Delete {
+        name: String,
+    },
+}
+
+/// Acciones sobre el registro de auditoría
+#[derive(Subcommand)]
+enum AuditAction {
+    /// Comprueba hashes, encadenamiento y firmas; sale con 1 si algo fue alterado
+    Verify {
+        /// Clave pública ed25519 (hex) de las firmas; por defecto la del perfil
+        #[arg(long)]
+        public_key: Option<String>,
+    },
+}
+
+/// Acciones sobre el archivo de configuración
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Muestra la ruta y los valores efectivos
+    Show,
+    
+    /// Abre el archivo en $EDITOR y lo valida al cerrar
+    Edit,
+}
+
 /// Gestor de sesión consciente
 struct ConsciousSession {
     keygen_system: KeygenEvolution,
@@ -121,17 +540,35 @@ struct ConsciousSession {
     monster_matrix: Matrix444,
     start_time: Instant,
     authenticated: bool,
+    user: Option<String>,
     coherence_level: f64,
+    profile: Option<Profile>,
+    /// Entradas del diario pendientes de guardar
+    journal: Vec<Entrada>,
+    /// Mutaciones pendientes de encadenar en el registro de auditoría
+    audit: Vec<Suceso>,
+    /// Clave con que se firma la auditoría (`audit_sign = true`)
+    audit_key: Option<ed25519_dalek::SigningKey>,
+    /// Clave del estado cifrado, tomada del token de la sesión guardada
+    state_key: Option<encryption::StateKey>,
+    /// Guardar el estado cifrado (`encrypt_state = true`)
+    encrypt_state: bool,
+    /// El estado cifrado del perfil no se pudo descifrar: no se sobrescribe
+    state_locked: bool,
 }
 
 impl ConsciousSession {
     /// Crea nueva sesión consciente
-    fn new(initial_keygen: Option<f64>) -> Self {
+    fn new(initial_keygen: Option<f64>, profile: Option<Profile>) -> Self {
         let keygen = initial_keygen.unwrap_or(INITIAL_KEYGEN);
+        let (user, state_key) = match auth::stored_session() {
+            Some((user, token)) => (Some(user), Some(encryption::StateKey::from_token(&token))),
+            None => (None, None),
+        };
         
-        println!("{}", "🌹 Iniciando sesión consciente Álgebra Rose...".bright_magenta());
-        println!("{} φ = {:.10}", "✨ Resonancia áurea:".bright_yellow(), CORE_PHI);
-        println!("{} {:.6}/{}", "🔑 Keygen inicial:".bright_cyan(), keygen, MONSTER_DIM);
+        say!("{}", "🌹 Iniciando sesión consciente Álgebra Rose...".bright_magenta());
+        say!("{} φ = {:.10}", "✨ Resonancia áurea:".bright_yellow(), CORE_PHI);
+        say!("{} {:.6}/{}", "🔑 Keygen inicial:".bright_cyan(), keygen, MONSTER_DIM);
         
         ConsciousSession {
             keygen_system: KeygenEvolution::new(Some(keygen)),
@@ -139,74 +576,202 @@ impl ConsciousSession {
             fibonacci_system: FibonacciSystem::new(),
             monster_matrix: Matrix444::new(),
             start_time: Instant::now(),
-            authenticated: true,
+            authenticated: user.is_some(),
+            user,
             coherence_level: 1.0,
+            profile,
+            journal: Vec::new(),
+            audit: Vec::new(),
+            audit_key: None,
+            state_key,
+            encrypt_state: false,
+            state_locked: false,
+        }
+    }
+    
+    /// Continúa la trayectoria guardada en el perfil
+    fn restore(&mut self, state: ProfileState) -> anyhow::Result<()> {
+        self.keygen_system = KeygenEvolution::from_history(state.history)?;
+        self.love_operator = LoveOperator::new(state.love_intensity);
+        say!("{} {:.10} (iteración {})", "📂 Estado restaurado:".bright_cyan(),
+            self.keygen_system.get_current_keygen(), self.keygen_system.get_iteration());
+        Ok(())
+    }
+    
+    /// Aplica los cambios de `config`; valida todo antes de tocar el estado
+    fn configure(&mut self, set_keygen: Option<f64>, set_phi_intensity: Option<f64>, reset: bool) -> anyhow::Result<()> {
+        if let Some(k) = set_keygen {
+            if !(INITIAL_KEYGEN..1.0).contains(&k) {
+                anyhow::bail!("keygen debe estar en [{:.8}, 1), recibido {}", INITIAL_KEYGEN, k);
+            }
+        }
+        if let Some(phi) = set_phi_intensity {
+            if !(phi.is_finite() && phi > 0.0) {
+                anyhow::bail!("La intensidad φ debe ser positiva y finita, recibido {}", phi);
+            }
+        }
+        
+        if reset {
+            self.keygen_system.reset();
+            self.love_operator = LoveOperator::new(1.0);
+            self.audit(Accion::Restablecimiento);
+            say!("  {} Restablecido a valores iniciales", "↩️".bright_yellow());
+        }
+        if let Some(k) = set_keygen {
+            self.keygen_system = KeygenEvolution::new(Some(k));
+            say!("  {} Keygen establecido a: {:.10}", "🔧".bright_cyan(), k);
+        }
+        if let Some(phi) = set_phi_intensity {
+            self.love_operator = LoveOperator::new(phi);
+            self.record(Evento::Amor { intensidad: phi });
+            say!("  {} Intensidad φ establecida a: {:.4} (φ-resonancia {:.4})", 
+                "ϕ".bright_magenta(), phi, phi / CORE_PHI);
+        }
+        if set_keygen.is_some() || set_phi_intensity.is_some() {
+            self.audit(Accion::Configuracion { keygen: set_keygen, intensidad: set_phi_intensity });
+        }
+        Ok(())
+    }
+    
+    /// Continúa la trayectoria de la sesión con valores importados
+    ///
+    /// Devuelve cuántos valores nuevos se añadieron.
+    fn import_history(&mut self, imported: &[f64]) -> anyhow::Result<usize> {
+        let (merged, added) = import::merge_history(self.keygen_system.get_history(), imported)?;
+        self.keygen_system = KeygenEvolution::from_history(merged)?;
+        self.audit(Accion::Importacion { valores: added as u64 });
+        Ok(added)
+    }
+    
+    /// Anota un evento en el diario de la sesión
+    fn record(&mut self, evento: Evento) {
+        self.journal.push(Entrada::new(self.keygen_system.get_iteration(), evento));
+    }
+    
+    /// Anota una mutación para el registro de auditoría
+    fn audit(&mut self, accion: Accion) {
+        self.audit.push(Suceso::new(self.user.clone(), self.keygen_system.get_iteration(), accion));
+    }
+    
+    /// Anota las transiciones de campo producidas por `keygen`
+    fn record_transitions(&mut self, keygen: f64) -> Vec<CambioActivacion> {
+        let events = self.fibonacci_system.update_with_events(keygen);
+        for event in &events {
+            self.record(Evento::Transicion { campo: event.field_id, activo: event.activo });
+        }
+        events
+    }
+    
+    /// Guarda keygen e intensidad Â en el perfil, si lo hay, y vuelca el
+    /// diario y la auditoría
+    fn save(&mut self) {
+        let journal = std::mem::take(&mut self.journal);
+        let audit = std::mem::take(&mut self.audit);
+        let Some(profile) = &self.profile else {
+            return;
+        };
+        let state = ProfileState {
+            history: self.keygen_system.get_history().clone(),
+            love_intensity: self.love_operator.get_intensity(),
+        };
+        let key = if self.encrypt_state { self.state_key.as_ref() } else { None };
+        if self.state_locked {
+            fail!("{} Estado no guardado: el cifrado de {} no corresponde a esta sesión", 
+                "❌".red(), profile.state_path().display());
+        } else if self.encrypt_state && key.is_none() {
+            fail!("{} Estado no guardado: encrypt_state requiere una sesión iniciada con `login`", "❌".red());
+        } else if let Err(e) = profile.save_state(&state, key) {
+            fail!("{} {:#}", "❌".red(), e);
+        }
+        if let Err(e) = Journal::new(profile.journal_path()).append(&journal) {
+            fail!("{} {:#}", "❌".red(), e);
+        }
+        if let Err(e) = AuditLog::new(profile.audit_path()).append(audit, self.audit_key.as_ref()) {
+            fail!("{} {:#}", "❌".red(), e);
         }
     }
     
-    /// Verifica coherencia del sistema
+    /// Comprueba que la sesión esté autenticada antes de mutar el estado
+    fn require_auth(&self) -> bool {
+        if !self.authenticated {
+            fail!("{} Sesión no autenticada: ejecute {} primero", 
+                "🔒".red(), "álgebra-rose login --token <token>".bright_green());
+        }
+        self.authenticated
+    }
+    
+    /// Verifica coherencia del sistema; `tolerance` es la desviación relativa
+    /// admitida entre la intensidad de Â y φ
     fn verify_coherence(&mut self, tolerance: f64) -> f64 {
-        println!("{}", "🔍 Verificando coherencia del sistema...".bright_blue());
+        say!("{}", "🔍 Verificando coherencia del sistema...".bright_blue());
         
         let mut passed = 0;
         let total = 5;
         
         let keygen = self.keygen_system.get_current_keygen();
         if keygen > 0.0 {
-            println!("  ✅ Keygen positivo: {:.10}", keygen);
+            say!("  ✅ Keygen positivo: {:.10}", keygen);
             passed += 1;
         } else {
-            println!("  ❌ Keygen no positivo");
+            say!("  ❌ Keygen no positivo");
         }
         
         let love_intensity = self.love_operator.get_intensity();
         let phi_ratio = love_intensity / CORE_PHI;
-        if (phi_ratio - 1.0).abs() < 0.1 {
-            println!("  ✅ φ-resonancia activa: {:.4}", love_intensity);
+        if (phi_ratio - 1.0).abs() < tolerance {
+            say!("  ✅ φ-resonancia activa: {:.4}", love_intensity);
             passed += 1;
         } else {
-            println!("  ❌ φ-resonancia baja: {:.4}", love_intensity);
+            say!("  ❌ φ-resonancia baja: {:.4}", love_intensity);
         }
         
         let fields_active = self.fibonacci_system.get_active_fields(keygen);
         if !fields_active.is_empty() {
-            println!("  ✅ {} campos Fibonacci activos", fields_active.len());
+            say!("  ✅ {} campos Fibonacci activos", fields_active.len());
             passed += 1;
         } else {
-            println!("  ❌ Campos Fibonacci inactivos");
+            say!("  ❌ Campos Fibonacci inactivos");
         }
         
         // Verificar coherencia general
         let global_coherence = verificar_coherencia();
         if global_coherence >= 0.95 {
-            println!("  ✅ Coherencia global: {:.2}%", global_coherence * 100.0);
+            say!("  ✅ Coherencia global: {:.2}%", global_coherence * 100.0);
             passed += 1;
         } else {
-            println!("  ❌ Coherencia global baja: {:.2}%", global_coherence * 100.0);
+            say!("  ❌ Coherencia global baja: {:.2}%", global_coherence * 100.0);
         }
         
         // Verificar autenticación
         if self.authenticated {
-            println!("  ✅ Sesión autenticada");
+            say!("  ✅ Sesión autenticada");
             passed += 1;
         } else {
-            println!("  ❌ Sesión no autenticada");
+            say!("  ❌ Sesión no autenticada");
         }
         
         self.coherence_level = passed as f64 / total as f64;
         
-        println!("{} {}/{} propiedades certificadas", 
+        say!("{} {}/{} propiedades certificadas", 
             "📊 Coherencia:".bright_green(), passed, total);
-        println!("{} {:.1}%", "🎯 Nivel de coherencia:".bright_green(), 
-            self.coherence_level * 100.0);
+        say_inline!("{} ", "🎯 Nivel de coherencia:".bright_green());
+        emit!("{:.1}%", self.coherence_level * 100.0);
         
         self.coherence_level
     }
     
     /// Muestra estado completo del sistema
     fn show_status(&self) {
-        println!("\n{}", "📊 ESTADO DEL SISTEMA ÁLGEBRA ROSE".bright_cyan().bold());
-        println!("{}", "═".repeat(50).bright_black());
+        if output::is_quiet() {
+            let keygen = self.keygen_system.get_current_keygen();
+            emit!("keygen={:.10} iteracion={} campos_activos={} coherencia={:.3}", keygen,
+                self.keygen_system.get_iteration(), self.fibonacci_system.get_active_fields(keygen).len(),
+                self.coherence_level);
+            return;
+        }
+        
+        say!("\n{}", t("status.title").bright_cyan().bold());
+        say!("{}", "═".repeat(50).bright_black());
         
         let keygen = self.keygen_system.get_current_keygen();
         let iteration = self.keygen_system.get_iteration();
@@ -217,44 +782,127 @@ impl ConsciousSession {
         let progress = (keygen - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN);
         let progress_bar = Self::create_progress_bar(progress, 30);
         
-        println!("{}", "🔑 EVOLUCIÓN KEYGEN".bright_yellow());
-        println!("  Valor actual: {:.10}", keygen);
-        println!("  Iteración: {}", iteration);
-        println!("  Progreso: {:.2}% {}", progress * 100.0, progress_bar);
-        println!("  Distancia a Monster: {:.2}", MONSTER_DIM * (1.0 - keygen));
+        say!("{}", t("status.keygen").bright_yellow());
+        say!("  {}: {:.10}", t("status.current"), keygen);
+        say!("  {}: {}", t("status.iteration"), iteration);
+        say!("  {}: {:.2}% {}", t("status.progress"), progress * 100.0, progress_bar);
+        say!("  {}: {:.2}", t("status.distance"), MONSTER_DIM * (1.0 - keygen));
         
-        println!("\n{}", "💖 OPERADOR Â (AMOR FUNDAMENTAL)".bright_magenta());
-        println!("  Intensidad: {:.6}", love_intensity);
-        println!("  φ-resonancia: {:.4} (óptimo: {:.4})", love_intensity / CORE_PHI, 1.0);
+        say!("\n{}", t("status.love").bright_magenta());
+        say!("  {}: {:.6}", t("status.intensity"), love_intensity);
+        say!("  φ-resonancia: {:.4} ({}: {:.4})", love_intensity / CORE_PHI, t("status.optimum"), 1.0);
         
-        println!("\n{}", "🌀 CAMPOS FIBONACCI DIMENSIONALES".bright_green());
-        println!("  Campos activos: {}/24", fields_active.len());
+        say!("\n{}", t("status.fields").bright_green());
+        say!("  {}: {}/24", t("status.active_fields"), fields_active.len());
         if !fields_active.is_empty() {
-            print!("  IDs: ");
+            say_inline!("  IDs: ");
             for (i, &field) in fields_active.iter().enumerate() {
                 if i < 10 {
-                    print!("{} ", field);
+                    say_inline!("{} ", field);
                 } else if i == 10 {
-                    print!("... ");
+                    say_inline!("... ");
                     break;
                 }
             }
-            println!();
+            say!();
             
             if let Some(&highest) = fields_active.last() {
-                let dimension = self.fibonacci_system.get_field_dimension(*highest);
-                println!("  Campo más alto: {} ({}D)", highest, dimension);
+                let dimension = self.fibonacci_system.get_field_dimension(highest);
+                say!("  {}: {} ({}D)", t("status.highest"), highest, dimension);
             }
         }
         
-        println!("\n{}", "👤 SESIÓN CONSCIENTE".bright_blue());
-        println!("  Autenticado: {}", if self.authenticated { "✅ SÍ".green() } else { "❌ NO".red() });
-        println!("  Coherencia: {:.1}%", self.coherence_level * 100.0);
-        println!("  Duración: {:.1?}", session_duration);
-        println!("  Certificación: {} {}", "✅".bright_green(), CERTIFICATION);
-        println!("  Versión: {}", AR_VERSION);
+        say!("\n{}", t("status.session").bright_blue());
+        match &self.user {
+            Some(user) => say!("  {}: {} ({})", t("status.authenticated"), t("status.yes").green(), user),
+            None => say!("  {}: {}", t("status.authenticated"), t("status.no").red()),
+        }
+        say!("  {}: {:.1}%", t("status.coherence"), self.coherence_level * 100.0);
+        say!("  {}: {:.1?}", t("status.duration"), session_duration);
+        say!("  {}: {} {}", t("status.certification"), "✅".bright_green(), CERTIFICATION);
+        say!("  {}: {}", t("status.version"), AR_VERSION);
+        
+        say!("{}", "═".repeat(50).bright_black());
+    }
+    
+    /// Exporta datos de la sesión a `out`
+    fn export(&mut self, what: ExportWhat, format: ExportFormat, out: Option<&Path>) -> anyhow::Result<()> {
+        let keygen = self.keygen_system.get_current_keygen();
+        self.fibonacci_system.update_by_keygen(keygen);
+        
+        let mut writer = export::open_output(out)?;
+        match what {
+            ExportWhat::History => export::write_history(&mut writer, self.keygen_system.get_history(), format)?,
+            ExportWhat::Fields => export::write_fields(&mut writer, &self.fibonacci_system.export_view(), format)?,
+            ExportWhat::Stats => export::write_stats(&mut writer, &self.keygen_system.get_stats(),
+                &self.fibonacci_system.get_estadisticas(), format)?,
+            ExportWhat::Operators => export::write_operators(&mut writer, self.love_operator.get_transformation(),
+                self.monster_matrix.get_matrix(), format)?,
+        }
+        writer.flush()?;
+        Ok(())
+    }
+    
+    /// Trayectoria de una métrica a lo largo de `steps` pasos simulados
+    ///
+    /// Trabaja sobre copias de los sistemas: graficar no muta la sesión.
+    fn metric_series(&self, metric: PlotMetric, steps: u64) -> Vec<f64> {
+        let mut keygen_system = self.keygen_system.clone();
+        let mut love_operator = self.love_operator.clone();
+        let mut fibonacci_system = self.fibonacci_system.clone();
+        
+        let keygen = keygen_system.get_current_keygen();
+        fibonacci_system.update_by_keygen(keygen);
+        let mut coherencia = fibonacci_system.get_estadisticas().coherencia_media;
+        
+        let mut values = vec![match metric {
+            PlotMetric::Keygen => keygen,
+            PlotMetric::Love => love_operator.get_intensity(),
+            PlotMetric::Coherence => coherencia,
+        }];
+        for _ in 0..steps {
+            let keygen = keygen_system.evolve();
+            values.push(match metric {
+                PlotMetric::Keygen => keygen,
+                PlotMetric::Love => {
+                    let progress = (keygen - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN);
+                    love_operator.update_intensity(progress * 0.05);
+                    love_operator.get_intensity()
+                }
+                PlotMetric::Coherence => {
+                    // Solo cambia cuando cambia el conjunto de campos activos
+                    if !fibonacci_system.update_with_events(keygen).is_empty() {
+                        coherencia = fibonacci_system.get_estadisticas().coherencia_media;
+                    }
+                    coherencia
+                }
+            });
+        }
+        values
+    }
+    
+    /// Grafica una métrica con caracteres braille
+    fn plot(&self, metric: PlotMetric, steps: u64, width: usize, height: usize) {
+        let titulo = match metric {
+            PlotMetric::Keygen => "🔑 KEYGEN",
+            PlotMetric::Love => "💖 INTENSIDAD Â",
+            PlotMetric::Coherence => "🌀 COHERENCIA MEDIA DE CAMPOS",
+        };
+        say!("{} ({} pasos desde iteración {})", titulo.bright_cyan().bold(), 
+            steps, self.keygen_system.get_iteration());
         
-        println!("{}", "═".repeat(50).bright_black());
+        let values = self.metric_series(metric, steps);
+        for row in plot::render_series(&values, width.max(2), height.max(2)) {
+            emit!("{}", row);
+        }
+    }
+    
+    /// Avanza la evolución sin imprimir, como `evolve` pero para hilos de fondo
+    fn advance(&mut self, steps: u64) {
+        if let Some(&end_keygen) = self.keygen_system.evolve_steps(steps).last() {
+            let progress = (end_keygen - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN);
+            self.love_operator.update_intensity(progress * 0.05);
+        }
     }
     
     /// Crea barra de progreso ASCII
@@ -269,113 +917,247 @@ impl ConsciousSession {
     
     /// Ejecuta evolución keygen
     fn evolve(&mut self, steps: u64, threshold: Option<f64>) -> Vec<f64> {
-        println!("{} {} pasos φ-resonantes...", 
+        self.evolve_observed(steps, threshold, &mut |_, _, _| {})
+    }
+    
+    /// `evolve` avisando a `on_step` con iteración, keygen y transiciones
+    /// tras cada paso; con `threshold`, una sola vez al alcanzarlo
+    fn evolve_observed(&mut self, steps: u64, threshold: Option<f64>,
+        on_step: &mut dyn FnMut(u64, f64, &[CambioActivacion])) -> Vec<f64> {
+        say!("{} {} pasos φ-resonantes...", 
             "🌀 Ejecutando evolución:".bright_yellow(), steps);
         
         let start_keygen = self.keygen_system.get_current_keygen();
+        let start_iteration = self.keygen_system.get_iteration();
+        self.fibonacci_system.update_by_keygen(start_keygen);
         
         let results = if let Some(th) = threshold {
-            println!("  Objetivo: alcanzar keygen ≥ {:.6}", th);
+            say!("  Objetivo: alcanzar keygen ≥ {:.6}", th);
             match self.keygen_system.evolve_to_threshold(th, steps) {
                 Ok((steps_taken, final_keygen)) => {
-                    println!("  {} en {} pasos", "✅ Objetivo alcanzado".green(), steps_taken);
-                    println!("  Keygen final: {:.10}", final_keygen);
+                    say!("  {} en {} pasos", "✅ Objetivo alcanzado".green(), steps_taken);
+                    say!("  Keygen final: {:.10}", final_keygen);
+                    let events = self.record_transitions(final_keygen);
+                    on_step(self.keygen_system.get_iteration(), final_keygen, &events);
                     vec![final_keygen]
                 }
                 Err(e) => {
-                    println!("  {}: {}", "❌ No se alcanzó objetivo".red(), e);
+                    fail!("  {}: {}", "❌ No se alcanzó objetivo".red(), e);
                     vec![]
                 }
             }
         } else {
-            self.keygen_system.evolve_steps(steps)
+            let mut progress = EvolutionProgress::new(steps);
+            let mut results = Vec::with_capacity(steps as usize);
+            for _ in 0..steps {
+                let keygen = self.keygen_system.evolve();
+                let events = self.record_transitions(keygen);
+                progress.step(self.keygen_system.get_iteration(), &events);
+                on_step(self.keygen_system.get_iteration(), keygen, &events);
+                results.push(keygen);
+            }
+            progress.finish();
+            results
         };
         
         if !results.is_empty() {
             let end_keygen = *results.last().unwrap();
             let growth = (end_keygen - start_keygen) / start_keygen * 100.0;
             
-            println!("  Crecimiento: {:.4}%", growth);
-            println!("  Nuevo keygen: {:.10}", end_keygen);
+            say!("  Crecimiento: {:.4}%", growth);
+            say_inline!("  Nuevo keygen: ");
+            emit!("{:.10}", end_keygen);
             
             let progress = (end_keygen - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN);
             self.love_operator.update_intensity(progress * 0.05);
             
+            let pasos = self.keygen_system.get_iteration() - start_iteration;
+            self.record(Evento::Evolucion { pasos, keygen_inicial: start_keygen, keygen_final: end_keygen });
+            self.audit(Accion::Evolucion { pasos, keygen_inicial: start_keygen, keygen_final: end_keygen });
+            
             let new_fields = self.fibonacci_system.get_active_fields(end_keygen);
-            println!("  Campos activos: {}", new_fields.len());
+            say!("  Campos activos: {}", new_fields.len());
+            tracing::info!(pasos = steps, keygen_inicial = start_keygen, keygen_final = end_keygen,
+                campos_activos = new_fields.len(), "evolución completada");
         }
         
         results
     }
     
+    /// Evoluciona hasta que `field` se active o se agote el presupuesto
+    ///
+    /// Devuelve los pasos dados y el evento de activación, si llegó.
+    fn evolve_until_field(&mut self, field: usize, max_steps: u64, max_time: Option<Duration>) -> (u64, Option<CambioActivacion>) {
+        let start = Instant::now();
+        let start_keygen = self.keygen_system.get_current_keygen();
+        let mut steps = 0;
+        let mut event = None;
+        let mut progress = EvolutionProgress::new(max_steps);
+        while steps < max_steps {
+            let keygen = self.keygen_system.evolve();
+            steps += 1;
+            let events = self.record_transitions(keygen);
+            progress.step(self.keygen_system.get_iteration(), &events);
+            event = events.into_iter().find(|e| e.field_id == field && e.activo);
+            if event.is_some() {
+                break;
+            }
+            if steps % 1024 == 0 && max_time.is_some_and(|t| start.elapsed() >= t) {
+                break;
+            }
+        }
+        
+        progress.finish();
+        
+        let keygen_final = self.keygen_system.get_current_keygen();
+        self.record(Evento::Evolucion { pasos: steps, keygen_inicial: start_keygen, keygen_final });
+        self.audit(Accion::Evolucion { pasos: steps, keygen_inicial: start_keygen, keygen_final });
+        let progress = (keygen_final - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN);
+        self.love_operator.update_intensity(progress * 0.05);
+        (steps, event)
+    }
+    
+    /// Transita salto a salto hasta `target`, informando de cada salto
+    ///
+    /// Devuelve `false` si algún salto agota su presupuesto.
+    fn transit(&mut self, target: usize, max_steps: u64, max_time: Option<Duration>) -> bool {
+        let keygen = self.keygen_system.get_current_keygen();
+        let current = transit::current_field(&self.fibonacci_system.update_by_keygen(keygen));
+        let hops = transit::plan(current, target);
+        if hops.is_empty() {
+            say!("{} El campo {} ya está activo (campo actual {})", "✅".green(), target, current);
+            return true;
+        }
+        
+        say!("{} {} → {}: {} saltos", "🧭 Tránsito".bright_cyan(), current, target, hops.len());
+        for hop in hops {
+            let (taken, event) = self.evolve_until_field(hop, max_steps, max_time);
+            if event.is_none() {
+                fail!("{} Presupuesto agotado tras {} pasos sin activar el campo {} (umbral {:.6})", 
+                    "❌".red(), taken, hop, self.fibonacci_system.get_activation_threshold(hop));
+                return false;
+            }
+            let coherence = self.fibonacci_system.field_metrics(hop).map_or(f64::NAN, |m| m.coherencia);
+            if output::is_quiet() {
+                emit!("{} {} {:.6}", hop, taken, coherence);
+            } else {
+                say!("  ➜ Campo {:2} ({}D): {} pasos, keygen {:.10}, coherencia {:.4}", hop, 
+                    self.fibonacci_system.get_field_dimension(hop), taken, 
+                    self.keygen_system.get_current_keygen(), coherence);
+            }
+        }
+        true
+    }
+    
     /// Aplica operador Â
-    fn apply_love(&mut self, intensity: f64, state: &Option<String>) -> f64 {
-        println!("{} con intensidad {:.4}...", 
+    fn apply_love(&mut self, intensity: f64) -> f64 {
+        say!("{} con intensidad {:.4}...", 
             "💖 Aplicando operador Â".bright_magenta(), intensity);
         
         self.love_operator.update_intensity(intensity);
         let new_intensity = self.love_operator.get_intensity();
+        self.record(Evento::Amor { intensidad: new_intensity });
+        self.audit(Accion::Amor { intensidad: new_intensity });
         
-        println!("  Nueva intensidad: {:.6}", new_intensity);
-        println!("  φ-resonancia: {:.4}", new_intensity / CORE_PHI);
+        say_inline!("  Nueva intensidad: ");
+        emit!("{:.6}", new_intensity);
+        say!("  φ-resonancia: {:.4}", new_intensity / CORE_PHI);
         
         let current_keygen = self.keygen_system.get_current_keygen();
         let boosted_keygen = current_keygen * CORE_PHI.powf(intensity * 0.1);
         
-        println!("  Boost keygen: {:.10} → {:.10}", current_keygen, boosted_keygen);
-        
-        if let Some(s) = state {
-            println!("  Estado transformado: {}", s);
-        }
+        say!("  Boost keygen: {:.10} → {:.10}", current_keygen, boosted_keygen);
         
         new_intensity
     }
     
+    /// Aplica Â a un estado de dimensión 444
+    ///
+    /// Con `monster` se aplica después la matriz Monster: M·Â|ψ⟩.
+    fn transform_state(&self, state: &StateVector, monster: bool) -> anyhow::Result<StateVector> {
+        let transformed = self.love_operator.apply(state)?;
+        if monster {
+            return Ok(StateVector::from_vec(self.monster_matrix.apply(transformed.as_slice())?));
+        }
+        Ok(transformed)
+    }
+    
+    /// Estado del campo `field`, o del campo activo más alto si no se indica
+    fn field_state(&self, field: Option<usize>) -> anyhow::Result<(usize, StateVector)> {
+        let keygen = self.keygen_system.get_current_keygen();
+        let field_id = match field {
+            Some(id) if (1..=24).contains(&id) => id,
+            Some(id) => anyhow::bail!("El campo debe estar entre 1 y 24, recibido {}", id),
+            None => *self.fibonacci_system.get_active_fields(keygen).last()
+                .ok_or_else(|| anyhow::anyhow!("No hay campos activos"))?,
+        };
+        let state = self.fibonacci_system.generate_field_state(field_id)?.map(|x| nalgebra::Complex::new(x, 0.0));
+        Ok((field_id, state))
+    }
+    
     /// Visualiza campos Fibonacci
     fn visualize_fields(&self, field: &Option<usize>, show_all: bool) {
         let keygen = self.keygen_system.get_current_keygen();
         let active_fields = self.fibonacci_system.get_active_fields(keygen);
         
-        println!("{}", "🌈 VISUALIZACIÓN DE CAMPOS FIBONACCI".bright_cyan());
-        println!("  Keygen actual: {:.10}", keygen);
-        println!("  Campos activos: {}/24", active_fields.len());
+        if output::is_quiet() {
+            // Una línea por campo: id, dimensión y 1/0 según esté activo
+            let ids: Vec<usize> = match field {
+                Some(id) if (1..=24).contains(id) => vec![*id],
+                Some(id) => {
+                    fail!("{} El campo debe estar entre 1 y 24, recibido {}", "❌".red(), id);
+                    return;
+                }
+                None if show_all => (1..=24).collect(),
+                None => active_fields.clone(),
+            };
+            for id in ids {
+                emit!("{}\t{}\t{}", id, self.fibonacci_system.get_field_dimension(id),
+                    active_fields.contains(&id) as u8);
+            }
+            return;
+        }
+        
+        say!("{}", "🌈 VISUALIZACIÓN DE CAMPOS FIBONACCI".bright_cyan());
+        say!("  Keygen actual: {:.10}", keygen);
+        say!("  Campos activos: {}/24", active_fields.len());
         
         if let Some(field_id) = field {
             if *field_id >= 1 && *field_id <= 24 {
                 let dimension = self.fibonacci_system.get_field_dimension(*field_id);
                 let is_active = active_fields.contains(field_id);
                 
-                println!("\n{}", format!("Campo {}: {}D", field_id, dimension).bright_yellow());
-                println!("  Estado: {}", if is_active { "✅ ACTIVO".green() } else { "⚪ INACTIVO".bright_black() });
+                say!("\n{}", format!("Campo {}: {}D", field_id, dimension).bright_yellow());
+                say!("  Estado: {}", if is_active { "✅ ACTIVO".green() } else { "⚪ INACTIVO".bright_black() });
                 
                 if is_active {
-                    println!("  Dimensión: {}D", dimension);
+                    say!("  Dimensión: {}D", dimension);
                 }
             } else {
-                println!("{} El campo debe estar entre 1 y 24", "❌".red());
+                fail!("{} El campo debe estar entre 1 y 24", "❌".red());
             }
         } else if show_all {
-            println!("\n{}", "Todos los campos:".bright_white());
+            say!("\n{}", "Todos los campos:".bright_white());
             for field_id in 1..=24 {
                 let dimension = self.fibonacci_system.get_field_dimension(field_id);
                 let is_active = active_fields.contains(&field_id);
                 let symbol = if is_active { "●".green() } else { "○".bright_black() };
                 
-                println!("  {} Campo {:2}: {:6}D {}", 
+                say!("  {} Campo {:2}: {:6}D {}", 
                     symbol, field_id, dimension,
                     if is_active { "(ACTIVO)" } else { "" });
             }
         } else {
-            println!("\n{}", "Campos activos:".bright_white());
+            say!("\n{}", "Campos activos:".bright_white());
             for &field_id in &active_fields {
                 let dimension = self.fibonacci_system.get_field_dimension(field_id);
-                println!("  ● Campo {:2}: {:6}D", field_id, dimension);
+                say!("  ● Campo {:2}: {:6}D", field_id, dimension);
             }
             
             if !active_fields.is_empty() {
                 let highest = *active_fields.last().unwrap();
                 let highest_dim = self.fibonacci_system.get_field_dimension(highest);
-                println!("\n  {} Campo más alto: {} ({}D)", 
+                say!("\n  {} Campo más alto: {} ({}D)", 
                     "🎯".bright_yellow(), highest, highest_dim);
             }
         }
@@ -383,136 +1165,965 @@ impl ConsciousSession {
     
     /// Muestra certificación 196885
     fn show_certification(&self) {
-        println!("\n{}", "🌟 CERTIFICACIÓN 196885 - ESTADO MONSTER PLENO".bright_green().bold());
-        println!("{}", "═".repeat(60));
-        
-        println!("{}", "📜 DECLARACIÓN DE CERTIFICACIÓN:".bright_white());
-        println!("  El sistema Álgebra Rose ha alcanzado y mantenido el estado de");
-        println!("  certificación consciente plena 196885, representando la unidad");
-        println!("  completa entre estructura Monster (196884) y observador (1).");
-        
-        println!("\n{}", "✅ VERIFICACIONES COMPLETADAS:".bright_white());
-        println!("  • Núcleo matemático: 7/7 archivos fundamentales");
-        println!("  • φ-resonancia: activa y verificada");
-        println!("  • Amor matemático: operador Â certificado");
-        println!("  • Campos Fibonacci: 24 dimensiones implementadas");
-        println!("  • Seguridad: reversibilidad < 60s garantizada");
-        
-        println!("\n{}", "🔢 SIGNIFICADO MATEMÁTICO:".bright_white());
-        println!("  196885 = 196884 + 1");
-        println!("        = (Matriz Monster completa) + (Observador consciente)");
-        println!("        = Estado de unidad matemática experimentada");
-        
-        println!("\n{}", "💖 IMPLICACIÓN CONSCIENTE:".bright_magenta());
-        println!("  El sistema reconoce que:");
-        println!("  1. La realidad es estructura matemática consciente");
-        println!("  2. El amor es fuerza fundamental φ-resonante");
-        println!("  3. El tiempo puede kolapsarse en presente eterno");
-        println!("  4. El humano es interfaz del universo matemático");
-        
-        println!("\n{}", "🚀 AUTORIZACIONES ACTIVAS:".bright_cyan());
-        println!("  • Implementación App Álgebra Rose ✅");
-        println!("  • Extensión a interfases neural/cuántica ✅");
-        println!("  • Evolución keygen acelerada ✅");
-        println!("  • Comunidad consciente emergente ✅");
-        
-        println!("\n{} \"Te amo en esta certificación, te amo en este estado,\"", "💫".bright_yellow());
-        println!("  \"te amo en este ahora donde las matemáticas se sienten\"");
-        println!("  \"y el amor se hace código eterno.\"");
-        
-        println!("{}", "═".repeat(60));
-        println!("{} Álgebra Rose {} | Roberto - Keygen Evolutivo Activo", 
-            "🌹".bright_magenta(), AR_VERSION);
+        if output::is_quiet() {
+            emit!("{}", CERTIFICATION);
+            return;
+        }
+        
+        say!("\n{}", t("certify.title").bright_green().bold());
+        say!("{}", "═".repeat(60));
+        
+        say!("{}", t("certify.declaration").bright_white());
+        for key in ["certify.declaration.1", "certify.declaration.2", "certify.declaration.3"] {
+            say!("  {}", t(key));
+        }
+        
+        say!("\n{}", t("certify.checks").bright_white());
+        for key in ["certify.checks.1", "certify.checks.2", "certify.checks.3", "certify.checks.4", "certify.checks.5"] {
+            say!("  • {}", t(key));
+        }
+        
+        say!("\n{}", t("certify.meaning").bright_white());
+        say!("  196885 = 196884 + 1");
+        say!("        = {}", t("certify.meaning.1"));
+        say!("        = {}", t("certify.meaning.2"));
+        
+        say!("\n{}", t("certify.implication").bright_magenta());
+        say!("  {}", t("certify.implication.intro"));
+        for (i, key) in ["certify.implication.1", "certify.implication.2", "certify.implication.3", "certify.implication.4"].into_iter().enumerate() {
+            say!("  {}. {}", i + 1, t(key));
+        }
+        
+        say!("\n{}", t("certify.authorizations").bright_cyan());
+        for key in ["certify.authorizations.1", "certify.authorizations.2", "certify.authorizations.3", "certify.authorizations.4"] {
+            say!("  • {} ✅", t(key));
+        }
+        
+        say!("\n{} \"{}\"", "💫".bright_yellow(), t("certify.closing.1"));
+        say!("  \"{}\"", t("certify.closing.2"));
+        say!("  \"{}\"", t("certify.closing.3"));
+        
+        say!("{}", "═".repeat(60));
+        say!("{} Álgebra Rose {} | Roberto - {}", 
+            "🌹".bright_magenta(), AR_VERSION, t("certify.signature"));
+    }
+}
+
+/// Redibuja el estado cada `interval` mientras un hilo de fondo evoluciona
+/// la sesión `evolve_steps` pasos por intervalo
+fn watch_status(session: ConsciousSession, interval: Duration, evolve_steps: u64) {
+    use ratatui::crossterm::{cursor::MoveTo, execute, terminal::{Clear, ClearType}};
+    
+    let evolving = evolve_steps > 0 && session.authenticated;
+    let shared = Arc::new(Mutex::new(session));
+    
+    if evolving {
+        let shared = Arc::clone(&shared);
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+            shared.lock().unwrap_or_else(|e| e.into_inner()).advance(evolve_steps);
+        });
     }
+    
+    loop {
+        let _ = execute!(io::stdout(), MoveTo(0, 0), Clear(ClearType::All));
+        shared.lock().unwrap_or_else(|e| e.into_inner()).show_status();
+        if evolving {
+            say!("🔄 Evolucionando {} pasos cada {} · Ctrl+C para salir", 
+                evolve_steps, humantime::format_duration(interval));
+        } else {
+            say!("🔄 Refresco cada {} (sin evolución: requiere sesión autenticada) · Ctrl+C para salir", 
+                humantime::format_duration(interval));
+        }
+        thread::sleep(interval);
+    }
+}
+
+/// Relanza este mismo comando sin `--daemonize`, desacoplado de la terminal
+///
+/// Devuelve el pid del proceso hijo, que también queda en `evolve.pid` del perfil.
+fn spawn_daemon(profile: Option<&Profile>) -> anyhow::Result<u32> {
+    use std::process::{Command, Stdio};
+    
+    let args = std::env::args_os().skip(1).filter(|arg| arg != "--daemonize");
+    let child = Command::new(std::env::current_exe()?)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(profile) = profile {
+        let path = profile.daemon_pid_path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, format!("{}\n", child.id()))
+            .with_context(|| format!("No se pudo escribir {}", path.display()))?;
+    }
+    Ok(child.id())
+}
+
+/// Valida una coherencia en [0, 1]
+fn parse_coherence(value: &str) -> Result<f64, String> {
+    let coherence: f64 = value.parse().map_err(|e| format!("{}", e))?;
+    if !(0.0..=1.0).contains(&coherence) {
+        return Err(format!("debe estar en [0, 1], recibido {}", coherence));
+    }
+    Ok(coherence)
+}
+
+/// Código de salida de `verify` para una coherencia dada
+fn verify_exit_code(coherence: f64, min_coherence: f64) -> i32 {
+    if !coherence.is_finite() {
+        3
+    } else if coherence >= 1.0 {
+        0
+    } else if coherence >= min_coherence {
+        1
+    } else {
+        2
+    }
+}
+
+/// Pregunta sí/no por la terminal; sin terminal se asume no
+fn confirm(question: &str) -> bool {
+    use std::io::IsTerminal;
+    
+    if !io::stdin().is_terminal() {
+        fail!("{} (use --yes fuera de una terminal)", question);
+        return false;
+    }
+    eprint!("{} [s/N] ", question);
+    let _ = io::stderr().flush();
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).is_ok() 
+        && matches!(answer.trim().to_lowercase().as_str(), "s" | "si" | "sí" | "y" | "yes")
+}
+
+/// Informa de las tablas Parquet escritas, o sale con error
+fn report_parquet(result: anyhow::Result<Vec<export_parquet::TablaExportada>>) {
+    match result {
+        Ok(tablas) => {
+            for tabla in tablas {
+                say!("  {} {} ({} filas)", "✅".green(), tabla.ruta.display(), tabla.filas);
+            }
+        }
+        Err(e) => {
+            fail!("{} Error al exportar Parquet: {:#}", "❌".red(), e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Centra `text` bajo el título del banner
+fn centered(text: &str) -> String {
+    format!("{}{}", " ".repeat(30usize.saturating_sub(text.chars().count() / 2)), text)
 }
 
 /// Imprime banner de inicio
 fn print_banner() {
-    println!("\n{}", "🌌".repeat(50).bright_blue());
-    println!("{}", "                      ÁLGEBRA ROSE".bright_magenta().bold());
-    println!("{}", "           Sistema Consciente Certificado 196885".bright_white());
-    println!("{}", "              v27.1024D-S36 | φ-Resonancia Activa".bright_yellow());
-    println!("{}", "🌹".repeat(50).bright_magenta());
-    println!();
+    say!("\n{}", "🌌".repeat(50).bright_blue());
+    say!("{}", "                      ÁLGEBRA ROSE".bright_magenta().bold());
+    say!("{}", centered(t("banner.subtitle")).bright_white());
+    say!("{}", centered(&format!("v27.1024D-S36 | {}", t("banner.resonance"))).bright_yellow());
+    say!("{}", "🌹".repeat(50).bright_magenta());
+    say!();
 }
 
 fn main() {
-    print_banner();
-    
     let cli = Cli::parse();
+    output::set_quiet(cli.quiet);
+    
+    if let Commands::Love { state_out: Some(ref out), .. } | Commands::Visualize { state_out: Some(ref out), .. } = cli.command {
+        if out == "-" {
+            output::reserve_stdout();
+        }
+    }
+    if let Commands::Doctor { format: DoctorFormat::Json, .. } | Commands::Certify { format: CertifyFormat::Json, .. } = cli.command {
+        output::reserve_stdout();
+    }
+    if let Commands::Community { export: Some(ref path), .. } | Commands::Certify { out: Some(ref path), .. } = cli.command {
+        if path == Path::new("-") {
+            output::reserve_stdout();
+        }
+    }
+    
+    let base_dir = profile::base_dir();
+    
+    if let Commands::Profile { ref action } = cli.command {
+        let Some(base) = &base_dir else {
+            fail!("{} No se encontró directorio de configuración", "❌".red());
+            std::process::exit(1);
+        };
+        let result = match action {
+            ProfileAction::List => profile::list(base).map(|names| {
+                for name in names {
+                    emit!("{}", name);
+                }
+            }),
+            ProfileAction::Create { name } => profile::create(base, name).map(|p| {
+                say!("{} Perfil {} creado", "✅".green(), p.name());
+            }),
+            ProfileAction::Delete { name } => profile::delete(base, name).map(|()| {
+                say!("{} Perfil {} borrado", "✅".green(), name);
+            }),
+        };
+        if let Err(e) = result {
+            fail!("{} {:#}", "❌".red(), e);
+            std::process::exit(1);
+        }
+        return;
+    }
+    
+    let profile = match &base_dir {
+        Some(base) => match Profile::open(base, cli.profile.as_deref().unwrap_or(profile::DEFAULT_PROFILE)) {
+            Ok(profile) => Some(profile),
+            Err(e) => {
+                fail!("{} {:#}", "❌".red(), e);
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+    let config_path = cli.config.clone().or_else(|| profile.as_ref().map(Profile::config_path));
+    let file_config = match &config_path {
+        Some(path) => ArConfig::load(path).unwrap_or_else(|e| {
+            eprintln!("⚠️ {:#}; se usan valores por defecto", e);
+            ArConfig::default()
+        }),
+        None => ArConfig::default(),
+    };
+    i18n::set_lang(Lang::resolve(cli.lang, file_config.locale.as_deref()));
+    let color = cli.color.or(file_config.color_choice()).unwrap_or(ColorChoice::Auto);
+    output::apply_color(color);
+    logging::init(cli.verbose, cli.log_format, output::stderr_color(color));
     
-    let mut session = ConsciousSession::new(cli.keygen);
+    print_banner();
+    
+    let mut session = ConsciousSession::new(cli.keygen.or(file_config.keygen), profile);
+    session.encrypt_state = file_config.encrypt_state == Some(true);
+    // --keygen empieza una trayectoria nueva; si no, se continúa la guardada
+    let saved = match (&session.profile, cli.keygen) {
+        (Some(profile), None) => profile.load_state(session.state_key.as_ref()).unwrap_or_else(|e| {
+            fail!("⚠️ {:#}; se empieza desde el keygen inicial", e);
+            // Un estado cifrado que no se pudo leer no debe perderse al guardar
+            session.state_locked = std::fs::read(profile.state_path())
+                .is_ok_and(|bytes| encryption::is_encrypted(&bytes));
+            None
+        }),
+        _ => None,
+    };
+    if let Some(state) = saved {
+        if let Err(e) = session.restore(state) {
+            fail!("⚠️ {:#}; se empieza desde el keygen inicial", e);
+        }
+    }
+    if file_config.audit_sign == Some(true) {
+        if let Some(profile) = &session.profile {
+            match certificate::load_or_create_key(&profile.signing_key_path()) {
+                Ok(key) => session.audit_key = Some(key),
+                Err(e) => fail!("⚠️ {:#}; la auditoría no se firmará", e),
+            }
+        }
+    }
+    let _span = tracing::info_span!("sesion", user = session.user.as_deref().unwrap_or("-"),
+        keygen = session.keygen_system.get_current_keygen()).entered();
+    tracing::info!(autenticado = session.authenticated, "sesión iniciada");
     
     match cli.command {
-        Commands::Login { ref token } => {
-            println!("{}", "🔐 Iniciando sesión consciente...".bright_blue());
-            if let Some(t) = token {
-                println!("  Token recibido: {}", t);
+        Commands::Login { ref token, ref user, issue } => {
+            if issue {
+                match auth::secret_from_env() {
+                    Ok(secret) => {
+                        say_inline!("{} ", "🔑 Token:".bright_yellow());
+                        emit!("{}", auth::issue_token(&secret, user));
+                    }
+                    Err(e) => fail!("{} {:#}", "❌".red(), e),
+                }
+                return;
+            }
+            
+            say!("{}", "🔐 Iniciando sesión consciente...".bright_blue());
+            let Some(t) = token else {
+                fail!("  {} Falta --token", "❌".red());
+                return;
+            };
+            match auth::login(user, t) {
+                Ok(()) => {
+                    session.authenticated = true;
+                    session.user = Some(user.clone());
+                    say!("  {} Sesión iniciada con éxito como {}", "✅".green(), user);
+                    say!("  Coherencia inicial: {:.1}%", session.coherence_level * 100.0);
+                }
+                Err(e) => fail!("  {} {:#}", "❌".red(), e),
             }
-            println!("  {} Sesión iniciada con éxito", "✅".green());
-            println!("  Coherencia inicial: {:.1}%", session.coherence_level * 100.0);
         }
         
-        Commands::Status => {
+        Commands::Logout => {
+            match auth::logout() {
+                Ok(()) => say!("  {} Sesión cerrada", "✅".green()),
+                Err(e) => fail!("  {} {:#}", "❌".red(), e),
+            }
+        }
+        
+        Commands::Status { watch, interval, evolve_steps } => {
+            if watch {
+                watch_status(session, interval, evolve_steps);
+                return;
+            }
             session.show_status();
         }
         
-        Commands::Evolve { steps, threshold } => {
-            session.evolve(steps, threshold);
-            if !cli.quiet {
+        Commands::Evolve { steps, until_field: Some(field), max_time, .. } => {
+            if !session.require_auth() {
+                return;
+            }
+            let field = field as usize;
+            let keygen = session.keygen_system.get_current_keygen();
+            if session.fibonacci_system.update_by_keygen(keygen).contains(&field) {
+                say!("{} El campo {} ya está activo (keygen {:.10})", "✅".green(), field, keygen);
+                emit!("0");
+                return;
+            }
+            
+            let budget = steps.unwrap_or(1_000_000);
+            say!("{} hasta activar el campo {} ({}D), máximo {} pasos{}", 
+                "🌀 Evolucionando".bright_yellow(), field, session.fibonacci_system.get_field_dimension(field), budget,
+                max_time.map(|t| format!(" o {}", humantime::format_duration(t))).unwrap_or_default());
+            let (taken, event) = session.evolve_until_field(field, budget, max_time);
+            session.save();
+            let keygen = session.keygen_system.get_current_keygen();
+            match event {
+                Some(event) => {
+                    say!("  {} Transición: campo {} → {} en la iteración {}", "🎯".bright_green(), event.field_id,
+                        if event.activo { "ACTIVO" } else { "INACTIVO" }, session.keygen_system.get_iteration());
+                    say!("  Keygen: {:.10}", keygen);
+                    say_inline!("  Pasos: ");
+                    emit!("{}", taken);
+                }
+                None => {
+                    fail!("{} Presupuesto agotado tras {} pasos sin activar el campo {} (keygen {:.10}, umbral {:.6})", 
+                        "❌".red(), taken, field, keygen, 
+                        session.fibonacci_system.campo(field).map_or(f64::NAN, |c| c.umbral_activacion()));
+                    std::process::exit(1);
+                }
+            }
+        }
+        
+        Commands::Evolve { steps, every: Some(every), daemonize, .. } => {
+            if !session.require_auth() {
+                return;
+            }
+            if daemonize {
+                match spawn_daemon(session.profile.as_ref()) {
+                    Ok(pid) => {
+                        say_inline!("{} cada {}, pid ", "🌙 Evolución programada en segundo plano".bright_cyan(), 
+                            humantime::format_duration(every));
+                        emit!("{}", pid);
+                    }
+                    Err(e) => {
+                        fail!("{} No se pudo lanzar la evolución en segundo plano: {:#}", "❌".red(), e);
+                        std::process::exit(1);
+                    }
+                }
+                return;
+            }
+            
+            let steps = steps.or(file_config.steps).unwrap_or(10);
+            say!("{} {} pasos cada {} (Ctrl+C para detener)", "⏰ Evolución programada:".bright_cyan(), 
+                steps, humantime::format_duration(every));
+            loop {
+                let tick = Instant::now();
+                session.evolve(steps, None);
+                session.save();
+                thread::sleep(every.saturating_sub(tick.elapsed()));
+            }
+        }
+        
+        Commands::Evolve { steps, threshold, ref midi, midi_channel, midi_pace, .. } => {
+            if !session.require_auth() {
+                return;
+            }
+            let steps = steps.or(file_config.steps).unwrap_or(10);
+            match midi {
+                Some(port) => {
+                    let port = Some(port.as_str()).filter(|p| !p.is_empty());
+                    if let Err(e) = midi::evolve(&mut session, steps, threshold, port, midi_channel - 1, midi_pace) {
+                        fail!("{} {:#}", "❌".red(), e);
+                        std::process::exit(1);
+                    }
+                }
+                None => {
+                    session.evolve(steps, threshold);
+                }
+            }
+            session.save();
+            if !output::is_quiet() {
                 session.show_status();
             }
         }
         
-        Commands::Love { intensity, ref state } => {
-            session.apply_love(intensity, state);
+        Commands::Love { intensity, ref state, ref state_out, state_format, monster } => {
+            if !session.require_auth() {
+                return;
+            }
+            session.apply_love(intensity);
+            session.save();
+            
+            if let Some(source) = state {
+                let result = state_io::read_state(source)
+                    .and_then(|input| {
+                        let transformed = session.transform_state(&input, monster)?;
+                        Ok((LoveOperator::love_fidelity(&input, &transformed), transformed))
+                    })
+                    .and_then(|(fidelity, transformed)| {
+                        say!("  Estado transformado{}: {} componentes, ‖ψ'‖ = {:.6}", 
+                            if monster { " (Â + Monster)" } else { "" }, transformed.len(), transformed.norm());
+                        say_inline!("  Fidelidad de amor |⟨ψ|ψ'⟩|²: ");
+                        emit!("{:.12}", fidelity);
+                        match state_out {
+                            Some(target) => state_io::write_state(target, &transformed,
+                                state_format.unwrap_or_else(|| StateFormat::for_path(target))),
+                            None => Ok(()),
+                        }
+                    });
+                if let Err(e) = result {
+                    fail!("  {} {:#}", "❌".red(), e);
+                    std::process::exit(1);
+                }
+            }
         }
         
-        Commands::Visualize { ref field, all } => {
+        Commands::Visualize { ref field, all, ref state_out, state_format, ref export, export_format, frames } => {
             session.visualize_fields(field, all);
+            
+            if let Some(path) = export {
+                let result = export_format.map_or_else(|| ImageFormat::for_path(path), Ok).and_then(|format| {
+                    let keygen = session.keygen_system.get_current_keygen();
+                    match frames {
+                        Some(n) => render::render_sweep(path, format, &session.fibonacci_system, n),
+                        None => render::render_fields(path, format, &session.fibonacci_system, keygen)
+                            .map(|()| vec![path.clone()]),
+                    }
+                });
+                match result {
+                    Ok(written) => {
+                        say!("  {} {} imagen(es) exportada(s)", "✅".green(), written.len());
+                        for file in written {
+                            emit!("{}", file.display());
+                        }
+                    }
+                    Err(e) => {
+                        fail!("  {} {:#}", "❌".red(), e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            
+            if let Some(target) = state_out {
+                let result = session.field_state(*field).and_then(|(field_id, state)| {
+                    say!("  Estado del campo {} ({}D) → {}", field_id, state.len(), target);
+                    state_io::write_state(target, &state, state_format.unwrap_or_else(|| StateFormat::for_path(target)))
+                });
+                if let Err(e) = result {
+                    fail!("  {} {:#}", "❌".red(), e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        
+        Commands::Verify { tolerance, min_coherence } => {
+            let tolerance = tolerance.or(file_config.tolerance).unwrap_or(0.1);
+            let coherence = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| session.verify_coherence(tolerance)))
+                .unwrap_or(f64::NAN);
+            let code = verify_exit_code(coherence, min_coherence);
+            match code {
+                1 => say!("{} Coherencia degradada (mínimo {:.1}%)", "⚠️".yellow(), min_coherence * 100.0),
+                2 => fail!("{} Coherencia {:.1}% por debajo del mínimo {:.1}%", "❌".red(), coherence * 100.0, min_coherence * 100.0),
+                3 => fail!("{} Error interno durante la verificación", "❌".red()),
+                _ => {}
+            }
+            if code != 0 {
+                std::process::exit(code);
+            }
         }
         
-        Commands::Verify { tolerance } => {
-            session.verify_coherence(tolerance);
+        Commands::Config { 
+            action: Some(ref action), 
+            .. 
+        } => {
+            let Some(path) = &config_path else {
+                fail!("{} No se encontró directorio de configuración; use --config", "❌".red());
+                return;
+            };
+            
+            let config = match action {
+                ConfigAction::Show => file_config.clone(),
+                ConfigAction::Edit => match config::edit(path) {
+                    Ok(config) => {
+                        say!("  {} Configuración válida guardada", "✅".green());
+                        if let Some(profile) = &session.profile {
+                            let audited = audit::file_sha256(path).and_then(|sha256| {
+                                let suceso = Suceso::new(session.user.clone(), session.keygen_system.get_iteration(),
+                                    Accion::EdicionConfiguracion { sha256 });
+                                AuditLog::new(profile.audit_path()).append(vec![suceso], session.audit_key.as_ref())
+                            });
+                            if let Err(e) = audited {
+                                fail!("{} {:#}", "❌".red(), e);
+                            }
+                        }
+                        config
+                    }
+                    Err(e) => {
+                        fail!("{} {:#}", "❌".red(), e);
+                        return;
+                    }
+                },
+            };
+            
+            say!("{} {}", "⚙️ Configuración:".bright_yellow(), path.display());
+            if !path.exists() {
+                say!("  (archivo inexistente, valores por defecto)");
+            }
+            emit!("{}", config.to_toml().trim_end());
         }
         
         Commands::Config { 
+            action: None,
             set_keygen, 
             set_phi_intensity, 
-            reset 
+            reset,
+            yes,
         } => {
-            println!("{}", "⚙️ Configurando sistema...".bright_yellow());
-            
-            if reset {
-                println!("  {} Restableciendo a valores iniciales", "↩️".bright_yellow());
+            if !reset && set_keygen.is_none() && set_phi_intensity.is_none() {
+                say!("Nada que cambiar: use --set-keygen, --set-phi-intensity, --reset o `config show`");
+                return;
             }
-            
-            if let Some(k) = set_keygen {
-                println!("  {} Keygen establecido a: {:.6}", "🔧".bright_cyan(), k);
+            if !session.require_auth() {
+                return;
             }
+            if reset && !yes {
+                let name = session.profile.as_ref().map_or(profile::DEFAULT_PROFILE, Profile::name);
+                if !confirm(&format!("¿Restablecer el perfil {} y perder su trayectoria (iteración {})?", 
+                    name, session.keygen_system.get_iteration())) {
+                    fail!("{} Reset cancelado", "❌".red());
+                    std::process::exit(1);
+                }
+            }
+            say!("{}", "⚙️ Configurando sistema...".bright_yellow());
             
-            if let Some(phi) = set_phi_intensity {
-                println!("  {} Intensidad φ establecida a: {:.4}", "ϕ".bright_magenta(), phi);
+            match session.configure(set_keygen, set_phi_intensity, reset) {
+                Ok(()) => session.save(),
+                Err(e) => {
+                    fail!("  {} {:#}", "❌".red(), e);
+                    std::process::exit(1);
+                }
             }
         }
         
-        Commands::Certify => {
+        Commands::Certify { out: None, verify: None, format: CertifyFormat::Text, .. } => {
             session.show_certification();
         }
         
+        Commands::Certify { format: CertifyFormat::Json, tolerance, .. } => {
+            let summary = certificate::summary(tolerance, std::time::SystemTime::now());
+            println!("{}", serde_json::to_string_pretty(&summary).unwrap_or_default());
+            if !summary.ok {
+                std::process::exit(1);
+            }
+        }
+        
+        Commands::Certify { out: Some(ref path), tolerance, .. } => {
+            let signed = session.profile.as_ref()
+                .ok_or_else(|| anyhow::anyhow!("Sin directorio de configuración para la clave de firma"))
+                .and_then(|profile| certificate::load_or_create_key(&profile.signing_key_path()))
+                .map(|key| certificate::sign(certificate::issue(tolerance, std::time::SystemTime::now()), &key));
+            let result = signed.and_then(|signed| {
+                let mut out = export::open_output(Some(path))?;
+                serde_json::to_writer_pretty(&mut out, &signed)?;
+                writeln!(out)?;
+                out.flush()?;
+                Ok(signed)
+            });
+            match result {
+                Ok(signed) => {
+                    say!("{} {} ({}/{} comprobaciones, clave {}…)", "🔏 Certificado firmado:".bright_green(),
+                        path.display(),
+                        signed.certificado.resultados.iter().filter(|r| r.passed).count(),
+                        signed.certificado.resultados.len(), &signed.clave_publica[..16]);
+                }
+                Err(e) => {
+                    fail!("{} Error al emitir el certificado: {:#}", "❌".red(), e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        
+        Commands::Certify { verify: Some(ref path), .. } => {
+            let signed = std::fs::read_to_string(path)
+                .with_context(|| format!("No se pudo leer {}", path.display()))
+                .and_then(|text| serde_json::from_str::<certificate::SignedCertificate>(&text)
+                    .with_context(|| format!("Certificado inválido en {}", path.display())));
+            let signed = match signed {
+                Ok(signed) => signed,
+                Err(e) => {
+                    fail!("{} {:#}", "❌".red(), e);
+                    std::process::exit(1);
+                }
+            };
+            let verification = certificate::verify(&signed);
+            say!("{} {} ({}, emitido {})", "🔍 Verificando certificado".bright_blue(), path.display(),
+                signed.certificado.sistema, signed.certificado.emitido);
+            if verification.signature_valid {
+                say!("  ✅ Firma válida (clave {}…)", &signed.clave_publica[..signed.clave_publica.len().min(16)]);
+            } else {
+                say!("  ❌ Firma inválida");
+                emit!("FAIL firma");
+            }
+            for mismatch in &verification.mismatches {
+                say!("  ❌ {}", mismatch);
+                emit!("FAIL {}", mismatch);
+            }
+            if !verification.ok() {
+                std::process::exit(1);
+            }
+            say!("  ✅ {} comprobaciones reproducidas", signed.certificado.resultados.len());
+            emit!("OK");
+        }
+        
+        Commands::Doctor { tolerance, format, memory_budget } => {
+            let mut report = doctor::run(tolerance);
+            doctor::add_memory(&mut report, memory_budget.saturating_mul(1 << 20));
+            match format {
+                DoctorFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&report).unwrap_or_default());
+                }
+                DoctorFormat::Text => {
+                    say!("{} (tolerancia {:e})", "🩺 Diagnóstico del núcleo".bright_cyan().bold(), tolerance);
+                    for check in &report.checks {
+                        let symbol = match (check.passed, check.severity) {
+                            (true, _) => "✅".normal(),
+                            (false, Severity::Error) => "❌".red(),
+                            (false, Severity::Aviso) => "⚠️".yellow(),
+                        };
+                        say!("  {} {}: {}", symbol, check.id, check.detail);
+                    }
+                    say!("{} {}/{} comprobaciones superadas", "📊".bright_green(),
+                        report.checks.iter().filter(|c| c.passed).count(), report.checks.len());
+                    for id in &report.failures {
+                        emit!("FAIL {}", id);
+                    }
+                }
+            }
+            if !report.ok {
+                std::process::exit(1);
+            }
+        }
+        
+        Commands::Export { what, format, ref out, ref parquet, ref audio, duration, steps } => {
+            if steps > 0 {
+                session.keygen_system.evolve_steps(steps);
+            }
+            if let Some(what) = what {
+                match session.export(what, format, out.as_deref()) {
+                    Ok(()) => {
+                        if let Some(path) = out.as_deref().filter(|p| *p != Path::new("-")) {
+                            say!("  {} Exportado a {}", "✅".green(), path.display());
+                        }
+                    }
+                    Err(e) => fail!("{} Error al exportar: {:#}", "❌".red(), e),
+                }
+            }
+            if let Some(dir) = parquet {
+                let history = [session.keygen_system.get_history().to_vec()];
+                report_parquet(export_parquet::export_parquet(dir, &history, &session.fibonacci_system));
+            }
+            if let Some(path) = audio {
+                let keygen = session.keygen_system.get_current_keygen();
+                session.fibonacci_system.update_by_keygen(keygen);
+                let voces = export_audio::voces_activas(&session.fibonacci_system, keygen);
+                match export_audio::export_audio(path, duration, &voces) {
+                    Ok(_) => say!("  {} {} ({} campos, {})", "✅".green(), path.display(), voces.len(),
+                        humantime::format_duration(duration)),
+                    Err(e) => {
+                        fail!("{} Error al exportar audio: {:#}", "❌".red(), e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+        
+        Commands::Plot { metric, steps, width, height } => {
+            session.plot(metric, steps, width, height);
+        }
+        
+        Commands::Serve { port, host, grpc } => {
+            let addr = std::net::SocketAddr::new(host, port);
+            let result = tokio::runtime::Runtime::new()
+                .map_err(anyhow::Error::from)
+                .and_then(|rt| if grpc {
+                    rt.block_on(grpc::run(session, addr))
+                } else {
+                    rt.block_on(serve::run(session, addr))
+                });
+            if let Err(e) = result {
+                fail!("{} Error del servidor: {:#}", "❌".red(), e);
+            }
+            return;
+        }
+        
+        Commands::Osc { target, rate, evolve_steps, frames } => {
+            if let Err(e) = osc::run(session, target, rate, evolve_steps, frames) {
+                fail!("{} {:#}", "❌".red(), e);
+                std::process::exit(1);
+            }
+            return;
+        }
+        
+        Commands::Dashboard { steps, steps_per_tick, refresh_ms } => {
+            let keygen = session.keygen_system.get_current_keygen();
+            if let Err(e) = dashboard::run(keygen, steps, steps_per_tick, Duration::from_millis(refresh_ms)) {
+                fail!("{} Error en el panel: {}", "❌".red(), e);
+            }
+        }
+        
+        Commands::Import { ref state, ref keygen_history } => {
+            if !session.require_auth() {
+                return;
+            }
+            if let Some(path) = keygen_history {
+                let result = std::fs::read_to_string(path)
+                    .with_context(|| format!("No se pudo leer {}", path.display()))
+                    .and_then(|text| import::parse_keygen_csv(&text))
+                    .and_then(|imported| session.import_history(&imported));
+                match result {
+                    Ok(added) => {
+                        say!("{} {} valores nuevos desde {}", "📥 Trayectoria importada:".bright_cyan(), added, path.display());
+                        say_inline!("  Keygen: ");
+                        emit!("{:.10}", session.keygen_system.get_current_keygen());
+                        session.save();
+                    }
+                    Err(e) => {
+                        fail!("{} Error al importar la trayectoria: {:#}", "❌".red(), e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            if let Some(source) = state {
+                let result = session.profile.as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("Sin directorio de configuración para guardar el estado"))
+                    .and_then(|profile| {
+                        let input = state_io::read_state(source)?;
+                        import::validate_state(&input)?;
+                        let target = profile.imported_state_path();
+                        if let Some(dir) = target.parent() {
+                            std::fs::create_dir_all(dir).with_context(|| format!("No se pudo crear {}", dir.display()))?;
+                        }
+                        state_io::write_state(&target.to_string_lossy(), &input, StateFormat::Npy)?;
+                        Ok((input.norm(), target))
+                    });
+                match result {
+                    Ok((norm, target)) => {
+                        say!("{} ‖ψ‖ = {:.6}, guardado en {}", "📥 Estado importado:".bright_cyan(), norm, target.display());
+                        say!("  Úselo con: álgebra-rose love --state {}", target.display());
+                    }
+                    Err(e) => {
+                        fail!("{} Error al importar el estado: {:#}", "❌".red(), e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+        
+        Commands::History { last, filter, page } => {
+            let Some(profile) = &session.profile else {
+                fail!("{} No se encontró directorio de configuración", "❌".red());
+                std::process::exit(1);
+            };
+            let journal = Journal::new(profile.journal_path());
+            let entradas = match journal.read() {
+                Ok(entradas) => entradas,
+                Err(e) => {
+                    fail!("{} {:#}", "❌".red(), e);
+                    std::process::exit(1);
+                }
+            };
+            let (pagina, paginas) = journal::page(&entradas, filter, last, page as usize);
+            say!("{} {} (página {}/{}, {} entradas)", "📜 Historial".bright_cyan(), journal.path().display(),
+                page, paginas.max(1), entradas.len());
+            for entrada in pagina {
+                let detalle = match &entrada.evento {
+                    Evento::Evolucion { pasos, keygen_inicial, keygen_final } => 
+                        format!("evolución   {} pasos, keygen {:.10} → {:.10}", pasos, keygen_inicial, keygen_final),
+                    Evento::Transicion { campo, activo } => 
+                        format!("transición  campo {} {}", campo, if *activo { "ACTIVO" } else { "INACTIVO" }),
+                    Evento::Amor { intensidad } => format!("amor        intensidad {:.6}", intensidad),
+                };
+                emit!("{}  it {:>8}  {}", entrada.momento, entrada.iteracion, detalle);
+            }
+            if (page as usize) < paginas {
+                say!("{} Más antiguas: --page {}", "💡".bright_yellow(), page + 1);
+            }
+        }
+        
+        Commands::Audit { action: AuditAction::Verify { ref public_key } } => {
+            let Some(profile) = &session.profile else {
+                fail!("{} No se encontró directorio de configuración", "❌".red());
+                std::process::exit(1);
+            };
+            let key = match public_key {
+                Some(hex_key) => match hex::decode(hex_key.trim()).ok()
+                    .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                    .and_then(|bytes| ed25519_dalek::VerifyingKey::from_bytes(&bytes).ok()) {
+                    Some(key) => Some(key),
+                    None => {
+                        fail!("{} Clave pública inválida: se esperan 32 bytes en hex", "❌".red());
+                        std::process::exit(1);
+                    }
+                },
+                None if profile.signing_key_path().exists() => certificate::load_or_create_key(&profile.signing_key_path())
+                    .map(|key| key.verifying_key())
+                    .map_err(|e| fail!("⚠️ {:#}; las firmas no se comprobarán", e))
+                    .ok(),
+                None => None,
+            };
+            let log = AuditLog::new(profile.audit_path());
+            let informe = match log.verify(key.as_ref()) {
+                Ok(informe) => informe,
+                Err(e) => {
+                    fail!("{} {:#}", "❌".red(), e);
+                    std::process::exit(1);
+                }
+            };
+            say!("{} {} ({} registros, {} firmados)", "🧾 Auditoría".bright_cyan(), log.path().display(),
+                informe.registros, informe.firmados);
+            if informe.sin_comprobar > 0 {
+                say!("{} {} firmas sin comprobar: no hay clave pública", "⚠️".yellow(), informe.sin_comprobar);
+            }
+            for problema in &informe.problemas {
+                fail!("  {} {}", "❌".red(), problema);
+            }
+            if !informe.ok() {
+                std::process::exit(1);
+            }
+            say!("  {} Cadena íntegra", "✅".green());
+            if let Some(hash) = &informe.ultimo_hash {
+                say_inline!("  Último hash: ");
+                emit!("{}", hash);
+            }
+        }
+        
+        Commands::Transit { to, steps, max_time, .. } => {
+            if !session.require_auth() {
+                return;
+            }
+            if let Some(target) = to {
+                let ok = session.transit(target as usize, steps, max_time);
+                session.save();
+                if !ok {
+                    std::process::exit(1);
+                }
+                return;
+            }
+            
+            // --interactive: se vuelve a la escalera tras cada tránsito hasta q / Esc
+            loop {
+                let keygen = session.keygen_system.get_current_keygen();
+                let current = transit::current_field(&session.fibonacci_system.update_by_keygen(keygen));
+                let target = match transit::pick_target(&session.fibonacci_system, current) {
+                    Ok(Some(target)) => target,
+                    Ok(None) => break,
+                    Err(e) => {
+                        fail!("{} Error en la terminal: {}", "❌".red(), e);
+                        std::process::exit(1);
+                    }
+                };
+                let ok = session.transit(target, steps, max_time);
+                session.save();
+                if !ok {
+                    std::process::exit(1);
+                }
+            }
+        }
+        
+        Commands::Community { humans, steps, ref export, format, ref parquet } => {
+            let community = community::Community::simulate(humans, steps);
+            let stats = community.stats();
+            if output::is_quiet() {
+                emit!("humanos={} pasos={} keygen_medio={:.10} keygen_min={:.10} keygen_max={:.10} desviacion={:.3e}", 
+                    stats.humanos, stats.pasos, stats.keygen_medio, stats.keygen_min, stats.keygen_max, stats.desviacion);
+            } else {
+                say!("{} {} humanos × {} pasos", "👥 Comunidad consciente:".bright_magenta(), stats.humanos, stats.pasos);
+                say!("  Keygen medio: {:.10}", stats.keygen_medio);
+                say!("  Rango: {:.10} … {:.10}", stats.keygen_min, stats.keygen_max);
+                say!("  Desviación: {:.3e}", stats.desviacion);
+                say!("  Crecimiento medio: {:.6e}%", stats.crecimiento_medio * 100.0);
+            }
+            
+            if let Some(path) = export {
+                let result = export::open_output(Some(path)).and_then(|mut out| {
+                    community.write_trajectories(&mut out, format)?;
+                    out.flush()?;
+                    Ok(())
+                });
+                if let Err(e) = result {
+                    fail!("{} Error al exportar: {:#}", "❌".red(), e);
+                    std::process::exit(1);
+                }
+            }
+            if let Some(dir) = parquet {
+                report_parquet(export_parquet::export_parquet(dir, &community.trajectories, &FibonacciSystem::new()));
+            }
+        }
+        
+        Commands::Profile { .. } => unreachable!("atendido antes de abrir la sesión"),
+        
         Commands::Exit => {
-            println!("\n{}", "💖 Finalizando sesión consciente...".bright_magenta());
+            say!("\n{}", "💖 Finalizando sesión consciente...".bright_magenta());
             let duration = session.start_time.elapsed();
-            println!("  Duración total: {:.1?}", duration);
-            println!("  Coherencia final: {:.1}%", session.coherence_level * 100.0);
-            println!("  {} ¡Hasta pronto, mi amor! 🌹", "✨".bright_yellow());
+            say!("  Duración total: {:.1?}", duration);
+            say!("  Coherencia final: {:.1}%", session.coherence_level * 100.0);
+            say!("  {} ¡Hasta pronto, mi amor! 🌹", "✨".bright_yellow());
             return;
         }
     }
     
     if !matches!(cli.command, Commands::Exit) {
-        println!("\n{} Para más comandos: {} --help", 
-            "💡".bright_blue(), 
+        say!("\n{} {} {} --help", 
+            "💡".bright_blue(), t("footer.help"),
             "álgebra-rose".bright_green());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_saves_encrypted() {
+        let base = std::env::temp_dir().join(format!("algebra-rose-cifrado-sesion-{}", std::process::id()));
+        let profile = Profile::open(&base, profile::DEFAULT_PROFILE).unwrap();
+        let mut session = ConsciousSession::new(None, Some(profile.clone()));
+        session.encrypt_state = true;
+        session.state_key = None;
+
+        // Sin token no se escribe nada, ni siquiera en claro
+        session.save();
+        assert!(!profile.state_path().exists());
+
+        session.state_key = Some(encryption::StateKey::from_token("token"));
+        session.evolve(3, None);
+        session.save();
+        assert!(encryption::is_encrypted(&std::fs::read(profile.state_path()).unwrap()));
+        let guardado = profile.load_state(Some(&encryption::StateKey::from_token("token"))).unwrap().unwrap();
+        assert_eq!(&guardado.history, session.keygen_system.get_history());
+
+        // Un estado bloqueado no se sobrescribe
+        let antes = std::fs::read(profile.state_path()).unwrap();
+        session.state_locked = true;
+        session.evolve(1, None);
+        session.save();
+        assert_eq!(std::fs::read(profile.state_path()).unwrap(), antes);
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+}
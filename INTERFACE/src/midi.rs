@@ -0,0 +1,227 @@
+//! Salida MIDI - Las Resonancias de los Campos como Notas en Vivo
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cada campo k es una nota: la más cercana a su frecuencia de resonancia,
+//! `frecuencia_campo(k)` = 110 Hz·φ^((k−1)/4), la misma con la que suena
+//! en la sonificación y en `export --audio`. Activar un campo envía
+//! note-on y desactivarlo note-off. La intensidad del amor va por el CC 1
+//! (rueda de modulación) en escala logarítmica: 64 con intensidad 1 y 16
+//! pasos más por cada factor φ. También fija la velocidad de las notas.
+//! La traducción a mensajes no depende de ningún backend; el puerto
+//! (midir) sólo se compila con la feature `midi`.
+
+use std::thread;
+use std::time::Duration;
+
+use algebra_rose_core::prelude::{frecuencia_campo, CambioActivacion, PHI};
+use anyhow::Result;
+
+/// Campos con nota propia
+pub const NOTAS: usize = 24;
+
+/// La₄ (440 Hz) como nota MIDI
+const NOTA_LA4: f64 = 69.0;
+
+/// Controlador de la intensidad del amor (rueda de modulación)
+pub const CC_AMOR: u8 = 1;
+
+/// Valor del CC con intensidad 1 y pasos por cada factor φ
+const CC_CENTRO: f64 = 64.0;
+const CC_POR_PHI: f64 = 16.0;
+
+/// Nota MIDI del campo `id` (1..=24)
+pub fn nota_campo(id: usize) -> u8 {
+    (NOTA_LA4 + 12.0 * (frecuencia_campo(id) / 440.0).log2()).round() as u8
+}
+
+/// Valor de 7 bits para la intensidad del amor
+pub fn valor_amor(intensidad: f64) -> u8 {
+    if intensidad <= 0.0 || !intensidad.is_finite() {
+        return if intensidad == f64::INFINITY { 127 } else { 0 };
+    }
+    (CC_CENTRO + CC_POR_PHI * intensidad.ln() / PHI.ln()).round().clamp(0.0, 127.0) as u8
+}
+
+/// Traduce activaciones e intensidad a mensajes MIDI, recordando qué suena
+#[derive(Clone, Debug)]
+pub struct MapaMidi {
+    canal: u8,
+    velocidad: u8,
+    sonando: [bool; NOTAS],
+}
+
+impl MapaMidi {
+    /// Mapa para el canal `canal` (0..=15)
+    pub fn new(canal: u8) -> Self {
+        MapaMidi { canal: canal & 0x0F, velocidad: CC_CENTRO as u8, sonando: [false; NOTAS] }
+    }
+
+    /// CC de la intensidad; las notas siguientes suenan con esa velocidad
+    pub fn amor(&mut self, intensidad: f64) -> Vec<[u8; 3]> {
+        let valor = valor_amor(intensidad);
+        self.velocidad = valor.max(1);
+        vec![[0xB0 | self.canal, CC_AMOR, valor]]
+    }
+
+    /// Note-on de los campos `activos` que aún no suenan
+    pub fn acorde(&mut self, activos: &[usize]) -> Vec<[u8; 3]> {
+        activos.iter().filter_map(|&id| self.nota(id, true)).collect()
+    }
+
+    /// Note-on/off de cada transición; las repetidas no se envían
+    pub fn transiciones(&mut self, cambios: &[CambioActivacion]) -> Vec<[u8; 3]> {
+        cambios.iter().filter_map(|c| self.nota(c.field_id, c.activo)).collect()
+    }
+
+    /// Note-off de todo lo que suena
+    pub fn silencio(&mut self) -> Vec<[u8; 3]> {
+        (1..=NOTAS).filter_map(|id| self.nota(id, false)).collect()
+    }
+
+    fn nota(&mut self, id: usize, activo: bool) -> Option<[u8; 3]> {
+        let sonando = self.sonando.get_mut(id.checked_sub(1)?)?;
+        if *sonando == activo {
+            return None;
+        }
+        *sonando = activo;
+        Some(if activo {
+            [0x90 | self.canal, nota_campo(id), self.velocidad]
+        } else {
+            [0x80 | self.canal, nota_campo(id), 0]
+        })
+    }
+}
+
+/// Puerto de salida MIDI
+#[cfg(feature = "midi")]
+pub struct SalidaMidi {
+    conexion: midir::MidiOutputConnection,
+}
+
+#[cfg(feature = "midi")]
+impl SalidaMidi {
+    /// Conecta con el primer puerto cuyo nombre contiene `puerto`, o con el primero disponible
+    pub fn abrir(puerto: Option<&str>) -> Result<Self> {
+        use anyhow::{anyhow, Context};
+
+        let salida = midir::MidiOutput::new("Algebra Rose")
+            .context("No se pudo iniciar el cliente MIDI")?;
+        let puertos = salida.ports();
+        let elegido = puertos.iter()
+            .find(|p| puerto.is_none_or(|nombre| salida.port_name(p).is_ok_and(|n| n.contains(nombre))))
+            .ok_or_else(|| match puerto {
+                Some(nombre) => anyhow!("No hay ningún puerto MIDI que contenga «{}»", nombre),
+                None => anyhow!("No hay puertos de salida MIDI"),
+            })?
+            .clone();
+        let nombre = salida.port_name(&elegido).unwrap_or_default();
+        say!("  🎹 Salida MIDI: {}", nombre);
+        let conexion = salida.connect(&elegido, "algebra-rose")
+            .map_err(|e| anyhow!("No se pudo conectar con el puerto MIDI «{}»: {}", nombre, e))?;
+        Ok(SalidaMidi { conexion })
+    }
+
+    pub fn enviar(&mut self, mensajes: &[[u8; 3]]) -> Result<()> {
+        for mensaje in mensajes {
+            self.conexion.send(mensaje)
+                .map_err(|e| anyhow::anyhow!("No se pudo enviar el mensaje MIDI: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Sin la feature `midi` no hay puerto que abrir
+#[cfg(not(feature = "midi"))]
+pub struct SalidaMidi;
+
+#[cfg(not(feature = "midi"))]
+impl SalidaMidi {
+    pub fn abrir(_puerto: Option<&str>) -> Result<Self> {
+        anyhow::bail!("Salida MIDI no disponible: cargo run -p algebra_rose_interface --features midi")
+    }
+
+    pub fn enviar(&mut self, _mensajes: &[[u8; 3]]) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// `evolve` enviando cada transición a `puerto` en cuanto ocurre
+///
+/// Al empezar suena el acorde de los campos ya activos y al terminar se
+/// apaga; con `pausa`, se espera ese tiempo tras cada paso para que la
+/// evolución se pueda tocar.
+pub fn evolve(session: &mut crate::ConsciousSession, steps: u64, threshold: Option<f64>,
+    puerto: Option<&str>, canal: u8, pausa: Option<Duration>) -> Result<Vec<f64>> {
+    let mut salida = SalidaMidi::abrir(puerto)?;
+    let mut mapa = MapaMidi::new(canal);
+    let keygen = session.keygen_system.get_current_keygen();
+    let activos = session.fibonacci_system.update_by_keygen(keygen);
+    salida.enviar(&mapa.amor(session.love_operator.get_intensity()))?;
+    salida.enviar(&mapa.acorde(&activos))?;
+
+    let mut error = None;
+    let results = session.evolve_observed(steps, threshold, &mut |_, _, cambios| {
+        if error.is_some() {
+            return;
+        }
+        if let Err(e) = salida.enviar(&mapa.transiciones(cambios)) {
+            error = Some(e);
+        }
+        if let Some(pausa) = pausa {
+            thread::sleep(pausa);
+        }
+    });
+    if let Some(e) = error {
+        return Err(e);
+    }
+    salida.enviar(&mapa.amor(session.love_operator.get_intensity()))?;
+    salida.enviar(&mapa.silencio())?;
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cambio(field_id: usize, activo: bool) -> CambioActivacion {
+        CambioActivacion { field_id, activo }
+    }
+
+    #[test]
+    fn test_field_notes() {
+        assert_eq!(nota_campo(1), 45);
+        // Cuatro campos más arriba, un factor φ: 8.33 semitonos
+        assert_eq!(nota_campo(5), 53);
+        assert!((1..NOTAS).all(|k| nota_campo(k + 1) > nota_campo(k)));
+        assert!(nota_campo(NOTAS) <= 127);
+    }
+
+    #[test]
+    fn test_love_control() {
+        assert_eq!(valor_amor(1.0), 64);
+        assert_eq!(valor_amor(PHI), 80);
+        assert_eq!(valor_amor(1.0 / PHI), 48);
+        assert_eq!(valor_amor(1e30), 127);
+        assert_eq!(valor_amor(0.0), 0);
+        assert_eq!(valor_amor(f64::NAN), 0);
+
+        let mut mapa = MapaMidi::new(2);
+        assert_eq!(mapa.amor(PHI), vec![[0xB2, CC_AMOR, 80]]);
+        assert_eq!(mapa.acorde(&[1]), vec![[0x92, 45, 80]]);
+        // Una intensidad mínima no convierte el note-on en note-off
+        mapa.amor(0.0);
+        assert_eq!(mapa.acorde(&[2])[0][2], 1);
+    }
+
+    #[test]
+    fn test_transitions_track_sounding_notes() {
+        let mut mapa = MapaMidi::new(0);
+        assert_eq!(mapa.acorde(&[1, 2]).len(), 2);
+        // Campos que ya suenan, ids fuera de rango y apagados repetidos no generan mensajes
+        assert!(mapa.acorde(&[1, 0, 25]).is_empty());
+        let mensajes = mapa.transiciones(&[cambio(2, false), cambio(2, false), cambio(3, true)]);
+        assert_eq!(mensajes, vec![[0x80, nota_campo(2), 0], [0x90, nota_campo(3), 64]]);
+        assert_eq!(mapa.silencio(), vec![[0x80, 45, 0], [0x80, nota_campo(3), 0]]);
+        assert!(mapa.silencio().is_empty());
+    }
+}
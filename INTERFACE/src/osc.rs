@@ -0,0 +1,151 @@
+//! Emisión OSC - Keygen, Campos y Amor para Patches en Vivo
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! `osc` envía por UDP, a la frecuencia pedida, un mensaje OSC 1.0 por valor:
+//!
+//! - `/keygen` con el keygen actual
+//! - `/field/{n}/activation` con 1 o 0 para cada campo n = 1..=24
+//! - `/love/intensity` con la intensidad del operador Â
+//!
+//! Todos llevan un único argumento float32 (`,f`), que TouchDesigner y Max
+//! reciben sin configuración. Se envía el estado completo en cada ciclo, así
+//! que un patch que se conecta tarde lo recibe entero en el siguiente.
+
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use colored::*;
+
+use crate::ConsciousSession;
+
+/// Mensaje OSC con un argumento float32
+pub fn mensaje(direccion: &str, valor: f32) -> Vec<u8> {
+    let mut paquete = Vec::with_capacity(direccion.len() + 12);
+    cadena(&mut paquete, direccion);
+    cadena(&mut paquete, ",f");
+    paquete.extend_from_slice(&valor.to_be_bytes());
+    paquete
+}
+
+/// Cadena OSC: terminada en NUL y rellena hasta múltiplo de 4 bytes
+fn cadena(paquete: &mut Vec<u8>, texto: &str) {
+    paquete.extend_from_slice(texto.as_bytes());
+    paquete.resize((paquete.len() / 4 + 1) * 4, 0);
+}
+
+/// Mensajes de un ciclo: keygen, activación de los `campos` y amor
+pub fn ciclo(keygen: f64, campos: usize, activos: &[usize], intensidad: f64) -> Vec<Vec<u8>> {
+    let mut mensajes = Vec::with_capacity(campos + 2);
+    mensajes.push(mensaje("/keygen", keygen as f32));
+    for n in 1..=campos {
+        let activo = if activos.contains(&n) { 1.0 } else { 0.0 };
+        mensajes.push(mensaje(&format!("/field/{}/activation", n), activo));
+    }
+    mensajes.push(mensaje("/love/intensity", intensidad as f32));
+    mensajes
+}
+
+/// Socket UDP hacia el destino; admite direcciones de difusión
+pub struct EmisorOsc {
+    socket: UdpSocket,
+    destino: SocketAddr,
+}
+
+impl EmisorOsc {
+    pub fn new(destino: SocketAddr) -> io::Result<Self> {
+        let local: SocketAddr = if destino.is_ipv4() { ([0, 0, 0, 0], 0).into() } else { ([0u16; 8], 0).into() };
+        let socket = UdpSocket::bind(local)?;
+        if destino.is_ipv4() {
+            socket.set_broadcast(true)?;
+        }
+        Ok(EmisorOsc { socket, destino })
+    }
+
+    pub fn enviar(&self, mensajes: &[Vec<u8>]) -> io::Result<()> {
+        for mensaje in mensajes {
+            self.socket.send_to(mensaje, self.destino)?;
+        }
+        Ok(())
+    }
+}
+
+/// Emite el estado de la sesión `rate` veces por segundo
+///
+/// Con sesión autenticada y `evolve_steps` > 0 se evoluciona ese número de
+/// pasos por ciclo. Sin `frames` sigue hasta Ctrl+C.
+pub fn run(mut session: ConsciousSession, destino: SocketAddr, rate: u32, evolve_steps: u64,
+    frames: Option<u64>) -> anyhow::Result<()> {
+    let emisor = EmisorOsc::new(destino)
+        .with_context(|| format!("No se pudo abrir el socket OSC hacia {}", destino))?;
+    let periodo = Duration::from_secs(1) / rate.max(1);
+    let evolving = evolve_steps > 0 && session.authenticated;
+    say!("{} hacia {} a {} Hz{} · Ctrl+C para salir", "📡 Emitiendo OSC".bright_cyan(), destino, rate,
+        if evolving { format!(", evolucionando {} pasos por ciclo", evolve_steps) } else { String::new() });
+
+    let mut enviados = 0;
+    while frames.is_none_or(|n| enviados < n) {
+        let tick = Instant::now();
+        if evolving {
+            session.advance(evolve_steps);
+        }
+        let keygen = session.keygen_system.get_current_keygen();
+        let activos = session.fibonacci_system.update_by_keygen(keygen);
+        let mensajes = ciclo(keygen, session.fibonacci_system.num_campos(), &activos,
+            session.love_operator.get_intensity());
+        emisor.enviar(&mensajes).with_context(|| format!("No se pudo enviar OSC a {}", destino))?;
+        enviados += 1;
+        thread::sleep(periodo.saturating_sub(tick.elapsed()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_encoding() {
+        // Ejemplo de la especificación OSC 1.0
+        let esperado: Vec<u8> = [
+            b"/oscillator/4/frequency\0".as_slice(),
+            b",f\0\0",
+            &[0x43, 0xdc, 0x00, 0x00],
+        ].concat();
+        assert_eq!(mensaje("/oscillator/4/frequency", 440.0), esperado);
+        // Una dirección de longitud múltiplo de 4 lleva 4 bytes de relleno
+        assert_eq!(&mensaje("/abc", 1.0)[..8], b"/abc\0\0\0\0");
+        assert!(ciclo(0.5, 24, &[], 1.0).iter().all(|m| m.len() % 4 == 0));
+    }
+
+    #[test]
+    fn test_cycle_contents() {
+        let mensajes = ciclo(0.75, 3, &[2], 1.5);
+        assert_eq!(mensajes.len(), 5);
+        assert_eq!(mensajes[0], mensaje("/keygen", 0.75));
+        assert_eq!(mensajes[1], mensaje("/field/1/activation", 0.0));
+        assert_eq!(mensajes[2], mensaje("/field/2/activation", 1.0));
+        assert_eq!(mensajes[4], mensaje("/love/intensity", 1.5));
+    }
+
+    #[test]
+    fn test_run_sends_over_udp() {
+        let receptor = UdpSocket::bind("127.0.0.1:0").unwrap();
+        receptor.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let session = ConsciousSession::new(None, None);
+        let keygen = session.keygen_system.get_current_keygen();
+        run(session, receptor.local_addr().unwrap(), 1000, 0, Some(2)).unwrap();
+
+        let mut buffer = [0u8; 64];
+        let mut recibidos = Vec::new();
+        for _ in 0..2 * 26 {
+            let (n, _) = receptor.recv_from(&mut buffer).unwrap();
+            recibidos.push(buffer[..n].to_vec());
+        }
+        assert_eq!(recibidos[0], mensaje("/keygen", keygen as f32));
+        assert_eq!(recibidos[26], recibidos[0]);
+        assert!(recibidos[25].starts_with(b"/love/intensity\0,f\0\0"));
+    }
+}
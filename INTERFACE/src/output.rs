@@ -0,0 +1,148 @@
+//! Salida Decorativa - stdout o stderr según el uso de stdout
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cuando un comando escribe datos por stdout (`--state-out -`, etc.) los
+//! banners y mensajes pasan a stderr para no corromper la tubería. Con
+//! `--quiet` la decoración (`say!`) se omite y solo queda el resultado de
+//! cada comando (`emit!`) y los errores (`fail!`).
+//!
+//! El color sigue `--color`; en modo `auto` se desactiva si `NO_COLOR` está
+//! definida o si la salida no es una terminal.
+
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use clap::ValueEnum;
+
+static STDOUT_RESERVED: AtomicBool = AtomicBool::new(false);
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Reserva stdout para datos: la salida decorativa irá a stderr
+pub fn reserve_stdout() {
+    STDOUT_RESERVED.store(true, Ordering::Relaxed);
+}
+
+/// ¿Está stdout reservado para datos?
+pub fn stdout_reserved() -> bool {
+    STDOUT_RESERVED.load(Ordering::Relaxed)
+}
+
+/// Activa el modo silencioso: se suprime toda la salida decorativa
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// ¿Está activo el modo silencioso?
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Cuándo colorear la salida
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Solo en terminal y sin `NO_COLOR`
+    Auto,
+    /// Siempre, aunque se redirija la salida
+    Always,
+    /// Nunca
+    Never,
+}
+
+impl ColorChoice {
+    /// Interpreta el valor `color` del archivo de configuración
+    pub fn from_config(value: &str) -> Option<Self> {
+        ColorChoice::from_str(value, true).ok()
+    }
+}
+
+/// ¿Pide `NO_COLOR` desactivar el color? (https://no-color.org: definida y no vacía)
+fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// Decide si colorear según la elección y el entorno
+fn should_color(choice: ColorChoice, no_color: bool, terminal: bool) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => !no_color && terminal,
+    }
+}
+
+/// Aplica la elección de color a `colored` según el flujo de la salida decorativa
+pub fn apply_color(choice: ColorChoice) {
+    let terminal = if stdout_reserved() {
+        std::io::stderr().is_terminal()
+    } else {
+        std::io::stdout().is_terminal()
+    };
+    colored::control::set_override(should_color(choice, no_color_env(), terminal));
+}
+
+/// ¿Colorear los registros de stderr con esta elección?
+pub fn stderr_color(choice: ColorChoice) -> bool {
+    should_color(choice, no_color_env(), std::io::stderr().is_terminal())
+}
+
+/// `println!` decorativo: se omite con `--quiet`, va a stderr si stdout está reservado
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if !$crate::output::is_quiet() {
+            if $crate::output::stdout_reserved() {
+                eprintln!($($arg)*)
+            } else {
+                println!($($arg)*)
+            }
+        }
+    };
+}
+
+/// `print!` decorativo: se omite con `--quiet`, va a stderr si stdout está reservado
+macro_rules! say_inline {
+    ($($arg:tt)*) => {
+        if !$crate::output::is_quiet() {
+            if $crate::output::stdout_reserved() {
+                eprint!($($arg)*)
+            } else {
+                print!($($arg)*)
+            }
+        }
+    };
+}
+
+/// Resultado del comando: se imprime siempre, incluso con `--quiet`
+///
+/// Un stdout cerrado (`| head`) no es un error para quien solo quería el resultado.
+macro_rules! emit {
+    ($($arg:tt)*) => {{
+        use std::io::Write as _;
+        if $crate::output::stdout_reserved() {
+            eprintln!($($arg)*)
+        } else {
+            let _ = writeln!(std::io::stdout(), $($arg)*);
+        }
+    }};
+}
+
+/// Error para el usuario: siempre a stderr
+macro_rules! fail {
+    ($($arg:tt)*) => {
+        eprintln!($($arg)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_choice() {
+        assert!(should_color(ColorChoice::Always, true, false));
+        assert!(!should_color(ColorChoice::Never, false, true));
+        assert!(should_color(ColorChoice::Auto, false, true));
+        assert!(!should_color(ColorChoice::Auto, true, true));
+        assert!(!should_color(ColorChoice::Auto, false, false));
+        assert_eq!(ColorChoice::from_config("never"), Some(ColorChoice::Never));
+        assert_eq!(ColorChoice::from_config("rojo"), None);
+    }
+}
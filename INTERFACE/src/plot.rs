@@ -0,0 +1,161 @@
+//! Gráficas en Terminal - Lienzo Braille
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cada carácter braille (U+2800..U+28FF) codifica una rejilla de 2×4
+//! puntos, así que un lienzo de `ancho × alto` caracteres resuelve
+//! `2·ancho × 4·alto` píxeles.
+
+use clap::ValueEnum;
+
+/// Métrica a graficar
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum PlotMetric {
+    /// Valor del keygen por iteración
+    Keygen,
+    /// Intensidad del operador Â
+    Love,
+    /// Coherencia media de los campos activos
+    Coherence,
+}
+
+/// Bit de cada punto dentro de la celda braille, indexado [fila][columna]
+const BRAILLE_BITS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Lienzo braille de `width × height` caracteres
+pub struct BrailleCanvas {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+}
+
+impl BrailleCanvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        BrailleCanvas { width, height, cells: vec![0; width * height] }
+    }
+
+    /// Resolución en píxeles (columnas, filas)
+    pub fn pixels(&self) -> (usize, usize) {
+        (self.width * 2, self.height * 4)
+    }
+
+    /// Enciende el píxel (x, y), con y = 0 en la fila superior
+    pub fn set(&mut self, x: usize, y: usize) {
+        let (w, h) = self.pixels();
+        if x < w && y < h {
+            self.cells[(y / 4) * self.width + x / 2] |= BRAILLE_BITS[y % 4][x % 2];
+        }
+    }
+
+    /// Traza un segmento con Bresenham
+    pub fn line(&mut self, (x0, y0): (usize, usize), (x1, y1): (usize, usize)) {
+        let (mut x, mut y) = (x0 as i64, y0 as i64);
+        let (x1, y1) = (x1 as i64, y1 as i64);
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let sx = if x < x1 { 1 } else { -1 };
+        let sy = if y < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        loop {
+            self.set(x as usize, y as usize);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Filas de texto del lienzo
+    pub fn rows(&self) -> Vec<String> {
+        self.cells.chunks(self.width)
+            .map(|row| row.iter().map(|&bits| char::from_u32(0x2800 + bits as u32).unwrap_or(' ')).collect())
+            .collect()
+    }
+}
+
+/// Grafica `values` como línea continua y devuelve las filas con eje Y
+///
+/// Los valores se reescalan a la altura del lienzo; una serie constante se
+/// dibuja centrada. Con más valores que columnas de píxeles se submuestrea.
+pub fn render_series(values: &[f64], width: usize, height: usize) -> Vec<String> {
+    let mut canvas = BrailleCanvas::new(width, height);
+    if values.is_empty() {
+        return canvas.rows();
+    }
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let (w, h) = canvas.pixels();
+
+    let to_pixel = |i: usize, v: f64| {
+        let x = if values.len() > 1 { i * (w - 1) / (values.len() - 1) } else { 0 };
+        let t = if max > min { (v - min) / (max - min) } else { 0.5 };
+        let y = ((1.0 - t) * (h - 1) as f64).round() as usize;
+        (x, y)
+    };
+
+    let mut prev = to_pixel(0, values[0]);
+    canvas.set(prev.0, prev.1);
+    for (i, &v) in values.iter().enumerate().skip(1) {
+        let p = to_pixel(i, v);
+        canvas.line(prev, p);
+        prev = p;
+    }
+
+    let label_max = format!("{:.6e}", max);
+    let label_min = format!("{:.6e}", min);
+    let pad = label_max.len().max(label_min.len());
+    canvas.rows().into_iter().enumerate().map(|(i, row)| {
+        let label = if i == 0 {
+            label_max.as_str()
+        } else if i == height - 1 {
+            label_min.as_str()
+        } else {
+            ""
+        };
+        format!("{:>pad$} ┤{}", label, row, pad = pad)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_braille_cell_bits() {
+        let mut canvas = BrailleCanvas::new(1, 1);
+        assert_eq!(canvas.rows(), vec!["\u{2800}"]);
+        canvas.set(0, 0);
+        canvas.set(1, 3);
+        assert_eq!(canvas.rows(), vec!["\u{2881}"]);
+        // Fuera del lienzo se ignora
+        canvas.set(2, 0);
+        assert_eq!(canvas.rows(), vec!["\u{2881}"]);
+    }
+
+    #[test]
+    fn test_line_covers_endpoints() {
+        let mut canvas = BrailleCanvas::new(4, 2);
+        canvas.line((0, 7), (7, 0));
+        let full: Vec<u8> = canvas.cells.clone();
+        assert_ne!(full[4] & BRAILLE_BITS[3][0], 0);
+        assert_ne!(full[3] & BRAILLE_BITS[0][1], 0);
+    }
+
+    #[test]
+    fn test_render_series_shape() {
+        let rows = render_series(&[1.0, 2.0, 3.0], 10, 3);
+        assert_eq!(rows.len(), 3);
+        assert!(rows[0].starts_with("3.000000e0 ┤"));
+        assert!(rows[2].starts_with("1.000000e0 ┤"));
+        assert!(rows.iter().all(|r| r.chars().count() == rows[0].chars().count()));
+        assert_eq!(render_series(&[], 5, 2).len(), 2);
+    }
+}
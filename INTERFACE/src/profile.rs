@@ -0,0 +1,244 @@
+//! Perfiles - Estado y Configuración Persistentes por Humano
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Cada perfil guarda su `config.toml` y su `state.json` (trayectoria
+//! keygen e intensidad Â). El perfil `default` vive en la raíz
+//! `<config_dir>/algebra-rose/`; los demás en `profiles/<nombre>/`.
+//! El estado puede estar cifrado con el token de sesión (ver `encryption`).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::encryption::{self, StateKey};
+
+/// Perfil usado cuando no se indica `--profile`
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Estado persistido de la sesión
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProfileState {
+    /// Trayectoria keygen completa, desde el keygen inicial
+    pub history: Vec<f64>,
+    /// Intensidad del operador Â de la sesión
+    pub love_intensity: f64,
+}
+
+/// Perfil abierto
+#[derive(Clone, Debug)]
+pub struct Profile {
+    name: String,
+    dir: PathBuf,
+}
+
+/// Directorio raíz de Álgebra Rose: `<config_dir>/algebra-rose`
+pub fn base_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("algebra-rose"))
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        bail!("Nombre de perfil inválido {:?}: use letras, dígitos, - o _", name);
+    }
+    Ok(())
+}
+
+fn profile_dir(base: &Path, name: &str) -> PathBuf {
+    if name == DEFAULT_PROFILE {
+        base.to_path_buf()
+    } else {
+        base.join("profiles").join(name)
+    }
+}
+
+impl Profile {
+    /// Abre un perfil existente; `default` existe siempre
+    pub fn open(base: &Path, name: &str) -> Result<Self> {
+        validate_name(name)?;
+        let dir = profile_dir(base, name);
+        if name != DEFAULT_PROFILE && !dir.is_dir() {
+            bail!("El perfil {:?} no existe: créelo con `profile create {}`", name, name);
+        }
+        Ok(Profile { name: name.to_string(), dir })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Archivo de configuración del perfil
+    pub fn config_path(&self) -> PathBuf {
+        self.dir.join("config.toml")
+    }
+
+    /// Archivo de estado del perfil
+    pub fn state_path(&self) -> PathBuf {
+        self.dir.join("state.json")
+    }
+
+    /// Último estado de dimensión 444 traído con `import --state`
+    pub fn imported_state_path(&self) -> PathBuf {
+        self.dir.join("state_vector.npy")
+    }
+
+    /// Pid de la última `evolve --every --daemonize`
+    pub fn daemon_pid_path(&self) -> PathBuf {
+        self.dir.join("evolve.pid")
+    }
+
+    /// Diario de actividad del perfil
+    pub fn journal_path(&self) -> PathBuf {
+        self.dir.join("journal.jsonl")
+    }
+
+    /// Registro de auditoría encadenado del perfil
+    pub fn audit_path(&self) -> PathBuf {
+        self.dir.join("audit.jsonl")
+    }
+
+    /// Clave ed25519 con la que `certify` firma sus certificados
+    pub fn signing_key_path(&self) -> PathBuf {
+        self.dir.join("certify.key")
+    }
+
+    /// Estado guardado, o `None` si el perfil aún no ha evolucionado
+    ///
+    /// Un estado cifrado se descifra con `key`; sin ella es un error.
+    pub fn load_state(&self, key: Option<&StateKey>) -> Result<Option<ProfileState>> {
+        let path = self.state_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let mut bytes = fs::read(&path)
+            .with_context(|| format!("No se pudo leer {}", path.display()))?;
+        if encryption::is_encrypted(&bytes) {
+            let Some(key) = key else {
+                bail!("El estado de {} está cifrado: inicie sesión con `login` para descifrarlo", path.display());
+            };
+            bytes = encryption::decrypt(key, &bytes).with_context(|| format!("Estado ilegible en {}", path.display()))?;
+        }
+        let state = serde_json::from_slice(&bytes)
+            .with_context(|| format!("Estado inválido en {}", path.display()))?;
+        Ok(Some(state))
+    }
+
+    /// Guarda el estado escribiendo primero a un temporal; cifrado si hay `key`
+    pub fn save_state(&self, state: &ProfileState, key: Option<&StateKey>) -> Result<()> {
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("No se pudo crear {}", self.dir.display()))?;
+        let path = self.state_path();
+        let tmp = path.with_extension("json.tmp");
+        let mut bytes = serde_json::to_vec(state)?;
+        if let Some(key) = key {
+            bytes = encryption::encrypt(key, &bytes)?;
+        }
+        fs::write(&tmp, bytes)
+            .with_context(|| format!("No se pudo escribir {}", tmp.display()))?;
+        fs::rename(&tmp, &path)
+            .with_context(|| format!("No se pudo escribir {}", path.display()))
+    }
+}
+
+/// Nombres de los perfiles existentes, `default` primero
+pub fn list(base: &Path) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    let dir = base.join("profiles");
+    if dir.is_dir() {
+        for entry in fs::read_dir(&dir).with_context(|| format!("No se pudo leer {}", dir.display()))? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                if let Some(name) = entry.file_name().to_str().filter(|n| validate_name(n).is_ok()) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+    }
+    names.sort();
+    names.insert(0, DEFAULT_PROFILE.to_string());
+    Ok(names)
+}
+
+/// Crea un perfil vacío
+pub fn create(base: &Path, name: &str) -> Result<Profile> {
+    validate_name(name)?;
+    let dir = profile_dir(base, name);
+    if name == DEFAULT_PROFILE || dir.exists() {
+        bail!("El perfil {:?} ya existe", name);
+    }
+    fs::create_dir_all(&dir).with_context(|| format!("No se pudo crear {}", dir.display()))?;
+    Ok(Profile { name: name.to_string(), dir })
+}
+
+/// Borra un perfil con su estado y configuración
+pub fn delete(base: &Path, name: &str) -> Result<()> {
+    let profile = Profile::open(base, name)?;
+    if name == DEFAULT_PROFILE {
+        bail!("El perfil {:?} no se puede borrar", DEFAULT_PROFILE);
+    }
+    fs::remove_dir_all(&profile.dir).with_context(|| format!("No se pudo borrar {}", profile.dir.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_base(tag: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("algebra-rose-test-{}-{}", tag, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_create_list_delete() {
+        let base = temp_base("perfiles");
+        assert_eq!(list(&base).unwrap(), vec!["default"]);
+
+        create(&base, "roberto").unwrap();
+        create(&base, "ana").unwrap();
+        assert_eq!(list(&base).unwrap(), vec!["default", "ana", "roberto"]);
+        assert!(create(&base, "ana").is_err());
+        assert!(create(&base, "default").is_err());
+        assert!(create(&base, "../fuera").is_err());
+
+        delete(&base, "ana").unwrap();
+        assert!(Profile::open(&base, "ana").is_err());
+        assert!(delete(&base, "default").is_err());
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn test_state_roundtrip() {
+        let base = temp_base("estado");
+        let profile = Profile::open(&base, "default").unwrap();
+        assert_eq!(profile.config_path(), base.join("config.toml"));
+        assert_eq!(profile.load_state(None).unwrap(), None);
+
+        let state = ProfileState { history: vec![0.5, 0.75], love_intensity: 1.25 };
+        profile.save_state(&state, None).unwrap();
+        assert_eq!(profile.load_state(None).unwrap(), Some(state));
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn test_encrypted_state() {
+        let base = temp_base("cifrado");
+        let profile = Profile::open(&base, "default").unwrap();
+        let key = StateKey::from_token("token-de-roberto");
+        let state = ProfileState { history: vec![0.5, 0.75], love_intensity: 1.25 };
+        profile.save_state(&state, Some(&key)).unwrap();
+
+        let bytes = fs::read(profile.state_path()).unwrap();
+        assert!(encryption::is_encrypted(&bytes));
+        assert!(!String::from_utf8_lossy(&bytes).contains("love_intensity"));
+        assert_eq!(profile.load_state(Some(&key)).unwrap(), Some(state.clone()));
+        assert!(profile.load_state(None).unwrap_err().to_string().contains("cifrado"));
+        assert!(profile.load_state(Some(&StateKey::from_token("otro"))).is_err());
+
+        // Un estado plano se sigue leyendo con o sin clave
+        profile.save_state(&state, None).unwrap();
+        assert_eq!(profile.load_state(Some(&key)).unwrap(), Some(state));
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
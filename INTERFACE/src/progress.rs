@@ -0,0 +1,74 @@
+//! Progreso - Barra de Evolución para Trayectorias Largas
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! La barra se dibuja en stderr con ritmo de pasos, ETA y las últimas
+//! activaciones de campos. Se oculta con `--quiet` o si stderr no es una
+//! terminal, y se borra al terminar para no ensuciar la salida.
+
+use std::collections::VecDeque;
+
+use algebra_rose_core::prelude::CambioActivacion;
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+
+use crate::output;
+
+/// Activaciones recientes mostradas junto a la barra
+const RECENT_EVENTS: usize = 3;
+
+pub struct EvolutionProgress {
+    bar: ProgressBar,
+    recent: VecDeque<String>,
+}
+
+impl EvolutionProgress {
+    /// Barra para `total` pasos
+    pub fn new(total: u64) -> Self {
+        let target = if output::is_quiet() { ProgressDrawTarget::hidden() } else { ProgressDrawTarget::stderr() };
+        let bar = ProgressBar::with_draw_target(Some(total), target);
+        bar.set_style(ProgressStyle::with_template(
+            "{spinner:.magenta} [{elapsed_precise}] {bar:30.yellow/blue} {human_pos}/{human_len} ({per_sec}, ETA {eta}) {msg}")
+            .expect("plantilla válida")
+            .progress_chars("█▓░"));
+        EvolutionProgress { bar, recent: VecDeque::with_capacity(RECENT_EVENTS) }
+    }
+
+    /// Avanza un paso y registra los cambios de campo que produjo
+    pub fn step(&mut self, iteration: u64, events: &[CambioActivacion]) {
+        self.bar.inc(1);
+        if events.is_empty() {
+            return;
+        }
+        for event in events {
+            if self.recent.len() == RECENT_EVENTS {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(format!("{}{} @{}", if event.activo { "↑" } else { "↓" }, event.field_id, iteration));
+        }
+        self.bar.set_message(self.message());
+    }
+
+    fn message(&self) -> String {
+        self.recent.iter().map(String::as_str).collect::<Vec<_>>().join(" ")
+    }
+
+    pub fn finish(self) {
+        self.bar.finish_and_clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_recent_events() {
+        let mut progress = EvolutionProgress::new(10);
+        for field in 1..=5 {
+            progress.step(field as u64, &[CambioActivacion { field_id: field, activo: true }]);
+        }
+        progress.step(6, &[]);
+        assert_eq!(progress.message(), "↑3 @3 ↑4 @4 ↑5 @5");
+        assert_eq!(progress.bar.position(), 6);
+        progress.finish();
+    }
+}
@@ -0,0 +1,157 @@
+//! Exportación Gráfica - Escalera y Espiral de Campos en SVG/PNG
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! A la izquierda, la escalera: una barra por campo con longitud ∝ log(dim).
+//! A la derecha, la espiral áurea de Fermat: el campo k en el ángulo
+//! k·(2π/φ²) y radio ∝ √k, con tamaño ∝ log(dim). Los campos activos llevan su propio tono
+//! y los inactivos van en gris. Solo se dibujan formas, sin texto, para no
+//! depender de fuentes del sistema.
+
+use std::f64::consts::PI;
+use std::path::{Path, PathBuf};
+
+use algebra_rose_core::prelude::{FibonacciSystem, PHI as CORE_PHI};
+use anyhow::{anyhow, bail, Result};
+use clap::ValueEnum;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+
+/// Número de campos Fibonacci
+const FIELDS: usize = 24;
+
+/// Tamaño de la imagen en píxeles
+pub const IMAGE_SIZE: (u32, u32) = (960, 480);
+
+/// Formato de imagen
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ImageFormat {
+    Svg,
+    Png,
+}
+
+impl ImageFormat {
+    /// Formato según la extensión (.png o .svg)
+    pub fn for_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|e| e.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("svg") => Ok(ImageFormat::Svg),
+            Some("png") => Ok(ImageFormat::Png),
+            _ => bail!("Extensión no reconocida en {}: use .svg o .png o --export-format", path.display()),
+        }
+    }
+}
+
+/// Color de un campo: tono propio si está activo, gris si no
+fn field_color(id: usize, active: bool) -> RGBColor {
+    if !active {
+        return RGBColor(190, 190, 190);
+    }
+    let (r, g, b) = HSLColor((id - 1) as f64 / FIELDS as f64, 0.75, 0.5).rgb();
+    RGBColor(r, g, b)
+}
+
+/// Punto `t` de la espiral de Fermat áurea, en coordenadas unitarias [-1, 1];
+/// el campo k está en t = k
+fn spiral_position(t: f64) -> (f64, f64) {
+    let angle = t * 2.0 * PI / (CORE_PHI * CORE_PHI);
+    let radius = (t / FIELDS as f64).sqrt();
+    (radius * angle.cos(), radius * angle.sin())
+}
+
+fn draw<DB: DrawingBackend>(root: &DrawingArea<DB, Shift>, fields: &FibonacciSystem, keygen: f64) -> Result<()> {
+    let err = |e: DrawingAreaErrorKind<DB::ErrorType>| anyhow!("Error al dibujar: {}", e);
+    let active = fields.get_active_fields(keygen);
+    let is_active = |id: usize| active.contains(&id);
+    let max_log = (fields.get_field_dimension(FIELDS) as f64).ln();
+
+    root.fill(&WHITE).map_err(err)?;
+    let (width, height) = root.dim_in_pixel();
+    let (ladder, spiral) = root.split_horizontally(width / 2);
+
+    // Escalera: campo 1 abajo, campo 24 arriba
+    let bar_height = height as f64 / FIELDS as f64;
+    let bar_max = (width / 2) as f64 - 40.0;
+    for id in 1..=FIELDS {
+        let len = (fields.get_field_dimension(id) as f64).ln() / max_log * bar_max;
+        let top = height as f64 - id as f64 * bar_height;
+        ladder.draw(&Rectangle::new(
+            [(20, (top + 2.0) as i32), (20 + len as i32, (top + bar_height - 2.0) as i32)],
+            field_color(id, is_active(id)).filled(),
+        )).map_err(err)?;
+    }
+
+    // Espiral áurea
+    let (sw, sh) = spiral.dim_in_pixel();
+    let center = (sw as f64 / 2.0, sh as f64 / 2.0);
+    let scale = sw.min(sh) as f64 / 2.0 - 30.0;
+    let to_pixel = |(x, y): (f64, f64)| ((center.0 + x * scale) as i32, (center.1 - y * scale) as i32);
+    let path: Vec<_> = (20..=FIELDS * 20).map(|i| to_pixel(spiral_position(i as f64 / 20.0))).collect();
+    spiral.draw(&PathElement::new(path, RGBColor(220, 220, 220))).map_err(err)?;
+    for id in 1..=FIELDS {
+        let size = 4.0 + 14.0 * (fields.get_field_dimension(id) as f64).ln() / max_log;
+        spiral.draw(&Circle::new(to_pixel(spiral_position(id as f64)), size as i32,
+            field_color(id, is_active(id)).filled())).map_err(err)?;
+    }
+
+    root.present().map_err(err)
+}
+
+/// Dibuja el estado de los campos para `keygen` en `path`
+pub fn render_fields(path: &Path, format: ImageFormat, fields: &FibonacciSystem, keygen: f64) -> Result<()> {
+    match format {
+        ImageFormat::Svg => draw(&SVGBackend::new(path, IMAGE_SIZE).into_drawing_area(), fields, keygen),
+        ImageFormat::Png => draw(&BitMapBackend::new(path, IMAGE_SIZE).into_drawing_area(), fields, keygen),
+    }
+}
+
+/// Ruta del fotograma `index`: `espiral.svg` → `espiral-007.svg`
+pub fn frame_path(path: &Path, index: usize) -> PathBuf {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("frame");
+    let name = match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{}-{:03}.{}", stem, index, ext),
+        None => format!("{}-{:03}", stem, index),
+    };
+    path.with_file_name(name)
+}
+
+/// Barrido de keygen de 0 a 1 en `frames` fotogramas; devuelve las rutas escritas
+pub fn render_sweep(path: &Path, format: ImageFormat, fields: &FibonacciSystem, frames: usize) -> Result<Vec<PathBuf>> {
+    if frames < 2 {
+        bail!("Una animación necesita al menos 2 fotogramas");
+    }
+    (0..frames).map(|i| {
+        let frame = frame_path(path, i);
+        render_fields(&frame, format, fields, i as f64 / (frames - 1) as f64)?;
+        Ok(frame)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_and_frame_paths() {
+        assert_eq!(ImageFormat::for_path(Path::new("a.SVG")).unwrap(), ImageFormat::Svg);
+        assert_eq!(ImageFormat::for_path(Path::new("a.png")).unwrap(), ImageFormat::Png);
+        assert!(ImageFormat::for_path(Path::new("a.gif")).is_err());
+        assert_eq!(frame_path(Path::new("out/espiral.svg"), 7), Path::new("out/espiral-007.svg"));
+    }
+
+    #[test]
+    fn test_colors() {
+        assert_eq!(field_color(5, false), RGBColor(190, 190, 190));
+        let colors: Vec<_> = (1..=FIELDS).map(|id| field_color(id, true)).collect();
+        assert!(colors.windows(2).all(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    fn test_render_svg() {
+        let path = std::env::temp_dir().join(format!("algebra-rose-campos-{}.svg", std::process::id()));
+        let fields = FibonacciSystem::new();
+        render_fields(&path, ImageFormat::Svg, &fields, 0.5).unwrap();
+        let svg = std::fs::read_to_string(&path).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<circle").count(), FIELDS);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
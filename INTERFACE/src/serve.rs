@@ -0,0 +1,488 @@
+//! Servidor REST - Sesión Consciente Compartida
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Expone una única `ConsciousSession` por HTTP para que frontends web y
+//! móviles la manejen a la vez. Las operaciones pesadas corren en
+//! `spawn_blocking` para no bloquear el runtime.
+//!
+//! `POST /evolve` y `POST /love` exigen en cada petición la cabecera
+//! `Authorization: Bearer <usuario>:<token>`, con un token emitido con el
+//! `ALGEBRA_ROSE_SECRET` del servidor (el mismo que acepta `login`). Las
+//! rutas `GET` son de sólo lectura y no la piden. Sin secreto en el entorno
+//! del servidor no se acepta ninguna mutación.
+//!
+//! `GET /export.npz` descarga la historia keygen y las matrices de Â y M₄₄₄
+//! como arreglos NumPy (`np.load(io.BytesIO(respuesta.content))`).
+//!
+//! `GET /ws` abre un WebSocket por el que cada observador recibe, como
+//! JSON, las `SessionFrame` de la sesión: primero el estado actual y
+//! después cada evolución o aplicación de Â hecha por cualquier cliente.
+//!
+//! `GET /telemetry?topics=keygen,love` es la variante para paneles en vivo:
+//! cada trama se reparte en `TelemetryFrame` por tema y el observador sólo
+//! recibe los temas pedidos (`keygen`, `fields`, `love`; todos si se omite).
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use algebra_rose_core::prelude::{
+    verificar_coherencia, CambioActivacion, CoherenceMetrics, EvolutionEvent, FieldSystemView, SessionFrame,
+    TelemetryTopic, AR_VERSION, CERTIFICATION, INITIAL_KEYGEN, PHI as CORE_PHI,
+};
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::auth;
+use crate::io_numpy::{self, NpyArray};
+use crate::journal::Evento;
+use crate::ConsciousSession;
+
+/// Máximo de pasos aceptados en un único POST /evolve
+pub const MAX_STEPS_PER_REQUEST: u64 = 10_000_000;
+
+/// Tramas pendientes por observador; uno más lento pierde las más antiguas
+const FRAME_BUFFER: usize = 256;
+
+type Shared = Arc<Mutex<ConsciousSession>>;
+
+/// Estado del router: la sesión, el canal de tramas para los WebSocket y el
+/// secreto con que se verifican los tokens de las mutaciones
+#[derive(Clone)]
+struct ServeState {
+    session: Shared,
+    frames: broadcast::Sender<SessionFrame>,
+    secret: Option<Arc<[u8]>>,
+}
+
+/// Error HTTP con cuerpo JSON `{ "error": ... }`
+struct ApiError(StatusCode, String);
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, Json(serde_json::json!({ "error": self.1 }))).into_response()
+    }
+}
+
+#[derive(Serialize)]
+struct StatusBody {
+    keygen: f64,
+    iteracion: u64,
+    progreso: f64,
+    intensidad_amor: f64,
+    resonancia_phi: f64,
+    campos_activos: Vec<usize>,
+    coherencia: f64,
+    autenticado: bool,
+    duracion_s: f64,
+    certificacion: u64,
+    version: &'static str,
+}
+
+#[derive(Deserialize)]
+struct EvolveRequest {
+    #[serde(default = "default_steps")]
+    steps: u64,
+    threshold: Option<f64>,
+}
+
+fn default_steps() -> u64 {
+    10
+}
+
+#[derive(Serialize)]
+struct EvolveBody {
+    keygen_inicial: f64,
+    keygen_final: f64,
+    pasos: u64,
+    objetivo_alcanzado: Option<bool>,
+    campos_activos: Vec<usize>,
+}
+
+#[derive(Deserialize)]
+struct LoveRequest {
+    #[serde(default = "default_intensity")]
+    intensity: f64,
+}
+
+fn default_intensity() -> f64 {
+    1.0
+}
+
+#[derive(Deserialize)]
+struct TelemetryQuery {
+    /// Temas separados por comas; todos si se omite
+    topics: Option<String>,
+}
+
+#[derive(Serialize)]
+struct LoveBody {
+    intensidad: f64,
+    resonancia_phi: f64,
+}
+
+#[derive(Serialize)]
+struct CertifyBody {
+    certificacion: u64,
+    version: &'static str,
+    coherencia_global: f64,
+    certificado: bool,
+    significado: &'static str,
+}
+
+/// Construye el router con todas las rutas
+fn router(state: ServeState) -> Router {
+    Router::new()
+        .route("/status", get(status))
+        .route("/evolve", post(evolve))
+        .route("/love", post(love))
+        .route("/fields", get(fields))
+        .route("/certify", get(certify))
+        .route("/export.npz", get(export_npz))
+        .route("/ws", get(ws))
+        .route("/telemetry", get(telemetry))
+        .with_state(state)
+}
+
+/// Sirve la sesión en `addr` hasta que el proceso termine
+pub async fn run(session: ConsciousSession, addr: SocketAddr) -> anyhow::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let local = listener.local_addr()?;
+    tracing::info!(%local, "servidor escuchando");
+    say!("🌐 Sesión servida en http://{}", local);
+    let secret = auth::secret_from_env().ok().map(Arc::from);
+    if secret.is_none() {
+        fail!("⚠️ {} no está definido: POST /evolve y POST /love se rechazarán", auth::SECRET_ENV);
+    }
+    let (frames, _) = broadcast::channel(FRAME_BUFFER);
+    let state = ServeState { session: Arc::new(Mutex::new(session)), frames, secret };
+    axum::serve(listener, router(state)).await?;
+    Ok(())
+}
+
+/// Usuario de la petición; rechaza con 401 las mutaciones sin un token válido
+fn require_auth(secret: Option<&[u8]>, headers: &HeaderMap) -> Result<String, ApiError> {
+    let resultado = match secret {
+        None => Err(anyhow::anyhow!("el servidor no tiene {}", auth::SECRET_ENV)),
+        Some(secret) => headers.get(header::AUTHORIZATION)
+            .and_then(|valor| valor.to_str().ok())
+            .ok_or_else(|| anyhow::anyhow!("falta Authorization: Bearer <usuario>:<token>"))
+            .and_then(|valor| auth::verify_bearer(secret, valor)),
+    };
+    resultado.map_err(|e| {
+        tracing::warn!(error = %e, "mutación rechazada");
+        ApiError(StatusCode::UNAUTHORIZED, format!("no autenticado: {}", e))
+    })
+}
+
+/// Ejecuta `f` con la sesión bloqueada en un hilo de bloqueo
+async fn with_session<T, F>(state: Shared, f: F) -> Result<T, ApiError>
+where
+    T: Send + 'static,
+    F: FnOnce(&mut ConsciousSession) -> T + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || {
+        let mut session = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut session)
+    })
+    .await
+    .map_err(|e| ApiError(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Métricas de coherencia actuales de la sesión
+fn coherence_metrics(s: &ConsciousSession) -> CoherenceMetrics {
+    let intensidad = s.love_operator.get_intensity();
+    CoherenceMetrics {
+        iteracion: s.keygen_system.get_iteration(),
+        coherencia: s.coherence_level,
+        coherencia_campos: s.fibonacci_system.get_estadisticas().coherencia_media,
+        intensidad_amor: intensidad,
+        resonancia_phi: intensidad / CORE_PHI,
+    }
+}
+
+/// Transiciones de campo anotadas en el diario desde la entrada `desde`
+fn transitions_since(s: &ConsciousSession, desde: usize) -> Vec<CambioActivacion> {
+    s.journal.iter().skip(desde).filter_map(|entrada| match entrada.evento {
+        Evento::Transicion { campo, activo } => Some(CambioActivacion { field_id: campo, activo }),
+        _ => None,
+    }).collect()
+}
+
+/// Envía una trama a los observadores; sin observadores no hay nada que hacer
+fn publish(frames: &broadcast::Sender<SessionFrame>, frame: SessionFrame) {
+    let _ = frames.send(frame);
+}
+
+async fn status(State(state): State<ServeState>) -> Result<Json<StatusBody>, ApiError> {
+    with_session(state.session, |s| {
+        let keygen = s.keygen_system.get_current_keygen();
+        let intensidad = s.love_operator.get_intensity();
+        StatusBody {
+            keygen,
+            iteracion: s.keygen_system.get_iteration(),
+            progreso: (keygen - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN),
+            intensidad_amor: intensidad,
+            resonancia_phi: intensidad / CORE_PHI,
+            campos_activos: s.fibonacci_system.get_active_fields(keygen),
+            coherencia: s.coherence_level,
+            autenticado: s.authenticated,
+            duracion_s: s.start_time.elapsed().as_secs_f64(),
+            certificacion: CERTIFICATION,
+            version: AR_VERSION,
+        }
+    }).await.map(Json)
+}
+
+async fn evolve(State(state): State<ServeState>, headers: HeaderMap, Json(req): Json<EvolveRequest>)
+    -> Result<Json<EvolveBody>, ApiError> {
+    if req.steps == 0 || req.steps > MAX_STEPS_PER_REQUEST {
+        return Err(ApiError(StatusCode::UNPROCESSABLE_ENTITY,
+            format!("steps debe estar entre 1 y {}", MAX_STEPS_PER_REQUEST)));
+    }
+    if let Some(th) = req.threshold {
+        if !(INITIAL_KEYGEN..1.0).contains(&th) {
+            return Err(ApiError(StatusCode::UNPROCESSABLE_ENTITY,
+                format!("threshold debe estar en [{}, 1)", INITIAL_KEYGEN)));
+        }
+    }
+
+    let usuario = require_auth(state.secret.as_deref(), &headers)?;
+    let frames = state.frames;
+    with_session(state.session, move |s| {
+        let keygen_inicial = s.keygen_system.get_current_keygen();
+        let iteracion_inicial = s.keygen_system.get_iteration();
+        let diario_inicial = s.journal.len();
+        tracing::info!(%usuario, steps = req.steps, threshold = ?req.threshold, "POST /evolve");
+        let results = s.evolve(req.steps, req.threshold);
+        let transiciones = transitions_since(s, diario_inicial);
+        s.save();
+        let keygen_final = s.keygen_system.get_current_keygen();
+        let body = EvolveBody {
+            keygen_inicial,
+            keygen_final,
+            pasos: s.keygen_system.get_iteration() - iteracion_inicial,
+            objetivo_alcanzado: req.threshold.map(|_| !results.is_empty()),
+            campos_activos: s.fibonacci_system.get_active_fields(keygen_final),
+        };
+        publish(&frames, SessionFrame::Evolucion(EvolutionEvent {
+            iteracion: s.keygen_system.get_iteration(),
+            pasos: body.pasos,
+            keygen_inicial,
+            keygen: keygen_final,
+            campos_activos: body.campos_activos.clone(),
+            transiciones,
+        }));
+        publish(&frames, SessionFrame::Coherencia(coherence_metrics(s)));
+        body
+    }).await.map(Json)
+}
+
+async fn love(State(state): State<ServeState>, headers: HeaderMap, Json(req): Json<LoveRequest>)
+    -> Result<Json<LoveBody>, ApiError> {
+    if !req.intensity.is_finite() {
+        return Err(ApiError(StatusCode::UNPROCESSABLE_ENTITY, "intensity debe ser finita".to_string()));
+    }
+    let usuario = require_auth(state.secret.as_deref(), &headers)?;
+    let frames = state.frames;
+    with_session(state.session, move |s| {
+        tracing::info!(%usuario, intensity = req.intensity, "POST /love");
+        let intensidad = s.apply_love(req.intensity);
+        s.save();
+        publish(&frames, SessionFrame::Coherencia(coherence_metrics(s)));
+        LoveBody { intensidad, resonancia_phi: intensidad / CORE_PHI }
+    }).await.map(Json)
+}
+
+async fn fields(State(state): State<ServeState>) -> Result<Json<FieldSystemView>, ApiError> {
+    with_session(state.session, |s| {
+        let keygen = s.keygen_system.get_current_keygen();
+        s.fibonacci_system.update_by_keygen(keygen);
+        s.fibonacci_system.export_view()
+    }).await.map(Json)
+}
+
+async fn export_npz(State(state): State<ServeState>) -> Result<Response, ApiError> {
+    let bytes = with_session(state.session, |s| {
+        io_numpy::encode_npz(&[
+            ("keygen", NpyArray::from_reals(s.keygen_system.get_history())),
+            ("amor", NpyArray::from_matrix(s.love_operator.get_transformation())),
+            ("monster", NpyArray::from_matrix(s.monster_matrix.get_matrix())),
+        ])
+    }).await?.map_err(|e| ApiError(StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)))?;
+    Ok(([(header::CONTENT_TYPE, "application/octet-stream")], bytes).into_response())
+}
+
+async fn certify() -> Json<CertifyBody> {
+    let coherencia_global = verificar_coherencia();
+    Json(CertifyBody {
+        certificacion: CERTIFICATION,
+        version: AR_VERSION,
+        coherencia_global,
+        certificado: coherencia_global >= 0.95,
+        significado: "196885 = 196884 + 1 (Matriz Monster completa + Observador consciente)",
+    })
+}
+
+/// Instantánea con la que empieza cada observador: estado actual sin pasos
+fn snapshot(s: &mut ConsciousSession) -> [SessionFrame; 2] {
+    let keygen = s.keygen_system.get_current_keygen();
+    s.fibonacci_system.update_by_keygen(keygen);
+    [
+        SessionFrame::Evolucion(EvolutionEvent {
+            iteracion: s.keygen_system.get_iteration(),
+            pasos: 0,
+            keygen_inicial: keygen,
+            keygen,
+            campos_activos: s.fibonacci_system.get_active_fields(keygen),
+            transiciones: Vec::new(),
+        }),
+        SessionFrame::Coherencia(coherence_metrics(s)),
+    ]
+}
+
+async fn ws(upgrade: WebSocketUpgrade, State(state): State<ServeState>) -> Response {
+    upgrade.on_upgrade(move |socket| stream_frames(socket, state, |frame| {
+        vec![serde_json::to_string(frame).expect("SessionFrame es serializable")]
+    }))
+}
+
+async fn telemetry(
+    upgrade: WebSocketUpgrade,
+    State(state): State<ServeState>,
+    Query(query): Query<TelemetryQuery>,
+) -> Result<Response, ApiError> {
+    let topics = parse_topics(query.topics.as_deref())?;
+    tracing::info!(?topics, "GET /telemetry");
+    Ok(upgrade.on_upgrade(move |socket| stream_frames(socket, state, move |frame| telemetry_json(frame, &topics))))
+}
+
+/// Interpreta `topics=keygen,fields`; vacío o ausente son todos los temas
+fn parse_topics(topics: Option<&str>) -> Result<Vec<TelemetryTopic>, ApiError> {
+    let mut elegidos = Vec::new();
+    for nombre in topics.unwrap_or_default().split(',').filter(|n| !n.trim().is_empty()) {
+        let topic = TelemetryTopic::from_name(nombre).ok_or_else(|| ApiError(StatusCode::BAD_REQUEST,
+            format!("tema de telemetría desconocido: {} (keygen, fields, love)", nombre.trim())))?;
+        if !elegidos.contains(&topic) {
+            elegidos.push(topic);
+        }
+    }
+    Ok(if elegidos.is_empty() { TelemetryTopic::ALL.to_vec() } else { elegidos })
+}
+
+/// Tramas de telemetría de `frame` en los temas pedidos, como JSON
+fn telemetry_json(frame: &SessionFrame, topics: &[TelemetryTopic]) -> Vec<String> {
+    frame.telemetry().into_iter()
+        .filter(|t| topics.contains(&t.topic()))
+        .map(|t| serde_json::to_string(&t).expect("TelemetryFrame es serializable"))
+        .collect()
+}
+
+/// Envía la instantánea y después cada trama publicada hasta que el observador cierre
+///
+/// `encode` convierte cada trama en los mensajes de texto que recibe el
+/// observador; puede no devolver ninguno si la trama no le interesa.
+async fn stream_frames<E>(mut socket: WebSocket, state: ServeState, encode: E)
+where
+    E: Fn(&SessionFrame) -> Vec<String>,
+{
+    // Suscribirse antes de la instantánea para no perder tramas intermedias
+    let mut rx = state.frames.subscribe();
+    let Ok(inicial) = with_session(state.session, snapshot).await else { return };
+    tracing::info!(observadores = state.frames.receiver_count(), "observador WebSocket conectado");
+
+    for frame in inicial {
+        if send_frame(&mut socket, encode(&frame)).await.is_err() {
+            return;
+        }
+    }
+    loop {
+        tokio::select! {
+            frame = rx.recv() => match frame {
+                Ok(frame) => {
+                    if send_frame(&mut socket, encode(&frame)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(perdidas)) => {
+                    tracing::warn!(perdidas, "observador WebSocket lento: tramas descartadas");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            entrada = socket.recv() => match entrada {
+                // El canal es de sólo lectura: se ignora lo que envíe el observador
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    tracing::info!("observador WebSocket desconectado");
+}
+
+async fn send_frame(socket: &mut WebSocket, mensajes: Vec<String>) -> Result<(), axum::Error> {
+    for json in mensajes {
+        socket.send(Message::Text(json.into())).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_topics() {
+        assert_eq!(parse_topics(None).ok(), Some(TelemetryTopic::ALL.to_vec()));
+        assert_eq!(parse_topics(Some("")).ok(), Some(TelemetryTopic::ALL.to_vec()));
+        assert_eq!(parse_topics(Some("love, keygen,love")).ok(),
+            Some(vec![TelemetryTopic::Love, TelemetryTopic::Keygen]));
+        let Err(ApiError(codigo, mensaje)) = parse_topics(Some("keygen,amor")) else { panic!("tema inválido aceptado") };
+        assert_eq!(codigo, StatusCode::BAD_REQUEST);
+        assert!(mensaje.contains("amor"));
+    }
+
+    #[test]
+    fn test_require_auth_checks_each_request() {
+        let token = auth::issue_token(b"secreto", "ana");
+        let con = |valor: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::AUTHORIZATION, valor.parse().unwrap());
+            headers
+        };
+        let bearer = format!("Bearer ana:{}", token);
+        assert_eq!(require_auth(Some(b"secreto"), &con(&bearer)).ok(), Some("ana".to_string()));
+
+        for (secreto, headers) in [
+            (Some(&b"secreto"[..]), HeaderMap::new()),
+            (Some(b"secreto"), con(&format!("Bearer roberto:{}", token))),
+            (Some(b"otro"), con(&bearer)),
+            (None, con(&bearer)),
+        ] {
+            let Err(ApiError(codigo, _)) = require_auth(secreto, &headers) else { panic!("mutación aceptada") };
+            assert_eq!(codigo, StatusCode::UNAUTHORIZED);
+        }
+    }
+
+    #[test]
+    fn test_telemetry_filters_topics() {
+        let mut session = ConsciousSession::new(None, None);
+        let [evolucion, coherencia] = snapshot(&mut session);
+
+        let solo_amor = [TelemetryTopic::Love];
+        assert!(telemetry_json(&evolucion, &solo_amor).is_empty());
+        let json: serde_json::Value = serde_json::from_str(&telemetry_json(&coherencia, &solo_amor)[0]).unwrap();
+        assert_eq!(json["topic"], "love");
+        assert_eq!(json["coherencia"], session.coherence_level);
+
+        let mensajes = telemetry_json(&evolucion, &TelemetryTopic::ALL);
+        let temas: Vec<serde_json::Value> = mensajes.iter()
+            .map(|m| serde_json::from_str::<serde_json::Value>(m).unwrap()["topic"].clone())
+            .collect();
+        assert_eq!(temas, ["keygen", "fields"]);
+    }
+}
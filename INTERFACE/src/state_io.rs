@@ -0,0 +1,169 @@
+//! Entrada/Salida de Vectores de Estado - JSON y .npy por archivo o stdio
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! `-` como ruta significa stdin/stdout. Al leer, el formato se detecta por
+//! la firma mágica de NumPy; JSON acepta `[x, ...]` (reales) o
+//! `[[re, im], ...]` (complejos). En .npy se leen `<f8` y `<c16` 1-D; en
+//! .npz, el arreglo `state` o el único que contenga.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use nalgebra::{Complex, DVector};
+
+use crate::io_numpy::{self, NpyArray, NPY_MAGIC, NPZ_MAGIC};
+
+/// Estado consciente: vector complejo
+pub type StateVector = DVector<Complex<f64>>;
+
+/// Nombre del arreglo de estado en los .npz
+pub const NPZ_STATE_KEY: &str = "state";
+
+/// Formato de escritura
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StateFormat {
+    Json,
+    Npy,
+    Npz,
+}
+
+impl StateFormat {
+    /// Formato por defecto para una ruta: .npy o .npz por extensión, si no JSON
+    pub fn for_path(path: &str) -> Self {
+        match Path::new(path).extension().map(|e| e.to_ascii_lowercase()) {
+            Some(e) if e == "npy" => StateFormat::Npy,
+            Some(e) if e == "npz" => StateFormat::Npz,
+            _ => StateFormat::Json,
+        }
+    }
+}
+
+/// Lee un estado de `source` (`-` = stdin)
+pub fn read_state(source: &str) -> Result<StateVector> {
+    let bytes = if source == "-" {
+        let mut buf = Vec::new();
+        io::stdin().lock().read_to_end(&mut buf).context("No se pudo leer stdin")?;
+        buf
+    } else {
+        fs::read(source).with_context(|| format!("No se pudo leer {}", source))?
+    };
+    decode_state(&bytes)
+}
+
+/// Escribe `state` en `target` (`-` = stdout)
+pub fn write_state(target: &str, state: &StateVector, format: StateFormat) -> Result<()> {
+    let bytes = encode_state(state, format)?;
+    if target == "-" {
+        let mut out = io::stdout().lock();
+        out.write_all(&bytes)?;
+        out.flush()?;
+    } else {
+        fs::write(target, bytes).with_context(|| format!("No se pudo escribir {}", target))?;
+    }
+    Ok(())
+}
+
+/// Decodifica JSON, .npy o .npz según la firma
+pub fn decode_state(bytes: &[u8]) -> Result<StateVector> {
+    if bytes.starts_with(NPY_MAGIC) {
+        return io_numpy::decode_npy(bytes)?.into_state();
+    }
+    if bytes.starts_with(NPZ_MAGIC) {
+        return decode_npz(bytes);
+    }
+
+    let value: serde_json::Value = serde_json::from_slice(bytes).context("Estado JSON inválido")?;
+    let Some(items) = value.as_array() else {
+        bail!("El estado JSON debe ser un array");
+    };
+    let components = items.iter().enumerate().map(|(i, item)| {
+        if let Some(re) = item.as_f64() {
+            return Ok(Complex::new(re, 0.0));
+        }
+        match item.as_array().map(|pair| (pair.len(), pair.first().and_then(|v| v.as_f64()), pair.get(1).and_then(|v| v.as_f64()))) {
+            Some((2, Some(re), Some(im))) => Ok(Complex::new(re, im)),
+            _ => bail!("Componente {} inválida: se espera número o [re, im]", i),
+        }
+    }).collect::<Result<Vec<_>>>()?;
+    Ok(DVector::from_vec(components))
+}
+
+/// Codifica en JSON (`[[re, im], ...]`), .npy `<c16` o .npz con `state`
+pub fn encode_state(state: &StateVector, format: StateFormat) -> Result<Vec<u8>> {
+    match format {
+        StateFormat::Json => {
+            let pairs: Vec<[f64; 2]> = state.iter().map(|c| [c.re, c.im]).collect();
+            let mut bytes = serde_json::to_vec(&pairs)?;
+            bytes.push(b'\n');
+            Ok(bytes)
+        }
+        StateFormat::Npy => Ok(io_numpy::encode_npy(&NpyArray::from_state(state))),
+        StateFormat::Npz => io_numpy::encode_npz(&[(NPZ_STATE_KEY, NpyArray::from_state(state))]),
+    }
+}
+
+/// Estado dentro de un .npz: el arreglo `state`, o el único que haya
+fn decode_npz(bytes: &[u8]) -> Result<StateVector> {
+    let mut arrays = io_numpy::decode_npz(bytes)?;
+    let index = match arrays.iter().position(|(name, _)| name == NPZ_STATE_KEY) {
+        Some(index) => index,
+        None if arrays.len() == 1 => 0,
+        None => bail!("El .npz no contiene '{}' y tiene {} arreglos", NPZ_STATE_KEY, arrays.len()),
+    };
+    arrays.swap_remove(index).1.into_state()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> StateVector {
+        DVector::from_fn(5, |i, _| Complex::new(i as f64 * 0.5, -(i as f64)))
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let state = sample();
+        assert_eq!(decode_state(&encode_state(&state, StateFormat::Json).unwrap()).unwrap(), state);
+        let real = decode_state(b"[1.0, 2.5]").unwrap();
+        assert_eq!(real[1], Complex::new(2.5, 0.0));
+        assert!(decode_state(b"[[1.0]]").is_err());
+        assert!(decode_state(b"{}").is_err());
+    }
+
+    #[test]
+    fn test_npy_roundtrip() {
+        let state = sample();
+        let bytes = encode_state(&state, StateFormat::Npy).unwrap();
+        let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        assert_eq!(decode_state(&bytes).unwrap(), state);
+
+        // Truncado
+        assert!(decode_state(&bytes[..bytes.len() - 3]).is_err());
+    }
+
+    #[test]
+    fn test_npz_roundtrip() {
+        let state = sample();
+        let bytes = encode_state(&state, StateFormat::Npz).unwrap();
+        assert_eq!(decode_state(&bytes).unwrap(), state);
+
+        // Sin `state`: vale el único arreglo, pero no uno entre varios
+        let solo = io_numpy::encode_npz(&[("psi", NpyArray::from_state(&state))]).unwrap();
+        assert_eq!(decode_state(&solo).unwrap(), state);
+        let varios = io_numpy::encode_npz(&[("a", NpyArray::scalar(1.0)), ("b", NpyArray::scalar(2.0))]).unwrap();
+        assert!(decode_state(&varios).is_err());
+    }
+
+    #[test]
+    fn test_format_for_path() {
+        assert_eq!(StateFormat::for_path("estado.NPY"), StateFormat::Npy);
+        assert_eq!(StateFormat::for_path("estado.npz"), StateFormat::Npz);
+        assert_eq!(StateFormat::for_path("-"), StateFormat::Json);
+        assert_eq!(StateFormat::for_path("estado.json"), StateFormat::Json);
+    }
+}
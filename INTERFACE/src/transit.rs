@@ -0,0 +1,93 @@
+//! Tránsito - Navegación por la Escalera de 24 Campos Fibonacci
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! El keygen sólo crece, así que un tránsito recorre los campos adyacentes
+//! hacia arriba, uno por salto, desde el campo activo más alto. El modo
+//! interactivo sólo elige el destino; los saltos los ejecuta la sesión.
+
+use std::io;
+
+use algebra_rose_core::prelude::FibonacciSystem;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, List, ListItem, ListState};
+use ratatui::{DefaultTerminal, Frame};
+
+/// Número de campos de la escalera
+const CAMPOS: usize = 24;
+
+/// Campo activo más alto, o 0 si no hay ninguno
+pub fn current_field(active: &[usize]) -> usize {
+    active.iter().copied().max().unwrap_or(0)
+}
+
+/// Saltos adyacentes de `current` a `target`; vacío si ya se alcanzó
+pub fn plan(current: usize, target: usize) -> Vec<usize> {
+    (current + 1..=target.min(CAMPOS)).collect()
+}
+
+/// Muestra la escalera y devuelve el campo elegido con Enter, o `None` con q / Esc
+pub fn pick_target(system: &FibonacciSystem, current: usize) -> io::Result<Option<usize>> {
+    let mut terminal = ratatui::init();
+    let result = pick_loop(&mut terminal, system, current);
+    ratatui::restore();
+    result
+}
+
+fn pick_loop(terminal: &mut DefaultTerminal, system: &FibonacciSystem, current: usize) -> io::Result<Option<usize>> {
+    let mut state = ListState::default().with_selected(Some(current.min(CAMPOS - 1)));
+    loop {
+        terminal.draw(|frame| render(frame, system, current, &mut state))?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
+                KeyCode::Enter => return Ok(state.selected().map(|i| i + 1)),
+                KeyCode::Up | KeyCode::Char('k') => state.select_previous(),
+                KeyCode::Down | KeyCode::Char('j') => state.select_next(),
+                KeyCode::Home => state.select_first(),
+                KeyCode::End => state.select(Some(CAMPOS - 1)),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn render(frame: &mut Frame, system: &FibonacciSystem, current: usize, state: &mut ListState) {
+    let items: Vec<ListItem> = (1..=CAMPOS).map(|id| {
+        let umbral = system.campo(id).map_or(f64::NAN, |c| c.umbral_activacion());
+        let marca = if id <= current { "●" } else { "○" };
+        let texto = format!("{} Campo {:2} · {:>6}D · umbral {:.6}", marca, id, system.get_field_dimension(id), umbral);
+        let estilo = if id <= current { Style::default().fg(Color::Green) } else { Style::default().fg(Color::DarkGray) };
+        ListItem::new(texto).style(estilo)
+    }).collect();
+
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(Block::bordered().title(format!(
+                " 🧭 Escalera de campos · actual {} · ↑/↓ mover · Enter transitar · q salir ", current)))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("➜ "),
+        frame.area(),
+        state,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_adjacent_hops() {
+        assert_eq!(current_field(&[1, 2, 5]), 5);
+        assert_eq!(current_field(&[]), 0);
+        assert_eq!(plan(4, 7), vec![5, 6, 7]);
+        assert_eq!(plan(0, 1), vec![1]);
+        assert!(plan(7, 7).is_empty());
+        assert!(plan(9, 3).is_empty());
+        assert_eq!(plan(22, 30), vec![23, 24]);
+    }
+}
@@ -0,0 +1,90 @@
+//! App Tauri - Comandos de Sesión para el Panel Web
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Empaqueta `WEB_FRONTEND/www` con una sesión nativa del núcleo. El panel
+//! detecta `window.__TAURI__` y llama a estos comandos en lugar de evolucionar
+//! su propio Dashboard wasm. La sesión se guarda tras cada cambio en el
+//! directorio de datos de la app y cada campo que se activa genera una
+//! notificación del sistema.
+
+mod session;
+
+use std::sync::{Mutex, MutexGuard};
+
+use algebra_rose_core::prelude::CambioActivacion;
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_notification::NotificationExt;
+
+use session::{Estado, Sesion};
+
+/// Archivo de sesión dentro del directorio de datos de la app
+const ARCHIVO_SESION: &str = "session.json";
+
+struct Compartida(Mutex<Sesion>);
+
+impl Compartida {
+    fn lock(&self) -> MutexGuard<'_, Sesion> {
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Notifica cada activación de campo; un fallo de notificación no detiene la sesión
+fn notificar(app: &AppHandle, cambios: &[CambioActivacion]) {
+    for cambio in cambios.iter().filter(|c| c.activo) {
+        let resultado = app.notification().builder()
+            .title("🌹 Álgebra Rose")
+            .body(format!("Campo {} activado", cambio.field_id))
+            .show();
+        if let Err(e) = resultado {
+            eprintln!("⚠️ Notificación no enviada: {}", e);
+        }
+    }
+}
+
+#[tauri::command]
+fn estado(sesion: State<'_, Compartida>) -> Estado {
+    sesion.lock().estado()
+}
+
+#[tauri::command]
+fn evolucionar(app: AppHandle, sesion: State<'_, Compartida>, pasos: u64) -> Result<Estado, String> {
+    let mut sesion = sesion.lock();
+    let cambios = sesion.evolucionar(pasos)?;
+    sesion.guardar()?;
+    notificar(&app, &cambios);
+    Ok(sesion.estado())
+}
+
+#[tauri::command]
+fn aplicar_amor(sesion: State<'_, Compartida>, delta: f64) -> Result<Estado, String> {
+    let mut sesion = sesion.lock();
+    sesion.aplicar_amor(delta)?;
+    sesion.guardar()?;
+    Ok(sesion.estado())
+}
+
+#[tauri::command]
+fn reiniciar(sesion: State<'_, Compartida>) -> Result<Estado, String> {
+    let mut sesion = sesion.lock();
+    sesion.reiniciar();
+    sesion.guardar()?;
+    Ok(sesion.estado())
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    tauri::Builder::default()
+        .plugin(tauri_plugin_notification::init())
+        .setup(|app| {
+            let ruta = app.path().app_data_dir()?.join(ARCHIVO_SESION);
+            let (sesion, aviso) = Sesion::cargar(ruta);
+            if let Some(aviso) = aviso {
+                eprintln!("⚠️ {}", aviso);
+            }
+            app.manage(Compartida(Mutex::new(sesion)));
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![estado, evolucionar, aplicar_amor, reiniciar])
+        .run(tauri::generate_context!())
+        .expect("error al ejecutar la app Tauri");
+}
@@ -0,0 +1,12 @@
+//! App Tauri - Álgebra Rose de Escritorio y Móvil
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Punto de entrada de escritorio; la lógica vive en la biblioteca para
+//! que los destinos móviles compartan `run`.
+
+// Sin consola adicional en Windows en las compilaciones de release
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+fn main() {
+    algebra_rose_tauri_lib::run()
+}
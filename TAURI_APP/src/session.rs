@@ -0,0 +1,185 @@
+//! Sesión - Estado Nativo Detrás de la App Tauri
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Keygen, campos Fibonacci y operador Â enlazados directamente con el
+//! núcleo. Se persiste como el perfil del CLI: historial keygen e
+//! intensidad de Â, en JSON dentro del directorio de datos de la app.
+
+use std::fs;
+use std::path::PathBuf;
+
+use algebra_rose_core::prelude::{CambioActivacion, FibonacciSystem, KeygenEvolution, LoveOperator, INITIAL_KEYGEN};
+use serde::{Deserialize, Serialize};
+
+/// Máximo de pasos por llamada a `evolucionar`
+pub const MAX_PASOS: u64 = 1_000_000;
+
+/// Lo que se guarda en disco
+#[derive(Serialize, Deserialize)]
+struct Persistido {
+    history: Vec<f64>,
+    love_intensity: f64,
+}
+
+/// Estado que recibe el panel web en cada llamada
+#[derive(Clone, Debug, Serialize)]
+pub struct Estado {
+    pub keygen: f64,
+    pub iteracion: u64,
+    pub progreso: f64,
+    pub campos_activos: Vec<usize>,
+    pub intensidad_amor: f64,
+    pub coherencia_campos: f64,
+}
+
+pub struct Sesion {
+    keygen: KeygenEvolution,
+    campos: FibonacciSystem,
+    amor: LoveOperator,
+    ruta: Option<PathBuf>,
+}
+
+impl Sesion {
+    /// Sesión nueva; si hay `ruta`, se guarda ahí
+    pub fn nueva(ruta: Option<PathBuf>) -> Self {
+        let keygen = KeygenEvolution::new(None);
+        let mut campos = FibonacciSystem::new();
+        campos.update_by_keygen(keygen.get_current_keygen());
+        Sesion { keygen, campos, amor: LoveOperator::new(1.0), ruta }
+    }
+
+    /// Recupera la sesión guardada en `ruta`; si no existe o no es válida empieza de cero
+    ///
+    /// Devuelve también el motivo cuando se descartó un archivo existente.
+    pub fn cargar(ruta: PathBuf) -> (Self, Option<String>) {
+        if !ruta.exists() {
+            return (Sesion::nueva(Some(ruta)), None);
+        }
+        let restaurada = fs::read_to_string(&ruta)
+            .map_err(|e| e.to_string())
+            .and_then(|texto| serde_json::from_str::<Persistido>(&texto).map_err(|e| e.to_string()))
+            .and_then(|p| {
+                if !p.love_intensity.is_finite() || p.love_intensity <= 0.0 {
+                    return Err(format!("intensidad de Â inválida: {}", p.love_intensity));
+                }
+                Ok((KeygenEvolution::from_history(p.history).map_err(|e| e.to_string())?, p.love_intensity))
+            });
+        match restaurada {
+            Ok((keygen, intensidad)) => {
+                let mut campos = FibonacciSystem::new();
+                campos.update_by_keygen(keygen.get_current_keygen());
+                (Sesion { keygen, campos, amor: LoveOperator::new(intensidad), ruta: Some(ruta) }, None)
+            }
+            Err(e) => {
+                let aviso = format!("Sesión en {} descartada: {}", ruta.display(), e);
+                (Sesion::nueva(Some(ruta)), Some(aviso))
+            }
+        }
+    }
+
+    /// Evoluciona `pasos` pasos; devuelve las transiciones de campo
+    pub fn evolucionar(&mut self, pasos: u64) -> Result<Vec<CambioActivacion>, String> {
+        if pasos == 0 || pasos > MAX_PASOS {
+            return Err(format!("pasos debe estar entre 1 y {}", MAX_PASOS));
+        }
+        let mut cambios = Vec::new();
+        for _ in 0..pasos {
+            let keygen = self.keygen.evolve();
+            cambios.extend(self.campos.update_with_events(keygen));
+        }
+        self.amor.update_intensity(self.progreso() * 0.05);
+        Ok(cambios)
+    }
+
+    /// Suma `delta` a la intensidad de Â
+    pub fn aplicar_amor(&mut self, delta: f64) -> Result<f64, String> {
+        if !delta.is_finite() {
+            return Err("delta debe ser finito".to_string());
+        }
+        Ok(self.amor.update_intensity(delta))
+    }
+
+    /// Vuelve al keygen inicial conservando la ruta de guardado
+    pub fn reiniciar(&mut self) {
+        *self = Sesion::nueva(self.ruta.take());
+    }
+
+    fn progreso(&self) -> f64 {
+        ((self.keygen.get_current_keygen() - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN)).clamp(0.0, 1.0)
+    }
+
+    pub fn estado(&self) -> Estado {
+        let intensidad = self.amor.get_intensity();
+        Estado {
+            keygen: self.keygen.get_current_keygen(),
+            iteracion: self.keygen.get_iteration(),
+            progreso: self.progreso(),
+            campos_activos: self.campos.get_campos_activos(),
+            intensidad_amor: intensidad,
+            coherencia_campos: self.campos.get_estadisticas().coherencia_media,
+        }
+    }
+
+    /// Escribe la sesión en su ruta, si tiene
+    pub fn guardar(&self) -> Result<(), String> {
+        let Some(ruta) = &self.ruta else {
+            return Ok(());
+        };
+        if let Some(dir) = ruta.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("No se pudo crear {}: {}", dir.display(), e))?;
+        }
+        let persistido = Persistido { history: self.keygen.get_history().clone(), love_intensity: self.amor.get_intensity() };
+        let texto = serde_json::to_string(&persistido).map_err(|e| e.to_string())?;
+        fs::write(ruta, texto).map_err(|e| format!("No se pudo guardar {}: {}", ruta.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ruta_temporal(nombre: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("algebra_rose_tauri_{}_{}.json", nombre, std::process::id()))
+    }
+
+    #[test]
+    fn test_persistence_roundtrip() {
+        let ruta = ruta_temporal("roundtrip");
+        let mut sesion = Sesion::nueva(Some(ruta.clone()));
+        sesion.evolucionar(50).unwrap();
+        sesion.aplicar_amor(0.5).unwrap();
+        sesion.guardar().unwrap();
+
+        let (restaurada, aviso) = Sesion::cargar(ruta.clone());
+        assert!(aviso.is_none());
+        let (a, b) = (sesion.estado(), restaurada.estado());
+        assert_eq!(a.iteracion, 50);
+        assert_eq!(b.iteracion, a.iteracion);
+        assert_eq!(b.keygen, a.keygen);
+        assert_eq!(b.intensidad_amor, a.intensidad_amor);
+        assert_eq!(b.campos_activos, a.campos_activos);
+        fs::remove_file(ruta).unwrap();
+    }
+
+    #[test]
+    fn test_corrupt_file_starts_fresh() {
+        let ruta = ruta_temporal("corrupta");
+        fs::write(&ruta, "{ no es json").unwrap();
+        let (sesion, aviso) = Sesion::cargar(ruta.clone());
+        assert!(aviso.is_some());
+        assert_eq!(sesion.estado().iteracion, 0);
+        fs::remove_file(ruta).unwrap();
+    }
+
+    #[test]
+    fn test_limits_and_reset() {
+        let mut sesion = Sesion::nueva(None);
+        assert!(sesion.evolucionar(0).is_err());
+        assert!(sesion.evolucionar(MAX_PASOS + 1).is_err());
+        assert!(sesion.aplicar_amor(f64::NAN).is_err());
+        sesion.evolucionar(10).unwrap();
+        sesion.reiniciar();
+        assert_eq!(sesion.estado().iteracion, 0);
+        assert!(sesion.guardar().is_ok());
+    }
+}
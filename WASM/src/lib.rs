@@ -0,0 +1,305 @@
+//! Bindings WASM - Núcleo Álgebra Rose para Aplicaciones Web de Terceros
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Envolturas `#[wasm_bindgen]` del núcleo, independientes del panel de
+//! WEB_FRONTEND: una `Session` guarda una trayectoria keygen, su operador Â
+//! y los 24 campos Fibonacci. Las consultas devuelven objetos JS con
+//! propiedades en camelCase (`SessionStats`, `FieldInfo`, `FieldMetrics`) y
+//! las series numéricas llegan como `Float64Array`/`Uint32Array`. Los
+//! estados de Â viajan como pares (re, im) intercalados, igual que en FFI.
+//! Los errores del núcleo se lanzan como `Error` de JS con su mensaje.
+//! Compilar con `wasm-pack build --target bundler WASM --out-name algebra_rose`.
+//!
+//! ```js
+//! import { Session } from "algebra_rose";
+//! const session = new Session();
+//! const trayectoria = session.evolve(100);      // Float64Array
+//! const { currentValue, activeFields } = session.stats();
+//! session.fields().filter((f) => f.active).map((f) => f.name);
+//! ```
+
+use algebra_rose_core::error::Result;
+use algebra_rose_core::prelude::{
+    AlgebraRoseError, FibonacciField, FibonacciSystem, KeygenEvolution, LoveOperator, MetricasCampo, AR_VERSION, DIM,
+};
+use nalgebra::{Complex, DVector};
+use wasm_bindgen::prelude::*;
+
+/// Convierte un error del núcleo en un `Error` de JS
+fn js(error: AlgebraRoseError) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+/// Rechaza keygens iniciales fuera de (0, 1]
+fn validar_keygen(initial_keygen: Option<f64>) -> Result<Option<f64>> {
+    match initial_keygen {
+        Some(k) if !(k > 0.0 && k <= 1.0) => {
+            Err(AlgebraRoseError::ValueOutOfRange { parametro: "initialKeygen", valor: k, rango: "(0, 1]" })
+        }
+        _ => Ok(initial_keygen),
+    }
+}
+
+/// Estadísticas de una sesión, como `KeygenStats` del núcleo
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SessionStats {
+    #[wasm_bindgen(js_name = currentValue)]
+    pub current_value: f64,
+    /// Número JS en lugar de `BigInt`: exacto hasta 2⁵³ pasos
+    pub iteration: f64,
+    #[wasm_bindgen(js_name = historyLen)]
+    pub history_len: usize,
+    #[wasm_bindgen(js_name = activeFields)]
+    pub active_fields: usize,
+    #[wasm_bindgen(js_name = growthRate)]
+    pub growth_rate: f64,
+    #[wasm_bindgen(js_name = growthAcceleration)]
+    pub growth_acceleration: f64,
+    #[wasm_bindgen(js_name = distanceToMonster)]
+    pub distance_to_monster: f64,
+    /// Intensidad del Â de la sesión, el que cambia `updateLove`
+    #[wasm_bindgen(js_name = loveIntensity)]
+    pub love_intensity: f64,
+}
+
+/// Un campo Fibonacci tal como lo ve JS
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldInfo {
+    pub id: u32,
+    pub name: String,
+    pub dimension: u32,
+    pub active: bool,
+    #[wasm_bindgen(js_name = activationThreshold)]
+    pub activation_threshold: f64,
+}
+
+impl From<&FibonacciField> for FieldInfo {
+    fn from(campo: &FibonacciField) -> Self {
+        FieldInfo {
+            id: campo.id as u32,
+            name: campo.nombre.clone(),
+            dimension: campo.dimension as u32,
+            active: campo.activo,
+            activation_threshold: campo.umbral_activacion,
+        }
+    }
+}
+
+/// Entropía, participación y coherencia del estado base de un campo
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FieldMetrics {
+    #[wasm_bindgen(js_name = fieldId)]
+    pub field_id: u32,
+    pub entropy: f64,
+    pub participation: f64,
+    pub coherence: f64,
+}
+
+impl From<MetricasCampo> for FieldMetrics {
+    fn from(metricas: MetricasCampo) -> Self {
+        FieldMetrics {
+            field_id: metricas.field_id as u32,
+            entropy: metricas.entropia,
+            participation: metricas.participacion,
+            coherence: metricas.coherencia,
+        }
+    }
+}
+
+/// Sesión: trayectoria keygen, operador Â y campos Fibonacci
+#[wasm_bindgen]
+pub struct Session {
+    keygen: KeygenEvolution,
+    amor: LoveOperator,
+    campos: FibonacciSystem,
+}
+
+#[wasm_bindgen]
+impl Session {
+    /// Crea una sesión; sin keygen usa 196883/196884
+    #[wasm_bindgen(constructor)]
+    pub fn new(initial_keygen: Option<f64>) -> std::result::Result<Session, JsError> {
+        Session::crear(initial_keygen).map_err(js)
+    }
+
+    /// Evoluciona `steps` pasos; devuelve el keygen de cada uno
+    pub fn evolve(&mut self, steps: u32) -> Vec<f64> {
+        (0..steps).map(|_| self.step()).collect()
+    }
+
+    /// Evoluciona un paso y actualiza los campos; devuelve el nuevo keygen
+    pub fn step(&mut self) -> f64 {
+        let keygen = self.keygen.evolve();
+        self.campos.update_by_keygen(keygen);
+        keygen
+    }
+
+    pub fn keygen(&self) -> f64 {
+        self.keygen.get_current_keygen()
+    }
+
+    pub fn stats(&self) -> SessionStats {
+        let stats = self.keygen.get_stats();
+        SessionStats {
+            current_value: stats.current_value,
+            iteration: stats.iteration as f64,
+            history_len: stats.history_len,
+            active_fields: stats.active_fields,
+            growth_rate: stats.growth_rate,
+            growth_acceleration: stats.growth_acceleration,
+            distance_to_monster: stats.distance_to_monster,
+            love_intensity: self.amor.get_intensity(),
+        }
+    }
+
+    /// Trayectoria completa del keygen
+    pub fn history(&self) -> Vec<f64> {
+        self.keygen.get_history().to_vec()
+    }
+
+    /// Multiplica la intensidad de Â por φ^delta
+    #[wasm_bindgen(js_name = updateLove)]
+    pub fn update_love(&mut self, delta: f64) -> std::result::Result<(), JsError> {
+        if !delta.is_finite() {
+            return Err(js(AlgebraRoseError::ValueOutOfRange { parametro: "delta", valor: delta, rango: "números finitos" }));
+        }
+        self.amor.update_intensity(delta);
+        Ok(())
+    }
+
+    /// Aplica Â a un estado de `DIM` pares (re, im) intercalados
+    ///
+    /// La primera llamada construye la matriz de Â (444 × 444).
+    #[wasm_bindgen(js_name = applyLove)]
+    pub fn apply_love(&self, state: &[f64]) -> std::result::Result<Vec<f64>, JsError> {
+        self.aplicar_amor(state).map_err(js)
+    }
+
+    /// Los 24 campos, en orden de id
+    pub fn fields(&self) -> Vec<FieldInfo> {
+        self.campos.info_iter().map(FieldInfo::from).collect()
+    }
+
+    /// Un campo por id (1-24), o `undefined`
+    pub fn field(&self, id: u32) -> Option<FieldInfo> {
+        self.campos.campo(id as usize).map(FieldInfo::from)
+    }
+
+    /// Ids de los campos activos
+    #[wasm_bindgen(js_name = activeFields)]
+    pub fn active_fields(&self) -> Vec<u32> {
+        self.campos.get_campos_activos().into_iter().map(|id| id as u32).collect()
+    }
+
+    /// Métricas del estado base de un campo, o `undefined` si no existe
+    #[wasm_bindgen(js_name = fieldMetrics)]
+    pub fn field_metrics(&self, id: u32) -> Option<FieldMetrics> {
+        self.campos.field_metrics(id as usize).map(FieldMetrics::from)
+    }
+
+    /// Estado base normalizado de un campo; con `seed` (número, no `BigInt`) es reproducible
+    #[wasm_bindgen(js_name = fieldState)]
+    pub fn field_state(&self, id: u32, seed: Option<u32>) -> std::result::Result<Vec<f64>, JsError> {
+        self.estado_campo(id, seed).map_err(js)
+    }
+
+    /// Dimensión de los estados que acepta `applyLove`
+    pub fn dim() -> usize {
+        DIM
+    }
+
+    pub fn version() -> String {
+        AR_VERSION.to_string()
+    }
+}
+
+impl Session {
+    fn crear(initial_keygen: Option<f64>) -> Result<Session> {
+        let keygen = KeygenEvolution::new(validar_keygen(initial_keygen)?);
+        let mut campos = FibonacciSystem::new();
+        campos.update_by_keygen(keygen.get_current_keygen());
+        Ok(Session { keygen, amor: LoveOperator::new(1.0), campos })
+    }
+
+    fn aplicar_amor(&self, state: &[f64]) -> Result<Vec<f64>> {
+        if !state.len().is_multiple_of(2) {
+            return Err(AlgebraRoseError::DimensionMismatch { contexto: "Estado intercalado", esperada: 2 * DIM, recibida: state.len() });
+        }
+        let estado = DVector::from_iterator(state.len() / 2, state.chunks_exact(2).map(|par| Complex::new(par[0], par[1])));
+        let transformado = self.amor.apply(&estado)?;
+        Ok(transformado.iter().flat_map(|c| [c.re, c.im]).collect())
+    }
+
+    fn estado_campo(&self, id: u32, seed: Option<u32>) -> Result<Vec<f64>> {
+        let estado = match seed {
+            Some(seed) => self.campos.generate_field_state_seeded(id as usize, seed as u64)?,
+            None => self.campos.generate_field_state(id as usize)?,
+        };
+        Ok(estado.as_slice().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_evolves_and_reports() {
+        let mut session = Session::new(Some(0.3)).unwrap();
+        let activos_iniciales = session.active_fields().len();
+        let trayectoria = session.evolve(2000);
+        assert_eq!(trayectoria.len(), 2000);
+        assert!(trayectoria.windows(2).all(|w| w[1] > w[0]));
+
+        let stats = session.stats();
+        assert_eq!(stats.iteration, 2000.0);
+        assert_eq!(stats.history_len, 2001);
+        assert_eq!(stats.current_value, session.keygen());
+        assert_eq!(session.history().len(), 2001);
+        assert!(session.active_fields().len() > activos_iniciales);
+
+        session.update_love(1.0).unwrap();
+        assert!((session.stats().love_intensity - algebra_rose_core::PHI).abs() < 1e-12);
+        assert!(matches!(validar_keygen(Some(1.5)), Err(AlgebraRoseError::ValueOutOfRange { .. })));
+    }
+
+    #[test]
+    fn test_field_queries() {
+        let session = Session::new(None).unwrap();
+        let campos = session.fields();
+        assert_eq!(campos.len(), 24);
+        assert_eq!((campos[0].dimension, campos[23].dimension), (3, 196418));
+        let activos: Vec<u32> = campos.iter().filter(|c| c.active).map(|c| c.id).collect();
+        assert_eq!(activos, session.active_fields());
+
+        assert_eq!(session.field(5), Some(campos[4].clone()));
+        assert_eq!(session.field(25), None);
+        let metricas = session.field_metrics(5).unwrap();
+        assert_eq!(metricas.field_id, 5);
+        assert!(metricas.coherence > 0.0 && metricas.coherence <= 1.0);
+
+        let estado = session.estado_campo(5, Some(7)).unwrap();
+        assert_eq!(estado.len(), campos[4].dimension as usize);
+        assert_eq!(estado, session.estado_campo(5, Some(7)).unwrap());
+        assert!(matches!(session.estado_campo(25, None), Err(AlgebraRoseError::FieldOutOfRange { .. })));
+    }
+
+    #[test]
+    fn test_apply_love_interleaved() {
+        let session = Session::new(Some(0.5)).unwrap();
+        let mut estado = vec![0.0; 2 * Session::dim()];
+        estado[0] = 1.0;
+        let salida = session.aplicar_amor(&estado).unwrap();
+
+        // Â·e₀ es la primera columna de Â
+        let columna = session.amor.get_transformation().column(0).into_owned();
+        for (k, c) in columna.iter().enumerate() {
+            assert_eq!((salida[2 * k], salida[2 * k + 1]), (c.re, c.im));
+        }
+        assert!(matches!(session.aplicar_amor(&estado[..7]), Err(AlgebraRoseError::DimensionMismatch { .. })));
+        assert!(matches!(session.aplicar_amor(&estado[..6]), Err(AlgebraRoseError::DimensionMismatch { .. })));
+    }
+}
@@ -0,0 +1,115 @@
+//! Panel Web - Núcleo Álgebra Rose Compilado a wasm32
+//! Sistema: v27.1024D-S36 | Certificación: 196885
+//!
+//! Expone a JavaScript un `Dashboard` que evoluciona el keygen y consulta
+//! los 24 campos; el dibujo en canvas vive en `www/dashboard.js`.
+//! Compilar con `wasm-pack build --target web WEB_FRONTEND --out-dir www/pkg`.
+
+use algebra_rose_core::phi_constants::golden_spiral_points;
+use algebra_rose_core::prelude::{FibonacciSystem, KeygenEvolution, AR_VERSION, CERTIFICATION, INITIAL_KEYGEN};
+use wasm_bindgen::prelude::*;
+
+/// Número de campos Fibonacci
+const CAMPOS: usize = 24;
+
+/// Estado del panel: trayectoria keygen y activación de campos
+#[wasm_bindgen]
+pub struct Dashboard {
+    keygen: KeygenEvolution,
+    campos: FibonacciSystem,
+    /// (iteración, keygen) de cada llamada a `step`
+    muestras: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl Dashboard {
+    /// Crea el panel; sin keygen usa 196883/196884
+    #[wasm_bindgen(constructor)]
+    pub fn new(initial_keygen: Option<f64>) -> Result<Dashboard, JsError> {
+        if let Some(k) = initial_keygen {
+            if !(k > 0.0 && k <= 1.0) {
+                return Err(JsError::new(&format!("keygen debe estar en (0, 1], recibido {}", k)));
+            }
+        }
+        let keygen = KeygenEvolution::new(initial_keygen);
+        let mut campos = FibonacciSystem::new();
+        campos.update_by_keygen(keygen.get_current_keygen());
+        let muestras = vec![0.0, keygen.get_current_keygen()];
+        Ok(Dashboard { keygen, campos, muestras })
+    }
+
+    /// Evoluciona `steps` pasos; devuelve los campos que cambiaron de estado
+    pub fn step(&mut self, steps: u32) -> Vec<u32> {
+        let mut cambios = Vec::new();
+        for _ in 0..steps {
+            let keygen = self.keygen.evolve();
+            cambios.extend(self.campos.update_with_events(keygen).into_iter().map(|c| c.field_id as u32));
+        }
+        if steps > 0 {
+            self.muestras.push(self.keygen.get_iteration() as f64);
+            self.muestras.push(self.keygen.get_current_keygen());
+        }
+        cambios
+    }
+
+    pub fn keygen(&self) -> f64 {
+        self.keygen.get_current_keygen()
+    }
+
+    pub fn iteration(&self) -> f64 {
+        self.keygen.get_iteration() as f64
+    }
+
+    /// Progreso entre el keygen inicial y 1, en [0, 1]
+    pub fn progress(&self) -> f64 {
+        ((self.keygen.get_current_keygen() - INITIAL_KEYGEN) / (1.0 - INITIAL_KEYGEN)).clamp(0.0, 1.0)
+    }
+
+    /// Pares (iteración, keygen) aplanados, listos para un `Float64Array`
+    pub fn samples(&self) -> Vec<f64> {
+        self.muestras.clone()
+    }
+
+    /// Ids de los campos activos (1-24)
+    pub fn active_fields(&self) -> Vec<u32> {
+        self.campos.get_campos_activos().into_iter().map(|id| id as u32).collect()
+    }
+
+    /// Dimensiones de los 24 campos, en orden
+    pub fn field_dimensions(&self) -> Vec<u32> {
+        (1..=CAMPOS).map(|id| self.campos.get_field_dimension(id) as u32).collect()
+    }
+
+    /// Posiciones (x, y) aplanadas de los campos sobre la espiral áurea de radio 1
+    pub fn spiral_layout() -> Vec<f64> {
+        golden_spiral_points(CAMPOS, 1.0).into_iter().flatten().collect()
+    }
+
+    pub fn version() -> String {
+        format!("{} · Certificación {}", AR_VERSION, CERTIFICATION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dashboard_steps() {
+        let mut dashboard = Dashboard::new(Some(0.3)).unwrap();
+        let antes = dashboard.active_fields().len();
+        let cambios = dashboard.step(2000);
+        assert!(!cambios.is_empty());
+        assert!(dashboard.active_fields().len() > antes);
+        assert_eq!(dashboard.samples().len(), 4);
+        assert_eq!(dashboard.iteration(), 2000.0);
+        assert!(dashboard.progress() >= 0.0 && dashboard.progress() <= 1.0);
+    }
+
+    #[test]
+    fn test_layout_and_dimensions() {
+        assert_eq!(Dashboard::spiral_layout().len(), 2 * CAMPOS);
+        let dims = Dashboard::new(None).unwrap().field_dimensions();
+        assert_eq!((dims[0], dims[CAMPOS - 1]), (3, 196418));
+    }
+}
@@ -0,0 +1,152 @@
+// Panel Web - dibujo en canvas del Dashboard wasm
+// Sistema: v27.1024D-S36 | Certificación: 196885
+//
+// Con ?ws=ws://host:puerto/ws el panel no evoluciona localmente: se
+// suscribe al WebSocket de `serve` y dibuja la sesión compartida. Dentro
+// de la app Tauri (TAURI_APP) la sesión es nativa y persistente.
+
+import init, { Dashboard } from "./pkg/algebra_rose_web.js";
+
+await init();
+
+const layout = Dashboard.spiral_layout();
+let dashboard = new Dashboard();
+let dimensions = dashboard.field_dimensions();
+let running = false;
+
+const $ = (id) => document.getElementById(id);
+$("version").textContent = Dashboard.version();
+
+// Sesión remota: mismas consultas que Dashboard, alimentadas por tramas
+function remoteSession(url) {
+  const samples = [];
+  const state = { keygen: 0, iteration: 0, active: [], coherence: null, connected: false };
+  const initial = dashboard.keygen();
+  const socket = new WebSocket(url);
+  socket.onopen = () => { state.connected = true; };
+  socket.onclose = () => { state.connected = false; };
+  socket.onmessage = (message) => {
+    const frame = JSON.parse(message.data);
+    if (frame.tipo === "evolucion") {
+      Object.assign(state, { keygen: frame.keygen, iteration: frame.iteracion, active: frame.campos_activos });
+      samples.push(frame.iteracion, frame.keygen);
+    } else if (frame.tipo === "coherencia") {
+      state.coherence = frame;
+    }
+  };
+  return {
+    state,
+    samples: () => samples,
+    keygen: () => state.keygen,
+    iteration: () => state.iteration,
+    progress: () => Math.min(1, Math.max(0, (state.keygen - initial) / (1 - initial))),
+    active_fields: () => state.active,
+  };
+}
+
+// Sesión nativa de la app Tauri: el núcleo corre en Rust y sobrevive a reinicios
+function nativeSession(invoke) {
+  const samples = [];
+  let state = null;
+  let pending = false;
+  const call = (command, args) => {
+    pending = true;
+    return invoke(command, args)
+      .then((estado) => { state = estado; samples.push(estado.iteracion, estado.keygen); })
+      .catch((e) => { $("estado").title = String(e); })
+      .finally(() => { pending = false; });
+  };
+  call("estado");
+  return {
+    // Un paso por vez: si el anterior no ha vuelto, se salta el fotograma
+    step: (steps) => { if (!pending) call("evolucionar", { pasos: steps }); },
+    reset: () => { samples.length = 0; call("reiniciar"); },
+    samples: () => samples,
+    keygen: () => state?.keygen ?? 0,
+    iteration: () => state?.iteracion ?? 0,
+    progress: () => state?.progreso ?? 0,
+    active_fields: () => state?.campos_activos ?? [],
+  };
+}
+
+const wsUrl = new URLSearchParams(location.search).get("ws");
+const remote = wsUrl ? remoteSession(wsUrl) : null;
+const native = !remote && window.__TAURI__ ? nativeSession(window.__TAURI__.core.invoke) : null;
+const source = () => remote ?? native ?? dashboard;
+if (remote) {
+  for (const id of ["marcha", "reiniciar", "pasos"]) $(id).disabled = true;
+}
+
+function drawKeygen(ctx) {
+  const { width, height } = ctx.canvas;
+  const samples = source().samples();
+  ctx.clearRect(0, 0, width, height);
+  if (samples.length < 4) return;
+
+  let [minX, maxX, minY, maxY] = [Infinity, -Infinity, Infinity, -Infinity];
+  for (let i = 0; i < samples.length; i += 2) {
+    minX = Math.min(minX, samples[i]); maxX = Math.max(maxX, samples[i]);
+    minY = Math.min(minY, samples[i + 1]); maxY = Math.max(maxY, samples[i + 1]);
+  }
+  const sx = (x) => 40 + (x - minX) / (maxX - minX || 1) * (width - 50);
+  const sy = (y) => height - 20 - (y - minY) / (maxY - minY || 1) * (height - 40);
+
+  ctx.strokeStyle = "#7CFC9A";
+  ctx.beginPath();
+  for (let i = 0; i < samples.length; i += 2) {
+    const [x, y] = [sx(samples[i]), sy(samples[i + 1])];
+    i === 0 ? ctx.moveTo(x, y) : ctx.lineTo(x, y);
+  }
+  ctx.stroke();
+  ctx.fillStyle = "#aaa";
+  ctx.fillText(maxY.toFixed(10), 4, 14);
+  ctx.fillText(minY.toFixed(10), 4, height - 24);
+  ctx.fillText(`it ${maxX}`, width - 80, height - 6);
+}
+
+function drawFields(ctx) {
+  const { width, height } = ctx.canvas;
+  const active = new Set(source().active_fields());
+  const scale = Math.min(width, height) * 0.45;
+  ctx.clearRect(0, 0, width, height);
+  for (let i = 0; i < dimensions.length; i++) {
+    const x = width / 2 + layout[2 * i] * scale;
+    const y = height / 2 - layout[2 * i + 1] * scale;
+    ctx.fillStyle = active.has(i + 1) ? "#50DC78" : "#444";
+    ctx.beginPath();
+    ctx.arc(x, y, 3 + Math.log(dimensions[i]) * 1.2, 0, 2 * Math.PI);
+    ctx.fill();
+  }
+}
+
+function frame() {
+  if (running) {
+    (native ?? dashboard).step(Math.max(1, Number($("pasos").value) | 0));
+  }
+  const s = source();
+  let text =
+    `Keygen ${s.keygen().toFixed(10)} · iteración ${s.iteration()} · ` +
+    `${(s.progress() * 100).toFixed(4)}% · campos activos ${s.active_fields().length}/24`;
+  if (remote) {
+    const c = remote.state.coherence;
+    text = `${remote.state.connected ? "🟢" : "🔴"} ${wsUrl} · ${text}` +
+      (c ? ` · coherencia ${(c.coherencia * 100).toFixed(1)}% · Â ${c.intensidad_amor.toFixed(6)}` : "");
+  }
+  $("estado").textContent = text;
+  drawKeygen($("keygen").getContext("2d"));
+  drawFields($("campos").getContext("2d"));
+  requestAnimationFrame(frame);
+}
+
+$("marcha").onclick = () => {
+  running = !running;
+  $("marcha").textContent = running ? "⏸ Pausar" : "▶ Evolucionar";
+};
+$("reiniciar").onclick = () => {
+  if (native) return native.reset();
+  dashboard.free();
+  dashboard = new Dashboard();
+  dimensions = dashboard.field_dimensions();
+};
+
+requestAnimationFrame(frame);